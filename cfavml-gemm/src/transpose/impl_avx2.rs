@@ -191,10 +191,15 @@ const fn _MM_SHUFFLE(z: u32, y: u32, x: u32, w: u32) -> i32 {
 #[cfg(all(test, not(miri)))] // This is just very expensive to do
 mod tests {
     use super::*;
-    use crate::transpose::test_suite::run_test_suites_f32;
+    use crate::transpose::test_suite::{run_test_suites_f32, run_test_suites_f64};
 
     #[test]
     fn test_avx2_f32() {
         run_test_suites_f32::<Avx2>();
     }
+
+    #[test]
+    fn test_avx2_f64() {
+        run_test_suites_f64::<Avx2>();
+    }
 }
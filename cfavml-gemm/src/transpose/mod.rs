@@ -280,4 +280,67 @@ mod test_suite {
         unsafe { generic_transpose::<f32, R>(1, 2, &input_matrix, &mut result) };
         assert_eq!(&result, expected_matrix.as_slice());
     }
+
+    pub fn run_test_suites_f64<R>()
+    where
+        R: TransposeMatrix<f64> + SimdRegister<f64>,
+    {
+        println!("Running 4x4 matrix");
+        let input_matrix = [
+            0.0, 0.1, 0.2, 0.3, 1.0, 1.1, 1.2, 1.3, 2.0, 2.1, 2.2, 2.3, 3.0, 3.1, 3.2,
+            3.3,
+        ];
+        let expected_matrix = [
+            0.0, 1.0, 2.0, 3.0, 0.1, 1.1, 2.1, 3.1, 0.2, 1.2, 2.2, 3.2, 0.3, 1.3, 2.3,
+            3.3,
+        ];
+        let mut result = [999.0; 16];
+        unsafe { generic_transpose::<f64, R>(4, 4, &input_matrix, &mut result) };
+        assert_eq!(result, expected_matrix);
+
+        println!("Running 8x8 matrix");
+        let (input_matrix, _) = crate::test_utils::get_sample_vectors(8 * 8);
+        let expected_matrix = crate::test_utils::basic_transpose(8, 8, &input_matrix);
+        let mut result = [999.0; 8 * 8];
+        unsafe { generic_transpose::<f64, R>(8, 8, &input_matrix, &mut result) };
+        assert_eq!(&result, expected_matrix.as_slice());
+
+        println!("Running 7x5 matrix");
+        let (input_matrix, _) = crate::test_utils::get_sample_vectors(7 * 5);
+        let expected_matrix = crate::test_utils::basic_transpose(7, 5, &input_matrix);
+        let mut result = [999.0; 7 * 5];
+        unsafe { generic_transpose::<f64, R>(7, 5, &input_matrix, &mut result) };
+        assert_eq!(&result, expected_matrix.as_slice());
+
+        println!("Running 13x19 matrix");
+        let (input_matrix, _) = crate::test_utils::get_sample_vectors(13 * 19);
+        let expected_matrix = crate::test_utils::basic_transpose(13, 19, &input_matrix);
+        let mut result = [999.0; 13 * 19];
+        unsafe { generic_transpose::<f64, R>(13, 19, &input_matrix, &mut result) };
+        assert_eq!(&result, expected_matrix.as_slice());
+
+        println!("Running 639x63 matrix");
+        let (input_matrix, _) = crate::test_utils::get_sample_vectors(639 * 63);
+        let expected_matrix = crate::test_utils::basic_transpose(639, 63, &input_matrix);
+        let mut result = vec![999.0; 639 * 63];
+        unsafe { generic_transpose::<f64, R>(639, 63, &input_matrix, &mut result) };
+        assert_eq!(result, expected_matrix);
+    }
+
+    #[test]
+    #[should_panic(expected = "Output buffer does not match input data")]
+    fn test_transpose_matrix_output_size_missmatch() {
+        let input_matrix = [0.0f64; 7 * 5];
+        let mut result = [0.0f64; 7 * 4];
+        transpose_matrix(7, 5, &input_matrix, &mut result);
+    }
+
+    #[test]
+    fn test_transpose_matrix_dispatch_f64() {
+        let (input_matrix, _) = crate::test_utils::get_sample_vectors::<f64>(7 * 5);
+        let expected_matrix = crate::test_utils::basic_transpose(7, 5, &input_matrix);
+        let mut result = [999.0; 7 * 5];
+        transpose_matrix(7, 5, &input_matrix, &mut result);
+        assert_eq!(&result, expected_matrix.as_slice());
+    }
 }
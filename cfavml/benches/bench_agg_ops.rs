@@ -56,3 +56,67 @@ mod sum {
         bencher.bench_local(|| cfavml::sum(black_box(&l1)));
     }
 }
+
+#[divan::bench_group(sample_count = 500, sample_size = 5000, threads = false)]
+mod sum_short {
+    use cfavml::safe_trait_agg_ops::AggOps;
+    use rand::distributions::{Distribution, Standard};
+
+    use super::*;
+
+    /// Lengths around `cfavml::danger::SUM_SCALAR_THRESHOLD` to check the crossover
+    /// between the scalar loop and SIMD registers.
+    const LENGTHS: &[usize] = &[4, 8, 12, 16, 24, 32, 48, 64, 128];
+
+    #[divan::bench(types = [f32, f64], args = LENGTHS)]
+    fn scalar<T>(bencher: Bencher, len: usize)
+    where
+        Standard: Distribution<T>,
+        T: Copy + num_traits::identities::Zero,
+    {
+        let (l1, _) = utils::get_sample_vectors::<T>(len);
+
+        bencher
+            .counter(ItemsCount::new(len))
+            .bench_local(|| black_box(&l1).iter().fold(T::zero(), |a, b| a + *b));
+    }
+
+    #[divan::bench(types = [f32, f64], args = LENGTHS)]
+    fn cfavml<T>(bencher: Bencher, len: usize)
+    where
+        Standard: Distribution<T>,
+        T: AggOps,
+    {
+        let (l1, _) = utils::get_sample_vectors::<T>(len);
+
+        bencher
+            .counter(ItemsCount::new(len))
+            .bench_local(|| cfavml::sum(black_box(&l1)));
+    }
+}
+
+#[divan::bench_group(sample_count = 100, sample_size = 10, threads = false)]
+mod sum_hint {
+    use cfavml::mem_loader::MemoryHint;
+
+    use super::*;
+
+    /// 32MB of `f64`s, large enough to be streamed from memory rather than cache.
+    const LARGE_DIMS: usize = 4 << 20;
+    /// The alignment of a 2MB huge page.
+    const HUGE_PAGE_SIZE: usize = 2 << 20;
+
+    const HINTS: &[MemoryHint] = &[MemoryHint::Default, MemoryHint::HugePage];
+
+    #[divan::bench(args = HINTS)]
+    fn cfavml(bencher: Bencher, hint: MemoryHint) {
+        let padding = HUGE_PAGE_SIZE / std::mem::size_of::<f64>();
+        let (buffer, _) = utils::get_sample_vectors::<f64>(LARGE_DIMS + padding);
+        let offset = buffer.as_ptr().align_offset(HUGE_PAGE_SIZE);
+        let l1 = &buffer[offset..][..LARGE_DIMS];
+
+        bencher
+            .counter(ItemsCount::new(LARGE_DIMS))
+            .bench_local(|| cfavml::sum_horizontal_hint(black_box(l1), hint));
+    }
+}
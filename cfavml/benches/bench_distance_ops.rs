@@ -10,6 +10,9 @@ use ndarray::ArrayView1;
 mod utils;
 
 const DIMS: usize = 1536;
+const SMALL_DIMS: usize = 64;
+const PAIRWISE_QUERIES: usize = 8;
+const PAIRWISE_DOCS: usize = 64;
 
 fn main() {
     divan::main();
@@ -69,6 +72,55 @@ mod dot_product {
 
         bencher.bench_local(|| cfavml::dot(black_box(&l1), black_box(&l2)));
     }
+
+    #[divan::bench(types = [f32, f64])]
+    fn cfavml_cached<T>(bencher: Bencher)
+    where
+        Standard: Distribution<T>,
+        T: DistanceOps,
+    {
+        let (l1, l2) = utils::get_sample_vectors::<T>(DIMS);
+        let mut dispatcher = cfavml::dispatch::CachedDot::new();
+
+        bencher.bench_local(|| dispatcher.dot(black_box(&l1), black_box(&l2)));
+    }
+}
+
+// Small dimensions where the per-call dispatch overhead is a noticeable part of the cost.
+#[divan::bench_group(
+    sample_count = 2500,
+    sample_size = 5000,
+    threads = false,
+    counters = [ItemsCount::new(SMALL_DIMS)],
+)]
+mod dot_product_small {
+    use cfavml::safe_trait_distance_ops::DistanceOps;
+    use rand::distributions::{Distribution, Standard};
+
+    use super::*;
+
+    #[divan::bench(types = [f32, f64])]
+    fn cfavml<T>(bencher: Bencher)
+    where
+        Standard: Distribution<T>,
+        T: DistanceOps,
+    {
+        let (l1, l2) = utils::get_sample_vectors::<T>(SMALL_DIMS);
+
+        bencher.bench_local(|| cfavml::dot(black_box(&l1), black_box(&l2)));
+    }
+
+    #[divan::bench(types = [f32, f64])]
+    fn cfavml_cached<T>(bencher: Bencher)
+    where
+        Standard: Distribution<T>,
+        T: DistanceOps,
+    {
+        let (l1, l2) = utils::get_sample_vectors::<T>(SMALL_DIMS);
+        let mut dispatcher = cfavml::dispatch::CachedDot::new();
+
+        bencher.bench_local(|| dispatcher.dot(black_box(&l1), black_box(&l2)));
+    }
 }
 
 #[divan::bench_group(
@@ -196,3 +248,113 @@ mod euclidean {
             .bench_local(|| cfavml::squared_euclidean(black_box(&l1), black_box(&l2)));
     }
 }
+
+// Compares widening every `f32` load to `f64` against accumulating in `f32` with
+// compensation, the relative accuracy of both is asserted in the danger test suite.
+#[divan::bench_group(
+    sample_count = 2500,
+    sample_size = 5000,
+    threads = false,
+    counters = [ItemsCount::new(DIMS)],
+)]
+mod dot_f32_widen {
+    use cfavml::danger::export_distance_ops::*;
+
+    use super::*;
+
+    #[divan::bench]
+    fn cfavml_widen(bencher: Bencher) {
+        let (l1, l2) = utils::get_sample_vectors::<f32>(DIMS);
+
+        #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+        if is_x86_feature_detected!("avx2") && is_x86_feature_detected!("fma") {
+            bencher.bench_local(|| unsafe {
+                f32_avx2fma_dot_widen(black_box(&l1), black_box(&l2))
+            });
+            return;
+        }
+
+        bencher.bench_local(|| unsafe {
+            f32_fallback_dot_widen(black_box(&l1), black_box(&l2))
+        });
+    }
+
+    #[divan::bench]
+    fn cfavml_compensated(bencher: Bencher) {
+        let (l1, l2) = utils::get_sample_vectors::<f32>(DIMS);
+
+        #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+        if is_x86_feature_detected!("avx2") && is_x86_feature_detected!("fma") {
+            bencher.bench_local(|| unsafe {
+                f32_avx2fma_dot_compensated(black_box(&l1), black_box(&l2))
+            });
+            return;
+        }
+
+        bencher.bench_local(|| unsafe {
+            f32_fallback_dot_compensated(black_box(&l1), black_box(&l2))
+        });
+    }
+}
+
+// The pairwise dot of pre-normalized query and doc matrices, comparing the 8 row
+// micro-kernel against a dot product call per pair.
+#[divan::bench_group(
+    sample_count = 500,
+    sample_size = 100,
+    threads = false,
+    counters = [ItemsCount::new(PAIRWISE_QUERIES * PAIRWISE_DOCS * DIMS)],
+)]
+mod pairwise_dot {
+    use cfavml::danger::export_distance_ops::*;
+
+    use super::*;
+
+    #[divan::bench]
+    fn cfavml_per_pair(bencher: Bencher) {
+        let (queries, _) = utils::get_sample_vectors::<f64>(PAIRWISE_QUERIES * DIMS);
+        let (docs, _) = utils::get_sample_vectors::<f64>(PAIRWISE_DOCS * DIMS);
+        let mut out = vec![0.0; PAIRWISE_QUERIES * PAIRWISE_DOCS];
+
+        bencher.bench_local(|| {
+            let docs = black_box(&docs);
+            for (query, out) in black_box(&queries)
+                .chunks(DIMS)
+                .zip(out.chunks_mut(PAIRWISE_DOCS))
+            {
+                for (doc, out) in docs.chunks(DIMS).zip(out.iter_mut()) {
+                    *out = cfavml::dot(query, doc);
+                }
+            }
+        });
+    }
+
+    #[divan::bench]
+    fn cfavml_pairwise(bencher: Bencher) {
+        let (queries, _) = utils::get_sample_vectors::<f64>(PAIRWISE_QUERIES * DIMS);
+        let (docs, _) = utils::get_sample_vectors::<f64>(PAIRWISE_DOCS * DIMS);
+        let mut out = vec![0.0; PAIRWISE_QUERIES * PAIRWISE_DOCS];
+
+        #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+        if is_x86_feature_detected!("avx2") && is_x86_feature_detected!("fma") {
+            bencher.bench_local(|| unsafe {
+                generic_avx2fma_pairwise_dot(
+                    black_box(&queries),
+                    black_box(&docs),
+                    DIMS,
+                    &mut out,
+                )
+            });
+            return;
+        }
+
+        bencher.bench_local(|| unsafe {
+            generic_fallback_pairwise_dot(
+                black_box(&queries),
+                black_box(&docs),
+                DIMS,
+                &mut out,
+            )
+        });
+    }
+}
@@ -0,0 +1,190 @@
+//! Head to head comparisons of the core reductions against other linear algebra libraries
+//! across a range of vector sizes.
+#[cfg(unix)]
+extern crate blas_src;
+
+use std::hint::black_box;
+
+use divan::counter::ItemsCount;
+use divan::Bencher;
+use nalgebra::DVectorView;
+use ndarray::ArrayView1;
+
+mod utils;
+
+const DIMS: &[usize] = &[64, 384, 1536, 8192, 65536];
+
+fn main() {
+    divan::main();
+}
+
+#[divan::bench_group(sample_count = 500, sample_size = 1000, threads = false)]
+mod sum {
+    use cfavml::safe_trait_agg_ops::AggOps;
+    use ndarray::{Data, ViewRepr};
+    use rand::distributions::{Distribution, Standard};
+
+    use super::*;
+
+    #[divan::bench(types = [f32, f64], args = DIMS)]
+    fn ndarray<T>(bencher: Bencher, dims: usize)
+    where
+        T: Copy + num_traits::identities::Zero,
+        Standard: Distribution<T>,
+        for<'a> ViewRepr<&'a mut T>: Data<Elem = T>,
+    {
+        let (l1, _) = utils::get_sample_vectors::<T>(dims);
+        let l1_view = ArrayView1::from_shape((l1.len(),), &l1).unwrap();
+
+        bencher.counter(ItemsCount::new(dims)).bench_local(|| {
+            let l1_view = black_box(l1_view);
+
+            l1_view.sum()
+        });
+    }
+
+    #[divan::bench(types = [f32, f64], args = DIMS)]
+    fn nalgebra<T>(bencher: Bencher, dims: usize)
+    where
+        T: nalgebra::RealField + Copy,
+        Standard: Distribution<T>,
+    {
+        let (l1, _) = utils::get_sample_vectors::<T>(dims);
+        let l1_view = DVectorView::from_slice(&l1, l1.len());
+
+        bencher.counter(ItemsCount::new(dims)).bench_local(|| {
+            let l1_view = black_box(&l1_view);
+
+            l1_view.sum()
+        });
+    }
+
+    #[divan::bench(types = [f32, f64], args = DIMS)]
+    fn cfavml<T>(bencher: Bencher, dims: usize)
+    where
+        Standard: Distribution<T>,
+        T: AggOps,
+    {
+        let (l1, _) = utils::get_sample_vectors::<T>(dims);
+
+        bencher
+            .counter(ItemsCount::new(dims))
+            .bench_local(|| cfavml::sum(black_box(&l1)));
+    }
+}
+
+#[divan::bench_group(sample_count = 500, sample_size = 1000, threads = false)]
+mod dot_product {
+    use cfavml::safe_trait_distance_ops::DistanceOps;
+    use ndarray::linalg::Dot;
+    use ndarray::{Data, ViewRepr};
+    use rand::distributions::{Distribution, Standard};
+
+    use super::*;
+
+    #[divan::bench(types = [f32, f64], args = DIMS)]
+    fn ndarray<T>(bencher: Bencher, dims: usize)
+    where
+        Standard: Distribution<T>,
+        for<'a> ViewRepr<&'a mut T>: Data<Elem = T>,
+        for<'a> ArrayView1<'a, T>: Dot<ArrayView1<'a, T>>,
+    {
+        let (l1, l2) = utils::get_sample_vectors::<T>(dims);
+        let l1_view = ArrayView1::from_shape((l1.len(),), &l1).unwrap();
+        let l2_view = ArrayView1::from_shape((l2.len(),), &l2).unwrap();
+
+        bencher.counter(ItemsCount::new(dims)).bench_local(|| {
+            let l1_view = black_box(l1_view);
+            let l2_view = black_box(l2_view);
+
+            l1_view.dot(&l2_view)
+        });
+    }
+
+    #[divan::bench(types = [f32, f64], args = DIMS)]
+    fn nalgebra<T>(bencher: Bencher, dims: usize)
+    where
+        T: nalgebra::RealField + Copy,
+        Standard: Distribution<T>,
+    {
+        let (l1, l2) = utils::get_sample_vectors::<T>(dims);
+        let l1_view = DVectorView::from_slice(&l1, l1.len());
+        let l2_view = DVectorView::from_slice(&l2, l2.len());
+
+        bencher.counter(ItemsCount::new(dims)).bench_local(|| {
+            let l1_view = black_box(&l1_view);
+            let l2_view = black_box(&l2_view);
+
+            l1_view.dot(l2_view)
+        });
+    }
+
+    #[divan::bench(types = [f32, f64], args = DIMS)]
+    fn cfavml<T>(bencher: Bencher, dims: usize)
+    where
+        Standard: Distribution<T>,
+        T: DistanceOps,
+    {
+        let (l1, l2) = utils::get_sample_vectors::<T>(dims);
+
+        bencher
+            .counter(ItemsCount::new(dims))
+            .bench_local(|| cfavml::dot(black_box(&l1), black_box(&l2)));
+    }
+}
+
+#[divan::bench_group(sample_count = 500, sample_size = 1000, threads = false)]
+mod squared_norm {
+    use cfavml::safe_trait_distance_ops::DistanceOps;
+    use ndarray::linalg::Dot;
+    use ndarray::{Data, ViewRepr};
+    use rand::distributions::{Distribution, Standard};
+
+    use super::*;
+
+    #[divan::bench(types = [f32, f64], args = DIMS)]
+    fn ndarray<T>(bencher: Bencher, dims: usize)
+    where
+        Standard: Distribution<T>,
+        for<'a> ViewRepr<&'a mut T>: Data<Elem = T>,
+        for<'a> ArrayView1<'a, T>: Dot<ArrayView1<'a, T>>,
+    {
+        let (l1, _) = utils::get_sample_vectors::<T>(dims);
+        let l1_view = ArrayView1::from_shape((l1.len(),), &l1).unwrap();
+
+        bencher.counter(ItemsCount::new(dims)).bench_local(|| {
+            let l1_view = black_box(l1_view);
+
+            l1_view.dot(&l1_view)
+        });
+    }
+
+    #[divan::bench(types = [f32, f64], args = DIMS)]
+    fn nalgebra<T>(bencher: Bencher, dims: usize)
+    where
+        T: nalgebra::RealField + Copy,
+        Standard: Distribution<T>,
+    {
+        let (l1, _) = utils::get_sample_vectors::<T>(dims);
+        let l1_view = DVectorView::from_slice(&l1, l1.len());
+
+        bencher.counter(ItemsCount::new(dims)).bench_local(|| {
+            let l1_view = black_box(&l1_view);
+
+            l1_view.norm_squared()
+        });
+    }
+
+    #[divan::bench(types = [f32, f64], args = DIMS)]
+    fn cfavml<T>(bencher: Bencher, dims: usize)
+    where
+        Standard: Distribution<T>,
+        T: DistanceOps,
+    {
+        let (l1, _) = utils::get_sample_vectors::<T>(dims);
+
+        bencher
+            .counter(ItemsCount::new(dims))
+            .bench_local(|| cfavml::squared_norm(black_box(&l1)));
+    }
+}
@@ -13,6 +13,9 @@ pub trait WriteOnlyBuffer: sealed::Sealed {
     type Item;
 
     /// Returns the length of the buffer _including_ any uninitialized memory.
+    ///
+    /// This is never more than `isize::MAX` bytes worth of elements, which is
+    /// guaranteed by the slice implementations.
     fn raw_buffer_len(&self) -> usize;
 
     /// Returns a mutable pointer to the buffer that is only able to be written to.
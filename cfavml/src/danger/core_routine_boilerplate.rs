@@ -1,19 +1,20 @@
 use crate::buffer::WriteOnlyBuffer;
 use crate::danger::{DenseLane, SimdRegister};
 use crate::math::Math;
-use crate::mem_loader::{IntoMemLoader, MemLoader};
+use crate::mem_loader::{IntoMemLoader, MemLoader, SCRATCH_SPACE_SIZE};
+
+/// A kernel combining two values, registers or dense lanes into one, such as
+/// [SimdRegister::add_dense] or [Math::add].
+pub(crate) type BinaryKernel<V> = unsafe fn(V, V) -> V;
 
 #[inline(always)]
 pub(crate) unsafe fn apply_vertical_kernel<T, R, M, B1, B2, B3>(
     a: B1,
     b: B2,
     mut result: &mut [B3],
-    dense_lane_kernel: unsafe fn(
-        DenseLane<R::Register>,
-        DenseLane<R::Register>,
-    ) -> DenseLane<R::Register>,
-    reg_kernel: unsafe fn(R::Register, R::Register) -> R::Register,
-    single_kernel: unsafe fn(T, T) -> T,
+    dense_lane_kernel: BinaryKernel<DenseLane<R::Register>>,
+    reg_kernel: BinaryKernel<R::Register>,
+    single_kernel: BinaryKernel<T>,
 ) where
     T: Copy,
     R: SimdRegister<T>,
@@ -60,3 +61,196 @@ pub(crate) unsafe fn apply_vertical_kernel<T, R, M, B1, B2, B3>(
         i += 1;
     }
 }
+
+#[inline(always)]
+/// Reduces each column of the row-major `matrix` with a kernel, writing the result of
+/// each column to the matching element of `result`.
+///
+/// Each column starts from its value in the first row, the number of columns is the
+/// length of `result`.
+pub(crate) unsafe fn apply_columns_kernel<T, R>(
+    matrix: &[T],
+    result: &mut [T],
+    reg_kernel: BinaryKernel<R::Register>,
+    single_kernel: BinaryKernel<T>,
+) where
+    T: Copy,
+    R: SimdRegister<T>,
+{
+    let dims = result.len();
+    assert_ne!(dims, 0, "Buffer `result` must not be empty");
+    assert_eq!(
+        matrix.len() % dims,
+        0,
+        "Buffer `matrix` is not a multiple of `result` in length"
+    );
+    assert_ne!(
+        matrix.len(),
+        0,
+        "Buffer `matrix` must contain at least one row"
+    );
+
+    let num_rows = matrix.len() / dims;
+    let matrix_ptr = matrix.as_ptr();
+    let result_ptr = result.as_mut_ptr();
+
+    // Operate over single registers of columns first.
+    let offset_from = dims % R::elements_per_lane();
+    let mut i = 0;
+    while i < (dims - offset_from) {
+        let mut acc = R::load(matrix_ptr.add(i));
+        for row in 1..num_rows {
+            acc = reg_kernel(acc, R::load(matrix_ptr.add((row * dims) + i)));
+        }
+        R::write(result_ptr.add(i), acc);
+
+        i += R::elements_per_lane();
+    }
+
+    while i < dims {
+        let mut acc = matrix_ptr.add(i).read();
+        for row in 1..num_rows {
+            acc = single_kernel(acc, matrix_ptr.add((row * dims) + i).read());
+        }
+        result_ptr.add(i).write(acc);
+
+        i += 1;
+    }
+}
+
+#[inline(always)]
+/// Applies a kernel between each element of `a` and the broadcast `value`, writing the
+/// result back into `a`.
+pub(crate) unsafe fn apply_value_inplace_kernel<T, R>(
+    a: &mut [T],
+    value: T,
+    dense_lane_kernel: BinaryKernel<DenseLane<R::Register>>,
+    reg_kernel: BinaryKernel<R::Register>,
+    single_kernel: BinaryKernel<T>,
+) where
+    T: Copy,
+    R: SimdRegister<T>,
+{
+    let len = a.len();
+    let data_ptr = a.as_mut_ptr();
+    let offset_from = len % R::elements_per_dense();
+
+    // Operate over dense lanes first.
+    let value_dense = R::filled_dense(value);
+    let mut i = 0;
+    while i < (len - offset_from) {
+        let l1 = R::load_dense(data_ptr.add(i));
+        R::write_dense(data_ptr.add(i), dense_lane_kernel(l1, value_dense));
+
+        i += R::elements_per_dense();
+    }
+
+    // Operate over single registers next.
+    let value_reg = R::filled(value);
+    let offset_from = offset_from % R::elements_per_lane();
+    while i < (len - offset_from) {
+        let l1 = R::load(data_ptr.add(i));
+        R::write(data_ptr.add(i), reg_kernel(l1, value_reg));
+
+        i += R::elements_per_lane();
+    }
+
+    while i < len {
+        let element = data_ptr.add(i);
+        element.write(single_kernel(element.read(), value));
+
+        i += 1;
+    }
+}
+
+#[inline(always)]
+/// Counts the number of elements of `a` which are equal to `value`.
+///
+/// Each register comparison is written out to a scratch buffer and the set flags
+/// are counted, this avoids accumulating the count in `T` which could overflow
+/// for the smaller integer types.
+pub(crate) unsafe fn count_eq_value<T, R, M, B1>(a: B1, value: T) -> usize
+where
+    T: Copy,
+    R: SimdRegister<T>,
+    M: Math<T>,
+    B1: IntoMemLoader<T>,
+    B1::Loader: MemLoader<Value = T>,
+{
+    let mut a = a.into_mem_loader();
+    let len = a.projected_len();
+
+    let target = R::filled(value);
+    let mut flags = [M::zero(); SCRATCH_SPACE_SIZE];
+
+    let offset_from = len % R::elements_per_lane();
+    let mut count = 0;
+    let mut i = 0;
+    while i < (len - offset_from) {
+        let l1 = a.load::<R>();
+        R::write(flags.as_mut_ptr(), R::eq(l1, target));
+
+        count += flags[..R::elements_per_lane()]
+            .iter()
+            .filter(|flag| !M::cmp_eq(**flag, M::zero()))
+            .count();
+
+        i += R::elements_per_lane();
+    }
+
+    while i < len {
+        if M::cmp_eq(a.read(), value) {
+            count += 1;
+        }
+
+        i += 1;
+    }
+
+    count
+}
+
+#[inline(always)]
+/// Finds the index of the first element of `a` which is equal to `value`.
+///
+/// Each register comparison is written out to a scratch buffer and scanned for the first
+/// set flag, the scan stops at the first register containing a match.
+pub(crate) unsafe fn find_eq_value<T, R, M, B1>(a: B1, value: T) -> Option<usize>
+where
+    T: Copy,
+    R: SimdRegister<T>,
+    M: Math<T>,
+    B1: IntoMemLoader<T>,
+    B1::Loader: MemLoader<Value = T>,
+{
+    let mut a = a.into_mem_loader();
+    let len = a.projected_len();
+
+    let target = R::filled(value);
+    let mut flags = [M::zero(); SCRATCH_SPACE_SIZE];
+
+    let offset_from = len % R::elements_per_lane();
+    let mut i = 0;
+    while i < (len - offset_from) {
+        let l1 = a.load::<R>();
+        R::write(flags.as_mut_ptr(), R::eq(l1, target));
+
+        let position = flags[..R::elements_per_lane()]
+            .iter()
+            .position(|flag| !M::cmp_eq(*flag, M::zero()));
+        if let Some(position) = position {
+            return Some(i + position);
+        }
+
+        i += R::elements_per_lane();
+    }
+
+    while i < len {
+        if M::cmp_eq(a.read(), value) {
+            return Some(i);
+        }
+
+        i += 1;
+    }
+
+    None
+}
@@ -12,6 +12,9 @@
 #![allow(clippy::missing_safety_doc)]
 
 use core::mem;
+use core::slice;
+
+use crate::mem_loader::SCRATCH_SPACE_SIZE;
 
 #[doc(hidden)]
 #[macro_export]
@@ -126,6 +129,16 @@ pub trait SimdRegister<T: Copy> {
     /// Loads `Self::elements_per_lane` elements of `T` into a `Self::Register`.
     unsafe fn load(mem: *const T) -> Self::Register;
 
+    #[inline(always)]
+    /// Loads `Self::elements_per_lane` elements of `T` into a `Self::Register` from
+    /// memory which is aligned to `Self::Register`.
+    ///
+    /// The default implementation falls back to [SimdRegister::load], impls with a
+    /// dedicated aligned load instruction override this.
+    unsafe fn load_aligned(mem: *const T) -> Self::Register {
+        Self::load(mem)
+    }
+
     /// Loads `Self::elements_per_lane` elements of `value` into a `Self::Register`.
     unsafe fn filled(value: T) -> Self::Register;
 
@@ -149,6 +162,24 @@ pub trait SimdRegister<T: Copy> {
         }
     }
 
+    #[allow(clippy::identity_op)]
+    #[allow(clippy::erasing_op)]
+    #[inline(always)]
+    /// Loads `Self::element_per_dense` elements of `T` into a dense lane from memory
+    /// which is aligned to `Self::Register`.
+    unsafe fn load_dense_aligned(mem: *const T) -> DenseLane<Self::Register> {
+        DenseLane {
+            a: Self::load_aligned(mem.add(Self::elements_per_lane() * 0)),
+            b: Self::load_aligned(mem.add(Self::elements_per_lane() * 1)),
+            c: Self::load_aligned(mem.add(Self::elements_per_lane() * 2)),
+            d: Self::load_aligned(mem.add(Self::elements_per_lane() * 3)),
+            e: Self::load_aligned(mem.add(Self::elements_per_lane() * 4)),
+            f: Self::load_aligned(mem.add(Self::elements_per_lane() * 5)),
+            g: Self::load_aligned(mem.add(Self::elements_per_lane() * 6)),
+            h: Self::load_aligned(mem.add(Self::elements_per_lane() * 7)),
+        }
+    }
+
     #[inline(always)]
     /// Loads `Self::element_per_dense` elements of `T` into a `DenseLane<Self::Register>`.
     unsafe fn filled_dense(value: T) -> DenseLane<Self::Register> {
@@ -208,6 +239,30 @@ pub trait SimdRegister<T: Copy> {
     /// in `l1` are _greater than or equal to_ elements in `l2`.
     unsafe fn gte(l1: Self::Register, l2: Self::Register) -> Self::Register;
 
+    #[inline(always)]
+    /// Packs a mask register, as returned by the comparison methods, into the low
+    /// `Self::elements_per_lane` bits of a `u64` where bit `i` is set if element `i`
+    /// of the mask is non-zero.
+    ///
+    /// The default implementation writes the register out and checks each element,
+    /// impls with a native movemask or mask registers should override this.
+    unsafe fn movemask(reg: Self::Register) -> u64 {
+        let mut flags = [mem::MaybeUninit::<T>::uninit(); SCRATCH_SPACE_SIZE];
+        Self::write(flags.as_mut_ptr().cast(), reg);
+
+        let mut mask: u64 = 0;
+        for (i, flag) in flags.iter().take(Self::elements_per_lane()).enumerate() {
+            // A comparison mask only ever holds a `0` or `1`, so any set byte marks it.
+            let bytes =
+                slice::from_raw_parts(flag.as_ptr().cast::<u8>(), mem::size_of::<T>());
+            if bytes.iter().any(|byte| *byte != 0) {
+                mask |= 1 << i;
+            }
+        }
+
+        mask
+    }
+
     #[inline(always)]
     /// Perform a element wise add on two dense lanes.
     unsafe fn add_dense(
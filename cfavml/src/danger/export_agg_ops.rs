@@ -3,9 +3,34 @@
 //! These include routines that don't have a more suitable grouping (i.e. horizontal sum)
 //! but still provide useful value having SIMD variants.
 
-use crate::danger::{generic_sum, SimdRegister};
+use crate::danger::{
+    generic_adaptive_sum,
+    generic_approx_median,
+    generic_cross_entropy,
+    generic_cumulative_sum,
+    generic_entropy,
+    generic_logsumexp,
+    generic_map_sum,
+    generic_mean_variance,
+    generic_pearson,
+    generic_sum,
+    generic_sum_compensated,
+    generic_sum_exact,
+    generic_sum_hint,
+    generic_sum_i32_widen,
+    generic_sum_scaled,
+    generic_sum_vertical_accumulate,
+    generic_sum_with_status,
+    generic_weighted_mean,
+    generic_weighted_sum,
+    generic_weighted_sum_mean,
+    generic_welford_vertical,
+    ReductionStatus,
+    SimdRegister,
+    SumMap,
+};
 use crate::math::{AutoMath, Math};
-use crate::mem_loader::{IntoMemLoader, MemLoader};
+use crate::mem_loader::{IntoMemLoader, MemLoader, MemoryHint};
 
 macro_rules! define_sum_impl {
     (
@@ -14,6 +39,7 @@ macro_rules! define_sum_impl {
         $(target_features = $($feat:expr $(,)?)+)?
     ) => {
         #[inline]
+        #[must_use]
         $(#[target_feature($(enable = $feat, )*)])*
         #[doc = include_str!("../export_docs/agg_horizontal_sum.md")]
         $(
@@ -46,6 +72,1018 @@ define_sum_impl!(
 );
 #[cfg(target_arch = "aarch64")]
 define_sum_impl!(generic_neon_sum, Neon, target_features = "neon");
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_sum_impl!(generic_sse2_sum, Sse2, target_features = "sse2");
+
+macro_rules! define_sum_batch_impl {
+    (
+        $name:ident,
+        $imp:ident $(,)?
+        $(target_features = $($feat:expr $(,)?)+)?
+    ) => {
+        #[inline]
+        $(#[target_feature($(enable = $feat, )*)])*
+        #[doc = include_str!("../export_docs/agg_horizontal_sum_batch.md")]
+        $(
+
+            #[doc = concat!("- ", $("**`+", $feat, "`** ", )*)]
+            #[doc = "CPU features are available at runtime. Running on hardware _without_ this feature available will cause immediate UB."]
+        )*
+        pub unsafe fn $name<T>(vectors: &[&[T]], out: &mut [T])
+        where
+            T: Copy,
+            AutoMath: Math<T>,
+            crate::danger::$imp: SimdRegister<T>,
+        {
+            assert_eq!(
+                out.len(),
+                vectors.len(),
+                "Buffer `out` does not match the number of `vectors`"
+            );
+
+            for (total, a) in out.iter_mut().zip(vectors) {
+                *total = generic_sum::<T, crate::danger::$imp, AutoMath, _>(*a);
+            }
+        }
+    };
+}
+
+define_sum_batch_impl!(generic_fallback_sum_batch, Fallback);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_sum_batch_impl!(generic_avx2_sum_batch, Avx2, target_features = "avx2");
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+define_sum_batch_impl!(
+    generic_avx512_sum_batch,
+    Avx512,
+    target_features = "avx512f",
+    "avx512bw"
+);
+#[cfg(target_arch = "aarch64")]
+define_sum_batch_impl!(generic_neon_sum_batch, Neon, target_features = "neon");
+
+macro_rules! define_adaptive_sum_impl {
+    (
+        $name:ident,
+        $imp:ident $(,)?
+        $(target_features = $($feat:expr $(,)?)+)?
+    ) => {
+        #[inline]
+        #[must_use]
+        $(#[target_feature($(enable = $feat, )*)])*
+        #[doc = include_str!("../export_docs/agg_adaptive_sum.md")]
+        $(
+
+            #[doc = concat!("- ", $("**`+", $feat, "`** ", )*)]
+            #[doc = "CPU features are available at runtime. Running on hardware _without_ this feature available will cause immediate UB."]
+        )*
+        pub unsafe fn $name(a: &[f32]) -> f64 {
+            generic_adaptive_sum::<crate::danger::$imp, AutoMath>(a)
+        }
+    };
+}
+
+define_adaptive_sum_impl!(f32_fallback_adaptive_sum, Fallback);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_adaptive_sum_impl!(f32_avx2_adaptive_sum, Avx2, target_features = "avx2");
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+define_adaptive_sum_impl!(
+    f32_avx512_adaptive_sum,
+    Avx512,
+    target_features = "avx512f",
+    "avx512bw"
+);
+#[cfg(target_arch = "aarch64")]
+define_adaptive_sum_impl!(f32_neon_adaptive_sum, Neon, target_features = "neon");
+
+macro_rules! define_sum_i32_widen_impl {
+    (
+        $name:ident,
+        $imp:ident $(,)?
+        $(target_features = $($feat:expr $(,)?)+)?
+    ) => {
+        #[inline]
+        #[must_use]
+        $(#[target_feature($(enable = $feat, )*)])*
+        #[doc = include_str!("../export_docs/agg_sum_i32_widen.md")]
+        $(
+
+            #[doc = concat!("- ", $("**`+", $feat, "`** ", )*)]
+            #[doc = "CPU features are available at runtime. Running on hardware _without_ this feature available will cause immediate UB."]
+        )*
+        pub unsafe fn $name(a: &[i32]) -> i64 {
+            generic_sum_i32_widen::<crate::danger::$imp, AutoMath>(a)
+        }
+    };
+}
+
+define_sum_i32_widen_impl!(i32_fallback_sum_widen, Fallback);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_sum_i32_widen_impl!(i32_avx2_sum_widen, Avx2, target_features = "avx2");
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+define_sum_i32_widen_impl!(
+    i32_avx512_sum_widen,
+    Avx512,
+    target_features = "avx512f",
+    "avx512bw"
+);
+#[cfg(target_arch = "aarch64")]
+define_sum_i32_widen_impl!(i32_neon_sum_widen, Neon, target_features = "neon");
+
+macro_rules! define_cross_entropy_impl {
+    (
+        $name:ident,
+        $imp:ident $(,)?
+        $(target_features = $($feat:expr $(,)?)+)?
+    ) => {
+        #[inline]
+        #[must_use]
+        $(#[target_feature($(enable = $feat, )*)])*
+        #[doc = include_str!("../export_docs/agg_cross_entropy.md")]
+        $(
+
+            #[doc = concat!("- ", $("**`+", $feat, "`** ", )*)]
+            #[doc = "CPU features are available at runtime. Running on hardware _without_ this feature available will cause immediate UB."]
+        )*
+        pub unsafe fn $name(logits: &[f32], target: usize) -> f32 {
+            generic_cross_entropy::<crate::danger::$imp, AutoMath>(logits, target)
+        }
+    };
+}
+
+define_cross_entropy_impl!(f32_fallback_cross_entropy, Fallback);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_cross_entropy_impl!(f32_avx2_cross_entropy, Avx2, target_features = "avx2");
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+define_cross_entropy_impl!(
+    f32_avx512_cross_entropy,
+    Avx512,
+    target_features = "avx512f",
+    "avx512bw"
+);
+#[cfg(target_arch = "aarch64")]
+define_cross_entropy_impl!(f32_neon_cross_entropy, Neon, target_features = "neon");
+
+macro_rules! define_logsumexp_impl {
+    (
+        $name:ident,
+        $imp:ident $(,)?
+        $(target_features = $($feat:expr $(,)?)+)?
+    ) => {
+        #[inline]
+        #[must_use]
+        $(#[target_feature($(enable = $feat, )*)])*
+        #[doc = include_str!("../export_docs/agg_logsumexp.md")]
+        $(
+
+            #[doc = concat!("- ", $("**`+", $feat, "`** ", )*)]
+            #[doc = "CPU features are available at runtime. Running on hardware _without_ this feature available will cause immediate UB."]
+        )*
+        pub unsafe fn $name(x: &[f32]) -> f32 {
+            generic_logsumexp::<crate::danger::$imp, AutoMath>(x)
+        }
+    };
+}
+
+define_logsumexp_impl!(f32_fallback_logsumexp, Fallback);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_logsumexp_impl!(f32_avx2_logsumexp, Avx2, target_features = "avx2");
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+define_logsumexp_impl!(
+    f32_avx512_logsumexp,
+    Avx512,
+    target_features = "avx512f",
+    "avx512bw"
+);
+#[cfg(target_arch = "aarch64")]
+define_logsumexp_impl!(f32_neon_logsumexp, Neon, target_features = "neon");
+
+macro_rules! define_approx_median_impl {
+    (
+        $name:ident,
+        $imp:ident $(,)?
+        $(target_features = $($feat:expr $(,)?)+)?
+    ) => {
+        #[inline]
+        #[must_use]
+        $(#[target_feature($(enable = $feat, )*)])*
+        #[doc = include_str!("../export_docs/agg_approx_median.md")]
+        $(
+
+            #[doc = concat!("- ", $("**`+", $feat, "`** ", )*)]
+            #[doc = "CPU features are available at runtime. Running on hardware _without_ this feature available will cause immediate UB."]
+        )*
+        pub unsafe fn $name(a: &[f64]) -> f64 {
+            generic_approx_median::<crate::danger::$imp, AutoMath>(a)
+        }
+    };
+}
+
+define_approx_median_impl!(f64_fallback_approx_median, Fallback);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_approx_median_impl!(f64_avx2_approx_median, Avx2, target_features = "avx2");
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+define_approx_median_impl!(
+    f64_avx512_approx_median,
+    Avx512,
+    target_features = "avx512f",
+    "avx512bw"
+);
+#[cfg(target_arch = "aarch64")]
+define_approx_median_impl!(f64_neon_approx_median, Neon, target_features = "neon");
+
+macro_rules! define_entropy_impl {
+    (
+        $name:ident,
+        $imp:ident $(,)?
+        $(target_features = $($feat:expr $(,)?)+)?
+    ) => {
+        #[inline]
+        #[must_use]
+        $(#[target_feature($(enable = $feat, )*)])*
+        #[doc = include_str!("../export_docs/agg_entropy.md")]
+        $(
+
+            #[doc = concat!("- ", $("**`+", $feat, "`** ", )*)]
+            #[doc = "CPU features are available at runtime. Running on hardware _without_ this feature available will cause immediate UB."]
+        )*
+        pub unsafe fn $name(p: &[f64]) -> f64 {
+            generic_entropy::<crate::danger::$imp, AutoMath>(p)
+        }
+    };
+}
+
+define_entropy_impl!(f64_fallback_entropy, Fallback);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_entropy_impl!(f64_avx2_entropy, Avx2, target_features = "avx2");
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_entropy_impl!(
+    f64_avx2fma_entropy,
+    Avx2Fma,
+    target_features = "avx2",
+    "fma"
+);
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+define_entropy_impl!(
+    f64_avx512_entropy,
+    Avx512,
+    target_features = "avx512f",
+    "avx512bw"
+);
+#[cfg(target_arch = "aarch64")]
+define_entropy_impl!(f64_neon_entropy, Neon, target_features = "neon");
+
+macro_rules! define_sum_hint_impl {
+    (
+        $name:ident,
+        $imp:ident $(,)?
+        $(target_features = $($feat:expr $(,)?)+)?
+    ) => {
+        #[inline]
+        #[must_use]
+        $(#[target_feature($(enable = $feat, )*)])*
+        #[doc = include_str!("../export_docs/agg_horizontal_sum_hint.md")]
+        $(
+
+            #[doc = concat!("- ", $("**`+", $feat, "`** ", )*)]
+            #[doc = "CPU features are available at runtime. Running on hardware _without_ this feature available will cause immediate UB."]
+        )*
+        pub unsafe fn $name<T>(a: &[T], hint: MemoryHint) -> T
+        where
+            T: Copy,
+            AutoMath: Math<T>,
+            crate::danger::$imp: SimdRegister<T>,
+        {
+            generic_sum_hint::<T, crate::danger::$imp, AutoMath>(a, hint)
+        }
+    };
+}
+
+define_sum_hint_impl!(generic_fallback_sum_hint, Fallback);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_sum_hint_impl!(generic_avx2_sum_hint, Avx2, target_features = "avx2");
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+define_sum_hint_impl!(
+    generic_avx512_sum_hint,
+    Avx512,
+    target_features = "avx512f",
+    "avx512bw"
+);
+#[cfg(target_arch = "aarch64")]
+define_sum_hint_impl!(generic_neon_sum_hint, Neon, target_features = "neon");
+
+macro_rules! define_sum_compensated_impl {
+    (
+        $t:ident,
+        $name:ident,
+        $imp:ident $(,)?
+        $(target_features = $($feat:expr $(,)?)+)?
+    ) => {
+        #[inline]
+        #[must_use]
+        $(#[target_feature($(enable = $feat, )*)])*
+        #[doc = include_str!("../export_docs/agg_horizontal_sum_compensated.md")]
+        $(
+
+            #[doc = concat!("- ", $("**`+", $feat, "`** ", )*)]
+            #[doc = "CPU features are available at runtime. Running on hardware _without_ this feature available will cause immediate UB."]
+        )*
+        pub unsafe fn $name(a: &[$t]) -> $t {
+            generic_sum_compensated::<$t, crate::danger::$imp, AutoMath>(a)
+        }
+    };
+}
+
+define_sum_compensated_impl!(f32, f32_fallback_sum_compensated, Fallback);
+define_sum_compensated_impl!(f64, f64_fallback_sum_compensated, Fallback);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_sum_compensated_impl!(
+    f32,
+    f32_avx2_sum_compensated,
+    Avx2,
+    target_features = "avx2"
+);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_sum_compensated_impl!(
+    f64,
+    f64_avx2_sum_compensated,
+    Avx2,
+    target_features = "avx2"
+);
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+define_sum_compensated_impl!(
+    f32,
+    f32_avx512_sum_compensated,
+    Avx512,
+    target_features = "avx512f",
+    "avx512bw"
+);
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+define_sum_compensated_impl!(
+    f64,
+    f64_avx512_sum_compensated,
+    Avx512,
+    target_features = "avx512f",
+    "avx512bw"
+);
+#[cfg(target_arch = "aarch64")]
+define_sum_compensated_impl!(
+    f32,
+    f32_neon_sum_compensated,
+    Neon,
+    target_features = "neon"
+);
+#[cfg(target_arch = "aarch64")]
+define_sum_compensated_impl!(
+    f64,
+    f64_neon_sum_compensated,
+    Neon,
+    target_features = "neon"
+);
+
+macro_rules! define_sum_exact_impl {
+    (
+        $name:ident,
+        $imp:ident $(,)?
+        $(target_features = $($feat:expr $(,)?)+)?
+    ) => {
+        #[inline]
+        #[must_use]
+        $(#[target_feature($(enable = $feat, )*)])*
+        #[doc = include_str!("../export_docs/agg_horizontal_sum_exact.md")]
+        $(
+
+            #[doc = concat!("- ", $("**`+", $feat, "`** ", )*)]
+            #[doc = "CPU features are available at runtime. Running on hardware _without_ this feature available will cause immediate UB."]
+        )*
+        pub unsafe fn $name<T>(a: &[T]) -> T
+        where
+            T: Copy,
+            AutoMath: Math<T>,
+            crate::danger::$imp: SimdRegister<T>,
+        {
+            generic_sum_exact::<T, crate::danger::$imp, AutoMath>(a)
+        }
+    };
+}
+
+define_sum_exact_impl!(generic_fallback_sum_exact, Fallback);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_sum_exact_impl!(generic_avx2_sum_exact, Avx2, target_features = "avx2");
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+define_sum_exact_impl!(
+    generic_avx512_sum_exact,
+    Avx512,
+    target_features = "avx512f",
+    "avx512bw"
+);
+#[cfg(target_arch = "aarch64")]
+define_sum_exact_impl!(generic_neon_sum_exact, Neon, target_features = "neon");
+
+macro_rules! define_sum_with_status_impl {
+    (
+        $name:ident,
+        $imp:ident $(,)?
+        $(target_features = $($feat:expr $(,)?)+)?
+    ) => {
+        #[inline]
+        #[must_use]
+        $(#[target_feature($(enable = $feat, )*)])*
+        #[doc = include_str!("../export_docs/agg_horizontal_sum_with_status.md")]
+        $(
+
+            #[doc = concat!("- ", $("**`+", $feat, "`** ", )*)]
+            #[doc = "CPU features are available at runtime. Running on hardware _without_ this feature available will cause immediate UB."]
+        )*
+        pub unsafe fn $name<T>(a: &[T]) -> (T, ReductionStatus)
+        where
+            T: Copy,
+            AutoMath: Math<T>,
+            crate::danger::$imp: SimdRegister<T>,
+        {
+            generic_sum_with_status::<T, crate::danger::$imp, AutoMath>(a)
+        }
+    };
+}
+
+define_sum_with_status_impl!(generic_fallback_sum_with_status, Fallback);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_sum_with_status_impl!(
+    generic_avx2_sum_with_status,
+    Avx2,
+    target_features = "avx2"
+);
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+define_sum_with_status_impl!(
+    generic_avx512_sum_with_status,
+    Avx512,
+    target_features = "avx512f",
+    "avx512bw"
+);
+#[cfg(target_arch = "aarch64")]
+define_sum_with_status_impl!(
+    generic_neon_sum_with_status,
+    Neon,
+    target_features = "neon"
+);
+
+macro_rules! define_cumulative_sum_impl {
+    (
+        $name:ident,
+        $imp:ident $(,)?
+        $(target_features = $($feat:expr $(,)?)+)?
+    ) => {
+        #[inline]
+        $(#[target_feature($(enable = $feat, )*)])*
+        #[doc = include_str!("../export_docs/agg_cumulative_sum.md")]
+        $(
+
+            #[doc = concat!("- ", $("**`+", $feat, "`** ", )*)]
+            #[doc = "CPU features are available at runtime. Running on hardware _without_ this feature available will cause immediate UB."]
+        )*
+        pub unsafe fn $name<T>(x: &[T], result: &mut [T])
+        where
+            T: Copy,
+            AutoMath: Math<T>,
+            crate::danger::$imp: SimdRegister<T>,
+        {
+            generic_cumulative_sum::<T, crate::danger::$imp, AutoMath>(x, result)
+        }
+    };
+}
+
+define_cumulative_sum_impl!(generic_fallback_cumulative_sum, Fallback);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_cumulative_sum_impl!(generic_avx2_cumulative_sum, Avx2, target_features = "avx2");
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+define_cumulative_sum_impl!(
+    generic_avx512_cumulative_sum,
+    Avx512,
+    target_features = "avx512f",
+    "avx512bw"
+);
+#[cfg(target_arch = "aarch64")]
+define_cumulative_sum_impl!(generic_neon_cumulative_sum, Neon, target_features = "neon");
+
+macro_rules! define_sum_vertical_accumulate_impl {
+    (
+        $name:ident,
+        $imp:ident $(,)?
+        $(target_features = $($feat:expr $(,)?)+)?
+    ) => {
+        #[inline]
+        $(#[target_feature($(enable = $feat, )*)])*
+        #[doc = include_str!("../export_docs/agg_vertical_sum_accumulate.md")]
+        $(
+
+            #[doc = concat!("- ", $("**`+", $feat, "`** ", )*)]
+            #[doc = "CPU features are available at runtime. Running on hardware _without_ this feature available will cause immediate UB."]
+        )*
+        pub unsafe fn $name<T>(matrix: &[T], output: &mut [T])
+        where
+            T: Copy,
+            AutoMath: Math<T>,
+            crate::danger::$imp: SimdRegister<T>,
+        {
+            generic_sum_vertical_accumulate::<T, crate::danger::$imp, AutoMath>(
+                matrix,
+                output,
+            )
+        }
+    };
+}
+
+define_sum_vertical_accumulate_impl!(generic_fallback_sum_vertical_accumulate, Fallback);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_sum_vertical_accumulate_impl!(
+    generic_avx2_sum_vertical_accumulate,
+    Avx2,
+    target_features = "avx2"
+);
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+define_sum_vertical_accumulate_impl!(
+    generic_avx512_sum_vertical_accumulate,
+    Avx512,
+    target_features = "avx512f",
+    "avx512bw"
+);
+#[cfg(target_arch = "aarch64")]
+define_sum_vertical_accumulate_impl!(
+    generic_neon_sum_vertical_accumulate,
+    Neon,
+    target_features = "neon"
+);
+
+macro_rules! define_sum_scaled_impl {
+    (
+        $name:ident,
+        $imp:ident $(,)?
+        $(target_features = $($feat:expr $(,)?)+)?
+    ) => {
+        #[inline]
+        #[must_use]
+        $(#[target_feature($(enable = $feat, )*)])*
+        #[doc = include_str!("../export_docs/agg_horizontal_sum_scaled.md")]
+        $(
+
+            #[doc = concat!("- ", $("**`+", $feat, "`** ", )*)]
+            #[doc = "CPU features are available at runtime. Running on hardware _without_ this feature available will cause immediate UB."]
+        )*
+        pub unsafe fn $name<T, B1>(a: B1, scale: T) -> T
+        where
+            T: Copy,
+            B1: IntoMemLoader<T>,
+            B1::Loader: MemLoader<Value = T>,
+            AutoMath: Math<T>,
+            crate::danger::$imp: SimdRegister<T>,
+        {
+            generic_sum_scaled::<T, crate::danger::$imp, AutoMath, _>(a, scale)
+        }
+    };
+}
+
+define_sum_scaled_impl!(generic_fallback_sum_scaled, Fallback);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_sum_scaled_impl!(generic_avx2_sum_scaled, Avx2, target_features = "avx2");
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_sum_scaled_impl!(
+    generic_avx2fma_sum_scaled,
+    Avx2Fma,
+    target_features = "avx2",
+    "fma"
+);
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+define_sum_scaled_impl!(
+    generic_avx512_sum_scaled,
+    Avx512,
+    target_features = "avx512f",
+    "avx512bw"
+);
+#[cfg(target_arch = "aarch64")]
+define_sum_scaled_impl!(generic_neon_sum_scaled, Neon, target_features = "neon");
+
+macro_rules! define_map_sum_impl {
+    (
+        $name:ident,
+        $imp:ident $(,)?
+        $(target_features = $($feat:expr $(,)?)+)?
+    ) => {
+        #[inline]
+        #[must_use]
+        $(#[target_feature($(enable = $feat, )*)])*
+        #[doc = include_str!("../export_docs/agg_map_sum.md")]
+        $(
+
+            #[doc = concat!("- ", $("**`+", $feat, "`** ", )*)]
+            #[doc = "CPU features are available at runtime. Running on hardware _without_ this feature available will cause immediate UB."]
+        )*
+        pub unsafe fn $name<T, B1, F>(a: B1, map: &F) -> T
+        where
+            T: Copy,
+            B1: IntoMemLoader<T>,
+            B1::Loader: MemLoader<Value = T>,
+            F: SumMap<T>,
+            AutoMath: Math<T>,
+            crate::danger::$imp: SimdRegister<T>,
+        {
+            generic_map_sum::<T, crate::danger::$imp, AutoMath, _, F>(a, map)
+        }
+    };
+}
+
+define_map_sum_impl!(generic_fallback_map_sum, Fallback);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_map_sum_impl!(generic_avx2_map_sum, Avx2, target_features = "avx2");
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+define_map_sum_impl!(
+    generic_avx512_map_sum,
+    Avx512,
+    target_features = "avx512f",
+    "avx512bw"
+);
+#[cfg(target_arch = "aarch64")]
+define_map_sum_impl!(generic_neon_map_sum, Neon, target_features = "neon");
+
+macro_rules! define_pearson_impl {
+    (
+        $name:ident,
+        $imp:ident $(,)?
+        $(target_features = $($feat:expr $(,)?)+)?
+    ) => {
+        #[inline]
+        #[must_use]
+        $(#[target_feature($(enable = $feat, )*)])*
+        #[doc = include_str!("../export_docs/agg_pearson.md")]
+        $(
+
+            #[doc = concat!("- ", $("**`+", $feat, "`** ", )*)]
+            #[doc = "CPU features are available at runtime. Running on hardware _without_ this feature available will cause immediate UB."]
+        )*
+        pub unsafe fn $name<T, B1, B2>(a: B1, b: B2) -> T
+        where
+            T: Copy,
+            B1: IntoMemLoader<T>,
+            B1::Loader: MemLoader<Value = T>,
+            B2: IntoMemLoader<T>,
+            B2::Loader: MemLoader<Value = T>,
+            AutoMath: Math<T>,
+            crate::danger::$imp: SimdRegister<T>,
+        {
+            generic_pearson::<T, crate::danger::$imp, AutoMath, B1, B2>(a, b)
+        }
+    };
+}
+
+define_pearson_impl!(generic_fallback_pearson, Fallback);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_pearson_impl!(generic_avx2_pearson, Avx2, target_features = "avx2");
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_pearson_impl!(
+    generic_avx2fma_pearson,
+    Avx2Fma,
+    target_features = "avx2",
+    "fma",
+);
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+define_pearson_impl!(
+    generic_avx512_pearson,
+    Avx512,
+    target_features = "avx512f",
+    "avx512bw"
+);
+#[cfg(target_arch = "aarch64")]
+define_pearson_impl!(generic_neon_pearson, Neon, target_features = "neon");
+
+macro_rules! define_weighted_mean_impl {
+    (
+        $t:ident,
+        $name:ident,
+        $imp:ident $(,)?
+        $(target_features = $($feat:expr $(,)?)+)?
+    ) => {
+        #[inline]
+        #[must_use]
+        $(#[target_feature($(enable = $feat, )*)])*
+        #[doc = include_str!("../export_docs/agg_weighted_mean.md")]
+        $(
+
+            #[doc = concat!("- ", $("**`+", $feat, "`** ", )*)]
+            #[doc = "CPU features are available at runtime. Running on hardware _without_ this feature available will cause immediate UB."]
+        )*
+        pub unsafe fn $name<B1, B2>(x: B1, w: B2) -> $t
+        where
+            B1: IntoMemLoader<$t>,
+            B1::Loader: MemLoader<Value = $t>,
+            B2: IntoMemLoader<$t>,
+            B2::Loader: MemLoader<Value = $t>,
+        {
+            generic_weighted_mean::<$t, crate::danger::$imp, AutoMath, B1, B2>(x, w)
+        }
+    };
+}
+
+define_weighted_mean_impl!(f32, f32_fallback_weighted_mean, Fallback);
+define_weighted_mean_impl!(f64, f64_fallback_weighted_mean, Fallback);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_weighted_mean_impl!(
+    f32,
+    f32_avx2_weighted_mean,
+    Avx2,
+    target_features = "avx2"
+);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_weighted_mean_impl!(
+    f64,
+    f64_avx2_weighted_mean,
+    Avx2,
+    target_features = "avx2"
+);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_weighted_mean_impl!(
+    f32,
+    f32_avx2fma_weighted_mean,
+    Avx2Fma,
+    target_features = "avx2",
+    "fma",
+);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_weighted_mean_impl!(
+    f64,
+    f64_avx2fma_weighted_mean,
+    Avx2Fma,
+    target_features = "avx2",
+    "fma",
+);
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+define_weighted_mean_impl!(
+    f32,
+    f32_avx512_weighted_mean,
+    Avx512,
+    target_features = "avx512f",
+    "avx512bw"
+);
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+define_weighted_mean_impl!(
+    f64,
+    f64_avx512_weighted_mean,
+    Avx512,
+    target_features = "avx512f",
+    "avx512bw"
+);
+#[cfg(target_arch = "aarch64")]
+define_weighted_mean_impl!(
+    f32,
+    f32_neon_weighted_mean,
+    Neon,
+    target_features = "neon"
+);
+#[cfg(target_arch = "aarch64")]
+define_weighted_mean_impl!(
+    f64,
+    f64_neon_weighted_mean,
+    Neon,
+    target_features = "neon"
+);
+
+macro_rules! define_weighted_sum_impl {
+    (
+        $name:ident,
+        $imp:ident $(,)?
+        $(target_features = $($feat:expr $(,)?)+)?
+    ) => {
+        #[inline]
+        #[must_use]
+        $(#[target_feature($(enable = $feat, )*)])*
+        #[doc = include_str!("../export_docs/agg_weighted_sum.md")]
+        $(
+
+            #[doc = concat!("- ", $("**`+", $feat, "`** ", )*)]
+            #[doc = "CPU features are available at runtime. Running on hardware _without_ this feature available will cause immediate UB."]
+        )*
+        pub unsafe fn $name<T, B1, B2>(x: B1, w: B2) -> T
+        where
+            T: Copy,
+            B1: IntoMemLoader<T>,
+            B1::Loader: MemLoader<Value = T>,
+            B2: IntoMemLoader<T>,
+            B2::Loader: MemLoader<Value = T>,
+            AutoMath: Math<T>,
+            crate::danger::$imp: SimdRegister<T>,
+        {
+            generic_weighted_sum::<T, crate::danger::$imp, AutoMath, B1, B2>(x, w)
+        }
+    };
+}
+
+define_weighted_sum_impl!(generic_fallback_weighted_sum, Fallback);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_weighted_sum_impl!(generic_avx2_weighted_sum, Avx2, target_features = "avx2");
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_weighted_sum_impl!(
+    generic_avx2fma_weighted_sum,
+    Avx2Fma,
+    target_features = "avx2",
+    "fma",
+);
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+define_weighted_sum_impl!(
+    generic_avx512_weighted_sum,
+    Avx512,
+    target_features = "avx512f",
+    "avx512bw"
+);
+#[cfg(target_arch = "aarch64")]
+define_weighted_sum_impl!(generic_neon_weighted_sum, Neon, target_features = "neon");
+
+macro_rules! define_weighted_sum_mean_impl {
+    (
+        $t:ident,
+        $name:ident,
+        $imp:ident $(,)?
+        $(target_features = $($feat:expr $(,)?)+)?
+    ) => {
+        #[inline]
+        #[must_use]
+        $(#[target_feature($(enable = $feat, )*)])*
+        #[doc = include_str!("../export_docs/agg_weighted_sum_mean.md")]
+        $(
+
+            #[doc = concat!("- ", $("**`+", $feat, "`** ", )*)]
+            #[doc = "CPU features are available at runtime. Running on hardware _without_ this feature available will cause immediate UB."]
+        )*
+        pub unsafe fn $name<B1, B2>(x: B1, w: B2) -> $t
+        where
+            B1: IntoMemLoader<$t>,
+            B1::Loader: MemLoader<Value = $t>,
+            B2: IntoMemLoader<$t> + Copy,
+            B2::Loader: MemLoader<Value = $t>,
+        {
+            generic_weighted_sum_mean::<$t, crate::danger::$imp, AutoMath, B1, B2>(x, w)
+        }
+    };
+}
+
+define_weighted_sum_mean_impl!(f32, f32_fallback_weighted_sum_mean, Fallback);
+define_weighted_sum_mean_impl!(f64, f64_fallback_weighted_sum_mean, Fallback);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_weighted_sum_mean_impl!(
+    f32,
+    f32_avx2_weighted_sum_mean,
+    Avx2,
+    target_features = "avx2"
+);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_weighted_sum_mean_impl!(
+    f64,
+    f64_avx2_weighted_sum_mean,
+    Avx2,
+    target_features = "avx2"
+);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_weighted_sum_mean_impl!(
+    f32,
+    f32_avx2fma_weighted_sum_mean,
+    Avx2Fma,
+    target_features = "avx2",
+    "fma",
+);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_weighted_sum_mean_impl!(
+    f64,
+    f64_avx2fma_weighted_sum_mean,
+    Avx2Fma,
+    target_features = "avx2",
+    "fma",
+);
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+define_weighted_sum_mean_impl!(
+    f32,
+    f32_avx512_weighted_sum_mean,
+    Avx512,
+    target_features = "avx512f",
+    "avx512bw"
+);
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+define_weighted_sum_mean_impl!(
+    f64,
+    f64_avx512_weighted_sum_mean,
+    Avx512,
+    target_features = "avx512f",
+    "avx512bw"
+);
+#[cfg(target_arch = "aarch64")]
+define_weighted_sum_mean_impl!(
+    f32,
+    f32_neon_weighted_sum_mean,
+    Neon,
+    target_features = "neon"
+);
+#[cfg(target_arch = "aarch64")]
+define_weighted_sum_mean_impl!(
+    f64,
+    f64_neon_weighted_sum_mean,
+    Neon,
+    target_features = "neon"
+);
+
+macro_rules! define_welford_impl {
+    (
+        $name:ident,
+        $imp:ident $(,)?
+        $(target_features = $($feat:expr $(,)?)+)?
+    ) => {
+        #[inline]
+        $(#[target_feature($(enable = $feat, )*)])*
+        #[doc = include_str!("../export_docs/agg_welford_vertical.md")]
+        $(
+
+            #[doc = concat!("- ", $("**`+", $feat, "`** ", )*)]
+            #[doc = "CPU features are available at runtime. Running on hardware _without_ this feature available will cause immediate UB."]
+        )*
+        pub unsafe fn $name<T>(
+            matrix: &[T],
+            dims: usize,
+            count: &mut T,
+            mean: &mut [T],
+            m2: &mut [T],
+        )
+        where
+            T: Copy,
+            AutoMath: Math<T>,
+            crate::danger::$imp: SimdRegister<T>,
+        {
+            generic_welford_vertical::<T, crate::danger::$imp, AutoMath>(
+                matrix,
+                dims,
+                count,
+                mean,
+                m2,
+            )
+        }
+    };
+}
+
+define_welford_impl!(generic_fallback_welford_vertical, Fallback);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_welford_impl!(
+    generic_avx2_welford_vertical,
+    Avx2,
+    target_features = "avx2"
+);
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+define_welford_impl!(
+    generic_avx512_welford_vertical,
+    Avx512,
+    target_features = "avx512f",
+    "avx512bw"
+);
+#[cfg(target_arch = "aarch64")]
+define_welford_impl!(
+    generic_neon_welford_vertical,
+    Neon,
+    target_features = "neon"
+);
+
+macro_rules! define_mean_variance_impl {
+    ($name:ident, $imp:ident $(,)? $(target_features = $($feat:expr $(,)?)+)?) => {
+        #[inline]
+        #[must_use]
+        $(#[target_feature($(enable = $feat, )*)])*
+        #[doc = include_str!("../export_docs/agg_mean_variance.md")]
+        $(
+
+            #[doc = concat!("- ", $("**`+", $feat, "`** ", )*)]
+            #[doc = "CPU features are available at runtime. Running on hardware _without_ this feature available will cause immediate UB."]
+        )*
+        pub unsafe fn $name<T>(x: &[T]) -> (T, T)
+        where
+            T: Copy,
+            AutoMath: Math<T>,
+            crate::danger::$imp: SimdRegister<T>,
+        {
+            generic_mean_variance::<T, crate::danger::$imp, AutoMath>(x)
+        }
+    };
+}
+
+define_mean_variance_impl!(generic_fallback_mean_variance, Fallback);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_mean_variance_impl!(generic_avx2_mean_variance, Avx2, target_features = "avx2");
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_mean_variance_impl!(
+    generic_avx2fma_mean_variance,
+    Avx2Fma,
+    target_features = "avx2",
+    "fma",
+);
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+define_mean_variance_impl!(
+    generic_avx512_mean_variance,
+    Avx512,
+    target_features = "avx512f",
+    "avx512bw"
+);
+#[cfg(target_arch = "aarch64")]
+define_mean_variance_impl!(generic_neon_mean_variance, Neon, target_features = "neon");
 
 #[cfg(test)]
 mod tests {
@@ -133,4 +1171,9 @@ mod tests {
         u32,
         u64
     );
+    #[cfg(all(
+        any(target_arch = "x86", target_arch = "x86_64"),
+        target_feature = "sse2"
+    ))]
+    define_agg_test!(generic_sse2, types = f32, f64);
 }
@@ -4,9 +4,22 @@
 
 use crate::buffer::WriteOnlyBuffer;
 use crate::danger::{
+    generic_add_value_inplace,
     generic_add_vertical,
+    generic_axpy,
+    generic_diff,
+    generic_div_value_inplace,
     generic_div_vertical,
+    generic_hadamard_transform_inplace,
+    generic_linear_combine,
+    generic_linear_combine_inplace,
+    generic_momentum_update,
+    generic_mul_value_inplace,
     generic_mul_vertical,
+    generic_outer,
+    generic_quat_mul,
+    generic_recip_inplace,
+    generic_sub_value_inplace,
     generic_sub_vertical,
     SimdRegister,
 };
@@ -180,6 +193,518 @@ define_arithmetic_impls!(
     target_features = "neon"
 );
 
+macro_rules! define_value_inplace_impls {
+    (
+        add = $add_name:ident,
+        sub = $sub_name:ident,
+        mul = $mul_name:ident,
+        div = $div_name:ident,
+        $imp:ident $(,)?
+        $(target_features = $($feat:expr $(,)?)+)?
+    ) => {
+        #[inline]
+        $(#[target_feature($(enable = $feat, )*)])*
+        #[doc = include_str!("../export_docs/arithmetic_add_value_inplace.md")]
+        $(
+
+            #[doc = concat!("- ", $("**`+", $feat, "`** ", )*)]
+            #[doc = "CPU features are available at runtime. Running on hardware _without_ this feature available will cause immediate UB."]
+        )*
+        pub unsafe fn $add_name<T>(a: &mut [T], value: T)
+        where
+            T: Copy,
+            crate::danger::$imp: SimdRegister<T>,
+            AutoMath: Math<T>,
+        {
+            generic_add_value_inplace::<T, crate::danger::$imp, AutoMath>(a, value)
+        }
+
+        #[inline]
+        $(#[target_feature($(enable = $feat, )*)])*
+        #[doc = include_str!("../export_docs/arithmetic_sub_value_inplace.md")]
+        $(
+
+            #[doc = concat!("- ", $("**`+", $feat, "`** ", )*)]
+            #[doc = "CPU features are available at runtime. Running on hardware _without_ this feature available will cause immediate UB."]
+        )*
+        pub unsafe fn $sub_name<T>(a: &mut [T], value: T)
+        where
+            T: Copy,
+            crate::danger::$imp: SimdRegister<T>,
+            AutoMath: Math<T>,
+        {
+            generic_sub_value_inplace::<T, crate::danger::$imp, AutoMath>(a, value)
+        }
+
+        #[inline]
+        $(#[target_feature($(enable = $feat, )*)])*
+        #[doc = include_str!("../export_docs/arithmetic_mul_value_inplace.md")]
+        $(
+
+            #[doc = concat!("- ", $("**`+", $feat, "`** ", )*)]
+            #[doc = "CPU features are available at runtime. Running on hardware _without_ this feature available will cause immediate UB."]
+        )*
+        pub unsafe fn $mul_name<T>(a: &mut [T], value: T)
+        where
+            T: Copy,
+            crate::danger::$imp: SimdRegister<T>,
+            AutoMath: Math<T>,
+        {
+            generic_mul_value_inplace::<T, crate::danger::$imp, AutoMath>(a, value)
+        }
+
+        #[inline]
+        $(#[target_feature($(enable = $feat, )*)])*
+        #[doc = include_str!("../export_docs/arithmetic_div_value_inplace.md")]
+        $(
+
+            #[doc = concat!("- ", $("**`+", $feat, "`** ", )*)]
+            #[doc = "CPU features are available at runtime. Running on hardware _without_ this feature available will cause immediate UB."]
+        )*
+        pub unsafe fn $div_name<T>(a: &mut [T], value: T)
+        where
+            T: Copy,
+            crate::danger::$imp: SimdRegister<T>,
+            AutoMath: Math<T>,
+        {
+            generic_div_value_inplace::<T, crate::danger::$imp, AutoMath>(a, value)
+        }
+    };
+}
+
+define_value_inplace_impls!(
+    add = generic_fallback_add_value_inplace,
+    sub = generic_fallback_sub_value_inplace,
+    mul = generic_fallback_mul_value_inplace,
+    div = generic_fallback_div_value_inplace,
+    Fallback,
+);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_value_inplace_impls!(
+    add = generic_avx2_add_value_inplace,
+    sub = generic_avx2_sub_value_inplace,
+    mul = generic_avx2_mul_value_inplace,
+    div = generic_avx2_div_value_inplace,
+    Avx2,
+    target_features = "avx2"
+);
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+define_value_inplace_impls!(
+    add = generic_avx512_add_value_inplace,
+    sub = generic_avx512_sub_value_inplace,
+    mul = generic_avx512_mul_value_inplace,
+    div = generic_avx512_div_value_inplace,
+    Avx512,
+    target_features = "avx512f",
+    "avx512bw"
+);
+#[cfg(target_arch = "aarch64")]
+define_value_inplace_impls!(
+    add = generic_neon_add_value_inplace,
+    sub = generic_neon_sub_value_inplace,
+    mul = generic_neon_mul_value_inplace,
+    div = generic_neon_div_value_inplace,
+    Neon,
+    target_features = "neon"
+);
+
+macro_rules! define_outer_impl {
+    (
+        $name:ident,
+        $imp:ident $(,)?
+        $(target_features = $($feat:expr $(,)?)+)?
+    ) => {
+        #[inline]
+        $(#[target_feature($(enable = $feat, )*)])*
+        #[doc = include_str!("../export_docs/arithmetic_outer.md")]
+        $(
+
+            #[doc = concat!("- ", $("**`+", $feat, "`** ", )*)]
+            #[doc = "CPU features are available at runtime. Running on hardware _without_ this feature available will cause immediate UB."]
+        )*
+        pub unsafe fn $name<T, B3>(a: &[T], b: &[T], result: &mut [B3])
+        where
+            T: Copy + IntoMemLoader<T>,
+            T::Loader: MemLoader<Value = T>,
+            crate::danger::$imp: SimdRegister<T>,
+            AutoMath: Math<T>,
+            for<'a> &'a mut [B3]: WriteOnlyBuffer<Item = T>,
+        {
+            generic_outer::<T, crate::danger::$imp, AutoMath, B3>(a, b, result)
+        }
+    };
+}
+
+define_outer_impl!(generic_fallback_outer, Fallback);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_outer_impl!(generic_avx2_outer, Avx2, target_features = "avx2");
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+define_outer_impl!(
+    generic_avx512_outer,
+    Avx512,
+    target_features = "avx512f",
+    "avx512bw"
+);
+#[cfg(target_arch = "aarch64")]
+define_outer_impl!(generic_neon_outer, Neon, target_features = "neon");
+
+macro_rules! define_axpy_impl {
+    (
+        $name:ident,
+        $imp:ident $(,)?
+        $(target_features = $($feat:expr $(,)?)+)?
+    ) => {
+        #[inline]
+        $(#[target_feature($(enable = $feat, )*)])*
+        #[doc = include_str!("../export_docs/arithmetic_axpy.md")]
+        $(
+
+            #[doc = concat!("- ", $("**`+", $feat, "`** ", )*)]
+            #[doc = "CPU features are available at runtime. Running on hardware _without_ this feature available will cause immediate UB."]
+        )*
+        pub unsafe fn $name<T>(alpha: T, x: &[T], y: &mut [T])
+        where
+            T: Copy,
+            crate::danger::$imp: SimdRegister<T>,
+            AutoMath: Math<T>,
+        {
+            generic_axpy::<T, crate::danger::$imp, AutoMath>(alpha, x, y)
+        }
+    };
+}
+
+define_axpy_impl!(generic_fallback_axpy, Fallback);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_axpy_impl!(generic_avx2_axpy, Avx2, target_features = "avx2");
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_axpy_impl!(
+    generic_avx2fma_axpy,
+    Avx2Fma,
+    target_features = "avx2",
+    "fma",
+);
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+define_axpy_impl!(
+    generic_avx512_axpy,
+    Avx512,
+    target_features = "avx512f",
+    "avx512bw"
+);
+#[cfg(target_arch = "aarch64")]
+define_axpy_impl!(generic_neon_axpy, Neon, target_features = "neon");
+
+macro_rules! define_linear_combine_impls {
+    (
+        linear_combine = $name:ident,
+        inplace = $inplace_name:ident,
+        $imp:ident $(,)?
+        $(target_features = $($feat:expr $(,)?)+)?
+    ) => {
+        #[inline]
+        $(#[target_feature($(enable = $feat, )*)])*
+        #[doc = include_str!("../export_docs/arithmetic_linear_combine.md")]
+        $(
+
+            #[doc = concat!("- ", $("**`+", $feat, "`** ", )*)]
+            #[doc = "CPU features are available at runtime. Running on hardware _without_ this feature available will cause immediate UB."]
+        )*
+        pub unsafe fn $name<T>(x: &[T], a: T, y: &[T], b: T, out: &mut [T])
+        where
+            T: Copy,
+            crate::danger::$imp: SimdRegister<T>,
+            AutoMath: Math<T>,
+        {
+            generic_linear_combine::<T, crate::danger::$imp, AutoMath>(x, a, y, b, out)
+        }
+
+        #[inline]
+        $(#[target_feature($(enable = $feat, )*)])*
+        #[doc = include_str!("../export_docs/arithmetic_linear_combine_inplace.md")]
+        $(
+
+            #[doc = concat!("- ", $("**`+", $feat, "`** ", )*)]
+            #[doc = "CPU features are available at runtime. Running on hardware _without_ this feature available will cause immediate UB."]
+        )*
+        pub unsafe fn $inplace_name<T>(x: &mut [T], a: T, y: &[T], b: T)
+        where
+            T: Copy,
+            crate::danger::$imp: SimdRegister<T>,
+            AutoMath: Math<T>,
+        {
+            generic_linear_combine_inplace::<T, crate::danger::$imp, AutoMath>(x, a, y, b)
+        }
+    };
+}
+
+define_linear_combine_impls!(
+    linear_combine = generic_fallback_linear_combine,
+    inplace = generic_fallback_linear_combine_inplace,
+    Fallback
+);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_linear_combine_impls!(
+    linear_combine = generic_avx2_linear_combine,
+    inplace = generic_avx2_linear_combine_inplace,
+    Avx2,
+    target_features = "avx2"
+);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_linear_combine_impls!(
+    linear_combine = generic_avx2fma_linear_combine,
+    inplace = generic_avx2fma_linear_combine_inplace,
+    Avx2Fma,
+    target_features = "avx2",
+    "fma",
+);
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+define_linear_combine_impls!(
+    linear_combine = generic_avx512_linear_combine,
+    inplace = generic_avx512_linear_combine_inplace,
+    Avx512,
+    target_features = "avx512f",
+    "avx512bw"
+);
+#[cfg(target_arch = "aarch64")]
+define_linear_combine_impls!(
+    linear_combine = generic_neon_linear_combine,
+    inplace = generic_neon_linear_combine_inplace,
+    Neon,
+    target_features = "neon"
+);
+
+macro_rules! define_recip_inplace_impl {
+    (
+        $name:ident,
+        $imp:ident $(,)?
+        $(target_features = $($feat:expr $(,)?)+)?
+    ) => {
+        #[inline]
+        $(#[target_feature($(enable = $feat, )*)])*
+        #[doc = include_str!("../export_docs/arithmetic_recip_inplace.md")]
+        $(
+
+            #[doc = concat!("- ", $("**`+", $feat, "`** ", )*)]
+            #[doc = "CPU features are available at runtime. Running on hardware _without_ this feature available will cause immediate UB."]
+        )*
+        pub unsafe fn $name<T>(a: &mut [T])
+        where
+            T: Copy,
+            crate::danger::$imp: SimdRegister<T>,
+            AutoMath: Math<T>,
+        {
+            generic_recip_inplace::<T, crate::danger::$imp, AutoMath>(a)
+        }
+    };
+}
+
+define_recip_inplace_impl!(generic_fallback_recip_inplace, Fallback);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_recip_inplace_impl!(generic_avx2_recip_inplace, Avx2, target_features = "avx2");
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+define_recip_inplace_impl!(
+    generic_avx512_recip_inplace,
+    Avx512,
+    target_features = "avx512f",
+    "avx512bw"
+);
+#[cfg(target_arch = "aarch64")]
+define_recip_inplace_impl!(generic_neon_recip_inplace, Neon, target_features = "neon");
+
+macro_rules! define_momentum_update_impl {
+    (
+        $name:ident,
+        $imp:ident $(,)?
+        $(target_features = $($feat:expr $(,)?)+)?
+    ) => {
+        #[inline]
+        $(#[target_feature($(enable = $feat, )*)])*
+        #[doc = include_str!("../export_docs/arithmetic_momentum_update.md")]
+        $(
+
+            #[doc = concat!("- ", $("**`+", $feat, "`** ", )*)]
+            #[doc = "CPU features are available at runtime. Running on hardware _without_ this feature available will cause immediate UB."]
+        )*
+        pub unsafe fn $name<T>(
+            velocity: &mut [T],
+            gradient: &[T],
+            momentum: T,
+            learning_rate: T,
+            params: &mut [T],
+        )
+        where
+            T: Copy,
+            crate::danger::$imp: SimdRegister<T>,
+            AutoMath: Math<T>,
+        {
+            generic_momentum_update::<T, crate::danger::$imp, AutoMath>(
+                velocity,
+                gradient,
+                momentum,
+                learning_rate,
+                params,
+            )
+        }
+    };
+}
+
+define_momentum_update_impl!(generic_fallback_momentum_update, Fallback);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_momentum_update_impl!(
+    generic_avx2_momentum_update,
+    Avx2,
+    target_features = "avx2"
+);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_momentum_update_impl!(
+    generic_avx2fma_momentum_update,
+    Avx2Fma,
+    target_features = "avx2",
+    "fma",
+);
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+define_momentum_update_impl!(
+    generic_avx512_momentum_update,
+    Avx512,
+    target_features = "avx512f",
+    "avx512bw"
+);
+#[cfg(target_arch = "aarch64")]
+define_momentum_update_impl!(
+    generic_neon_momentum_update,
+    Neon,
+    target_features = "neon"
+);
+
+macro_rules! define_hadamard_transform_impl {
+    (
+        $name:ident,
+        $imp:ident $(,)?
+        $(target_features = $($feat:expr $(,)?)+)?
+    ) => {
+        #[inline]
+        $(#[target_feature($(enable = $feat, )*)])*
+        #[doc = include_str!("../export_docs/arithmetic_hadamard_transform_inplace.md")]
+        $(
+
+            #[doc = concat!("- ", $("**`+", $feat, "`** ", )*)]
+            #[doc = "CPU features are available at runtime. Running on hardware _without_ this feature available will cause immediate UB."]
+        )*
+        pub unsafe fn $name<T>(a: &mut [T])
+        where
+            T: Copy,
+            crate::danger::$imp: SimdRegister<T>,
+            AutoMath: Math<T>,
+        {
+            generic_hadamard_transform_inplace::<T, crate::danger::$imp, AutoMath>(a)
+        }
+    };
+}
+
+define_hadamard_transform_impl!(generic_fallback_hadamard_transform_inplace, Fallback);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_hadamard_transform_impl!(
+    generic_avx2_hadamard_transform_inplace,
+    Avx2,
+    target_features = "avx2"
+);
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+define_hadamard_transform_impl!(
+    generic_avx512_hadamard_transform_inplace,
+    Avx512,
+    target_features = "avx512f",
+    "avx512bw"
+);
+#[cfg(target_arch = "aarch64")]
+define_hadamard_transform_impl!(
+    generic_neon_hadamard_transform_inplace,
+    Neon,
+    target_features = "neon"
+);
+
+macro_rules! define_diff_impl {
+    (
+        $name:ident,
+        $imp:ident $(,)?
+        $(target_features = $($feat:expr $(,)?)+)?
+    ) => {
+        #[inline]
+        $(#[target_feature($(enable = $feat, )*)])*
+        #[doc = include_str!("../export_docs/arithmetic_diff.md")]
+        $(
+
+            #[doc = concat!("- ", $("**`+", $feat, "`** ", )*)]
+            #[doc = "CPU features are available at runtime. Running on hardware _without_ this feature available will cause immediate UB."]
+        )*
+        pub unsafe fn $name<T, B2>(x: &[T], result: &mut [B2])
+        where
+            T: Copy,
+            crate::danger::$imp: SimdRegister<T>,
+            AutoMath: Math<T>,
+            for<'a> &'a mut [B2]: WriteOnlyBuffer<Item = T>,
+        {
+            generic_diff::<T, crate::danger::$imp, AutoMath, B2>(x, result)
+        }
+    };
+}
+
+define_diff_impl!(generic_fallback_diff, Fallback);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_diff_impl!(generic_avx2_diff, Avx2, target_features = "avx2");
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+define_diff_impl!(
+    generic_avx512_diff,
+    Avx512,
+    target_features = "avx512f",
+    "avx512bw"
+);
+#[cfg(target_arch = "aarch64")]
+define_diff_impl!(generic_neon_diff, Neon, target_features = "neon");
+
+macro_rules! define_quat_mul_impl {
+    (
+        $name:ident,
+        $imp:ident $(,)?
+        $(target_features = $($feat:expr $(,)?)+)?
+    ) => {
+        #[inline]
+        $(#[target_feature($(enable = $feat, )*)])*
+        #[doc = include_str!("../export_docs/arithmetic_quat_mul.md")]
+        $(
+
+            #[doc = concat!("- ", $("**`+", $feat, "`** ", )*)]
+            #[doc = "CPU features are available at runtime. Running on hardware _without_ this feature available will cause immediate UB."]
+        )*
+        pub unsafe fn $name<T>(a: &[T], b: &[T], out: &mut [T])
+        where
+            T: Copy,
+            crate::danger::$imp: SimdRegister<T>,
+            AutoMath: Math<T>,
+        {
+            generic_quat_mul::<T, crate::danger::$imp, AutoMath>(a, b, out)
+        }
+    };
+}
+
+define_quat_mul_impl!(generic_fallback_quat_mul, Fallback);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_quat_mul_impl!(generic_avx2_quat_mul, Avx2, target_features = "avx2");
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_quat_mul_impl!(
+    generic_avx2fma_quat_mul,
+    Avx2Fma,
+    target_features = "avx2",
+    "fma",
+);
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+define_quat_mul_impl!(
+    generic_avx512_quat_mul,
+    Avx512,
+    target_features = "avx512f",
+    "avx512bw"
+);
+#[cfg(target_arch = "aarch64")]
+define_quat_mul_impl!(generic_neon_quat_mul, Neon, target_features = "neon");
+
 #[cfg(test)]
 mod tests {
     use super::*;
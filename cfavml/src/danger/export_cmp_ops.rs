@@ -5,16 +5,28 @@
 
 use crate::buffer::WriteOnlyBuffer;
 use crate::danger::{
+    generic_cmp_argmax,
+    generic_cmp_argmin,
+    generic_cmp_clamp,
+    generic_cmp_clamp_inplace,
     generic_cmp_eq_vertical,
+    generic_cmp_gt_bitmask,
     generic_cmp_gt_vertical,
     generic_cmp_gte_vertical,
     generic_cmp_lt_vertical,
     generic_cmp_lte_vertical,
     generic_cmp_max,
+    generic_cmp_max_columns,
+    generic_cmp_max_cumulative_inplace,
+    generic_cmp_max_floor_counting,
     generic_cmp_max_vertical,
+    generic_cmp_max_with_count,
     generic_cmp_min,
+    generic_cmp_min_columns,
     generic_cmp_min_vertical,
+    generic_cmp_min_with_count,
     generic_cmp_neq_vertical,
+    generic_cmp_top_k,
     SimdRegister,
 };
 use crate::math::{AutoMath, Math};
@@ -69,6 +81,7 @@ macro_rules! define_extra_horizontal_op {
         $(target_features = $($feat:expr $(,)?)+)?
     ) => {
         #[inline]
+        #[must_use]
         $(#[target_feature($(enable = $feat, )*)])*
         #[doc = include_str!($horizontal_doc)]
         $(
@@ -91,6 +104,265 @@ macro_rules! define_extra_horizontal_op {
     };
 }
 
+macro_rules! define_with_count_op {
+    (
+        name = $name:ident,
+        op = $op:ident,
+        doc = $doc:expr,
+        $imp:ident $(,)?
+        $(target_features = $($feat:expr $(,)?)+)?
+    ) => {
+        #[inline]
+        #[must_use]
+        $(#[target_feature($(enable = $feat, )*)])*
+        #[doc = include_str!($doc)]
+        $(
+
+            #[doc = concat!("- ", $("**`+", $feat, "`** ", )*)]
+            #[doc = "CPU features are available at runtime. Running on hardware _without_ this feature available will cause immediate UB."]
+        )*
+        pub unsafe fn $name<T, B1>(
+            a: B1,
+        ) -> (T, usize)
+        where
+            T: Copy,
+            B1: IntoMemLoader<T> + Copy,
+            B1::Loader: MemLoader<Value = T>,
+            crate::danger::$imp: SimdRegister<T>,
+            AutoMath: Math<T>,
+        {
+            $op::<T, crate::danger::$imp, AutoMath, B1>(a)
+        }
+    };
+}
+
+macro_rules! define_arg_op {
+    (
+        name = $name:ident,
+        op = $op:ident,
+        doc = $doc:expr,
+        $imp:ident $(,)?
+        $(target_features = $($feat:expr $(,)?)+)?
+    ) => {
+        #[inline]
+        #[must_use]
+        $(#[target_feature($(enable = $feat, )*)])*
+        #[doc = include_str!($doc)]
+        $(
+
+            #[doc = concat!("- ", $("**`+", $feat, "`** ", )*)]
+            #[doc = "CPU features are available at runtime. Running on hardware _without_ this feature available will cause immediate UB."]
+        )*
+        pub unsafe fn $name<T>(
+            a: &[T],
+        ) -> Option<(usize, T)>
+        where
+            T: Copy,
+            crate::danger::$imp: SimdRegister<T>,
+            AutoMath: Math<T>,
+        {
+            $op::<T, crate::danger::$imp, AutoMath>(a)
+        }
+    };
+}
+
+macro_rules! define_top_k_op {
+    (
+        name = $name:ident,
+        op = $op:ident,
+        doc = $doc:expr,
+        $imp:ident $(,)?
+        $(target_features = $($feat:expr $(,)?)+)?
+    ) => {
+        #[inline]
+        $(#[target_feature($(enable = $feat, )*)])*
+        #[doc = include_str!($doc)]
+        $(
+
+            #[doc = concat!("- ", $("**`+", $feat, "`** ", )*)]
+            #[doc = "CPU features are available at runtime. Running on hardware _without_ this feature available will cause immediate UB."]
+        )*
+        pub unsafe fn $name<T>(
+            a: &[T],
+            indices: &mut [usize],
+            values: &mut [T],
+        ) -> usize
+        where
+            T: Copy,
+            crate::danger::$imp: SimdRegister<T>,
+            AutoMath: Math<T>,
+        {
+            $op::<T, crate::danger::$imp, AutoMath>(a, indices, values)
+        }
+    };
+}
+
+macro_rules! define_floor_counting_op {
+    (
+        name = $name:ident,
+        op = $op:ident,
+        doc = $doc:expr,
+        $imp:ident $(,)?
+        $(target_features = $($feat:expr $(,)?)+)?
+    ) => {
+        #[inline]
+        $(#[target_feature($(enable = $feat, )*)])*
+        #[doc = include_str!($doc)]
+        $(
+
+            #[doc = concat!("- ", $("**`+", $feat, "`** ", )*)]
+            #[doc = "CPU features are available at runtime. Running on hardware _without_ this feature available will cause immediate UB."]
+        )*
+        pub unsafe fn $name<T>(
+            a: &mut [T],
+            floor: T,
+        ) -> usize
+        where
+            T: Copy,
+            crate::danger::$imp: SimdRegister<T>,
+            AutoMath: Math<T>,
+        {
+            $op::<T, crate::danger::$imp, AutoMath>(a, floor)
+        }
+    };
+}
+
+macro_rules! define_inplace_op {
+    (
+        name = $name:ident,
+        op = $op:ident,
+        doc = $doc:expr,
+        $imp:ident $(,)?
+        $(target_features = $($feat:expr $(,)?)+)?
+    ) => {
+        #[inline]
+        $(#[target_feature($(enable = $feat, )*)])*
+        #[doc = include_str!($doc)]
+        $(
+
+            #[doc = concat!("- ", $("**`+", $feat, "`** ", )*)]
+            #[doc = "CPU features are available at runtime. Running on hardware _without_ this feature available will cause immediate UB."]
+        )*
+        pub unsafe fn $name<T>(a: &mut [T])
+        where
+            T: Copy,
+            crate::danger::$imp: SimdRegister<T>,
+            AutoMath: Math<T>,
+        {
+            $op::<T, crate::danger::$imp, AutoMath>(a)
+        }
+    };
+}
+
+macro_rules! define_columns_op {
+    (
+        name = $name:ident,
+        op = $op:ident,
+        doc = $doc:expr,
+        $imp:ident $(,)?
+        $(target_features = $($feat:expr $(,)?)+)?
+    ) => {
+        #[inline]
+        $(#[target_feature($(enable = $feat, )*)])*
+        #[doc = include_str!($doc)]
+        $(
+
+            #[doc = concat!("- ", $("**`+", $feat, "`** ", )*)]
+            #[doc = "CPU features are available at runtime. Running on hardware _without_ this feature available will cause immediate UB."]
+        )*
+        pub unsafe fn $name<T>(matrix: &[T], result: &mut [T])
+        where
+            T: Copy,
+            crate::danger::$imp: SimdRegister<T>,
+            AutoMath: Math<T>,
+        {
+            $op::<T, crate::danger::$imp, AutoMath>(matrix, result)
+        }
+    };
+}
+
+macro_rules! define_clamp_op {
+    (
+        clamp = $name:ident,
+        inplace = $inplace_name:ident,
+        $imp:ident $(,)?
+        $(target_features = $($feat:expr $(,)?)+)?
+    ) => {
+        #[inline]
+        $(#[target_feature($(enable = $feat, )*)])*
+        #[doc = include_str!("../export_docs/cmp_clamp.md")]
+        $(
+
+            #[doc = concat!("- ", $("**`+", $feat, "`** ", )*)]
+            #[doc = "CPU features are available at runtime. Running on hardware _without_ this feature available will cause immediate UB."]
+        )*
+        pub unsafe fn $name<T>(x: &[T], lo: T, hi: T, result: &mut [T])
+        where
+            T: Copy,
+            crate::danger::$imp: SimdRegister<T>,
+            AutoMath: Math<T>,
+        {
+            generic_cmp_clamp::<T, crate::danger::$imp, AutoMath>(x, lo, hi, result)
+        }
+
+        #[inline]
+        $(#[target_feature($(enable = $feat, )*)])*
+        #[doc = include_str!("../export_docs/cmp_clamp_inplace.md")]
+        $(
+
+            #[doc = concat!("- ", $("**`+", $feat, "`** ", )*)]
+            #[doc = "CPU features are available at runtime. Running on hardware _without_ this feature available will cause immediate UB."]
+        )*
+        pub unsafe fn $inplace_name<T>(a: &mut [T], lo: T, hi: T)
+        where
+            T: Copy,
+            crate::danger::$imp: SimdRegister<T>,
+            AutoMath: Math<T>,
+        {
+            generic_cmp_clamp_inplace::<T, crate::danger::$imp, AutoMath>(a, lo, hi)
+        }
+    };
+}
+
+macro_rules! define_bitmask_op {
+    (
+        name = $name:ident,
+        op = $op:ident,
+        doc = $doc:expr,
+        $imp:ident $(,)?
+        $(target_features = $($feat:expr $(,)?)+)?
+    ) => {
+        #[inline]
+        $(#[target_feature($(enable = $feat, )*)])*
+        #[doc = include_str!($doc)]
+        $(
+
+            #[doc = concat!("- ", $("**`+", $feat, "`** ", )*)]
+            #[doc = "CPU features are available at runtime. Running on hardware _without_ this feature available will cause immediate UB."]
+        )*
+        pub unsafe fn $name<T, B1, B2>(
+            a: B1,
+            b: B2,
+            result: &mut [u64],
+        )
+        where
+            T: Copy,
+            B1: IntoMemLoader<T>,
+            B1::Loader: MemLoader<Value = T>,
+            B2: IntoMemLoader<T>,
+            B2::Loader: MemLoader<Value = T>,
+            crate::danger::$imp: SimdRegister<T>,
+            AutoMath: Math<T>,
+        {
+            $op::<T, crate::danger::$imp, AutoMath, B1, B2>(
+                a,
+                b,
+                result,
+            )
+        }
+    };
+}
+
 // OP-max
 define_op!(
     name = generic_fallback_cmp_max_vertical,
@@ -124,6 +396,138 @@ define_op!(
     target_features = "neon"
 );
 
+// OP-max-with-count
+define_with_count_op!(
+    name = generic_fallback_cmp_max_with_count,
+    op = generic_cmp_max_with_count,
+    doc = "../export_docs/cmp_max_with_count.md",
+    Fallback,
+);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_with_count_op!(
+    name = generic_avx2_cmp_max_with_count,
+    op = generic_cmp_max_with_count,
+    doc = "../export_docs/cmp_max_with_count.md",
+    Avx2,
+    target_features = "avx2"
+);
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+define_with_count_op!(
+    name = generic_avx512_cmp_max_with_count,
+    op = generic_cmp_max_with_count,
+    doc = "../export_docs/cmp_max_with_count.md",
+    Avx512,
+    target_features = "avx512f",
+    "avx512bw"
+);
+#[cfg(target_arch = "aarch64")]
+define_with_count_op!(
+    name = generic_neon_cmp_max_with_count,
+    op = generic_cmp_max_with_count,
+    doc = "../export_docs/cmp_max_with_count.md",
+    Neon,
+    target_features = "neon"
+);
+
+// OP-max-cumulative
+define_inplace_op!(
+    name = generic_fallback_cmp_max_cumulative_inplace,
+    op = generic_cmp_max_cumulative_inplace,
+    doc = "../export_docs/cmp_max_cumulative_inplace.md",
+    Fallback,
+);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_inplace_op!(
+    name = generic_avx2_cmp_max_cumulative_inplace,
+    op = generic_cmp_max_cumulative_inplace,
+    doc = "../export_docs/cmp_max_cumulative_inplace.md",
+    Avx2,
+    target_features = "avx2"
+);
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+define_inplace_op!(
+    name = generic_avx512_cmp_max_cumulative_inplace,
+    op = generic_cmp_max_cumulative_inplace,
+    doc = "../export_docs/cmp_max_cumulative_inplace.md",
+    Avx512,
+    target_features = "avx512f",
+    "avx512bw"
+);
+#[cfg(target_arch = "aarch64")]
+define_inplace_op!(
+    name = generic_neon_cmp_max_cumulative_inplace,
+    op = generic_cmp_max_cumulative_inplace,
+    doc = "../export_docs/cmp_max_cumulative_inplace.md",
+    Neon,
+    target_features = "neon"
+);
+
+// OP-max-columns
+define_columns_op!(
+    name = generic_fallback_cmp_max_columns,
+    op = generic_cmp_max_columns,
+    doc = "../export_docs/cmp_max_columns.md",
+    Fallback,
+);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_columns_op!(
+    name = generic_avx2_cmp_max_columns,
+    op = generic_cmp_max_columns,
+    doc = "../export_docs/cmp_max_columns.md",
+    Avx2,
+    target_features = "avx2"
+);
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+define_columns_op!(
+    name = generic_avx512_cmp_max_columns,
+    op = generic_cmp_max_columns,
+    doc = "../export_docs/cmp_max_columns.md",
+    Avx512,
+    target_features = "avx512f",
+    "avx512bw"
+);
+#[cfg(target_arch = "aarch64")]
+define_columns_op!(
+    name = generic_neon_cmp_max_columns,
+    op = generic_cmp_max_columns,
+    doc = "../export_docs/cmp_max_columns.md",
+    Neon,
+    target_features = "neon"
+);
+
+// OP-max-floor-counting
+define_floor_counting_op!(
+    name = generic_fallback_cmp_max_floor_counting,
+    op = generic_cmp_max_floor_counting,
+    doc = "../export_docs/cmp_max_floor_counting.md",
+    Fallback,
+);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_floor_counting_op!(
+    name = generic_avx2_cmp_max_floor_counting,
+    op = generic_cmp_max_floor_counting,
+    doc = "../export_docs/cmp_max_floor_counting.md",
+    Avx2,
+    target_features = "avx2"
+);
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+define_floor_counting_op!(
+    name = generic_avx512_cmp_max_floor_counting,
+    op = generic_cmp_max_floor_counting,
+    doc = "../export_docs/cmp_max_floor_counting.md",
+    Avx512,
+    target_features = "avx512f",
+    "avx512bw"
+);
+#[cfg(target_arch = "aarch64")]
+define_floor_counting_op!(
+    name = generic_neon_cmp_max_floor_counting,
+    op = generic_cmp_max_floor_counting,
+    doc = "../export_docs/cmp_max_floor_counting.md",
+    Neon,
+    target_features = "neon"
+);
+
 // OP-max-horizontal
 define_extra_horizontal_op!(
     horizontal_name = generic_fallback_cmp_max,
@@ -157,6 +561,72 @@ define_extra_horizontal_op!(
     target_features = "neon"
 );
 
+// OP-argmax
+define_arg_op!(
+    name = generic_fallback_cmp_argmax,
+    op = generic_cmp_argmax,
+    doc = "../export_docs/cmp_argmax_horizontal.md",
+    Fallback,
+);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_arg_op!(
+    name = generic_avx2_cmp_argmax,
+    op = generic_cmp_argmax,
+    doc = "../export_docs/cmp_argmax_horizontal.md",
+    Avx2,
+    target_features = "avx2"
+);
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+define_arg_op!(
+    name = generic_avx512_cmp_argmax,
+    op = generic_cmp_argmax,
+    doc = "../export_docs/cmp_argmax_horizontal.md",
+    Avx512,
+    target_features = "avx512f",
+    "avx512bw"
+);
+#[cfg(target_arch = "aarch64")]
+define_arg_op!(
+    name = generic_neon_cmp_argmax,
+    op = generic_cmp_argmax,
+    doc = "../export_docs/cmp_argmax_horizontal.md",
+    Neon,
+    target_features = "neon"
+);
+
+// OP-top-k
+define_top_k_op!(
+    name = generic_fallback_cmp_top_k,
+    op = generic_cmp_top_k,
+    doc = "../export_docs/cmp_top_k.md",
+    Fallback,
+);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_top_k_op!(
+    name = generic_avx2_cmp_top_k,
+    op = generic_cmp_top_k,
+    doc = "../export_docs/cmp_top_k.md",
+    Avx2,
+    target_features = "avx2"
+);
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+define_top_k_op!(
+    name = generic_avx512_cmp_top_k,
+    op = generic_cmp_top_k,
+    doc = "../export_docs/cmp_top_k.md",
+    Avx512,
+    target_features = "avx512f",
+    "avx512bw"
+);
+#[cfg(target_arch = "aarch64")]
+define_top_k_op!(
+    name = generic_neon_cmp_top_k,
+    op = generic_cmp_top_k,
+    doc = "../export_docs/cmp_top_k.md",
+    Neon,
+    target_features = "neon"
+);
+
 // OP-min
 define_op!(
     name = generic_fallback_cmp_min_vertical,
@@ -190,6 +660,39 @@ define_op!(
     target_features = "neon"
 );
 
+// OP-min-with-count
+define_with_count_op!(
+    name = generic_fallback_cmp_min_with_count,
+    op = generic_cmp_min_with_count,
+    doc = "../export_docs/cmp_min_with_count.md",
+    Fallback,
+);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_with_count_op!(
+    name = generic_avx2_cmp_min_with_count,
+    op = generic_cmp_min_with_count,
+    doc = "../export_docs/cmp_min_with_count.md",
+    Avx2,
+    target_features = "avx2"
+);
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+define_with_count_op!(
+    name = generic_avx512_cmp_min_with_count,
+    op = generic_cmp_min_with_count,
+    doc = "../export_docs/cmp_min_with_count.md",
+    Avx512,
+    target_features = "avx512f",
+    "avx512bw"
+);
+#[cfg(target_arch = "aarch64")]
+define_with_count_op!(
+    name = generic_neon_cmp_min_with_count,
+    op = generic_cmp_min_with_count,
+    doc = "../export_docs/cmp_min_with_count.md",
+    Neon,
+    target_features = "neon"
+);
+
 // OP-min-horizontal
 define_extra_horizontal_op!(
     horizontal_name = generic_fallback_cmp_min,
@@ -223,6 +726,68 @@ define_extra_horizontal_op!(
     target_features = "neon"
 );
 
+// OP-min-columns
+define_columns_op!(
+    name = generic_fallback_cmp_min_columns,
+    op = generic_cmp_min_columns,
+    doc = "../export_docs/cmp_min_columns.md",
+    Fallback,
+);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_columns_op!(
+    name = generic_avx2_cmp_min_columns,
+    op = generic_cmp_min_columns,
+    doc = "../export_docs/cmp_min_columns.md",
+    Avx2,
+    target_features = "avx2"
+);
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+define_columns_op!(
+    name = generic_avx512_cmp_min_columns,
+    op = generic_cmp_min_columns,
+    doc = "../export_docs/cmp_min_columns.md",
+    Avx512,
+    target_features = "avx512f",
+    "avx512bw"
+);
+#[cfg(target_arch = "aarch64")]
+define_columns_op!(
+    name = generic_neon_cmp_min_columns,
+    op = generic_cmp_min_columns,
+    doc = "../export_docs/cmp_min_columns.md",
+    Neon,
+    target_features = "neon"
+);
+
+// OP-clamp
+define_clamp_op!(
+    clamp = generic_fallback_cmp_clamp,
+    inplace = generic_fallback_cmp_clamp_inplace,
+    Fallback,
+);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_clamp_op!(
+    clamp = generic_avx2_cmp_clamp,
+    inplace = generic_avx2_cmp_clamp_inplace,
+    Avx2,
+    target_features = "avx2"
+);
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+define_clamp_op!(
+    clamp = generic_avx512_cmp_clamp,
+    inplace = generic_avx512_cmp_clamp_inplace,
+    Avx512,
+    target_features = "avx512f",
+    "avx512bw"
+);
+#[cfg(target_arch = "aarch64")]
+define_clamp_op!(
+    clamp = generic_neon_cmp_clamp,
+    inplace = generic_neon_cmp_clamp_inplace,
+    Neon,
+    target_features = "neon"
+);
+
 // OP-eq
 define_op!(
     name = generic_fallback_cmp_eq_vertical,
@@ -256,6 +821,39 @@ define_op!(
     target_features = "neon"
 );
 
+// OP-argmin
+define_arg_op!(
+    name = generic_fallback_cmp_argmin,
+    op = generic_cmp_argmin,
+    doc = "../export_docs/cmp_argmin_horizontal.md",
+    Fallback,
+);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_arg_op!(
+    name = generic_avx2_cmp_argmin,
+    op = generic_cmp_argmin,
+    doc = "../export_docs/cmp_argmin_horizontal.md",
+    Avx2,
+    target_features = "avx2"
+);
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+define_arg_op!(
+    name = generic_avx512_cmp_argmin,
+    op = generic_cmp_argmin,
+    doc = "../export_docs/cmp_argmin_horizontal.md",
+    Avx512,
+    target_features = "avx512f",
+    "avx512bw"
+);
+#[cfg(target_arch = "aarch64")]
+define_arg_op!(
+    name = generic_neon_cmp_argmin,
+    op = generic_cmp_argmin,
+    doc = "../export_docs/cmp_argmin_horizontal.md",
+    Neon,
+    target_features = "neon"
+);
+
 // OP-neq
 define_op!(
     name = generic_fallback_cmp_neq_vertical,
@@ -421,6 +1019,39 @@ define_op!(
     target_features = "neon"
 );
 
+// OP-gt-bitmask
+define_bitmask_op!(
+    name = generic_fallback_cmp_gt_bitmask,
+    op = generic_cmp_gt_bitmask,
+    doc = "../export_docs/cmp_gt_bitmask.md",
+    Fallback,
+);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_bitmask_op!(
+    name = generic_avx2_cmp_gt_bitmask,
+    op = generic_cmp_gt_bitmask,
+    doc = "../export_docs/cmp_gt_bitmask.md",
+    Avx2,
+    target_features = "avx2"
+);
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+define_bitmask_op!(
+    name = generic_avx512_cmp_gt_bitmask,
+    op = generic_cmp_gt_bitmask,
+    doc = "../export_docs/cmp_gt_bitmask.md",
+    Avx512,
+    target_features = "avx512f",
+    "avx512bw"
+);
+#[cfg(target_arch = "aarch64")]
+define_bitmask_op!(
+    name = generic_neon_cmp_gt_bitmask,
+    op = generic_cmp_gt_bitmask,
+    doc = "../export_docs/cmp_gt_bitmask.md",
+    Neon,
+    target_features = "neon"
+);
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -444,6 +1075,46 @@ mod tests {
                     );
                 }
 
+                #[test]
+                fn [< $variant _ $op _with_count_ $t >]() {
+                    let (mut l1, _) = crate::test_utils::get_sample_vectors::<$t>(533);
+                    for idx in [3, 266, 532] {
+                        l1[idx] = AutoMath::$op();
+                    }
+
+                    let (value, count) = unsafe { [< $variant _cmp_ $op _with_count >](&l1) };
+
+                    let expected_count = l1.iter()
+                        .filter(|v| AutoMath::cmp_eq(**v, AutoMath::$op()))
+                        .count();
+                    assert!(expected_count >= 3);
+                    assert_eq!(
+                        (value, count),
+                        (AutoMath::$op(), expected_count),
+                        "Routine result does not match expected",
+                    );
+                }
+
+                #[test]
+                fn [< $variant _arg $op _ $t >]() {
+                    let (mut l1, _) = crate::test_utils::get_sample_vectors::<$t>(533);
+                    for idx in [266, 3, 532] {
+                        l1[idx] = AutoMath::$op();
+                    }
+
+                    let result = unsafe { [< $variant _cmp_arg $op >](&l1) };
+
+                    let expected_index = l1.iter()
+                        .position(|v| AutoMath::cmp_eq(*v, AutoMath::$op()))
+                        .unwrap();
+                    assert!(expected_index <= 3);
+                    assert_eq!(
+                        result,
+                        Some((expected_index, AutoMath::$op())),
+                        "Routine result does not match expected",
+                    );
+                }
+
                 #[test]
                 fn [< $variant _ $op _value_ $t >]() {
                     let (l1, _) = crate::test_utils::get_sample_vectors::<$t>(533);
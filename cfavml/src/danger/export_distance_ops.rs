@@ -4,13 +4,48 @@
 //! dot product are more generic than simply vector search.
 
 use crate::danger::{
+    generic_bray_curtis,
+    generic_canberra,
+    generic_clip_norm,
+    generic_col_dot,
+    generic_complex_dot_planar,
     generic_cosine,
+    generic_cosine_fast,
+    generic_cosine_similarity,
+    generic_dense_forward,
     generic_dot,
+    generic_dot_dd,
+    generic_dot_dense_aligned,
+    generic_dot_diff,
+    generic_dot_f32_compensated,
+    generic_dot_f32_widen,
+    generic_dot_progress,
+    generic_dot_self_and_cross,
+    generic_dot_single_register,
+    generic_dot_skip_zero,
+    generic_dot_streaming_both,
+    generic_dot_stride2,
+    generic_dot_strided,
+    generic_dot_wrapping,
+    generic_frobenius_dot,
+    generic_masked_dot,
+    generic_matvec,
+    generic_minkowski,
+    generic_minmax_normalize_columns_inplace,
+    generic_normalize_inplace,
+    generic_pairwise_dot,
+    generic_pairwise_squared_euclidean,
+    generic_row_norms,
+    generic_scaled_dot,
     generic_squared_euclidean,
+    generic_squared_euclidean_batch,
+    generic_squared_euclidean_batch4,
     generic_squared_norm,
+    generic_weighted_squared_euclidean,
+    DotResult,
     SimdRegister,
 };
-use crate::math::{AutoMath, Math};
+use crate::math::{AutoMath, Math, StdMath};
 use crate::mem_loader::{IntoMemLoader, MemLoader};
 
 macro_rules! define_dist_impl {
@@ -22,6 +57,7 @@ macro_rules! define_dist_impl {
         $(target_features = $($feat:expr $(,)?)+)?
     ) => {
         #[inline]
+        #[must_use]
         $(#[target_feature($(enable = $feat, )*)])*
         #[doc = include_str!($doc)]
         $(
@@ -88,6 +124,47 @@ define_dist_impl!(
     target_features = "neon",
 );
 
+define_dist_impl!(
+    name = generic_fallback_cosine_similarity,
+    op = generic_cosine_similarity,
+    doc = "../export_docs/dist_cosine_similarity.md",
+    Fallback,
+);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_dist_impl!(
+    name = generic_avx2_cosine_similarity,
+    op = generic_cosine_similarity,
+    doc = "../export_docs/dist_cosine_similarity.md",
+    Avx2,
+    target_features = "avx2",
+);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_dist_impl!(
+    name = generic_avx2fma_cosine_similarity,
+    op = generic_cosine_similarity,
+    doc = "../export_docs/dist_cosine_similarity.md",
+    Avx2Fma,
+    target_features = "avx2",
+    "fma"
+);
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+define_dist_impl!(
+    name = generic_avx512_cosine_similarity,
+    op = generic_cosine_similarity,
+    doc = "../export_docs/dist_cosine_similarity.md",
+    Avx512,
+    target_features = "avx512f",
+    "avx512bw"
+);
+#[cfg(target_arch = "aarch64")]
+define_dist_impl!(
+    name = generic_neon_cosine_similarity,
+    op = generic_cosine_similarity,
+    doc = "../export_docs/dist_cosine_similarity.md",
+    Neon,
+    target_features = "neon",
+);
+
 define_dist_impl!(
     name = generic_fallback_dot,
     op = generic_dot,
@@ -129,6 +206,91 @@ define_dist_impl!(
     target_features = "neon"
 );
 
+#[inline(always)]
+/// Returns if `T` is a floating point type, integer types never use a fused multiply add.
+fn is_float<T: 'static>() -> bool {
+    use core::any::TypeId;
+
+    TypeId::of::<T>() == TypeId::of::<f32>() || TypeId::of::<T>() == TypeId::of::<f64>()
+}
+
+macro_rules! define_dot_with_diagnostics_impl {
+    (
+        $name:ident,
+        $imp:ident,
+        reduction_order = $order:expr,
+        fused = $fused:literal $(,)?
+        $(target_features = $($feat:expr $(,)?)+)?
+    ) => {
+        #[inline]
+        #[must_use]
+        $(#[target_feature($(enable = $feat, )*)])*
+        #[doc = include_str!("../export_docs/dist_dot_with_diagnostics.md")]
+        $(
+
+            #[doc = concat!("- ", $("**`+", $feat, "`** ", )*)]
+            #[doc = "CPU features are available at runtime. Running on hardware _without_ this feature available will cause immediate UB."]
+        )*
+        pub unsafe fn $name<T, B1, B2>(a: B1, b: B2) -> DotResult<T>
+        where
+            T: Copy + 'static,
+            B1: IntoMemLoader<T>,
+            B1::Loader: MemLoader<Value = T>,
+            B2: IntoMemLoader<T>,
+            B2::Loader: MemLoader<Value = T>,
+            crate::danger::$imp: SimdRegister<T>,
+            AutoMath: Math<T>,
+        {
+            DotResult {
+                value: generic_dot::<T, crate::danger::$imp, AutoMath, _, _>(a, b),
+                reduction_order: $order,
+                used_fma: $fused && is_float::<T>(),
+            }
+        }
+    };
+}
+
+define_dot_with_diagnostics_impl!(
+    generic_fallback_dot_with_diagnostics,
+    Fallback,
+    reduction_order = "8 scalar accumulators (Fallback), summed pairwise, then a sequential scalar tail",
+    fused = false,
+);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_dot_with_diagnostics_impl!(
+    generic_avx2_dot_with_diagnostics,
+    Avx2,
+    reduction_order = "8 x 256-bit accumulators (AVX2), summed pairwise, then a horizontal register sum and a sequential scalar tail",
+    fused = false,
+    target_features = "avx2"
+);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_dot_with_diagnostics_impl!(
+    generic_avx2fma_dot_with_diagnostics,
+    Avx2Fma,
+    reduction_order = "8 x 256-bit accumulators (AVX2 + FMA), summed pairwise, then a horizontal register sum and a sequential scalar tail",
+    fused = true,
+    target_features = "avx2",
+    "fma"
+);
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+define_dot_with_diagnostics_impl!(
+    generic_avx512_dot_with_diagnostics,
+    Avx512,
+    reduction_order = "8 x 512-bit accumulators (AVX512), summed pairwise, then a horizontal register sum and a sequential scalar tail",
+    fused = true,
+    target_features = "avx512f",
+    "avx512bw"
+);
+#[cfg(target_arch = "aarch64")]
+define_dot_with_diagnostics_impl!(
+    generic_neon_dot_with_diagnostics,
+    Neon,
+    reduction_order = "8 x 128-bit accumulators (NEON), summed pairwise, then a horizontal register sum and a sequential scalar tail",
+    fused = true,
+    target_features = "neon"
+);
+
 define_dist_impl!(
     name = generic_fallback_squared_euclidean,
     op = generic_squared_euclidean,
@@ -170,48 +332,1666 @@ define_dist_impl!(
     target_features = "neon"
 );
 
-macro_rules! define_norm_impl {
+macro_rules! define_cosine_fast_impl {
     ($name:ident, $imp:ident $(,)? $(target_features = $($feat:expr $(,)?)+)?) => {
         #[inline]
+        #[must_use]
         $(#[target_feature($(enable = $feat, )*)])*
-        #[doc = include_str!("../export_docs/dist_norm.md")]
+        #[doc = include_str!("../export_docs/dist_cosine_fast.md")]
         $(
 
             #[doc = concat!("- ", $("**`+", $feat, "`** ", )*)]
             #[doc = "CPU features are available at runtime. Running on hardware _without_ this feature available will cause immediate UB."]
         )*
-        pub unsafe fn $name<T, B1>(a: B1) -> T
+        pub unsafe fn $name<B1, B2>(a: B1, b: B2) -> f32
+        where
+            B1: IntoMemLoader<f32>,
+            B1::Loader: MemLoader<Value = f32>,
+            B2: IntoMemLoader<f32>,
+            B2::Loader: MemLoader<Value = f32>,
+        {
+            generic_cosine_fast::<crate::danger::$imp, AutoMath, _, _>(a, b)
+        }
+    };
+}
+
+define_cosine_fast_impl!(f32_fallback_cosine_fast, Fallback);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_cosine_fast_impl!(f32_avx2_cosine_fast, Avx2, target_features = "avx2");
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_cosine_fast_impl!(
+    f32_avx2fma_cosine_fast,
+    Avx2Fma,
+    target_features = "avx2",
+    "fma",
+);
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+define_cosine_fast_impl!(
+    f32_avx512_cosine_fast,
+    Avx512,
+    target_features = "avx512f",
+    "avx512bw"
+);
+#[cfg(target_arch = "aarch64")]
+define_cosine_fast_impl!(f32_neon_cosine_fast, Neon, target_features = "neon");
+
+macro_rules! define_dot_f32_widen_impl {
+    ($name:ident, $imp:ident $(,)? $(target_features = $($feat:expr $(,)?)+)?) => {
+        #[inline]
+        #[must_use]
+        $(#[target_feature($(enable = $feat, )*)])*
+        #[doc = include_str!("../export_docs/dist_dot_f32_widen.md")]
+        $(
+
+            #[doc = concat!("- ", $("**`+", $feat, "`** ", )*)]
+            #[doc = "CPU features are available at runtime. Running on hardware _without_ this feature available will cause immediate UB."]
+        )*
+        pub unsafe fn $name(a: &[f32], b: &[f32]) -> f64 {
+            generic_dot_f32_widen::<crate::danger::$imp, AutoMath>(a, b)
+        }
+    };
+}
+
+define_dot_f32_widen_impl!(f32_fallback_dot_widen, Fallback);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_dot_f32_widen_impl!(f32_avx2_dot_widen, Avx2, target_features = "avx2");
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_dot_f32_widen_impl!(
+    f32_avx2fma_dot_widen,
+    Avx2Fma,
+    target_features = "avx2",
+    "fma",
+);
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+define_dot_f32_widen_impl!(
+    f32_avx512_dot_widen,
+    Avx512,
+    target_features = "avx512f",
+    "avx512bw"
+);
+#[cfg(target_arch = "aarch64")]
+define_dot_f32_widen_impl!(f32_neon_dot_widen, Neon, target_features = "neon");
+
+macro_rules! define_dot_f32_compensated_impl {
+    ($name:ident, $imp:ident $(,)? $(target_features = $($feat:expr $(,)?)+)?) => {
+        #[inline]
+        #[must_use]
+        $(#[target_feature($(enable = $feat, )*)])*
+        #[doc = include_str!("../export_docs/dist_dot_f32_compensated.md")]
+        $(
+
+            #[doc = concat!("- ", $("**`+", $feat, "`** ", )*)]
+            #[doc = "CPU features are available at runtime. Running on hardware _without_ this feature available will cause immediate UB."]
+        )*
+        pub unsafe fn $name(a: &[f32], b: &[f32]) -> f64 {
+            generic_dot_f32_compensated::<crate::danger::$imp>(a, b)
+        }
+    };
+}
+
+define_dot_f32_compensated_impl!(f32_fallback_dot_compensated, Fallback);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_dot_f32_compensated_impl!(
+    f32_avx2_dot_compensated,
+    Avx2,
+    target_features = "avx2"
+);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_dot_f32_compensated_impl!(
+    f32_avx2fma_dot_compensated,
+    Avx2Fma,
+    target_features = "avx2",
+    "fma",
+);
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+define_dot_f32_compensated_impl!(
+    f32_avx512_dot_compensated,
+    Avx512,
+    target_features = "avx512f",
+    "avx512bw"
+);
+#[cfg(target_arch = "aarch64")]
+define_dot_f32_compensated_impl!(
+    f32_neon_dot_compensated,
+    Neon,
+    target_features = "neon"
+);
+
+macro_rules! define_dot_dd_impl {
+    ($name:ident, $imp:ident $(,)? $(target_features = $($feat:expr $(,)?)+)?) => {
+        #[inline]
+        #[must_use]
+        $(#[target_feature($(enable = $feat, )*)])*
+        #[doc = include_str!("../export_docs/dist_dot_dd.md")]
+        $(
+
+            #[doc = concat!("- ", $("**`+", $feat, "`** ", )*)]
+            #[doc = "CPU features are available at runtime. Running on hardware _without_ this feature available will cause immediate UB."]
+        )*
+        pub unsafe fn $name<T, B1, B2>(a: B1, b: B2) -> (T, T)
+        where
+            T: Copy,
+            B1: IntoMemLoader<T>,
+            B1::Loader: MemLoader<Value = T>,
+            B2: IntoMemLoader<T>,
+            B2::Loader: MemLoader<Value = T>,
+            crate::danger::$imp: SimdRegister<T>,
+            StdMath: Math<T>,
+        {
+            // Fast math is free to re-associate the error-free transformations away,
+            // so the scalar roll-up must always use the standard math operations.
+            generic_dot_dd::<T, crate::danger::$imp, StdMath, _, _>(a, b)
+        }
+    };
+}
+
+// Only backends with a true fused multiply add can provide the error-free products.
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_dot_dd_impl!(
+    generic_avx2fma_dot_dd,
+    Avx2Fma,
+    target_features = "avx2",
+    "fma"
+);
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+define_dot_dd_impl!(
+    generic_avx512_dot_dd,
+    Avx512,
+    target_features = "avx512f",
+    "avx512bw"
+);
+#[cfg(target_arch = "aarch64")]
+define_dot_dd_impl!(generic_neon_dot_dd, Neon, target_features = "neon");
+
+macro_rules! define_scaled_dot_impl {
+    ($name:ident, $imp:ident $(,)? $(target_features = $($feat:expr $(,)?)+)?) => {
+        #[inline]
+        #[must_use]
+        $(#[target_feature($(enable = $feat, )*)])*
+        #[doc = include_str!("../export_docs/dist_scaled_dot.md")]
+        $(
+
+            #[doc = concat!("- ", $("**`+", $feat, "`** ", )*)]
+            #[doc = "CPU features are available at runtime. Running on hardware _without_ this feature available will cause immediate UB."]
+        )*
+        pub unsafe fn $name<T, B1, B2>(a: B1, b: B2, scale: T) -> T
         where
             T: Copy,
             B1: IntoMemLoader<T>,
             B1::Loader: MemLoader<Value = T>,
+            B2: IntoMemLoader<T>,
+            B2::Loader: MemLoader<Value = T>,
             crate::danger::$imp: SimdRegister<T>,
             AutoMath: Math<T>,
         {
-            generic_squared_norm::<T, crate::danger::$imp, AutoMath, _>(a)
+            generic_scaled_dot::<T, crate::danger::$imp, AutoMath, B1, B2>(a, b, scale)
         }
     };
 }
 
-define_norm_impl!(generic_fallback_squared_norm, Fallback);
+define_scaled_dot_impl!(generic_fallback_scaled_dot, Fallback);
 #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
-define_norm_impl!(generic_avx2_squared_norm, Avx2, target_features = "avx2");
+define_scaled_dot_impl!(generic_avx2_scaled_dot, Avx2, target_features = "avx2");
 #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
-define_norm_impl!(
-    generic_avx2fma_squared_norm,
+define_scaled_dot_impl!(
+    generic_avx2fma_scaled_dot,
     Avx2Fma,
     target_features = "avx2",
     "fma",
 );
 #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
-define_norm_impl!(
-    generic_avx512_squared_norm,
+define_scaled_dot_impl!(
+    generic_avx512_scaled_dot,
     Avx512,
     target_features = "avx512f",
     "avx512bw"
 );
 #[cfg(target_arch = "aarch64")]
-define_norm_impl!(generic_neon_squared_norm, Neon, target_features = "neon");
+define_scaled_dot_impl!(generic_neon_scaled_dot, Neon, target_features = "neon");
+
+macro_rules! define_dot_wrapping_impl {
+    ($name:ident, $imp:ident $(,)? $(target_features = $($feat:expr $(,)?)+)?) => {
+        #[inline]
+        #[must_use]
+        $(#[target_feature($(enable = $feat, )*)])*
+        #[doc = include_str!("../export_docs/dist_dot_wrapping.md")]
+        $(
+
+            #[doc = concat!("- ", $("**`+", $feat, "`** ", )*)]
+            #[doc = "CPU features are available at runtime. Running on hardware _without_ this feature available will cause immediate UB."]
+        )*
+        pub unsafe fn $name<T>(buf: &[T], start: usize, len: usize, kernel: &[T]) -> T
+        where
+            T: Copy,
+            crate::danger::$imp: SimdRegister<T>,
+            AutoMath: Math<T>,
+        {
+            generic_dot_wrapping::<T, crate::danger::$imp, AutoMath>(
+                buf,
+                start,
+                len,
+                kernel,
+            )
+        }
+    };
+}
+
+define_dot_wrapping_impl!(generic_fallback_dot_wrapping, Fallback);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_dot_wrapping_impl!(generic_avx2_dot_wrapping, Avx2, target_features = "avx2");
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_dot_wrapping_impl!(
+    generic_avx2fma_dot_wrapping,
+    Avx2Fma,
+    target_features = "avx2",
+    "fma",
+);
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+define_dot_wrapping_impl!(
+    generic_avx512_dot_wrapping,
+    Avx512,
+    target_features = "avx512f",
+    "avx512bw"
+);
+#[cfg(target_arch = "aarch64")]
+define_dot_wrapping_impl!(generic_neon_dot_wrapping, Neon, target_features = "neon");
+
+macro_rules! define_dot_progress_impl {
+    ($name:ident, $imp:ident $(,)? $(target_features = $($feat:expr $(,)?)+)?) => {
+        #[inline]
+        #[must_use]
+        $(#[target_feature($(enable = $feat, )*)])*
+        #[doc = include_str!("../export_docs/dist_dot_progress.md")]
+        $(
+
+            #[doc = concat!("- ", $("**`+", $feat, "`** ", )*)]
+            #[doc = "CPU features are available at runtime. Running on hardware _without_ this feature available will cause immediate UB."]
+        )*
+        pub unsafe fn $name<T, B1, B2, F>(a: B1, b: B2, every: usize, progress: F) -> T
+        where
+            T: Copy,
+            B1: IntoMemLoader<T>,
+            B1::Loader: MemLoader<Value = T>,
+            B2: IntoMemLoader<T>,
+            B2::Loader: MemLoader<Value = T>,
+            F: FnMut(usize, T),
+            crate::danger::$imp: SimdRegister<T>,
+            AutoMath: Math<T>,
+        {
+            generic_dot_progress::<T, crate::danger::$imp, AutoMath, _, _, _>(
+                a,
+                b,
+                every,
+                progress,
+            )
+        }
+    };
+}
+
+define_dot_progress_impl!(generic_fallback_dot_progress, Fallback);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_dot_progress_impl!(generic_avx2_dot_progress, Avx2, target_features = "avx2");
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_dot_progress_impl!(
+    generic_avx2fma_dot_progress,
+    Avx2Fma,
+    target_features = "avx2",
+    "fma",
+);
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+define_dot_progress_impl!(
+    generic_avx512_dot_progress,
+    Avx512,
+    target_features = "avx512f",
+    "avx512bw"
+);
+#[cfg(target_arch = "aarch64")]
+define_dot_progress_impl!(generic_neon_dot_progress, Neon, target_features = "neon");
+
+macro_rules! define_dot_streaming_both_impl {
+    ($name:ident, $imp:ident $(,)? $(target_features = $($feat:expr $(,)?)+)?) => {
+        #[inline]
+        #[must_use]
+        $(#[target_feature($(enable = $feat, )*)])*
+        #[doc = include_str!("../export_docs/dist_dot_streaming_both.md")]
+        $(
+
+            #[doc = concat!("- ", $("**`+", $feat, "`** ", )*)]
+            #[doc = "CPU features are available at runtime. Running on hardware _without_ this feature available will cause immediate UB."]
+        )*
+        pub unsafe fn $name<'a, T, I1, I2>(a_chunks: I1, b_chunks: I2) -> T
+        where
+            T: Copy + 'a,
+            I1: Iterator<Item = &'a [T]>,
+            I2: Iterator<Item = &'a [T]>,
+            crate::danger::$imp: SimdRegister<T>,
+            AutoMath: Math<T>,
+        {
+            generic_dot_streaming_both::<T, crate::danger::$imp, AutoMath, _, _>(
+                a_chunks,
+                b_chunks,
+            )
+        }
+    };
+}
+
+define_dot_streaming_both_impl!(generic_fallback_dot_streaming_both, Fallback);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_dot_streaming_both_impl!(
+    generic_avx2_dot_streaming_both,
+    Avx2,
+    target_features = "avx2"
+);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_dot_streaming_both_impl!(
+    generic_avx2fma_dot_streaming_both,
+    Avx2Fma,
+    target_features = "avx2",
+    "fma",
+);
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+define_dot_streaming_both_impl!(
+    generic_avx512_dot_streaming_both,
+    Avx512,
+    target_features = "avx512f",
+    "avx512bw"
+);
+#[cfg(target_arch = "aarch64")]
+define_dot_streaming_both_impl!(
+    generic_neon_dot_streaming_both,
+    Neon,
+    target_features = "neon"
+);
+
+macro_rules! define_dot_diff_impl {
+    ($name:ident, $imp:ident $(,)? $(target_features = $($feat:expr $(,)?)+)?) => {
+        #[inline]
+        #[must_use]
+        $(#[target_feature($(enable = $feat, )*)])*
+        #[doc = include_str!("../export_docs/dist_dot_diff.md")]
+        $(
+
+            #[doc = concat!("- ", $("**`+", $feat, "`** ", )*)]
+            #[doc = "CPU features are available at runtime. Running on hardware _without_ this feature available will cause immediate UB."]
+        )*
+        pub unsafe fn $name<T, B1, B2, B3>(a: B1, b: B2, c: B3) -> T
+        where
+            T: Copy,
+            B1: IntoMemLoader<T>,
+            B1::Loader: MemLoader<Value = T>,
+            B2: IntoMemLoader<T>,
+            B2::Loader: MemLoader<Value = T>,
+            B3: IntoMemLoader<T>,
+            B3::Loader: MemLoader<Value = T>,
+            crate::danger::$imp: SimdRegister<T>,
+            AutoMath: Math<T>,
+        {
+            generic_dot_diff::<T, crate::danger::$imp, AutoMath, B1, B2, B3>(a, b, c)
+        }
+    };
+}
+
+define_dot_diff_impl!(generic_fallback_dot_diff, Fallback);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_dot_diff_impl!(generic_avx2_dot_diff, Avx2, target_features = "avx2");
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_dot_diff_impl!(
+    generic_avx2fma_dot_diff,
+    Avx2Fma,
+    target_features = "avx2",
+    "fma",
+);
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+define_dot_diff_impl!(
+    generic_avx512_dot_diff,
+    Avx512,
+    target_features = "avx512f",
+    "avx512bw"
+);
+#[cfg(target_arch = "aarch64")]
+define_dot_diff_impl!(generic_neon_dot_diff, Neon, target_features = "neon");
+
+macro_rules! define_col_dot_impl {
+    ($name:ident, $imp:ident $(,)? $(target_features = $($feat:expr $(,)?)+)?) => {
+        #[inline]
+        #[must_use]
+        $(#[target_feature($(enable = $feat, )*)])*
+        #[doc = include_str!("../export_docs/dist_col_dot.md")]
+        $(
+
+            #[doc = concat!("- ", $("**`+", $feat, "`** ", )*)]
+            #[doc = "CPU features are available at runtime. Running on hardware _without_ this feature available will cause immediate UB."]
+        )*
+        pub unsafe fn $name<T>(
+            matrix: &[T],
+            col: usize,
+            dims_per_row: usize,
+            nrows: usize,
+            query: &[T],
+        ) -> T
+        where
+            T: Copy,
+            crate::danger::$imp: SimdRegister<T>,
+            AutoMath: Math<T>,
+        {
+            generic_col_dot::<T, crate::danger::$imp, AutoMath>(
+                matrix,
+                col,
+                dims_per_row,
+                nrows,
+                query,
+            )
+        }
+    };
+}
+
+define_col_dot_impl!(generic_fallback_col_dot, Fallback);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_col_dot_impl!(generic_avx2_col_dot, Avx2, target_features = "avx2");
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_col_dot_impl!(
+    generic_avx2fma_col_dot,
+    Avx2Fma,
+    target_features = "avx2",
+    "fma",
+);
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+define_col_dot_impl!(
+    generic_avx512_col_dot,
+    Avx512,
+    target_features = "avx512f",
+    "avx512bw"
+);
+#[cfg(target_arch = "aarch64")]
+define_col_dot_impl!(generic_neon_col_dot, Neon, target_features = "neon");
+
+macro_rules! define_dot_strided_impl {
+    ($name:ident, $imp:ident $(,)? $(target_features = $($feat:expr $(,)?)+)?) => {
+        #[inline]
+        #[must_use]
+        $(#[target_feature($(enable = $feat, )*)])*
+        #[doc = include_str!("../export_docs/dist_dot_strided.md")]
+        $(
+
+            #[doc = concat!("- ", $("**`+", $feat, "`** ", )*)]
+            #[doc = "CPU features are available at runtime. Running on hardware _without_ this feature available will cause immediate UB."]
+        )*
+        pub unsafe fn $name<T>(n: usize, x: &[T], incx: isize, y: &[T], incy: isize) -> T
+        where
+            T: Copy,
+            crate::danger::$imp: SimdRegister<T>,
+            AutoMath: Math<T>,
+        {
+            generic_dot_strided::<T, crate::danger::$imp, AutoMath>(n, x, incx, y, incy)
+        }
+    };
+}
+
+define_dot_strided_impl!(generic_fallback_dot_strided, Fallback);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_dot_strided_impl!(generic_avx2_dot_strided, Avx2, target_features = "avx2");
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_dot_strided_impl!(
+    generic_avx2fma_dot_strided,
+    Avx2Fma,
+    target_features = "avx2",
+    "fma",
+);
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+define_dot_strided_impl!(
+    generic_avx512_dot_strided,
+    Avx512,
+    target_features = "avx512f",
+    "avx512bw"
+);
+#[cfg(target_arch = "aarch64")]
+define_dot_strided_impl!(generic_neon_dot_strided, Neon, target_features = "neon");
+
+macro_rules! define_dot_stride2_impl {
+    ($name:ident, $imp:ident $(,)? $(target_features = $($feat:expr $(,)?)+)?) => {
+        #[inline]
+        #[must_use]
+        $(#[target_feature($(enable = $feat, )*)])*
+        #[doc = include_str!("../export_docs/dist_dot_stride2.md")]
+        $(
+
+            #[doc = concat!("- ", $("**`+", $feat, "`** ", )*)]
+            #[doc = "CPU features are available at runtime. Running on hardware _without_ this feature available will cause immediate UB."]
+        )*
+        pub unsafe fn $name<T>(a: &[T], b: &[T]) -> T
+        where
+            T: Copy,
+            crate::danger::$imp: SimdRegister<T>,
+            AutoMath: Math<T>,
+        {
+            generic_dot_stride2::<T, crate::danger::$imp, AutoMath>(a, b)
+        }
+    };
+}
+
+define_dot_stride2_impl!(generic_fallback_dot_stride2, Fallback);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_dot_stride2_impl!(generic_avx2_dot_stride2, Avx2, target_features = "avx2");
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_dot_stride2_impl!(
+    generic_avx2fma_dot_stride2,
+    Avx2Fma,
+    target_features = "avx2",
+    "fma",
+);
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+define_dot_stride2_impl!(
+    generic_avx512_dot_stride2,
+    Avx512,
+    target_features = "avx512f",
+    "avx512bw"
+);
+#[cfg(target_arch = "aarch64")]
+define_dot_stride2_impl!(generic_neon_dot_stride2, Neon, target_features = "neon");
+
+macro_rules! define_frobenius_dot_impl {
+    ($name:ident, $imp:ident $(,)? $(target_features = $($feat:expr $(,)?)+)?) => {
+        #[inline]
+        #[must_use]
+        $(#[target_feature($(enable = $feat, )*)])*
+        #[doc = include_str!("../export_docs/dist_frobenius_dot.md")]
+        $(
+
+            #[doc = concat!("- ", $("**`+", $feat, "`** ", )*)]
+            #[doc = "CPU features are available at runtime. Running on hardware _without_ this feature available will cause immediate UB."]
+        )*
+        pub unsafe fn $name<T>(a: &[T], b: &[T]) -> T
+        where
+            T: Copy,
+            crate::danger::$imp: SimdRegister<T>,
+            AutoMath: Math<T>,
+        {
+            generic_frobenius_dot::<T, crate::danger::$imp, AutoMath>(a, b)
+        }
+    };
+}
+
+define_frobenius_dot_impl!(generic_fallback_frobenius_dot, Fallback);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_frobenius_dot_impl!(generic_avx2_frobenius_dot, Avx2, target_features = "avx2");
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_frobenius_dot_impl!(
+    generic_avx2fma_frobenius_dot,
+    Avx2Fma,
+    target_features = "avx2",
+    "fma",
+);
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+define_frobenius_dot_impl!(
+    generic_avx512_frobenius_dot,
+    Avx512,
+    target_features = "avx512f",
+    "avx512bw"
+);
+#[cfg(target_arch = "aarch64")]
+define_frobenius_dot_impl!(generic_neon_frobenius_dot, Neon, target_features = "neon");
+
+macro_rules! define_dense_forward_impl {
+    ($name:ident, $imp:ident $(,)? $(target_features = $($feat:expr $(,)?)+)?) => {
+        #[inline]
+        $(#[target_feature($(enable = $feat, )*)])*
+        #[doc = include_str!("../export_docs/dist_dense_forward.md")]
+        $(
+
+            #[doc = concat!("- ", $("**`+", $feat, "`** ", )*)]
+            #[doc = "CPU features are available at runtime. Running on hardware _without_ this feature available will cause immediate UB."]
+        )*
+        pub unsafe fn $name<T>(
+            weights: &[T],
+            bias: &[T],
+            input: &[T],
+            in_dim: usize,
+            out_dim: usize,
+            output: &mut [T],
+        )
+        where
+            T: Copy,
+            crate::danger::$imp: SimdRegister<T>,
+            AutoMath: Math<T>,
+        {
+            generic_dense_forward::<T, crate::danger::$imp, AutoMath>(
+                weights,
+                bias,
+                input,
+                in_dim,
+                out_dim,
+                output,
+            )
+        }
+    };
+}
+
+define_dense_forward_impl!(generic_fallback_dense_forward, Fallback);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_dense_forward_impl!(generic_avx2_dense_forward, Avx2, target_features = "avx2");
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_dense_forward_impl!(
+    generic_avx2fma_dense_forward,
+    Avx2Fma,
+    target_features = "avx2",
+    "fma",
+);
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+define_dense_forward_impl!(
+    generic_avx512_dense_forward,
+    Avx512,
+    target_features = "avx512f",
+    "avx512bw"
+);
+#[cfg(target_arch = "aarch64")]
+define_dense_forward_impl!(generic_neon_dense_forward, Neon, target_features = "neon");
+
+macro_rules! define_matvec_impl {
+    ($name:ident, $imp:ident $(,)? $(target_features = $($feat:expr $(,)?)+)?) => {
+        #[inline]
+        $(#[target_feature($(enable = $feat, )*)])*
+        #[doc = include_str!("../export_docs/dist_matvec.md")]
+        $(
+
+            #[doc = concat!("- ", $("**`+", $feat, "`** ", )*)]
+            #[doc = "CPU features are available at runtime. Running on hardware _without_ this feature available will cause immediate UB."]
+        )*
+        pub unsafe fn $name<T>(matrix: &[T], vector: &[T], out: &mut [T])
+        where
+            T: Copy,
+            crate::danger::$imp: SimdRegister<T>,
+            AutoMath: Math<T>,
+        {
+            generic_matvec::<T, crate::danger::$imp, AutoMath>(matrix, vector, out)
+        }
+    };
+}
+
+define_matvec_impl!(generic_fallback_matvec, Fallback);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_matvec_impl!(generic_avx2_matvec, Avx2, target_features = "avx2");
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_matvec_impl!(
+    generic_avx2fma_matvec,
+    Avx2Fma,
+    target_features = "avx2",
+    "fma",
+);
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+define_matvec_impl!(
+    generic_avx512_matvec,
+    Avx512,
+    target_features = "avx512f",
+    "avx512bw"
+);
+#[cfg(target_arch = "aarch64")]
+define_matvec_impl!(generic_neon_matvec, Neon, target_features = "neon");
+
+macro_rules! define_masked_dot_impl {
+    ($name:ident, $imp:ident $(,)? $(target_features = $($feat:expr $(,)?)+)?) => {
+        #[inline]
+        #[must_use]
+        $(#[target_feature($(enable = $feat, )*)])*
+        #[doc = include_str!("../export_docs/dist_masked_dot.md")]
+        $(
+
+            #[doc = concat!("- ", $("**`+", $feat, "`** ", )*)]
+            #[doc = "CPU features are available at runtime. Running on hardware _without_ this feature available will cause immediate UB."]
+        )*
+        pub unsafe fn $name<T>(a: &[T], b: &[T], active: &[u64]) -> T
+        where
+            T: Copy,
+            crate::danger::$imp: SimdRegister<T>,
+            AutoMath: Math<T>,
+        {
+            generic_masked_dot::<T, crate::danger::$imp, AutoMath>(a, b, active)
+        }
+    };
+}
+
+define_masked_dot_impl!(generic_fallback_masked_dot, Fallback);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_masked_dot_impl!(generic_avx2_masked_dot, Avx2, target_features = "avx2");
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_masked_dot_impl!(
+    generic_avx2fma_masked_dot,
+    Avx2Fma,
+    target_features = "avx2",
+    "fma",
+);
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+define_masked_dot_impl!(
+    generic_avx512_masked_dot,
+    Avx512,
+    target_features = "avx512f",
+    "avx512bw"
+);
+#[cfg(target_arch = "aarch64")]
+define_masked_dot_impl!(generic_neon_masked_dot, Neon, target_features = "neon");
+
+macro_rules! define_pairwise_dot_impl {
+    ($name:ident, $imp:ident $(,)? $(target_features = $($feat:expr $(,)?)+)?) => {
+        #[inline]
+        $(#[target_feature($(enable = $feat, )*)])*
+        #[doc = include_str!("../export_docs/dist_pairwise_dot.md")]
+        $(
+
+            #[doc = concat!("- ", $("**`+", $feat, "`** ", )*)]
+            #[doc = "CPU features are available at runtime. Running on hardware _without_ this feature available will cause immediate UB."]
+        )*
+        pub unsafe fn $name<T>(queries: &[T], docs: &[T], dims: usize, out: &mut [T])
+        where
+            T: Copy,
+            crate::danger::$imp: SimdRegister<T>,
+            AutoMath: Math<T>,
+        {
+            generic_pairwise_dot::<T, crate::danger::$imp, AutoMath>(
+                queries,
+                docs,
+                dims,
+                out,
+            )
+        }
+    };
+}
+
+define_pairwise_dot_impl!(generic_fallback_pairwise_dot, Fallback);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_pairwise_dot_impl!(generic_avx2_pairwise_dot, Avx2, target_features = "avx2");
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_pairwise_dot_impl!(
+    generic_avx2fma_pairwise_dot,
+    Avx2Fma,
+    target_features = "avx2",
+    "fma",
+);
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+define_pairwise_dot_impl!(
+    generic_avx512_pairwise_dot,
+    Avx512,
+    target_features = "avx512f",
+    "avx512bw"
+);
+#[cfg(target_arch = "aarch64")]
+define_pairwise_dot_impl!(generic_neon_pairwise_dot, Neon, target_features = "neon");
+
+macro_rules! define_pairwise_squared_euclidean_impl {
+    ($name:ident, $imp:ident $(,)? $(target_features = $($feat:expr $(,)?)+)?) => {
+        #[inline]
+        $(#[target_feature($(enable = $feat, )*)])*
+        #[doc = include_str!("../export_docs/dist_pairwise_squared_euclidean.md")]
+        $(
+
+            #[doc = concat!("- ", $("**`+", $feat, "`** ", )*)]
+            #[doc = "CPU features are available at runtime. Running on hardware _without_ this feature available will cause immediate UB."]
+        )*
+        pub unsafe fn $name<T>(a: &[T], b: &[T], dims: usize, out: &mut [T])
+        where
+            T: Copy,
+            crate::danger::$imp: SimdRegister<T>,
+            AutoMath: Math<T>,
+        {
+            generic_pairwise_squared_euclidean::<T, crate::danger::$imp, AutoMath>(
+                a,
+                b,
+                dims,
+                out,
+            )
+        }
+    };
+}
+
+define_pairwise_squared_euclidean_impl!(
+    generic_fallback_pairwise_squared_euclidean,
+    Fallback
+);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_pairwise_squared_euclidean_impl!(
+    generic_avx2_pairwise_squared_euclidean,
+    Avx2,
+    target_features = "avx2"
+);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_pairwise_squared_euclidean_impl!(
+    generic_avx2fma_pairwise_squared_euclidean,
+    Avx2Fma,
+    target_features = "avx2",
+    "fma",
+);
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+define_pairwise_squared_euclidean_impl!(
+    generic_avx512_pairwise_squared_euclidean,
+    Avx512,
+    target_features = "avx512f",
+    "avx512bw"
+);
+#[cfg(target_arch = "aarch64")]
+define_pairwise_squared_euclidean_impl!(
+    generic_neon_pairwise_squared_euclidean,
+    Neon,
+    target_features = "neon"
+);
+
+macro_rules! define_euclidean_batch4_impl {
+    ($name:ident, $imp:ident $(,)? $(target_features = $($feat:expr $(,)?)+)?) => {
+        #[inline]
+        $(#[target_feature($(enable = $feat, )*)])*
+        #[doc = include_str!("../export_docs/dist_squared_euclidean_batch4.md")]
+        $(
+
+            #[doc = concat!("- ", $("**`+", $feat, "`** ", )*)]
+            #[doc = "CPU features are available at runtime. Running on hardware _without_ this feature available will cause immediate UB."]
+        )*
+        pub unsafe fn $name<T>(query: &[T], docs4: &[T], dims: usize, out: &mut [T; 4])
+        where
+            T: Copy,
+            crate::danger::$imp: SimdRegister<T>,
+            AutoMath: Math<T>,
+        {
+            generic_squared_euclidean_batch4::<T, crate::danger::$imp, AutoMath>(
+                query,
+                docs4,
+                dims,
+                out,
+            )
+        }
+    };
+}
+
+define_euclidean_batch4_impl!(generic_fallback_squared_euclidean_batch4, Fallback);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_euclidean_batch4_impl!(
+    generic_avx2_squared_euclidean_batch4,
+    Avx2,
+    target_features = "avx2"
+);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_euclidean_batch4_impl!(
+    generic_avx2fma_squared_euclidean_batch4,
+    Avx2Fma,
+    target_features = "avx2",
+    "fma",
+);
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+define_euclidean_batch4_impl!(
+    generic_avx512_squared_euclidean_batch4,
+    Avx512,
+    target_features = "avx512f",
+    "avx512bw"
+);
+#[cfg(target_arch = "aarch64")]
+define_euclidean_batch4_impl!(
+    generic_neon_squared_euclidean_batch4,
+    Neon,
+    target_features = "neon"
+);
+
+macro_rules! define_euclidean_batch_impl {
+    ($name:ident, $imp:ident $(,)? $(target_features = $($feat:expr $(,)?)+)?) => {
+        #[inline]
+        $(#[target_feature($(enable = $feat, )*)])*
+        #[doc = include_str!("../export_docs/dist_squared_euclidean_batch.md")]
+        $(
+
+            #[doc = concat!("- ", $("**`+", $feat, "`** ", )*)]
+            #[doc = "CPU features are available at runtime. Running on hardware _without_ this feature available will cause immediate UB."]
+        )*
+        pub unsafe fn $name<T>(query: &[T], matrix: &[T], out: &mut [T])
+        where
+            T: Copy,
+            crate::danger::$imp: SimdRegister<T>,
+            AutoMath: Math<T>,
+        {
+            generic_squared_euclidean_batch::<T, crate::danger::$imp, AutoMath>(
+                query,
+                matrix,
+                out,
+            )
+        }
+    };
+}
+
+define_euclidean_batch_impl!(generic_fallback_squared_euclidean_batch, Fallback);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_euclidean_batch_impl!(
+    generic_avx2_squared_euclidean_batch,
+    Avx2,
+    target_features = "avx2"
+);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_euclidean_batch_impl!(
+    generic_avx2fma_squared_euclidean_batch,
+    Avx2Fma,
+    target_features = "avx2",
+    "fma",
+);
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+define_euclidean_batch_impl!(
+    generic_avx512_squared_euclidean_batch,
+    Avx512,
+    target_features = "avx512f",
+    "avx512bw"
+);
+#[cfg(target_arch = "aarch64")]
+define_euclidean_batch_impl!(
+    generic_neon_squared_euclidean_batch,
+    Neon,
+    target_features = "neon"
+);
+
+macro_rules! define_weighted_euclidean_impl {
+    ($name:ident, $imp:ident $(,)? $(target_features = $($feat:expr $(,)?)+)?) => {
+        #[inline]
+        #[must_use]
+        $(#[target_feature($(enable = $feat, )*)])*
+        #[doc = include_str!("../export_docs/dist_weighted_squared_euclidean.md")]
+        $(
+
+            #[doc = concat!("- ", $("**`+", $feat, "`** ", )*)]
+            #[doc = "CPU features are available at runtime. Running on hardware _without_ this feature available will cause immediate UB."]
+        )*
+        pub unsafe fn $name<T, B1, B2, B3>(a: B1, b: B2, w: B3) -> T
+        where
+            T: Copy,
+            B1: IntoMemLoader<T>,
+            B1::Loader: MemLoader<Value = T>,
+            B2: IntoMemLoader<T>,
+            B2::Loader: MemLoader<Value = T>,
+            B3: IntoMemLoader<T>,
+            B3::Loader: MemLoader<Value = T>,
+            crate::danger::$imp: SimdRegister<T>,
+            AutoMath: Math<T>,
+        {
+            generic_weighted_squared_euclidean::<
+                T,
+                crate::danger::$imp,
+                AutoMath,
+                B1,
+                B2,
+                B3,
+            >(a, b, w)
+        }
+    };
+}
+
+define_weighted_euclidean_impl!(generic_fallback_weighted_squared_euclidean, Fallback);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_weighted_euclidean_impl!(
+    generic_avx2_weighted_squared_euclidean,
+    Avx2,
+    target_features = "avx2"
+);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_weighted_euclidean_impl!(
+    generic_avx2fma_weighted_squared_euclidean,
+    Avx2Fma,
+    target_features = "avx2",
+    "fma",
+);
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+define_weighted_euclidean_impl!(
+    generic_avx512_weighted_squared_euclidean,
+    Avx512,
+    target_features = "avx512f",
+    "avx512bw"
+);
+#[cfg(target_arch = "aarch64")]
+define_weighted_euclidean_impl!(
+    generic_neon_weighted_squared_euclidean,
+    Neon,
+    target_features = "neon"
+);
+
+macro_rules! define_dot_self_and_cross_impl {
+    ($name:ident, $imp:ident $(,)? $(target_features = $($feat:expr $(,)?)+)?) => {
+        #[inline]
+        #[must_use]
+        $(#[target_feature($(enable = $feat, )*)])*
+        #[doc = include_str!("../export_docs/dist_dot_self_and_cross.md")]
+        $(
+
+            #[doc = concat!("- ", $("**`+", $feat, "`** ", )*)]
+            #[doc = "CPU features are available at runtime. Running on hardware _without_ this feature available will cause immediate UB."]
+        )*
+        pub unsafe fn $name<T, B1, B2>(x: B1, y: B2) -> (T, T)
+        where
+            T: Copy,
+            B1: IntoMemLoader<T>,
+            B1::Loader: MemLoader<Value = T>,
+            B2: IntoMemLoader<T>,
+            B2::Loader: MemLoader<Value = T>,
+            crate::danger::$imp: SimdRegister<T>,
+            AutoMath: Math<T>,
+        {
+            generic_dot_self_and_cross::<T, crate::danger::$imp, AutoMath, B1, B2>(x, y)
+        }
+    };
+}
+
+define_dot_self_and_cross_impl!(generic_fallback_dot_self_and_cross, Fallback);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_dot_self_and_cross_impl!(
+    generic_avx2_dot_self_and_cross,
+    Avx2,
+    target_features = "avx2"
+);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_dot_self_and_cross_impl!(
+    generic_avx2fma_dot_self_and_cross,
+    Avx2Fma,
+    target_features = "avx2",
+    "fma",
+);
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+define_dot_self_and_cross_impl!(
+    generic_avx512_dot_self_and_cross,
+    Avx512,
+    target_features = "avx512f",
+    "avx512bw"
+);
+#[cfg(target_arch = "aarch64")]
+define_dot_self_and_cross_impl!(
+    generic_neon_dot_self_and_cross,
+    Neon,
+    target_features = "neon"
+);
+
+macro_rules! define_dot_skip_zero_impl {
+    ($name:ident, $imp:ident $(,)? $(target_features = $($feat:expr $(,)?)+)?) => {
+        #[inline]
+        #[must_use]
+        $(#[target_feature($(enable = $feat, )*)])*
+        #[doc = include_str!("../export_docs/dist_dot_skip_zero.md")]
+        $(
+
+            #[doc = concat!("- ", $("**`+", $feat, "`** ", )*)]
+            #[doc = "CPU features are available at runtime. Running on hardware _without_ this feature available will cause immediate UB."]
+        )*
+        pub unsafe fn $name<T, B1, B2>(a: B1, b: B2) -> T
+        where
+            T: Copy,
+            B1: IntoMemLoader<T> + Copy,
+            B1::Loader: MemLoader<Value = T>,
+            B2: IntoMemLoader<T> + Copy,
+            B2::Loader: MemLoader<Value = T>,
+            crate::danger::$imp: SimdRegister<T>,
+            AutoMath: Math<T>,
+        {
+            generic_dot_skip_zero::<T, crate::danger::$imp, AutoMath, B1, B2>(a, b)
+        }
+    };
+}
+
+define_dot_skip_zero_impl!(generic_fallback_dot_skip_zero, Fallback);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_dot_skip_zero_impl!(generic_avx2_dot_skip_zero, Avx2, target_features = "avx2");
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_dot_skip_zero_impl!(
+    generic_avx2fma_dot_skip_zero,
+    Avx2Fma,
+    target_features = "avx2",
+    "fma",
+);
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+define_dot_skip_zero_impl!(
+    generic_avx512_dot_skip_zero,
+    Avx512,
+    target_features = "avx512f",
+    "avx512bw"
+);
+#[cfg(target_arch = "aarch64")]
+define_dot_skip_zero_impl!(generic_neon_dot_skip_zero, Neon, target_features = "neon");
+
+macro_rules! define_dot_single_register_impl {
+    ($name:ident, $imp:ident $(,)? $(target_features = $($feat:expr $(,)?)+)?) => {
+        #[inline]
+        #[must_use]
+        $(#[target_feature($(enable = $feat, )*)])*
+        #[doc = include_str!("../export_docs/dist_dot_single_register.md")]
+        $(
+
+            #[doc = concat!("- ", $("**`+", $feat, "`** ", )*)]
+            #[doc = "CPU features are available at runtime. Running on hardware _without_ this feature available will cause immediate UB."]
+        )*
+        pub unsafe fn $name<T, B1, B2>(a: B1, b: B2) -> T
+        where
+            T: Copy,
+            B1: IntoMemLoader<T>,
+            B1::Loader: MemLoader<Value = T>,
+            B2: IntoMemLoader<T>,
+            B2::Loader: MemLoader<Value = T>,
+            crate::danger::$imp: SimdRegister<T>,
+            AutoMath: Math<T>,
+        {
+            generic_dot_single_register::<T, crate::danger::$imp, AutoMath, B1, B2>(a, b)
+        }
+    };
+}
+
+define_dot_single_register_impl!(generic_fallback_dot_single_register, Fallback);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_dot_single_register_impl!(
+    generic_avx2_dot_single_register,
+    Avx2,
+    target_features = "avx2"
+);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_dot_single_register_impl!(
+    generic_avx2fma_dot_single_register,
+    Avx2Fma,
+    target_features = "avx2",
+    "fma",
+);
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+define_dot_single_register_impl!(
+    generic_avx512_dot_single_register,
+    Avx512,
+    target_features = "avx512f",
+    "avx512bw"
+);
+#[cfg(target_arch = "aarch64")]
+define_dot_single_register_impl!(
+    generic_neon_dot_single_register,
+    Neon,
+    target_features = "neon"
+);
+
+/// A dot product kernel over two slices of equal length, as selected by the
+/// `*_dot_kernel` routines.
+///
+/// # Safety
+///
+/// The kernel must only be called with slices of the length it was selected for.
+pub type DotKernel<T> = unsafe fn(&[T], &[T]) -> T;
+
+macro_rules! define_dot_kernel_impl {
+    (
+        name = $name:ident,
+        aligned = $aligned:ident,
+        general = $general:ident,
+        $imp:ident $(,)?
+        $(target_features = $($feat:expr $(,)?)+)?
+    ) => {
+        #[inline]
+        #[must_use]
+        $(#[target_feature($(enable = $feat, )*)])*
+        #[doc = include_str!("../export_docs/dist_dot_dense_aligned.md")]
+        $(
+
+            #[doc = concat!("- ", $("**`+", $feat, "`** ", )*)]
+            #[doc = "CPU features are available at runtime. Running on hardware _without_ this feature available will cause immediate UB."]
+        )*
+        pub unsafe fn $aligned<T>(a: &[T], b: &[T]) -> T
+        where
+            T: Copy,
+            crate::danger::$imp: SimdRegister<T>,
+            AutoMath: Math<T>,
+        {
+            generic_dot_dense_aligned::<T, crate::danger::$imp, AutoMath>(a, b)
+        }
+
+        #[inline]
+        $(#[target_feature($(enable = $feat, )*)])*
+        unsafe fn $general<T>(a: &[T], b: &[T]) -> T
+        where
+            T: Copy,
+            crate::danger::$imp: SimdRegister<T>,
+            AutoMath: Math<T>,
+        {
+            generic_dot::<T, crate::danger::$imp, AutoMath, _, _>(a, b)
+        }
+
+        #[inline]
+        #[must_use]
+        #[doc = include_str!("../export_docs/dist_dot_kernel.md")]
+        $(
+
+            #[doc = concat!("- ", $("**`+", $feat, "`** ", )*)]
+            #[doc = "CPU features are available at runtime. Calling the returned kernel on hardware _without_ this feature available will cause immediate UB."]
+        )*
+        pub fn $name<T>(dims: usize) -> DotKernel<T>
+        where
+            T: Copy,
+            crate::danger::$imp: SimdRegister<T>,
+            AutoMath: Math<T>,
+        {
+            let dense = <crate::danger::$imp as SimdRegister<T>>::elements_per_dense();
+            if dims % dense == 0 {
+                $aligned::<T>
+            } else {
+                $general::<T>
+            }
+        }
+    };
+}
+
+define_dot_kernel_impl!(
+    name = generic_fallback_dot_kernel,
+    aligned = generic_fallback_dot_dense_aligned,
+    general = generic_fallback_dot_general,
+    Fallback,
+);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_dot_kernel_impl!(
+    name = generic_avx2_dot_kernel,
+    aligned = generic_avx2_dot_dense_aligned,
+    general = generic_avx2_dot_general,
+    Avx2,
+    target_features = "avx2"
+);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_dot_kernel_impl!(
+    name = generic_avx2fma_dot_kernel,
+    aligned = generic_avx2fma_dot_dense_aligned,
+    general = generic_avx2fma_dot_general,
+    Avx2Fma,
+    target_features = "avx2",
+    "fma"
+);
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+define_dot_kernel_impl!(
+    name = generic_avx512_dot_kernel,
+    aligned = generic_avx512_dot_dense_aligned,
+    general = generic_avx512_dot_general,
+    Avx512,
+    target_features = "avx512f",
+    "avx512bw"
+);
+#[cfg(target_arch = "aarch64")]
+define_dot_kernel_impl!(
+    name = generic_neon_dot_kernel,
+    aligned = generic_neon_dot_dense_aligned,
+    general = generic_neon_dot_general,
+    Neon,
+    target_features = "neon"
+);
+
+macro_rules! define_complex_dot_planar_impl {
+    ($name:ident, $imp:ident $(,)? $(target_features = $($feat:expr $(,)?)+)?) => {
+        #[inline]
+        #[must_use]
+        $(#[target_feature($(enable = $feat, )*)])*
+        #[doc = include_str!("../export_docs/dist_complex_dot_planar.md")]
+        $(
+
+            #[doc = concat!("- ", $("**`+", $feat, "`** ", )*)]
+            #[doc = "CPU features are available at runtime. Running on hardware _without_ this feature available will cause immediate UB."]
+        )*
+        pub unsafe fn $name<T, B1, B2, B3, B4>(
+            a_re: B1,
+            a_im: B2,
+            b_re: B3,
+            b_im: B4,
+        ) -> (T, T)
+        where
+            T: Copy,
+            B1: IntoMemLoader<T>,
+            B1::Loader: MemLoader<Value = T>,
+            B2: IntoMemLoader<T>,
+            B2::Loader: MemLoader<Value = T>,
+            B3: IntoMemLoader<T>,
+            B3::Loader: MemLoader<Value = T>,
+            B4: IntoMemLoader<T>,
+            B4::Loader: MemLoader<Value = T>,
+            crate::danger::$imp: SimdRegister<T>,
+            AutoMath: Math<T>,
+        {
+            generic_complex_dot_planar::<T, crate::danger::$imp, AutoMath, B1, B2, B3, B4>(
+                a_re,
+                a_im,
+                b_re,
+                b_im,
+            )
+        }
+    };
+}
+
+define_complex_dot_planar_impl!(generic_fallback_complex_dot_planar, Fallback);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_complex_dot_planar_impl!(
+    generic_avx2_complex_dot_planar,
+    Avx2,
+    target_features = "avx2"
+);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_complex_dot_planar_impl!(
+    generic_avx2fma_complex_dot_planar,
+    Avx2Fma,
+    target_features = "avx2",
+    "fma",
+);
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+define_complex_dot_planar_impl!(
+    generic_avx512_complex_dot_planar,
+    Avx512,
+    target_features = "avx512f",
+    "avx512bw"
+);
+#[cfg(target_arch = "aarch64")]
+define_complex_dot_planar_impl!(
+    generic_neon_complex_dot_planar,
+    Neon,
+    target_features = "neon"
+);
+
+macro_rules! define_norm_impl {
+    ($name:ident, $imp:ident $(,)? $(target_features = $($feat:expr $(,)?)+)?) => {
+        #[inline]
+        #[must_use]
+        $(#[target_feature($(enable = $feat, )*)])*
+        #[doc = include_str!("../export_docs/dist_norm.md")]
+        $(
+
+            #[doc = concat!("- ", $("**`+", $feat, "`** ", )*)]
+            #[doc = "CPU features are available at runtime. Running on hardware _without_ this feature available will cause immediate UB."]
+        )*
+        pub unsafe fn $name<T, B1>(a: B1) -> T
+        where
+            T: Copy,
+            B1: IntoMemLoader<T>,
+            B1::Loader: MemLoader<Value = T>,
+            crate::danger::$imp: SimdRegister<T>,
+            AutoMath: Math<T>,
+        {
+            generic_squared_norm::<T, crate::danger::$imp, AutoMath, _>(a)
+        }
+    };
+}
+
+define_norm_impl!(generic_fallback_squared_norm, Fallback);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_norm_impl!(generic_avx2_squared_norm, Avx2, target_features = "avx2");
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_norm_impl!(
+    generic_avx2fma_squared_norm,
+    Avx2Fma,
+    target_features = "avx2",
+    "fma",
+);
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+define_norm_impl!(
+    generic_avx512_squared_norm,
+    Avx512,
+    target_features = "avx512f",
+    "avx512bw"
+);
+#[cfg(target_arch = "aarch64")]
+define_norm_impl!(generic_neon_squared_norm, Neon, target_features = "neon");
+
+macro_rules! define_clip_norm_impl {
+    ($name:ident, $imp:ident $(,)? $(target_features = $($feat:expr $(,)?)+)?) => {
+        #[inline]
+        $(#[target_feature($(enable = $feat, )*)])*
+        #[doc = include_str!("../export_docs/dist_clip_norm.md")]
+        $(
+
+            #[doc = concat!("- ", $("**`+", $feat, "`** ", )*)]
+            #[doc = "CPU features are available at runtime. Running on hardware _without_ this feature available will cause immediate UB."]
+        )*
+        pub unsafe fn $name<T>(a: &mut [T], max_norm: T) -> T
+        where
+            T: Copy,
+            crate::danger::$imp: SimdRegister<T>,
+            AutoMath: Math<T>,
+        {
+            generic_clip_norm::<T, crate::danger::$imp, AutoMath>(a, max_norm)
+        }
+    };
+}
+
+define_clip_norm_impl!(generic_fallback_clip_norm, Fallback);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_clip_norm_impl!(generic_avx2_clip_norm, Avx2, target_features = "avx2");
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_clip_norm_impl!(
+    generic_avx2fma_clip_norm,
+    Avx2Fma,
+    target_features = "avx2",
+    "fma",
+);
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+define_clip_norm_impl!(
+    generic_avx512_clip_norm,
+    Avx512,
+    target_features = "avx512f",
+    "avx512bw"
+);
+#[cfg(target_arch = "aarch64")]
+define_clip_norm_impl!(generic_neon_clip_norm, Neon, target_features = "neon");
+
+macro_rules! define_normalize_inplace_impl {
+    ($name:ident, $imp:ident $(,)? $(target_features = $($feat:expr $(,)?)+)?) => {
+        #[inline]
+        $(#[target_feature($(enable = $feat, )*)])*
+        #[doc = include_str!("../export_docs/dist_normalize_inplace.md")]
+        $(
+
+            #[doc = concat!("- ", $("**`+", $feat, "`** ", )*)]
+            #[doc = "CPU features are available at runtime. Running on hardware _without_ this feature available will cause immediate UB."]
+        )*
+        pub unsafe fn $name<T>(a: &mut [T]) -> T
+        where
+            T: Copy,
+            crate::danger::$imp: SimdRegister<T>,
+            AutoMath: Math<T>,
+        {
+            generic_normalize_inplace::<T, crate::danger::$imp, AutoMath>(a)
+        }
+    };
+}
+
+define_normalize_inplace_impl!(generic_fallback_normalize_inplace, Fallback);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_normalize_inplace_impl!(
+    generic_avx2_normalize_inplace,
+    Avx2,
+    target_features = "avx2"
+);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_normalize_inplace_impl!(
+    generic_avx2fma_normalize_inplace,
+    Avx2Fma,
+    target_features = "avx2",
+    "fma",
+);
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+define_normalize_inplace_impl!(
+    generic_avx512_normalize_inplace,
+    Avx512,
+    target_features = "avx512f",
+    "avx512bw"
+);
+#[cfg(target_arch = "aarch64")]
+define_normalize_inplace_impl!(
+    generic_neon_normalize_inplace,
+    Neon,
+    target_features = "neon"
+);
+
+macro_rules! define_row_norms_impl {
+    ($name:ident, $imp:ident $(,)? $(target_features = $($feat:expr $(,)?)+)?) => {
+        #[inline]
+        $(#[target_feature($(enable = $feat, )*)])*
+        #[doc = include_str!("../export_docs/dist_row_norms.md")]
+        $(
+
+            #[doc = concat!("- ", $("**`+", $feat, "`** ", )*)]
+            #[doc = "CPU features are available at runtime. Running on hardware _without_ this feature available will cause immediate UB."]
+        )*
+        pub unsafe fn $name<T>(matrix: &[T], dims: usize, out: &mut [T])
+        where
+            T: Copy,
+            crate::danger::$imp: SimdRegister<T>,
+            AutoMath: Math<T>,
+        {
+            generic_row_norms::<T, crate::danger::$imp, AutoMath>(matrix, dims, out)
+        }
+    };
+}
+
+define_row_norms_impl!(generic_fallback_row_norms, Fallback);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_row_norms_impl!(generic_avx2_row_norms, Avx2, target_features = "avx2");
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_row_norms_impl!(
+    generic_avx2fma_row_norms,
+    Avx2Fma,
+    target_features = "avx2",
+    "fma",
+);
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+define_row_norms_impl!(
+    generic_avx512_row_norms,
+    Avx512,
+    target_features = "avx512f",
+    "avx512bw"
+);
+#[cfg(target_arch = "aarch64")]
+define_row_norms_impl!(generic_neon_row_norms, Neon, target_features = "neon");
+
+macro_rules! define_minmax_normalize_columns_impl {
+    ($name:ident, $imp:ident $(,)? $(target_features = $($feat:expr $(,)?)+)?) => {
+        #[inline]
+        $(#[target_feature($(enable = $feat, )*)])*
+        #[doc = include_str!("../export_docs/dist_minmax_normalize_columns_inplace.md")]
+        $(
+
+            #[doc = concat!("- ", $("**`+", $feat, "`** ", )*)]
+            #[doc = "CPU features are available at runtime. Running on hardware _without_ this feature available will cause immediate UB."]
+        )*
+        pub unsafe fn $name<T>(matrix: &mut [T], dims: usize)
+        where
+            T: Copy,
+            crate::danger::$imp: SimdRegister<T>,
+            AutoMath: Math<T>,
+        {
+            generic_minmax_normalize_columns_inplace::<T, crate::danger::$imp, AutoMath>(
+                matrix, dims,
+            )
+        }
+    };
+}
+
+define_minmax_normalize_columns_impl!(
+    generic_fallback_minmax_normalize_columns_inplace,
+    Fallback
+);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_minmax_normalize_columns_impl!(
+    generic_avx2_minmax_normalize_columns_inplace,
+    Avx2,
+    target_features = "avx2"
+);
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+define_minmax_normalize_columns_impl!(
+    generic_avx512_minmax_normalize_columns_inplace,
+    Avx512,
+    target_features = "avx512f",
+    "avx512bw"
+);
+#[cfg(target_arch = "aarch64")]
+define_minmax_normalize_columns_impl!(
+    generic_neon_minmax_normalize_columns_inplace,
+    Neon,
+    target_features = "neon"
+);
+
+macro_rules! define_canberra_impl {
+    ($name:ident, $imp:ident $(,)? $(target_features = $($feat:expr $(,)?)+)?) => {
+        #[inline]
+        #[must_use]
+        $(#[target_feature($(enable = $feat, )*)])*
+        #[doc = include_str!("../export_docs/dist_canberra.md")]
+        $(
+
+            #[doc = concat!("- ", $("**`+", $feat, "`** ", )*)]
+            #[doc = "CPU features are available at runtime. Running on hardware _without_ this feature available will cause immediate UB."]
+        )*
+        pub unsafe fn $name(a: &[f64], b: &[f64]) -> f64 {
+            generic_canberra::<crate::danger::$imp, AutoMath>(a, b)
+        }
+    };
+}
+
+define_canberra_impl!(f64_fallback_canberra, Fallback);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_canberra_impl!(f64_avx2_canberra, Avx2, target_features = "avx2");
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+define_canberra_impl!(
+    f64_avx512_canberra,
+    Avx512,
+    target_features = "avx512f",
+    "avx512bw"
+);
+#[cfg(target_arch = "aarch64")]
+define_canberra_impl!(f64_neon_canberra, Neon, target_features = "neon");
+
+macro_rules! define_bray_curtis_impl {
+    ($name:ident, $imp:ident $(,)? $(target_features = $($feat:expr $(,)?)+)?) => {
+        #[inline]
+        #[must_use]
+        $(#[target_feature($(enable = $feat, )*)])*
+        #[doc = include_str!("../export_docs/dist_bray_curtis.md")]
+        $(
+
+            #[doc = concat!("- ", $("**`+", $feat, "`** ", )*)]
+            #[doc = "CPU features are available at runtime. Running on hardware _without_ this feature available will cause immediate UB."]
+        )*
+        pub unsafe fn $name(a: &[f64], b: &[f64]) -> f64 {
+            generic_bray_curtis::<crate::danger::$imp, AutoMath>(a, b)
+        }
+    };
+}
+
+define_bray_curtis_impl!(f64_fallback_bray_curtis, Fallback);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_bray_curtis_impl!(f64_avx2_bray_curtis, Avx2, target_features = "avx2");
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+define_bray_curtis_impl!(
+    f64_avx512_bray_curtis,
+    Avx512,
+    target_features = "avx512f",
+    "avx512bw"
+);
+#[cfg(target_arch = "aarch64")]
+define_bray_curtis_impl!(f64_neon_bray_curtis, Neon, target_features = "neon");
+
+macro_rules! define_minkowski_impl {
+    ($name:ident, $imp:ident $(,)? $(target_features = $($feat:expr $(,)?)+)?) => {
+        #[inline]
+        #[must_use]
+        $(#[target_feature($(enable = $feat, )*)])*
+        #[doc = include_str!("../export_docs/dist_minkowski.md")]
+        $(
+
+            #[doc = concat!("- ", $("**`+", $feat, "`** ", )*)]
+            #[doc = "CPU features are available at runtime. Running on hardware _without_ this feature available will cause immediate UB."]
+        )*
+        pub unsafe fn $name(a: &[f64], b: &[f64], p: f64) -> f64 {
+            generic_minkowski::<crate::danger::$imp, AutoMath>(a, b, p)
+        }
+    };
+}
+
+define_minkowski_impl!(f64_fallback_minkowski, Fallback);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_minkowski_impl!(f64_avx2_minkowski, Avx2, target_features = "avx2");
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_minkowski_impl!(
+    f64_avx2fma_minkowski,
+    Avx2Fma,
+    target_features = "avx2",
+    "fma"
+);
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+define_minkowski_impl!(
+    f64_avx512_minkowski,
+    Avx512,
+    target_features = "avx512f",
+    "avx512bw"
+);
+#[cfg(target_arch = "aarch64")]
+define_minkowski_impl!(f64_neon_minkowski, Neon, target_features = "neon");
 
 #[cfg(test)]
 mod tests {
@@ -0,0 +1,81 @@
+//! Data layout related operations
+//!
+//! I.e. Decimation, strided gathers, scatters...
+
+use crate::buffer::WriteOnlyBuffer;
+use crate::danger::{generic_decimate, generic_scatter_add, SimdRegister};
+use crate::math::{AutoMath, Math};
+
+macro_rules! define_decimate_impl {
+    (
+        $name:ident,
+        $imp:ident $(,)?
+        $(target_features = $($feat:expr $(,)?)+)?
+    ) => {
+        #[inline]
+        $(#[target_feature($(enable = $feat, )*)])*
+        #[doc = include_str!("../export_docs/layout_decimate.md")]
+        $(
+
+            #[doc = concat!("- ", $("**`+", $feat, "`** ", )*)]
+            #[doc = "CPU features are available at runtime. Running on hardware _without_ this feature available will cause immediate UB."]
+        )*
+        pub unsafe fn $name<T, B1>(a: &[T], factor: usize, result: &mut [B1])
+        where
+            T: Copy,
+            AutoMath: Math<T>,
+            crate::danger::$imp: SimdRegister<T>,
+            for<'a> &'a mut [B1]: WriteOnlyBuffer<Item = T>,
+        {
+            generic_decimate::<T, crate::danger::$imp, AutoMath, B1>(a, factor, result)
+        }
+    };
+}
+
+define_decimate_impl!(generic_fallback_decimate, Fallback);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_decimate_impl!(generic_avx2_decimate, Avx2, target_features = "avx2");
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+define_decimate_impl!(
+    generic_avx512_decimate,
+    Avx512,
+    target_features = "avx512f",
+    "avx512bw"
+);
+#[cfg(target_arch = "aarch64")]
+define_decimate_impl!(generic_neon_decimate, Neon, target_features = "neon");
+
+macro_rules! define_scatter_add_impl {
+    (
+        $name:ident $(,)?
+        $(target_features = $($feat:expr $(,)?)+)?
+    ) => {
+        #[inline]
+        $(#[target_feature($(enable = $feat, )*)])*
+        #[doc = include_str!("../export_docs/layout_scatter_add.md")]
+        $(
+
+            #[doc = concat!("- ", $("**`+", $feat, "`** ", )*)]
+            #[doc = "CPU features are available at runtime. Running on hardware _without_ this feature available will cause immediate UB."]
+        )*
+        pub unsafe fn $name<T>(indices: &[u32], values: &[T], result: &mut [T])
+        where
+            T: Copy,
+            AutoMath: Math<T>,
+        {
+            generic_scatter_add::<T, AutoMath>(indices, values, result)
+        }
+    };
+}
+
+define_scatter_add_impl!(generic_fallback_scatter_add);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_scatter_add_impl!(generic_avx2_scatter_add, target_features = "avx2");
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+define_scatter_add_impl!(
+    generic_avx512_scatter_add,
+    target_features = "avx512f",
+    "avx512bw"
+);
+#[cfg(target_arch = "aarch64")]
+define_scatter_add_impl!(generic_neon_scatter_add, target_features = "neon");
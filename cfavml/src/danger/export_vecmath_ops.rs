@@ -0,0 +1,322 @@
+//! Element-wise math functions
+//!
+//! I.e. Exp, Log, Sqrt and activations like Softplus and GELU over `f32` vectors,
+//! written back in place, and Pow and Softmax over `f64` vectors.
+
+use crate::danger::{
+    generic_exp_inplace,
+    generic_gelu_inplace,
+    generic_log_inplace,
+    generic_pow_vertical,
+    generic_softmax,
+    generic_softmax_inplace,
+    generic_softplus_inplace,
+    generic_sqrt_inplace,
+};
+use crate::math::AutoMath;
+
+macro_rules! define_vecmath_impls {
+    (
+        exp = $exp_name:ident,
+        log = $log_name:ident,
+        sqrt = $sqrt_name:ident,
+        $imp:ident $(,)?
+        $(target_features = $($feat:expr $(,)?)+)?
+    ) => {
+        #[inline]
+        $(#[target_feature($(enable = $feat, )*)])*
+        #[doc = include_str!("../export_docs/vecmath_exp_inplace.md")]
+        $(
+
+            #[doc = concat!("- ", $("**`+", $feat, "`** ", )*)]
+            #[doc = "CPU features are available at runtime. Running on hardware _without_ this feature available will cause immediate UB."]
+        )*
+        pub unsafe fn $exp_name(a: &mut [f32]) {
+            generic_exp_inplace::<crate::danger::$imp, AutoMath>(a)
+        }
+
+        #[inline]
+        $(#[target_feature($(enable = $feat, )*)])*
+        #[doc = include_str!("../export_docs/vecmath_log_inplace.md")]
+        $(
+
+            #[doc = concat!("- ", $("**`+", $feat, "`** ", )*)]
+            #[doc = "CPU features are available at runtime. Running on hardware _without_ this feature available will cause immediate UB."]
+        )*
+        pub unsafe fn $log_name(a: &mut [f32]) {
+            generic_log_inplace::<crate::danger::$imp, AutoMath>(a)
+        }
+
+        #[inline]
+        $(#[target_feature($(enable = $feat, )*)])*
+        #[doc = include_str!("../export_docs/vecmath_sqrt_inplace.md")]
+        $(
+
+            #[doc = concat!("- ", $("**`+", $feat, "`** ", )*)]
+            #[doc = "CPU features are available at runtime. Running on hardware _without_ this feature available will cause immediate UB."]
+        )*
+        pub unsafe fn $sqrt_name(a: &mut [f32]) {
+            generic_sqrt_inplace::<AutoMath>(a)
+        }
+    };
+}
+
+define_vecmath_impls!(
+    exp = f32_fallback_exp_inplace,
+    log = f32_fallback_log_inplace,
+    sqrt = f32_fallback_sqrt_inplace,
+    Fallback,
+);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_vecmath_impls!(
+    exp = f32_avx2_exp_inplace,
+    log = f32_avx2_log_inplace,
+    sqrt = f32_avx2_sqrt_inplace,
+    Avx2,
+    target_features = "avx2"
+);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_vecmath_impls!(
+    exp = f32_avx2fma_exp_inplace,
+    log = f32_avx2fma_log_inplace,
+    sqrt = f32_avx2fma_sqrt_inplace,
+    Avx2Fma,
+    target_features = "avx2",
+    "fma"
+);
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+define_vecmath_impls!(
+    exp = f32_avx512_exp_inplace,
+    log = f32_avx512_log_inplace,
+    sqrt = f32_avx512_sqrt_inplace,
+    Avx512,
+    target_features = "avx512f",
+    "avx512bw"
+);
+#[cfg(target_arch = "aarch64")]
+define_vecmath_impls!(
+    exp = f32_neon_exp_inplace,
+    log = f32_neon_log_inplace,
+    sqrt = f32_neon_sqrt_inplace,
+    Neon,
+    target_features = "neon"
+);
+
+macro_rules! define_pow_impl {
+    (
+        $name:ident,
+        $imp:ident $(,)?
+        $(target_features = $($feat:expr $(,)?)+)?
+    ) => {
+        #[inline]
+        $(#[target_feature($(enable = $feat, )*)])*
+        #[doc = include_str!("../export_docs/vecmath_pow_vertical.md")]
+        $(
+
+            #[doc = concat!("- ", $("**`+", $feat, "`** ", )*)]
+            #[doc = "CPU features are available at runtime. Running on hardware _without_ this feature available will cause immediate UB."]
+        )*
+        pub unsafe fn $name(base: &[f64], exponent: &[f64], result: &mut [f64]) {
+            generic_pow_vertical::<crate::danger::$imp, AutoMath>(base, exponent, result)
+        }
+    };
+}
+
+define_pow_impl!(f64_fallback_pow_vertical, Fallback);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_pow_impl!(f64_avx2_pow_vertical, Avx2, target_features = "avx2");
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+define_pow_impl!(
+    f64_avx512_pow_vertical,
+    Avx512,
+    target_features = "avx512f",
+    "avx512bw"
+);
+#[cfg(target_arch = "aarch64")]
+define_pow_impl!(f64_neon_pow_vertical, Neon, target_features = "neon");
+
+macro_rules! define_softmax_impls {
+    (
+        softmax = $softmax_name:ident,
+        inplace = $inplace_name:ident,
+        $imp:ident $(,)?
+        $(target_features = $($feat:expr $(,)?)+)?
+    ) => {
+        #[inline]
+        $(#[target_feature($(enable = $feat, )*)])*
+        #[doc = include_str!("../export_docs/vecmath_softmax.md")]
+        $(
+
+            #[doc = concat!("- ", $("**`+", $feat, "`** ", )*)]
+            #[doc = "CPU features are available at runtime. Running on hardware _without_ this feature available will cause immediate UB."]
+        )*
+        pub unsafe fn $softmax_name(x: &[f64], result: &mut [f64]) {
+            generic_softmax::<crate::danger::$imp, AutoMath>(x, result)
+        }
+
+        #[inline]
+        $(#[target_feature($(enable = $feat, )*)])*
+        #[doc = include_str!("../export_docs/vecmath_softmax_inplace.md")]
+        $(
+
+            #[doc = concat!("- ", $("**`+", $feat, "`** ", )*)]
+            #[doc = "CPU features are available at runtime. Running on hardware _without_ this feature available will cause immediate UB."]
+        )*
+        pub unsafe fn $inplace_name(a: &mut [f64]) {
+            generic_softmax_inplace::<crate::danger::$imp, AutoMath>(a)
+        }
+    };
+}
+
+define_softmax_impls!(
+    softmax = f64_fallback_softmax,
+    inplace = f64_fallback_softmax_inplace,
+    Fallback,
+);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_softmax_impls!(
+    softmax = f64_avx2_softmax,
+    inplace = f64_avx2_softmax_inplace,
+    Avx2,
+    target_features = "avx2"
+);
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+define_softmax_impls!(
+    softmax = f64_avx512_softmax,
+    inplace = f64_avx512_softmax_inplace,
+    Avx512,
+    target_features = "avx512f",
+    "avx512bw"
+);
+#[cfg(target_arch = "aarch64")]
+define_softmax_impls!(
+    softmax = f64_neon_softmax,
+    inplace = f64_neon_softmax_inplace,
+    Neon,
+    target_features = "neon"
+);
+
+macro_rules! define_softplus_impl {
+    (
+        $name:ident,
+        $imp:ident $(,)?
+        $(target_features = $($feat:expr $(,)?)+)?
+    ) => {
+        #[inline]
+        $(#[target_feature($(enable = $feat, )*)])*
+        #[doc = include_str!("../export_docs/vecmath_softplus_inplace.md")]
+        $(
+
+            #[doc = concat!("- ", $("**`+", $feat, "`** ", )*)]
+            #[doc = "CPU features are available at runtime. Running on hardware _without_ this feature available will cause immediate UB."]
+        )*
+        pub unsafe fn $name(a: &mut [f32]) {
+            generic_softplus_inplace::<crate::danger::$imp, AutoMath>(a)
+        }
+    };
+}
+
+define_softplus_impl!(f32_fallback_softplus_inplace, Fallback);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_softplus_impl!(f32_avx2_softplus_inplace, Avx2, target_features = "avx2");
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_softplus_impl!(
+    f32_avx2fma_softplus_inplace,
+    Avx2Fma,
+    target_features = "avx2",
+    "fma"
+);
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+define_softplus_impl!(
+    f32_avx512_softplus_inplace,
+    Avx512,
+    target_features = "avx512f",
+    "avx512bw"
+);
+#[cfg(target_arch = "aarch64")]
+define_softplus_impl!(f32_neon_softplus_inplace, Neon, target_features = "neon");
+
+macro_rules! define_gelu_impl {
+    (
+        $name:ident,
+        $imp:ident $(,)?
+        $(target_features = $($feat:expr $(,)?)+)?
+    ) => {
+        #[inline]
+        $(#[target_feature($(enable = $feat, )*)])*
+        #[doc = include_str!("../export_docs/vecmath_gelu_inplace.md")]
+        $(
+
+            #[doc = concat!("- ", $("**`+", $feat, "`** ", )*)]
+            #[doc = "CPU features are available at runtime. Running on hardware _without_ this feature available will cause immediate UB."]
+        )*
+        pub unsafe fn $name(a: &mut [f32]) {
+            generic_gelu_inplace::<crate::danger::$imp, AutoMath>(a)
+        }
+    };
+}
+
+define_gelu_impl!(f32_fallback_gelu_inplace, Fallback);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_gelu_impl!(f32_avx2_gelu_inplace, Avx2, target_features = "avx2");
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_gelu_impl!(
+    f32_avx2fma_gelu_inplace,
+    Avx2Fma,
+    target_features = "avx2",
+    "fma"
+);
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+define_gelu_impl!(
+    f32_avx512_gelu_inplace,
+    Avx512,
+    target_features = "avx512f",
+    "avx512bw"
+);
+#[cfg(target_arch = "aarch64")]
+define_gelu_impl!(f32_neon_gelu_inplace, Neon, target_features = "neon");
+
+#[cfg(test)]
+mod tests {
+    macro_rules! define_sqrt_test {
+        ($variant:ident) => {
+            paste::paste! {
+                #[test]
+                fn [< test_ $variant _sqrt_inplace >]() {
+                    let mut values = (0..533).map(|i| i as f32 * 0.731).collect::<Vec<_>>();
+                    let expected = values.iter().map(|v| v.sqrt()).collect::<Vec<_>>();
+
+                    unsafe { super::[< $variant _sqrt_inplace >](&mut values) };
+                    assert_eq!(values, expected, "Routine result does not match expected");
+
+                    let mut special = [-1.0f32, f32::INFINITY, -0.0];
+                    unsafe { super::[< $variant _sqrt_inplace >](&mut special) };
+                    assert!(special[0].is_nan());
+                    assert_eq!(special[1], f32::INFINITY);
+                    assert_eq!(special[2].to_bits(), (-0.0f32).to_bits());
+                }
+            }
+        };
+    }
+
+    define_sqrt_test!(f32_fallback);
+    #[cfg(all(
+        any(target_arch = "x86", target_arch = "x86_64"),
+        target_feature = "avx2"
+    ))]
+    define_sqrt_test!(f32_avx2);
+    #[cfg(all(
+        any(target_arch = "x86", target_arch = "x86_64"),
+        target_feature = "avx2",
+        target_feature = "fma"
+    ))]
+    define_sqrt_test!(f32_avx2fma);
+    #[cfg(all(
+        any(target_arch = "x86", target_arch = "x86_64"),
+        feature = "nightly",
+        target_feature = "avx512f"
+    ))]
+    define_sqrt_test!(f32_avx512);
+    #[cfg(target_arch = "aarch64")]
+    define_sqrt_test!(f32_neon);
+}
@@ -21,6 +21,11 @@ impl SimdRegister<f32> for Avx2 {
         _mm256_loadu_ps(mem)
     }
 
+    #[inline(always)]
+    unsafe fn load_aligned(mem: *const f32) -> Self::Register {
+        _mm256_load_ps(mem)
+    }
+
     #[inline(always)]
     unsafe fn filled(value: f32) -> Self::Register {
         _mm256_set1_ps(value)
@@ -107,6 +112,12 @@ impl SimdRegister<f32> for Avx2 {
         _mm256_and_ps(mask, _mm256_set1_ps(1.0))
     }
 
+    #[inline(always)]
+    unsafe fn movemask(reg: Self::Register) -> u64 {
+        let mask = _mm256_cmp_ps::<_CMP_NEQ_UQ>(reg, _mm256_setzero_ps());
+        _mm256_movemask_ps(mask) as u64
+    }
+
     #[inline(always)]
     unsafe fn fmadd_dense(
         l1: DenseLane<Self::Register>,
@@ -177,6 +188,11 @@ impl SimdRegister<f64> for Avx2 {
         _mm256_loadu_pd(mem)
     }
 
+    #[inline(always)]
+    unsafe fn load_aligned(mem: *const f64) -> Self::Register {
+        _mm256_load_pd(mem)
+    }
+
     #[inline(always)]
     unsafe fn filled(value: f64) -> Self::Register {
         _mm256_set1_pd(value)
@@ -264,6 +280,12 @@ impl SimdRegister<f64> for Avx2 {
         _mm256_and_pd(mask, _mm256_set1_pd(1.0))
     }
 
+    #[inline(always)]
+    unsafe fn movemask(reg: Self::Register) -> u64 {
+        let mask = _mm256_cmp_pd::<_CMP_NEQ_UQ>(reg, _mm256_setzero_pd());
+        _mm256_movemask_pd(mask) as u64
+    }
+
     #[inline(always)]
     unsafe fn fmadd_dense(
         l1: DenseLane<Self::Register>,
@@ -323,6 +345,11 @@ impl SimdRegister<i8> for Avx2 {
         _mm256_loadu_si256(mem.cast())
     }
 
+    #[inline(always)]
+    unsafe fn load_aligned(mem: *const i8) -> Self::Register {
+        _mm256_load_si256(mem.cast())
+    }
+
     #[inline(always)]
     unsafe fn filled(value: i8) -> Self::Register {
         _mm256_set1_epi8(value)
@@ -430,6 +457,12 @@ impl SimdRegister<i8> for Avx2 {
         _mm256_andnot_si256(swapped_cmp, _mm256_set1_epi8(1))
     }
 
+    #[inline(always)]
+    unsafe fn movemask(reg: Self::Register) -> u64 {
+        let eq_mask = _mm256_cmpeq_epi8(reg, _mm256_setzero_si256());
+        !(_mm256_movemask_epi8(eq_mask) as u32) as u64
+    }
+
     #[inline(always)]
     unsafe fn mul_dense(
         l1: DenseLane<Self::Register>,
@@ -621,6 +654,11 @@ impl SimdRegister<i16> for Avx2 {
         _mm256_loadu_si256(mem.cast())
     }
 
+    #[inline(always)]
+    unsafe fn load_aligned(mem: *const i16) -> Self::Register {
+        _mm256_load_si256(mem.cast())
+    }
+
     #[inline(always)]
     unsafe fn filled(value: i16) -> Self::Register {
         _mm256_set1_epi16(value)
@@ -725,6 +763,17 @@ impl SimdRegister<i16> for Avx2 {
         _mm256_srli_epi16::<15>(mask)
     }
 
+    #[inline(always)]
+    unsafe fn movemask(reg: Self::Register) -> u64 {
+        let eq_mask = _mm256_cmpeq_epi16(reg, _mm256_setzero_si256());
+        // The pack works within each 128 bit half, bytes `0..8` hold elements `0..8`
+        // and bytes `16..24` hold elements `8..16`.
+        let packed = _mm256_packs_epi16(eq_mask, eq_mask);
+        let bits = _mm256_movemask_epi8(packed) as u32;
+        let eq_bits = (bits & 0xFF) | ((bits >> 8) & 0xFF00);
+        (!eq_bits & 0xFFFF) as u64
+    }
+
     #[inline(always)]
     unsafe fn mul_dense(
         l1: DenseLane<Self::Register>,
@@ -906,6 +955,11 @@ impl SimdRegister<i32> for Avx2 {
         _mm256_loadu_si256(mem.cast())
     }
 
+    #[inline(always)]
+    unsafe fn load_aligned(mem: *const i32) -> Self::Register {
+        _mm256_load_si256(mem.cast())
+    }
+
     #[inline(always)]
     unsafe fn filled(value: i32) -> Self::Register {
         _mm256_set1_epi32(value)
@@ -1010,6 +1064,12 @@ impl SimdRegister<i32> for Avx2 {
         _mm256_srli_epi32::<31>(mask)
     }
 
+    #[inline(always)]
+    unsafe fn movemask(reg: Self::Register) -> u64 {
+        let eq_mask = _mm256_cmpeq_epi32(reg, _mm256_setzero_si256());
+        (!_mm256_movemask_ps(_mm256_castsi256_ps(eq_mask)) & 0xFF) as u64
+    }
+
     #[inline(always)]
     unsafe fn mul_dense(
         l1: DenseLane<Self::Register>,
@@ -1161,6 +1221,11 @@ impl SimdRegister<i64> for Avx2 {
         _mm256_loadu_si256(mem.cast())
     }
 
+    #[inline(always)]
+    unsafe fn load_aligned(mem: *const i64) -> Self::Register {
+        _mm256_load_si256(mem.cast())
+    }
+
     #[inline(always)]
     unsafe fn filled(value: i64) -> Self::Register {
         _mm256_set1_epi64x(value)
@@ -1276,6 +1341,12 @@ impl SimdRegister<i64> for Avx2 {
         _mm256_andnot_si256(swapped_cmp, _mm256_set1_epi64x(1))
     }
 
+    #[inline(always)]
+    unsafe fn movemask(reg: Self::Register) -> u64 {
+        let eq_mask = _mm256_cmpeq_epi64(reg, _mm256_setzero_si256());
+        (!_mm256_movemask_pd(_mm256_castsi256_pd(eq_mask)) & 0xF) as u64
+    }
+
     #[inline(always)]
     unsafe fn mul_dense(
         l1: DenseLane<Self::Register>,
@@ -1424,6 +1495,11 @@ impl SimdRegister<u8> for Avx2 {
         _mm256_loadu_si256(mem.cast())
     }
 
+    #[inline(always)]
+    unsafe fn load_aligned(mem: *const u8) -> Self::Register {
+        _mm256_load_si256(mem.cast())
+    }
+
     #[inline(always)]
     unsafe fn filled(value: u8) -> Self::Register {
         _mm256_set1_epi8(value as i8)
@@ -1523,6 +1599,12 @@ impl SimdRegister<u8> for Avx2 {
         _mm256_and_si256(mask, _mm256_set1_epi8(1))
     }
 
+    #[inline(always)]
+    unsafe fn movemask(reg: Self::Register) -> u64 {
+        let eq_mask = _mm256_cmpeq_epi8(reg, _mm256_setzero_si256());
+        !(_mm256_movemask_epi8(eq_mask) as u32) as u64
+    }
+
     #[inline(always)]
     unsafe fn mul_dense(
         l1: DenseLane<Self::Register>,
@@ -1747,6 +1829,11 @@ impl SimdRegister<u16> for Avx2 {
         _mm256_loadu_si256(mem.cast())
     }
 
+    #[inline(always)]
+    unsafe fn load_aligned(mem: *const u16) -> Self::Register {
+        _mm256_load_si256(mem.cast())
+    }
+
     #[inline(always)]
     unsafe fn filled(value: u16) -> Self::Register {
         _mm256_set1_epi16(value as i16)
@@ -1851,6 +1938,17 @@ impl SimdRegister<u16> for Avx2 {
         _mm256_srli_epi16::<15>(mask)
     }
 
+    #[inline(always)]
+    unsafe fn movemask(reg: Self::Register) -> u64 {
+        let eq_mask = _mm256_cmpeq_epi16(reg, _mm256_setzero_si256());
+        // The pack works within each 128 bit half, bytes `0..8` hold elements `0..8`
+        // and bytes `16..24` hold elements `8..16`.
+        let packed = _mm256_packs_epi16(eq_mask, eq_mask);
+        let bits = _mm256_movemask_epi8(packed) as u32;
+        let eq_bits = (bits & 0xFF) | ((bits >> 8) & 0xFF00);
+        (!eq_bits & 0xFFFF) as u64
+    }
+
     #[inline(always)]
     unsafe fn mul_dense(
         l1: DenseLane<Self::Register>,
@@ -2075,6 +2173,11 @@ impl SimdRegister<u32> for Avx2 {
         _mm256_loadu_si256(mem.cast())
     }
 
+    #[inline(always)]
+    unsafe fn load_aligned(mem: *const u32) -> Self::Register {
+        _mm256_load_si256(mem.cast())
+    }
+
     #[inline(always)]
     unsafe fn filled(value: u32) -> Self::Register {
         _mm256_set1_epi32(value as i32)
@@ -2179,6 +2282,12 @@ impl SimdRegister<u32> for Avx2 {
         _mm256_srli_epi32::<31>(mask)
     }
 
+    #[inline(always)]
+    unsafe fn movemask(reg: Self::Register) -> u64 {
+        let eq_mask = _mm256_cmpeq_epi32(reg, _mm256_setzero_si256());
+        (!_mm256_movemask_ps(_mm256_castsi256_ps(eq_mask)) & 0xFF) as u64
+    }
+
     #[inline(always)]
     unsafe fn mul_dense(
         l1: DenseLane<Self::Register>,
@@ -2373,6 +2482,11 @@ impl SimdRegister<u64> for Avx2 {
         _mm256_loadu_si256(mem.cast())
     }
 
+    #[inline(always)]
+    unsafe fn load_aligned(mem: *const u64) -> Self::Register {
+        _mm256_load_si256(mem.cast())
+    }
+
     #[inline(always)]
     unsafe fn filled(value: u64) -> Self::Register {
         _mm256_set1_epi64x(value as i64)
@@ -2504,6 +2618,12 @@ impl SimdRegister<u64> for Avx2 {
         _mm256_andnot_si256(swapped_cmp, _mm256_set1_epi64x(1))
     }
 
+    #[inline(always)]
+    unsafe fn movemask(reg: Self::Register) -> u64 {
+        let eq_mask = _mm256_cmpeq_epi64(reg, _mm256_setzero_si256());
+        (!_mm256_movemask_pd(_mm256_castsi256_pd(eq_mask)) & 0xF) as u64
+    }
+
     #[inline(always)]
     unsafe fn fmadd_dense(
         l1: DenseLane<Self::Register>,
@@ -19,6 +19,11 @@ impl SimdRegister<f32> for Avx2Fma {
         Avx2::load(mem)
     }
 
+    #[inline(always)]
+    unsafe fn load_aligned(mem: *const f32) -> Self::Register {
+        Avx2::load_aligned(mem)
+    }
+
     #[inline(always)]
     unsafe fn filled(value: f32) -> Self::Register {
         Avx2::filled(value)
@@ -98,6 +103,11 @@ impl SimdRegister<f32> for Avx2Fma {
         <Avx2 as SimdRegister<f32>>::gte(l1, l2)
     }
 
+    #[inline(always)]
+    unsafe fn movemask(reg: Self::Register) -> u64 {
+        <Avx2 as SimdRegister<f32>>::movemask(reg)
+    }
+
     #[inline(always)]
     unsafe fn sum_to_value(reg: Self::Register) -> f32 {
         Avx2::sum_to_value(reg)
@@ -127,6 +137,11 @@ impl SimdRegister<f64> for Avx2Fma {
         Avx2::load(mem)
     }
 
+    #[inline(always)]
+    unsafe fn load_aligned(mem: *const f64) -> Self::Register {
+        Avx2::load_aligned(mem)
+    }
+
     #[inline(always)]
     unsafe fn filled(value: f64) -> Self::Register {
         Avx2::filled(value)
@@ -205,6 +220,12 @@ impl SimdRegister<f64> for Avx2Fma {
     unsafe fn gte(l1: Self::Register, l2: Self::Register) -> Self::Register {
         <Avx2 as SimdRegister<f64>>::gte(l1, l2)
     }
+
+    #[inline(always)]
+    unsafe fn movemask(reg: Self::Register) -> u64 {
+        <Avx2 as SimdRegister<f64>>::movemask(reg)
+    }
+
     #[inline(always)]
     unsafe fn sum_to_value(reg: Self::Register) -> f64 {
         Avx2::sum_to_value(reg)
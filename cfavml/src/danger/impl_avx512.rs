@@ -27,6 +27,11 @@ impl SimdRegister<f32> for Avx512 {
         _mm512_loadu_ps(mem)
     }
 
+    #[inline(always)]
+    unsafe fn load_aligned(mem: *const f32) -> Self::Register {
+        _mm512_load_ps(mem)
+    }
+
     #[inline(always)]
     unsafe fn filled(value: f32) -> Self::Register {
         _mm512_set1_ps(value)
@@ -112,6 +117,11 @@ impl SimdRegister<f32> for Avx512 {
         fast_cvt_mask16_to_m512(mask)
     }
 
+    #[inline(always)]
+    unsafe fn movemask(reg: Self::Register) -> u64 {
+        _mm512_cmp_ps_mask::<_CMP_NEQ_UQ>(reg, _mm512_setzero_ps()) as u64
+    }
+
     #[inline(always)]
     unsafe fn sum_to_value(reg: Self::Register) -> f32 {
         _mm512_reduce_add_ps(reg)
@@ -141,6 +151,11 @@ impl SimdRegister<f64> for Avx512 {
         _mm512_loadu_pd(mem)
     }
 
+    #[inline(always)]
+    unsafe fn load_aligned(mem: *const f64) -> Self::Register {
+        _mm512_load_pd(mem)
+    }
+
     #[inline(always)]
     unsafe fn filled(value: f64) -> Self::Register {
         _mm512_set1_pd(value)
@@ -226,6 +241,11 @@ impl SimdRegister<f64> for Avx512 {
         fast_cvt_mask8_to_m512d(mask)
     }
 
+    #[inline(always)]
+    unsafe fn movemask(reg: Self::Register) -> u64 {
+        _mm512_cmp_pd_mask::<_CMP_NEQ_UQ>(reg, _mm512_setzero_pd()) as u64
+    }
+
     #[inline(always)]
     unsafe fn sum_to_value(reg: Self::Register) -> f64 {
         _mm512_reduce_add_pd(reg)
@@ -255,6 +275,11 @@ impl SimdRegister<i8> for Avx512 {
         _mm512_loadu_si512(mem.cast())
     }
 
+    #[inline(always)]
+    unsafe fn load_aligned(mem: *const i8) -> Self::Register {
+        _mm512_load_si512(mem.cast())
+    }
+
     #[inline(always)]
     unsafe fn filled(value: i8) -> Self::Register {
         _mm512_set1_epi8(value)
@@ -361,6 +386,11 @@ impl SimdRegister<i8> for Avx512 {
         fast_cvt_mask64_to_m512i(mask)
     }
 
+    #[inline(always)]
+    unsafe fn movemask(reg: Self::Register) -> u64 {
+        _mm512_test_epi8_mask(reg, reg)
+    }
+
     #[inline(always)]
     unsafe fn mul_dense(
         l1: DenseLane<Self::Register>,
@@ -433,6 +463,11 @@ impl SimdRegister<i16> for Avx512 {
         _mm512_loadu_si512(mem.cast())
     }
 
+    #[inline(always)]
+    unsafe fn load_aligned(mem: *const i16) -> Self::Register {
+        _mm512_load_si512(mem.cast())
+    }
+
     #[inline(always)]
     unsafe fn filled(value: i16) -> Self::Register {
         _mm512_set1_epi16(value)
@@ -534,6 +569,11 @@ impl SimdRegister<i16> for Avx512 {
         fast_cvt_mask32_to_m512i(mask)
     }
 
+    #[inline(always)]
+    unsafe fn movemask(reg: Self::Register) -> u64 {
+        _mm512_test_epi16_mask(reg, reg) as u64
+    }
+
     #[inline(always)]
     unsafe fn mul_dense(
         l1: DenseLane<Self::Register>,
@@ -596,6 +636,11 @@ impl SimdRegister<i32> for Avx512 {
         _mm512_loadu_si512(mem.cast())
     }
 
+    #[inline(always)]
+    unsafe fn load_aligned(mem: *const i32) -> Self::Register {
+        _mm512_load_si512(mem.cast())
+    }
+
     #[inline(always)]
     unsafe fn filled(value: i32) -> Self::Register {
         _mm512_set1_epi32(value)
@@ -697,6 +742,11 @@ impl SimdRegister<i32> for Avx512 {
         fast_cvt_mask16_to_m512i(mask)
     }
 
+    #[inline(always)]
+    unsafe fn movemask(reg: Self::Register) -> u64 {
+        _mm512_test_epi32_mask(reg, reg) as u64
+    }
+
     #[inline(always)]
     unsafe fn mul_dense(
         l1: DenseLane<Self::Register>,
@@ -744,6 +794,11 @@ impl SimdRegister<i64> for Avx512 {
         _mm512_loadu_si512(mem.cast())
     }
 
+    #[inline(always)]
+    unsafe fn load_aligned(mem: *const i64) -> Self::Register {
+        _mm512_load_si512(mem.cast())
+    }
+
     #[inline(always)]
     unsafe fn filled(value: i64) -> Self::Register {
         _mm512_set1_epi64(value)
@@ -846,6 +901,11 @@ impl SimdRegister<i64> for Avx512 {
         fast_cvt_mask8_to_m512i(mask)
     }
 
+    #[inline(always)]
+    unsafe fn movemask(reg: Self::Register) -> u64 {
+        _mm512_test_epi64_mask(reg, reg) as u64
+    }
+
     #[inline(always)]
     unsafe fn fmadd_dense(
         l1: DenseLane<Self::Register>,
@@ -885,6 +945,11 @@ impl SimdRegister<u8> for Avx512 {
         _mm512_loadu_si512(mem.cast())
     }
 
+    #[inline(always)]
+    unsafe fn load_aligned(mem: *const u8) -> Self::Register {
+        _mm512_load_si512(mem.cast())
+    }
+
     #[inline(always)]
     unsafe fn filled(value: u8) -> Self::Register {
         _mm512_set1_epi8(value as i8)
@@ -985,6 +1050,11 @@ impl SimdRegister<u8> for Avx512 {
         fast_cvt_mask64_to_m512i(mask)
     }
 
+    #[inline(always)]
+    unsafe fn movemask(reg: Self::Register) -> u64 {
+        _mm512_test_epi8_mask(reg, reg)
+    }
+
     #[inline(always)]
     unsafe fn mul_dense(
         l1: DenseLane<Self::Register>,
@@ -1042,6 +1112,11 @@ impl SimdRegister<u16> for Avx512 {
         _mm512_loadu_si512(mem.cast())
     }
 
+    #[inline(always)]
+    unsafe fn load_aligned(mem: *const u16) -> Self::Register {
+        _mm512_load_si512(mem.cast())
+    }
+
     #[inline(always)]
     unsafe fn filled(value: u16) -> Self::Register {
         _mm512_set1_epi16(value as i16)
@@ -1142,6 +1217,11 @@ impl SimdRegister<u16> for Avx512 {
         fast_cvt_mask32_to_m512i(mask)
     }
 
+    #[inline(always)]
+    unsafe fn movemask(reg: Self::Register) -> u64 {
+        _mm512_test_epi16_mask(reg, reg) as u64
+    }
+
     #[inline(always)]
     unsafe fn mul_dense(
         l1: DenseLane<Self::Register>,
@@ -1199,6 +1279,11 @@ impl SimdRegister<u32> for Avx512 {
         _mm512_loadu_si512(mem.cast())
     }
 
+    #[inline(always)]
+    unsafe fn load_aligned(mem: *const u32) -> Self::Register {
+        _mm512_load_si512(mem.cast())
+    }
+
     #[inline(always)]
     unsafe fn filled(value: u32) -> Self::Register {
         _mm512_set1_epi32(value as i32)
@@ -1299,6 +1384,11 @@ impl SimdRegister<u32> for Avx512 {
         fast_cvt_mask16_to_m512i(mask)
     }
 
+    #[inline(always)]
+    unsafe fn movemask(reg: Self::Register) -> u64 {
+        _mm512_test_epi32_mask(reg, reg) as u64
+    }
+
     #[inline(always)]
     unsafe fn mul_dense(
         l1: DenseLane<Self::Register>,
@@ -1346,6 +1436,11 @@ impl SimdRegister<u64> for Avx512 {
         _mm512_loadu_si512(mem.cast())
     }
 
+    #[inline(always)]
+    unsafe fn load_aligned(mem: *const u64) -> Self::Register {
+        _mm512_load_si512(mem.cast())
+    }
+
     #[inline(always)]
     unsafe fn filled(value: u64) -> Self::Register {
         _mm512_set1_epi64(value as i64)
@@ -1455,6 +1550,11 @@ impl SimdRegister<u64> for Avx512 {
         fast_cvt_mask8_to_m512i(mask)
     }
 
+    #[inline(always)]
+    unsafe fn movemask(reg: Self::Register) -> u64 {
+        _mm512_test_epi64_mask(reg, reg) as u64
+    }
+
     #[inline(always)]
     unsafe fn fmadd_dense(
         l1: DenseLane<Self::Register>,
@@ -0,0 +1,312 @@
+#[cfg(target_arch = "x86")]
+use core::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use core::arch::x86_64::*;
+use core::mem;
+
+use super::core_simd_api::{DenseLane, SimdRegister};
+
+/// SSE2 enabled SIMD operations.
+///
+/// This requires the `sse2` CPU feature be enabled, which is part of the `x86_64`
+/// baseline but not guaranteed on 32 bit `x86`.
+///
+/// Only the `f32` and `f64` registers are provided, SSE2 lacks a large portion of the
+/// integer multiply, min/max and comparison instructions the other types would need,
+/// those types will always use the fallback routines when AVX2 is not available.
+pub struct Sse2;
+
+impl SimdRegister<f32> for Sse2 {
+    type Register = __m128;
+
+    #[inline(always)]
+    unsafe fn load(mem: *const f32) -> Self::Register {
+        _mm_loadu_ps(mem)
+    }
+
+    #[inline(always)]
+    unsafe fn load_aligned(mem: *const f32) -> Self::Register {
+        _mm_load_ps(mem)
+    }
+
+    #[inline(always)]
+    unsafe fn filled(value: f32) -> Self::Register {
+        _mm_set1_ps(value)
+    }
+
+    #[inline(always)]
+    unsafe fn zeroed() -> Self::Register {
+        _mm_setzero_ps()
+    }
+
+    #[inline(always)]
+    unsafe fn add(l1: Self::Register, l2: Self::Register) -> Self::Register {
+        _mm_add_ps(l1, l2)
+    }
+
+    #[inline(always)]
+    unsafe fn sub(l1: Self::Register, l2: Self::Register) -> Self::Register {
+        _mm_sub_ps(l1, l2)
+    }
+
+    #[inline(always)]
+    unsafe fn mul(l1: Self::Register, l2: Self::Register) -> Self::Register {
+        _mm_mul_ps(l1, l2)
+    }
+
+    #[inline(always)]
+    unsafe fn div(l1: Self::Register, l2: Self::Register) -> Self::Register {
+        _mm_div_ps(l1, l2)
+    }
+
+    #[inline(always)]
+    unsafe fn fmadd(
+        l1: Self::Register,
+        l2: Self::Register,
+        acc: Self::Register,
+    ) -> Self::Register {
+        // SSE2 has no fused multiply add.
+        let res = <Self as SimdRegister<f32>>::mul(l1, l2);
+        <Self as SimdRegister<f32>>::add(res, acc)
+    }
+
+    #[inline(always)]
+    unsafe fn max(l1: Self::Register, l2: Self::Register) -> Self::Register {
+        _mm_max_ps(l1, l2)
+    }
+
+    #[inline(always)]
+    unsafe fn min(l1: Self::Register, l2: Self::Register) -> Self::Register {
+        _mm_min_ps(l1, l2)
+    }
+
+    #[inline(always)]
+    unsafe fn eq(l1: Self::Register, l2: Self::Register) -> Self::Register {
+        let mask = _mm_cmpeq_ps(l1, l2);
+        _mm_and_ps(mask, _mm_set1_ps(1.0))
+    }
+
+    #[inline(always)]
+    unsafe fn neq(l1: Self::Register, l2: Self::Register) -> Self::Register {
+        let mask = _mm_cmpneq_ps(l1, l2);
+        _mm_and_ps(mask, _mm_set1_ps(1.0))
+    }
+
+    #[inline(always)]
+    unsafe fn lt(l1: Self::Register, l2: Self::Register) -> Self::Register {
+        let mask = _mm_cmplt_ps(l1, l2);
+        _mm_and_ps(mask, _mm_set1_ps(1.0))
+    }
+
+    #[inline(always)]
+    unsafe fn lte(l1: Self::Register, l2: Self::Register) -> Self::Register {
+        let mask = _mm_cmple_ps(l1, l2);
+        _mm_and_ps(mask, _mm_set1_ps(1.0))
+    }
+
+    #[inline(always)]
+    unsafe fn gt(l1: Self::Register, l2: Self::Register) -> Self::Register {
+        let mask = _mm_cmpgt_ps(l1, l2);
+        _mm_and_ps(mask, _mm_set1_ps(1.0))
+    }
+
+    #[inline(always)]
+    unsafe fn gte(l1: Self::Register, l2: Self::Register) -> Self::Register {
+        let mask = _mm_cmpge_ps(l1, l2);
+        _mm_and_ps(mask, _mm_set1_ps(1.0))
+    }
+
+    #[inline(always)]
+    unsafe fn movemask(reg: Self::Register) -> u64 {
+        _mm_movemask_ps(_mm_cmpneq_ps(reg, _mm_setzero_ps())) as u64
+    }
+
+    #[inline(always)]
+    unsafe fn fmadd_dense(
+        l1: DenseLane<Self::Register>,
+        l2: DenseLane<Self::Register>,
+        acc: DenseLane<Self::Register>,
+    ) -> DenseLane<Self::Register> {
+        let res = <Self as SimdRegister<f32>>::mul_dense(l1, l2);
+        <Self as SimdRegister<f32>>::add_dense(res, acc)
+    }
+
+    #[inline(always)]
+    unsafe fn sum_to_value(reg: Self::Register) -> f32 {
+        let left_half = reg;
+        let right_half = _mm_movehl_ps(reg, reg);
+        let sum_dual = _mm_add_ps(left_half, right_half);
+
+        let left_half = sum_dual;
+        let right_half = _mm_shuffle_ps::<0x1>(sum_dual, sum_dual);
+        let sum = _mm_add_ss(left_half, right_half);
+
+        _mm_cvtss_f32(sum)
+    }
+
+    #[inline(always)]
+    unsafe fn max_to_value(reg: Self::Register) -> f32 {
+        let [a, b, c, d] = mem::transmute::<__m128, [f32; 4]>(reg);
+
+        let m1 = a.max(b);
+        let m2 = c.max(d);
+
+        m1.max(m2)
+    }
+
+    #[inline(always)]
+    unsafe fn min_to_value(reg: Self::Register) -> f32 {
+        let [a, b, c, d] = mem::transmute::<__m128, [f32; 4]>(reg);
+
+        let m1 = a.min(b);
+        let m2 = c.min(d);
+
+        m1.min(m2)
+    }
+
+    #[inline(always)]
+    unsafe fn write(mem: *mut f32, reg: Self::Register) {
+        _mm_storeu_ps(mem, reg)
+    }
+}
+
+impl SimdRegister<f64> for Sse2 {
+    type Register = __m128d;
+
+    #[inline(always)]
+    unsafe fn load(mem: *const f64) -> Self::Register {
+        _mm_loadu_pd(mem)
+    }
+
+    #[inline(always)]
+    unsafe fn load_aligned(mem: *const f64) -> Self::Register {
+        _mm_load_pd(mem)
+    }
+
+    #[inline(always)]
+    unsafe fn filled(value: f64) -> Self::Register {
+        _mm_set1_pd(value)
+    }
+
+    #[inline(always)]
+    unsafe fn zeroed() -> Self::Register {
+        _mm_setzero_pd()
+    }
+
+    #[inline(always)]
+    unsafe fn add(l1: Self::Register, l2: Self::Register) -> Self::Register {
+        _mm_add_pd(l1, l2)
+    }
+
+    #[inline(always)]
+    unsafe fn sub(l1: Self::Register, l2: Self::Register) -> Self::Register {
+        _mm_sub_pd(l1, l2)
+    }
+
+    #[inline(always)]
+    unsafe fn mul(l1: Self::Register, l2: Self::Register) -> Self::Register {
+        _mm_mul_pd(l1, l2)
+    }
+
+    #[inline(always)]
+    unsafe fn div(l1: Self::Register, l2: Self::Register) -> Self::Register {
+        _mm_div_pd(l1, l2)
+    }
+
+    #[inline(always)]
+    unsafe fn fmadd(
+        l1: Self::Register,
+        l2: Self::Register,
+        acc: Self::Register,
+    ) -> Self::Register {
+        // SSE2 has no fused multiply add.
+        let res = <Self as SimdRegister<f64>>::mul(l1, l2);
+        <Self as SimdRegister<f64>>::add(res, acc)
+    }
+
+    #[inline(always)]
+    unsafe fn max(l1: Self::Register, l2: Self::Register) -> Self::Register {
+        _mm_max_pd(l1, l2)
+    }
+
+    #[inline(always)]
+    unsafe fn min(l1: Self::Register, l2: Self::Register) -> Self::Register {
+        _mm_min_pd(l1, l2)
+    }
+
+    #[inline(always)]
+    unsafe fn eq(l1: Self::Register, l2: Self::Register) -> Self::Register {
+        let mask = _mm_cmpeq_pd(l1, l2);
+        _mm_and_pd(mask, _mm_set1_pd(1.0))
+    }
+
+    #[inline(always)]
+    unsafe fn neq(l1: Self::Register, l2: Self::Register) -> Self::Register {
+        let mask = _mm_cmpneq_pd(l1, l2);
+        _mm_and_pd(mask, _mm_set1_pd(1.0))
+    }
+
+    #[inline(always)]
+    unsafe fn lt(l1: Self::Register, l2: Self::Register) -> Self::Register {
+        let mask = _mm_cmplt_pd(l1, l2);
+        _mm_and_pd(mask, _mm_set1_pd(1.0))
+    }
+
+    #[inline(always)]
+    unsafe fn lte(l1: Self::Register, l2: Self::Register) -> Self::Register {
+        let mask = _mm_cmple_pd(l1, l2);
+        _mm_and_pd(mask, _mm_set1_pd(1.0))
+    }
+
+    #[inline(always)]
+    unsafe fn gt(l1: Self::Register, l2: Self::Register) -> Self::Register {
+        let mask = _mm_cmpgt_pd(l1, l2);
+        _mm_and_pd(mask, _mm_set1_pd(1.0))
+    }
+
+    #[inline(always)]
+    unsafe fn gte(l1: Self::Register, l2: Self::Register) -> Self::Register {
+        let mask = _mm_cmpge_pd(l1, l2);
+        _mm_and_pd(mask, _mm_set1_pd(1.0))
+    }
+
+    #[inline(always)]
+    unsafe fn movemask(reg: Self::Register) -> u64 {
+        _mm_movemask_pd(_mm_cmpneq_pd(reg, _mm_setzero_pd())) as u64
+    }
+
+    #[inline(always)]
+    unsafe fn fmadd_dense(
+        l1: DenseLane<Self::Register>,
+        l2: DenseLane<Self::Register>,
+        acc: DenseLane<Self::Register>,
+    ) -> DenseLane<Self::Register> {
+        let res = <Self as SimdRegister<f64>>::mul_dense(l1, l2);
+        <Self as SimdRegister<f64>>::add_dense(res, acc)
+    }
+
+    #[inline(always)]
+    unsafe fn sum_to_value(reg: Self::Register) -> f64 {
+        let undef = _mm_undefined_ps();
+        let shuffle_tmp = _mm_movehl_ps(undef, _mm_castpd_ps(reg));
+        let shuffle = _mm_castps_pd(shuffle_tmp);
+        _mm_cvtsd_f64(_mm_add_sd(reg, shuffle))
+    }
+
+    #[inline(always)]
+    unsafe fn max_to_value(reg: Self::Register) -> f64 {
+        let [a, b] = mem::transmute::<__m128d, [f64; 2]>(reg);
+        a.max(b)
+    }
+
+    #[inline(always)]
+    unsafe fn min_to_value(reg: Self::Register) -> f64 {
+        let [a, b] = mem::transmute::<__m128d, [f64; 2]>(reg);
+        a.min(b)
+    }
+
+    #[inline(always)]
+    unsafe fn write(mem: *mut f64, reg: Self::Register) {
+        _mm_storeu_pd(mem, reg)
+    }
+}
@@ -1,5 +1,5 @@
-use std::fmt::Debug;
-use std::iter::zip;
+use core::fmt::Debug;
+use core::iter::zip;
 
 use rand::distributions::{Distribution, Standard};
 
@@ -276,4 +276,21 @@ where
             "Dense lane write failed dense task"
         );
     }
+
+    {
+        let l1 = R::load(small_sample_l1.as_ptr());
+        let l2 = R::load(small_sample_l2.as_ptr());
+        let mask = R::movemask(R::gt(l1, l2));
+
+        let expected_mask = zip(small_sample_l1.iter(), small_sample_l2.iter())
+            .enumerate()
+            .fold(0, |mask, (i, (a, b))| {
+                mask | ((AutoMath::cmp_gt(*a, *b) as u64) << i)
+            });
+        assert_eq!(mask, expected_mask, "Movemask test failed on single task");
+
+        let mask = R::movemask(R::eq(l1, l1));
+        let expected_mask = u64::MAX >> (64 - R::elements_per_lane());
+        assert_eq!(mask, expected_mask, "Movemask test failed on a full mask");
+    }
 }
@@ -1,3 +1,10 @@
+//! The raw, unsafe SIMD routines which power the safe APIs.
+//!
+//! All routines compute offsets into their buffers via pointer arithmetic, which
+//! requires every buffer to be no larger than `isize::MAX` bytes. This is always the
+//! case for slices, and the built-in [MemLoader](crate::mem_loader::MemLoader)
+//! implementations assert it when created.
+
 #![allow(clippy::missing_transmute_annotations)]
 
 mod core_simd_api;
@@ -10,20 +17,44 @@ mod impl_avx512;
 mod impl_fallback;
 #[cfg(target_arch = "aarch64")]
 mod impl_neon;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+mod impl_sse2;
 mod op_arithmetic_vertical;
+mod op_bray_curtis;
+mod op_canberra;
+mod op_cmp_bitmask;
+mod op_cmp_clamp;
 mod op_cmp_max;
 mod op_cmp_min;
+mod op_complex;
 mod op_cosine;
+mod op_cross_entropy;
+mod op_decimate;
 mod op_dot;
+mod op_dot_widen;
+mod op_entropy;
 mod op_euclidean;
+mod op_hadamard;
+mod op_map_sum;
+mod op_median;
+mod op_minkowski;
 mod op_norm;
+mod op_pearson;
+mod op_quaternion;
+mod op_scatter;
+mod op_sign;
 mod op_sum;
+mod op_vecmath;
+mod op_weighted_mean;
+mod op_welford;
 
 mod core_routine_boilerplate;
 pub mod export_agg_ops;
 pub mod export_arithmetic_ops;
 pub mod export_cmp_ops;
 pub mod export_distance_ops;
+pub mod export_layout_ops;
+pub mod export_vecmath_ops;
 #[cfg(test)]
 mod impl_test;
 mod op_cmp_vertical;
@@ -40,14 +71,46 @@ pub use self::impl_avx512::*;
 pub use self::impl_fallback::*;
 #[cfg(target_arch = "aarch64")]
 pub use self::impl_neon::*;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+pub use self::impl_sse2::*;
 pub use self::op_arithmetic_vertical::{
+    generic_add_value_inplace,
     generic_add_vertical,
+    generic_axpy,
+    generic_diff,
+    generic_div_value_inplace,
     generic_div_vertical,
+    generic_linear_combine,
+    generic_linear_combine_inplace,
+    generic_momentum_update,
+    generic_mul_value_inplace,
     generic_mul_vertical,
+    generic_outer,
+    generic_recip_inplace,
+    generic_sub_value_inplace,
     generic_sub_vertical,
 };
-pub use self::op_cmp_max::{generic_cmp_max, generic_cmp_max_vertical};
-pub use self::op_cmp_min::{generic_cmp_min, generic_cmp_min_vertical};
+pub use self::op_bray_curtis::generic_bray_curtis;
+pub use self::op_canberra::generic_canberra;
+pub use self::op_cmp_bitmask::generic_cmp_gt_bitmask;
+pub use self::op_cmp_clamp::{generic_cmp_clamp, generic_cmp_clamp_inplace};
+pub use self::op_cmp_max::{
+    generic_cmp_argmax,
+    generic_cmp_max,
+    generic_cmp_max_columns,
+    generic_cmp_max_cumulative_inplace,
+    generic_cmp_max_floor_counting,
+    generic_cmp_max_vertical,
+    generic_cmp_max_with_count,
+    generic_cmp_top_k,
+};
+pub use self::op_cmp_min::{
+    generic_cmp_argmin,
+    generic_cmp_min,
+    generic_cmp_min_columns,
+    generic_cmp_min_vertical,
+    generic_cmp_min_with_count,
+};
 pub use self::op_cmp_vertical::{
     generic_cmp_eq_vertical,
     generic_cmp_gt_vertical,
@@ -56,13 +119,105 @@ pub use self::op_cmp_vertical::{
     generic_cmp_lte_vertical,
     generic_cmp_neq_vertical,
 };
+pub use self::op_complex::generic_complex_dot_planar;
 #[cfg(test)]
 pub(crate) use self::op_cosine::cosine;
-pub use self::op_cosine::generic_cosine;
-pub use self::op_dot::generic_dot;
-pub use self::op_euclidean::generic_squared_euclidean;
-pub use self::op_norm::generic_squared_norm;
-pub use self::op_sum::generic_sum;
+pub use self::op_cosine::{
+    generic_cosine,
+    generic_cosine_fast,
+    generic_cosine_similarity,
+};
+pub use self::op_cross_entropy::{generic_cross_entropy, generic_logsumexp};
+pub use self::op_decimate::generic_decimate;
+pub use self::op_dot::{
+    generic_col_dot,
+    generic_dense_forward,
+    generic_dot,
+    generic_dot_dd,
+    generic_dot_dense_aligned,
+    generic_dot_diff,
+    generic_dot_progress,
+    generic_dot_self_and_cross,
+    generic_dot_single_register,
+    generic_dot_skip_zero,
+    generic_dot_streaming_both,
+    generic_dot_stride2,
+    generic_dot_strided,
+    generic_dot_wrapping,
+    generic_frobenius_dot,
+    generic_masked_dot,
+    generic_matvec,
+    generic_pairwise_dot,
+    generic_scaled_dot,
+    DotResult,
+    PAIRWISE_DOT_ROWS,
+};
+pub use self::op_dot_widen::{generic_dot_f32_compensated, generic_dot_f32_widen};
+pub use self::op_entropy::{generic_entropy, ENTROPY_MAX_RELATIVE_ERROR};
+pub use self::op_euclidean::{
+    generic_pairwise_squared_euclidean,
+    generic_squared_euclidean,
+    generic_squared_euclidean_batch,
+    generic_squared_euclidean_batch4,
+    generic_weighted_squared_euclidean,
+};
+pub use self::op_hadamard::generic_hadamard_transform_inplace;
+pub use self::op_map_sum::{generic_map_sum, MapScale, MapSquare, SumMap};
+pub use self::op_median::{
+    generic_approx_median,
+    APPROX_MEDIAN_BINS,
+    APPROX_MEDIAN_MAX_RANGE_ERROR,
+};
+pub use self::op_minkowski::{generic_minkowski, MINKOWSKI_MAX_RELATIVE_ERROR};
+pub use self::op_norm::{
+    generic_clip_norm,
+    generic_minmax_normalize_columns_inplace,
+    generic_normalize_inplace,
+    generic_row_norms,
+    generic_squared_norm,
+};
+pub use self::op_pearson::generic_pearson;
+pub use self::op_quaternion::generic_quat_mul;
+pub use self::op_scatter::generic_scatter_add;
+pub use self::op_sign::{generic_copysign_vertical, generic_signum_inplace};
+pub use self::op_sum::{
+    generic_adaptive_sum,
+    generic_cumulative_sum,
+    generic_sum,
+    generic_sum_compensated,
+    generic_sum_exact,
+    generic_sum_hint,
+    generic_sum_i32_widen,
+    generic_sum_scaled,
+    generic_sum_vertical_accumulate,
+    generic_sum_with_status,
+    ReductionStatus,
+    ADAPTIVE_SUM_BLOCK_SIZE,
+    ADAPTIVE_SUM_PROMOTE_THRESHOLD,
+    SUM_SCALAR_THRESHOLD,
+};
+pub use self::op_vecmath::{
+    generic_exp_inplace,
+    generic_gelu_inplace,
+    generic_log_inplace,
+    generic_pow_vertical,
+    generic_softmax,
+    generic_softmax_inplace,
+    generic_softplus_inplace,
+    generic_sqrt_inplace,
+    EXP_MAX_RELATIVE_ERROR,
+    GELU_MAX_ABSOLUTE_ERROR,
+    LOG_MAX_RELATIVE_ERROR,
+    POW_MAX_RELATIVE_ERROR,
+    SOFTMAX_MAX_ABSOLUTE_ERROR,
+    SOFTPLUS_MAX_RELATIVE_ERROR,
+};
+pub use self::op_weighted_mean::{
+    generic_weighted_mean,
+    generic_weighted_sum,
+    generic_weighted_sum_mean,
+};
+pub use self::op_welford::{generic_mean_variance, generic_welford_vertical};
 
 #[allow(non_snake_case)]
 pub(crate) const fn _MM_SHUFFLE(z: u32, y: u32, x: u32, w: u32) -> i32 {
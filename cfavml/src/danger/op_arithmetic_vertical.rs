@@ -1,11 +1,14 @@
-use super::core_routine_boilerplate::apply_vertical_kernel;
+use super::core_routine_boilerplate::{
+    apply_value_inplace_kernel,
+    apply_vertical_kernel,
+};
 use super::core_simd_api::SimdRegister;
 use crate::buffer::WriteOnlyBuffer;
 use crate::math::Math;
 use crate::mem_loader::{IntoMemLoader, MemLoader};
 
 #[inline(always)]
-/// A generic vector addition implementation over one vector and single value.
+/// A generic vector addition implementation adding vector `b` to `a`.
 ///
 /// # Safety
 ///
@@ -34,7 +37,7 @@ where
 }
 
 #[inline(always)]
-/// A generic vector subtraction implementation over one vector and single value.
+/// A generic vector subtraction implementation subtracting vector `b` from `a`.
 ///
 /// # Safety
 ///
@@ -63,7 +66,7 @@ where
 }
 
 #[inline(always)]
-/// A generic vector multiplication implementation over one vector and single value.
+/// A generic vector multiplication implementation multiplying `a` by vector `b`.
 ///
 /// # Safety
 ///
@@ -120,6 +123,491 @@ where
     )
 }
 
+#[inline(always)]
+/// A generic outer product implementation of vectors `a` and `b`, writing the
+/// `a.len() x b.len()` row-major matrix to `result`.
+///
+/// Each row `i` of the matrix is `a[i] * b`, computed as a broadcast multiply of `b`
+/// with [generic_mul_vertical].
+///
+/// # Panics
+///
+/// If `result` is not exactly `a.len() * b.len()` in length.
+///
+/// # Safety
+///
+/// The safety requirements of `M` definition the basic math operations and the
+/// requirements of `R` SIMD register must also be followed.
+pub unsafe fn generic_outer<T, R, M, B3>(a: &[T], b: &[T], result: &mut [B3])
+where
+    T: Copy + IntoMemLoader<T>,
+    T::Loader: MemLoader<Value = T>,
+    R: SimdRegister<T>,
+    M: Math<T>,
+    for<'a> &'a mut [B3]: WriteOnlyBuffer<Item = T>,
+{
+    assert_eq!(
+        result.len(),
+        a.len() * b.len(),
+        "Buffer `result` does not match the size of the outer product of `a` and `b`"
+    );
+
+    // An empty `b` has no rows to write and `chunks_exact_mut` requires a non-zero size.
+    if b.is_empty() {
+        return;
+    }
+
+    for (row, value) in result.chunks_exact_mut(b.len()).zip(a.iter().copied()) {
+        generic_mul_vertical::<T, R, M, _, _, B3>(value, b, row);
+    }
+}
+
+#[inline(always)]
+/// A generic discrete derivative implementation of vector `x`, writing the difference
+/// between each pair of neighbouring elements `x[i + 1] - x[i]` to `result`.
+///
+/// Each block is computed by loading `x` twice, once offset by one element, and
+/// subtracting the two. The offset load starts part way through a register of `x` and
+/// crosses into the next, so both loads are unaligned and every element of `x` is read
+/// by two neighbouring blocks. The blocks stop one element short of the end of `x` so
+/// the offset load never reads past it, the last elements are handled one at a time.
+///
+/// # Panics
+///
+/// If `result` is not exactly `x.len() - 1` in length, an empty `x` requires an empty
+/// `result`.
+///
+/// # Safety
+///
+/// The safety requirements of `M` definition the basic math operations and the
+/// requirements of `R` SIMD register must also be followed.
+pub unsafe fn generic_diff<T, R, M, B2>(x: &[T], mut result: &mut [B2])
+where
+    T: Copy,
+    R: SimdRegister<T>,
+    M: Math<T>,
+    for<'a> &'a mut [B2]: WriteOnlyBuffer<Item = T>,
+{
+    let len = x.len().saturating_sub(1);
+    assert_eq!(
+        result.raw_buffer_len(),
+        len,
+        "Buffer `result` is not one element shorter than `x`"
+    );
+
+    let x_ptr = x.as_ptr();
+    let result_ptr = result.as_write_only_ptr();
+    let offset_from = len % R::elements_per_dense();
+
+    // Operate over dense lanes first.
+    let mut i = 0;
+    while i < (len - offset_from) {
+        let l1 = R::load_dense(x_ptr.add(i + 1));
+        let l2 = R::load_dense(x_ptr.add(i));
+        R::write_dense(result_ptr.add(i), R::sub_dense(l1, l2));
+
+        i += R::elements_per_dense();
+    }
+
+    // Operate over single registers next.
+    let offset_from = offset_from % R::elements_per_lane();
+    while i < (len - offset_from) {
+        let l1 = R::load(x_ptr.add(i + 1));
+        let l2 = R::load(x_ptr.add(i));
+        R::write(result_ptr.add(i), R::sub(l1, l2));
+
+        i += R::elements_per_lane();
+    }
+
+    // Handle the remainder.
+    while i < len {
+        let value = M::sub(x_ptr.add(i + 1).read(), x_ptr.add(i).read());
+        result.write_at(i, value);
+
+        i += 1;
+    }
+}
+
+#[inline(always)]
+/// A generic in place addition implementation adding `value` to each element of `a`.
+///
+/// # Safety
+///
+/// The safety requirements of `M` definition the basic math operations and the
+/// requirements of `R` SIMD register must also be followed.
+pub unsafe fn generic_add_value_inplace<T, R, M>(a: &mut [T], value: T)
+where
+    T: Copy,
+    R: SimdRegister<T>,
+    M: Math<T>,
+{
+    apply_value_inplace_kernel::<T, R>(a, value, R::add_dense, R::add, M::add)
+}
+
+#[inline(always)]
+/// A generic in place subtraction implementation subtracting `value` from each element of `a`.
+///
+/// # Safety
+///
+/// The safety requirements of `M` definition the basic math operations and the
+/// requirements of `R` SIMD register must also be followed.
+pub unsafe fn generic_sub_value_inplace<T, R, M>(a: &mut [T], value: T)
+where
+    T: Copy,
+    R: SimdRegister<T>,
+    M: Math<T>,
+{
+    apply_value_inplace_kernel::<T, R>(a, value, R::sub_dense, R::sub, M::sub)
+}
+
+#[inline(always)]
+/// A generic in place multiplication implementation multiplying each element of `a` by `value`.
+///
+/// # Safety
+///
+/// The safety requirements of `M` definition the basic math operations and the
+/// requirements of `R` SIMD register must also be followed.
+pub unsafe fn generic_mul_value_inplace<T, R, M>(a: &mut [T], value: T)
+where
+    T: Copy,
+    R: SimdRegister<T>,
+    M: Math<T>,
+{
+    apply_value_inplace_kernel::<T, R>(a, value, R::mul_dense, R::mul, M::mul)
+}
+
+#[inline(always)]
+/// A generic in place division implementation dividing each element of `a` by `value`.
+///
+/// A zero `value` is not special cased, floats follow IEEE 754 giving a signed infinity
+/// or `NaN` for zero elements.
+///
+/// # Safety
+///
+/// The safety requirements of `M` definition the basic math operations and the
+/// requirements of `R` SIMD register must also be followed.
+pub unsafe fn generic_div_value_inplace<T, R, M>(a: &mut [T], value: T)
+where
+    T: Copy,
+    R: SimdRegister<T>,
+    M: Math<T>,
+{
+    apply_value_inplace_kernel::<T, R>(a, value, R::div_dense, R::div, M::div)
+}
+
+#[inline(always)]
+/// A generic `axpy` implementation, adding `x` scaled by `alpha` to `y` in place.
+///
+/// `alpha` is broadcast once and each block of `y` is updated with a single fused
+/// multiply add where the register supports it, this is the core update of stochastic
+/// gradient descent, i.e. `weights += -learning_rate * gradient`.
+///
+/// # Panics
+///
+/// If `x` and `y` are not the same length.
+///
+/// # Safety
+///
+/// The safety requirements of `M` definition the basic math operations and the
+/// requirements of `R` SIMD register must also be followed.
+pub unsafe fn generic_axpy<T, R, M>(alpha: T, x: &[T], y: &mut [T])
+where
+    T: Copy,
+    R: SimdRegister<T>,
+    M: Math<T>,
+{
+    assert_eq!(x.len(), y.len(), "Buffers `x` and `y` do not match in size");
+
+    let len = x.len();
+    let x_ptr = x.as_ptr();
+    let y_ptr = y.as_mut_ptr();
+    let offset_from = len % R::elements_per_dense();
+
+    // Operate over dense lanes first.
+    let alpha_dense = R::filled_dense(alpha);
+    let mut i = 0;
+    while i < (len - offset_from) {
+        let l1 = R::load_dense(x_ptr.add(i));
+        let l2 = R::load_dense(y_ptr.add(i));
+        R::write_dense(y_ptr.add(i), R::fmadd_dense(alpha_dense, l1, l2));
+
+        i += R::elements_per_dense();
+    }
+
+    // Operate over single registers next.
+    let alpha_reg = R::filled(alpha);
+    let offset_from = offset_from % R::elements_per_lane();
+    while i < (len - offset_from) {
+        let l1 = R::load(x_ptr.add(i));
+        let l2 = R::load(y_ptr.add(i));
+        R::write(y_ptr.add(i), R::fmadd(alpha_reg, l1, l2));
+
+        i += R::elements_per_lane();
+    }
+
+    // Handle the remainder.
+    while i < len {
+        let value = y_ptr.add(i);
+        value.write(M::add(value.read(), M::mul(alpha, x_ptr.add(i).read())));
+
+        i += 1;
+    }
+}
+
+#[inline(always)]
+/// A generic linear combination implementation of vectors `x` and `y`, writing
+/// `a * x[i] + b * y[i]` to `out`.
+///
+/// `a` and `b` are broadcast once and each block is computed as `fmadd(a, x, b * y)`,
+/// which is a single multiply and fused multiply add where the register supports it.
+///
+/// # Panics
+///
+/// If `x`, `y` and `out` are not all the same length.
+///
+/// # Safety
+///
+/// The safety requirements of `M` definition the basic math operations and the
+/// requirements of `R` SIMD register must also be followed.
+pub unsafe fn generic_linear_combine<T, R, M>(
+    x: &[T],
+    a: T,
+    y: &[T],
+    b: T,
+    out: &mut [T],
+) where
+    T: Copy,
+    R: SimdRegister<T>,
+    M: Math<T>,
+{
+    assert!(
+        x.len() == y.len() && x.len() == out.len(),
+        "Buffers `x`, `y` and `out` do not match in size"
+    );
+
+    linear_combine::<T, R, M>(x.as_ptr(), a, y.as_ptr(), b, out.as_mut_ptr(), x.len())
+}
+
+#[inline(always)]
+/// A generic linear combination implementation of vectors `x` and `y`, writing
+/// `a * x[i] + b * y[i]` back into `x`.
+///
+/// This is [generic_linear_combine] where the output aliases `x`, to alias `y` instead
+/// swap the vectors along with their scales.
+///
+/// # Panics
+///
+/// If `x` and `y` are not the same length.
+///
+/// # Safety
+///
+/// The safety requirements of `M` definition the basic math operations and the
+/// requirements of `R` SIMD register must also be followed.
+pub unsafe fn generic_linear_combine_inplace<T, R, M>(x: &mut [T], a: T, y: &[T], b: T)
+where
+    T: Copy,
+    R: SimdRegister<T>,
+    M: Math<T>,
+{
+    assert_eq!(x.len(), y.len(), "Buffers `x` and `y` do not match in size");
+
+    let x_ptr = x.as_mut_ptr();
+    linear_combine::<T, R, M>(x_ptr, a, y.as_ptr(), b, x_ptr, x.len())
+}
+
+#[inline(always)]
+/// A generic element-wise reciprocal implementation, writing `1 / a[i]` back into `a`.
+///
+/// This is an exact division against a broadcast `1`, not an approximation, so a zero
+/// element gives an infinity with the sign of the zero as per IEEE 754.
+///
+/// This is only meaningful for floating point types.
+///
+/// # Safety
+///
+/// The safety requirements of `M` definition the basic math operations and the
+/// requirements of `R` SIMD register must also be followed.
+pub unsafe fn generic_recip_inplace<T, R, M>(a: &mut [T])
+where
+    T: Copy,
+    R: SimdRegister<T>,
+    M: Math<T>,
+{
+    let len = a.len();
+    let data_ptr = a.as_mut_ptr();
+    let offset_from = len % R::elements_per_dense();
+
+    // Operate over dense lanes first.
+    let one_dense = R::filled_dense(M::one());
+    let mut i = 0;
+    while i < (len - offset_from) {
+        let l1 = R::load_dense(data_ptr.add(i));
+        R::write_dense(data_ptr.add(i), R::div_dense(one_dense, l1));
+
+        i += R::elements_per_dense();
+    }
+
+    // Operate over single registers next.
+    let one_reg = R::filled(M::one());
+    let offset_from = offset_from % R::elements_per_lane();
+    while i < (len - offset_from) {
+        let l1 = R::load(data_ptr.add(i));
+        R::write(data_ptr.add(i), R::div(one_reg, l1));
+
+        i += R::elements_per_lane();
+    }
+
+    // Handle the remainder.
+    while i < len {
+        let value = data_ptr.add(i);
+        value.write(M::div(M::one(), value.read()));
+
+        i += 1;
+    }
+}
+
+#[inline(always)]
+/// A generic momentum SGD implementation, updating `velocity` and `params` in place
+/// with `velocity[i] = momentum * velocity[i] + gradient[i]` followed by
+/// `params[i] -= learning_rate * velocity[i]`.
+///
+/// Both updates are done while a block is held in registers, so each buffer is only
+/// passed over once, using fused multiply adds where the register supports them.
+///
+/// # Panics
+///
+/// If `velocity`, `gradient` and `params` are not the same length.
+///
+/// # Safety
+///
+/// The safety requirements of `M` definition the basic math operations and the
+/// requirements of `R` SIMD register must also be followed.
+pub unsafe fn generic_momentum_update<T, R, M>(
+    velocity: &mut [T],
+    gradient: &[T],
+    momentum: T,
+    learning_rate: T,
+    params: &mut [T],
+) where
+    T: Copy,
+    R: SimdRegister<T>,
+    M: Math<T>,
+{
+    assert!(
+        velocity.len() == gradient.len() && velocity.len() == params.len(),
+        "Buffers `velocity`, `gradient` and `params` do not match in size"
+    );
+
+    let len = velocity.len();
+    let velocity_ptr = velocity.as_mut_ptr();
+    let gradient_ptr = gradient.as_ptr();
+    let params_ptr = params.as_mut_ptr();
+    let offset_from = len % R::elements_per_dense();
+
+    // Subtracting the step is done as adding the step scaled by the negated rate.
+    let step = M::sub(M::zero(), learning_rate);
+
+    // Operate over dense lanes first.
+    let momentum_dense = R::filled_dense(momentum);
+    let step_dense = R::filled_dense(step);
+    let mut i = 0;
+    while i < (len - offset_from) {
+        let v = R::load_dense(velocity_ptr.add(i));
+        let g = R::load_dense(gradient_ptr.add(i));
+        let p = R::load_dense(params_ptr.add(i));
+
+        let v = R::fmadd_dense(momentum_dense, v, g);
+        R::write_dense(velocity_ptr.add(i), v);
+        R::write_dense(params_ptr.add(i), R::fmadd_dense(step_dense, v, p));
+
+        i += R::elements_per_dense();
+    }
+
+    // Operate over single registers next.
+    let momentum_reg = R::filled(momentum);
+    let step_reg = R::filled(step);
+    let offset_from = offset_from % R::elements_per_lane();
+    while i < (len - offset_from) {
+        let v = R::load(velocity_ptr.add(i));
+        let g = R::load(gradient_ptr.add(i));
+        let p = R::load(params_ptr.add(i));
+
+        let v = R::fmadd(momentum_reg, v, g);
+        R::write(velocity_ptr.add(i), v);
+        R::write(params_ptr.add(i), R::fmadd(step_reg, v, p));
+
+        i += R::elements_per_lane();
+    }
+
+    // Handle the remainder.
+    while i < len {
+        let v = M::add(
+            M::mul(momentum, velocity_ptr.add(i).read()),
+            gradient_ptr.add(i).read(),
+        );
+        velocity_ptr.add(i).write(v);
+
+        let p = params_ptr.add(i);
+        p.write(M::add(M::mul(step, v), p.read()));
+
+        i += 1;
+    }
+}
+
+#[inline(always)]
+/// Writes `a * x[i] + b * y[i]` to `out[i]` for `len` elements.
+///
+/// Each block is fully loaded before it is written, so `out` may point to the same
+/// memory as `x` or `y`.
+unsafe fn linear_combine<T, R, M>(
+    x_ptr: *const T,
+    a: T,
+    y_ptr: *const T,
+    b: T,
+    out_ptr: *mut T,
+    len: usize,
+) where
+    T: Copy,
+    R: SimdRegister<T>,
+    M: Math<T>,
+{
+    let offset_from = len % R::elements_per_dense();
+
+    // Operate over dense lanes first.
+    let a_dense = R::filled_dense(a);
+    let b_dense = R::filled_dense(b);
+    let mut i = 0;
+    while i < (len - offset_from) {
+        let l1 = R::load_dense(x_ptr.add(i));
+        let l2 = R::load_dense(y_ptr.add(i));
+        let result = R::fmadd_dense(a_dense, l1, R::mul_dense(b_dense, l2));
+        R::write_dense(out_ptr.add(i), result);
+
+        i += R::elements_per_dense();
+    }
+
+    // Operate over single registers next.
+    let a_reg = R::filled(a);
+    let b_reg = R::filled(b);
+    let offset_from = offset_from % R::elements_per_lane();
+    while i < (len - offset_from) {
+        let l1 = R::load(x_ptr.add(i));
+        let l2 = R::load(y_ptr.add(i));
+        R::write(out_ptr.add(i), R::fmadd(a_reg, l1, R::mul(b_reg, l2)));
+
+        i += R::elements_per_lane();
+    }
+
+    // Handle the remainder.
+    while i < len {
+        let l1 = x_ptr.add(i).read();
+        let l2 = y_ptr.add(i).read();
+        out_ptr.add(i).write(M::add(M::mul(a, l1), M::mul(b, l2)));
+
+        i += 1;
+    }
+}
+
 #[cfg(test)]
 pub(crate) mod tests {
     use super::*;
@@ -128,7 +616,7 @@ pub(crate) mod tests {
 
     pub(crate) unsafe fn test_simple_vector_add<T, R>(l1: Vec<T>, l2: Vec<T>)
     where
-        T: Copy + PartialEq + std::fmt::Debug,
+        T: Copy + PartialEq + core::fmt::Debug,
         R: SimdRegister<T>,
         crate::math::AutoMath: Math<T>,
         for<'a> &'a mut [T]: WriteOnlyBuffer<Item = T>,
@@ -146,9 +634,73 @@ pub(crate) mod tests {
         assert_eq!(result, expected_result, "value mismatch");
     }
 
+    pub(crate) unsafe fn test_vertical_add_rows<T, R>(l1: Vec<T>, l2: Vec<T>)
+    where
+        T: Copy + PartialEq + core::fmt::Debug,
+        R: SimdRegister<T>,
+        crate::math::AutoMath: Math<T>,
+        for<'a> &'a mut [T]: WriteOnlyBuffer<Item = T>,
+    {
+        use crate::math::AutoMath;
+
+        // Accumulating the rows of a matrix must give the column sums, which differ from
+        // the column min in any column holding more than one non-zero value.
+        let dims = l1.len() / 2;
+        let rows = [
+            &l1[..dims],
+            &l2[..dims],
+            &l1[dims..dims * 2],
+            &l2[dims..dims * 2],
+        ];
+
+        let mut result = vec![AutoMath::zero(); dims];
+        for row in rows {
+            let total = result.clone();
+            generic_add_vertical::<T, R, AutoMath, _, _, _>(&total, row, &mut result);
+        }
+
+        let mut expected_sum = vec![AutoMath::zero(); dims];
+        let mut expected_min = rows[0].to_vec();
+        for row in rows {
+            for (i, value) in row.iter().copied().enumerate() {
+                expected_sum[i] = AutoMath::add(expected_sum[i], value);
+                expected_min[i] = AutoMath::cmp_min(expected_min[i], value);
+            }
+        }
+        assert_eq!(result, expected_sum, "value mismatch");
+        assert_ne!(
+            result, expected_min,
+            "vertical add should not match the vertical min"
+        );
+    }
+
+    pub(crate) unsafe fn test_vertical_lengths<T, R>(l1: Vec<T>, l2: Vec<T>)
+    where
+        T: Copy + PartialEq + core::fmt::Debug,
+        R: SimdRegister<T>,
+        crate::math::AutoMath: Math<T>,
+        for<'a> &'a mut [T]: WriteOnlyBuffer<Item = T>,
+    {
+        // Lengths made up of only dense lanes, as well as ones leaving a register and
+        // scalar tail.
+        let lens = [
+            R::elements_per_dense() * 2,
+            R::elements_per_dense() + R::elements_per_lane(),
+            131,
+            3,
+            0,
+        ];
+        for len in lens {
+            let (a, b) = (l1[..len].to_vec(), l2[..len].to_vec());
+            test_simple_vector_add::<T, R>(a.clone(), b.clone());
+            test_simple_vector_sub::<T, R>(a.clone(), b.clone());
+            test_simple_vector_mul::<T, R>(a, b);
+        }
+    }
+
     pub(crate) unsafe fn test_simple_vector_sub<T, R>(l1: Vec<T>, l2: Vec<T>)
     where
-        T: Copy + PartialEq + std::fmt::Debug,
+        T: Copy + PartialEq + core::fmt::Debug,
         R: SimdRegister<T>,
         crate::math::AutoMath: Math<T>,
         for<'a> &'a mut [T]: WriteOnlyBuffer<Item = T>,
@@ -168,7 +720,7 @@ pub(crate) mod tests {
 
     pub(crate) unsafe fn test_simple_vector_div<T, R>(l1: Vec<T>, l2: Vec<T>)
     where
-        T: Copy + PartialEq + std::fmt::Debug,
+        T: Copy + PartialEq + core::fmt::Debug,
         R: SimdRegister<T>,
         crate::math::AutoMath: Math<T>,
         for<'a> &'a mut [T]: WriteOnlyBuffer<Item = T>,
@@ -186,9 +738,242 @@ pub(crate) mod tests {
         assert_eq!(result, expected_result, "value mismatch");
     }
 
+    pub(crate) unsafe fn test_simple_outer<T, R>(l1: Vec<T>, l2: Vec<T>)
+    where
+        T: Copy + PartialEq + core::fmt::Debug + IntoMemLoader<T>,
+        T::Loader: MemLoader<Value = T>,
+        R: SimdRegister<T>,
+        crate::math::AutoMath: Math<T>,
+        for<'a> &'a mut [T]: WriteOnlyBuffer<Item = T>,
+    {
+        use crate::math::AutoMath;
+
+        let mut result = vec![AutoMath::zero(); l1.len() * l2.len()];
+        generic_outer::<T, R, AutoMath, _>(&l1, &l2, &mut result);
+
+        let mut expected_result = Vec::new();
+        for a in l1.iter().copied() {
+            for b in l2.iter().copied() {
+                expected_result.push(AutoMath::mul(a, b));
+            }
+        }
+        assert_eq!(result, expected_result, "value mismatch");
+    }
+
+    pub(crate) unsafe fn test_value_inplace<T, R>(l1: Vec<T>, value: T)
+    where
+        T: Copy + PartialEq + core::fmt::Debug,
+        R: SimdRegister<T>,
+        crate::math::AutoMath: Math<T>,
+    {
+        use crate::math::AutoMath;
+
+        let check = |result: &[T], op: fn(T, T) -> T, case: &str| {
+            let expected_result = l1[..result.len()]
+                .iter()
+                .map(|v| op(*v, value))
+                .collect::<Vec<_>>();
+            assert_eq!(result, expected_result, "value mismatch on {case}");
+        };
+
+        for len in [l1.len(), 131, 3] {
+            let mut result = l1[..len].to_vec();
+            generic_add_value_inplace::<T, R, AutoMath>(&mut result, value);
+            check(&result, AutoMath::add, "add");
+
+            let mut result = l1[..len].to_vec();
+            generic_sub_value_inplace::<T, R, AutoMath>(&mut result, value);
+            check(&result, AutoMath::sub, "sub");
+
+            let mut result = l1[..len].to_vec();
+            generic_mul_value_inplace::<T, R, AutoMath>(&mut result, value);
+            check(&result, AutoMath::mul, "mul");
+
+            let mut result = l1[..len].to_vec();
+            generic_div_value_inplace::<T, R, AutoMath>(&mut result, value);
+            check(&result, AutoMath::div, "div");
+        }
+    }
+
+    pub(crate) unsafe fn test_simple_axpy<T, R>(l1: Vec<T>, l2: Vec<T>, alpha: T)
+    where
+        T: Copy + PartialEq + core::fmt::Debug,
+        R: SimdRegister<T>,
+        crate::math::AutoMath: Math<T>,
+    {
+        use crate::math::AutoMath;
+
+        let mut result = l2.clone();
+        generic_axpy::<T, R, AutoMath>(alpha, &l1, &mut result);
+
+        for (i, value) in result.iter().enumerate() {
+            let expected_value = AutoMath::add(l2[i], AutoMath::mul(alpha, l1[i]));
+            assert!(
+                AutoMath::is_close(*value, expected_value),
+                "value mismatch on index {i} {value:?} vs {expected_value:?}"
+            );
+        }
+    }
+
+    pub(crate) unsafe fn test_simple_linear_combine<T, R>(
+        l1: Vec<T>,
+        l2: Vec<T>,
+        a: T,
+        b: T,
+    ) where
+        T: Copy + PartialEq + core::fmt::Debug,
+        R: SimdRegister<T>,
+        crate::math::AutoMath: Math<T>,
+    {
+        use crate::math::AutoMath;
+
+        let expected_result = l1
+            .iter()
+            .zip(l2.iter())
+            .map(|(x, y)| AutoMath::add(AutoMath::mul(a, *x), AutoMath::mul(b, *y)))
+            .collect::<Vec<_>>();
+        let check = |result: &[T], case: &str| {
+            for (i, (value, expected_value)) in
+                result.iter().zip(&expected_result).enumerate()
+            {
+                assert!(
+                    AutoMath::is_close(*value, *expected_value),
+                    "value mismatch on {case} index {i} {value:?} vs {expected_value:?}"
+                );
+            }
+        };
+
+        let mut result = vec![AutoMath::zero(); l1.len()];
+        generic_linear_combine::<T, R, AutoMath>(&l1, a, &l2, b, &mut result);
+        check(&result, "out");
+
+        // The output aliasing `x`.
+        let mut result = l1.clone();
+        generic_linear_combine_inplace::<T, R, AutoMath>(&mut result, a, &l2, b);
+        check(&result, "aliased x");
+
+        // The output aliasing `y`, by swapping the vectors and their scales.
+        let mut result = l2.clone();
+        generic_linear_combine_inplace::<T, R, AutoMath>(&mut result, b, &l1, a);
+        check(&result, "aliased y");
+    }
+
+    pub(crate) unsafe fn test_simple_recip_inplace<T, R>(l1: Vec<T>, special: Vec<T>)
+    where
+        T: Copy + PartialEq + core::fmt::Debug,
+        R: SimdRegister<T>,
+        crate::math::AutoMath: Math<T>,
+    {
+        use crate::math::AutoMath;
+
+        // The special values are placed at the start and in the remainder.
+        let mut values = l1.clone();
+        values[..special.len()].copy_from_slice(&special);
+        let tail = values.len() - special.len();
+        values[tail..].copy_from_slice(&special);
+
+        let mut result = values.clone();
+        generic_recip_inplace::<T, R, AutoMath>(&mut result);
+
+        for (i, (value, x)) in result.iter().zip(values.iter()).enumerate() {
+            let expected_value = AutoMath::div(AutoMath::one(), *x);
+            assert_eq!(
+                *value, expected_value,
+                "value mismatch on index {i} 1 / {x:?}"
+            );
+        }
+    }
+
+    pub(crate) unsafe fn test_simple_momentum_update<T, R>(
+        l1: Vec<T>,
+        l2: Vec<T>,
+        momentum: T,
+        learning_rate: T,
+    ) where
+        T: Copy + PartialEq + core::fmt::Debug,
+        R: SimdRegister<T>,
+        crate::math::AutoMath: Math<T>,
+    {
+        use crate::math::AutoMath;
+
+        let mut velocity = vec![AutoMath::zero(); l1.len()];
+        let mut params = l2.clone();
+        let mut expected_velocity = velocity.clone();
+        let mut expected_params = params.clone();
+
+        // The second step starts from a non-zero velocity so the momentum is exercised.
+        for step in 0..2 {
+            generic_momentum_update::<T, R, AutoMath>(
+                &mut velocity,
+                &l1,
+                momentum,
+                learning_rate,
+                &mut params,
+            );
+
+            for i in 0..l1.len() {
+                let v =
+                    AutoMath::add(AutoMath::mul(momentum, expected_velocity[i]), l1[i]);
+                expected_velocity[i] = v;
+                expected_params[i] =
+                    AutoMath::sub(expected_params[i], AutoMath::mul(learning_rate, v));
+            }
+
+            let results = velocity.iter().zip(&expected_velocity);
+            for (i, (value, expected_value)) in results.enumerate() {
+                assert!(
+                    AutoMath::is_close(*value, *expected_value),
+                    "value mismatch on step {step} velocity {i} {value:?}"
+                );
+            }
+            let results = params.iter().zip(&expected_params);
+            for (i, (value, expected_value)) in results.enumerate() {
+                assert!(
+                    AutoMath::is_close(*value, *expected_value),
+                    "value mismatch on step {step} params {i} {value:?}"
+                );
+            }
+        }
+    }
+
+    pub(crate) unsafe fn test_simple_diff<T, R>(l1: Vec<T>)
+    where
+        T: Copy + PartialEq + core::fmt::Debug,
+        R: SimdRegister<T>,
+        crate::math::AutoMath: Math<T>,
+        for<'a> &'a mut [T]: WriteOnlyBuffer<Item = T>,
+    {
+        use crate::math::AutoMath;
+
+        // Cover the dense, single register and remainder loops, as well as lengths
+        // where the offset load ends exactly on the last element.
+        let lens = [
+            0,
+            1,
+            2,
+            R::elements_per_lane(),
+            R::elements_per_lane() + 1,
+            R::elements_per_dense() + 1,
+            R::elements_per_dense() + R::elements_per_lane() + 3,
+            l1.len(),
+        ];
+        for len in lens {
+            let x = &l1[..len];
+
+            let mut result = vec![AutoMath::zero(); len.saturating_sub(1)];
+            generic_diff::<T, R, AutoMath, _>(x, &mut result);
+
+            let expected_result = x
+                .windows(2)
+                .map(|pair| AutoMath::sub(pair[1], pair[0]))
+                .collect::<Vec<_>>();
+            assert_eq!(result, expected_result, "value mismatch on len {len}");
+        }
+    }
+
     pub(crate) unsafe fn test_simple_vector_mul<T, R>(l1: Vec<T>, l2: Vec<T>)
     where
-        T: Copy + PartialEq + std::fmt::Debug,
+        T: Copy + PartialEq + core::fmt::Debug,
         R: SimdRegister<T>,
         crate::math::AutoMath: Math<T>,
         for<'a> &'a mut [T]: WriteOnlyBuffer<Item = T>,
@@ -208,7 +993,7 @@ pub(crate) mod tests {
 
     pub(crate) unsafe fn test_broadcast_value_add<T, R>(l1: Vec<T>, value: T)
     where
-        T: Copy + PartialEq + std::fmt::Debug + IntoMemLoader<T>,
+        T: Copy + PartialEq + core::fmt::Debug + IntoMemLoader<T>,
         R: SimdRegister<T>,
         crate::math::AutoMath: Math<T>,
         for<'a> &'a mut [T]: WriteOnlyBuffer<Item = T>,
@@ -228,7 +1013,7 @@ pub(crate) mod tests {
 
     pub(crate) unsafe fn test_broadcast_value_sub<T, R>(l1: Vec<T>, value: T)
     where
-        T: Copy + PartialEq + std::fmt::Debug + IntoMemLoader<T>,
+        T: Copy + PartialEq + core::fmt::Debug + IntoMemLoader<T>,
         R: SimdRegister<T>,
         crate::math::AutoMath: Math<T>,
         for<'a> &'a mut [T]: WriteOnlyBuffer<Item = T>,
@@ -248,7 +1033,7 @@ pub(crate) mod tests {
 
     pub(crate) unsafe fn test_broadcast_value_div<T, R>(l1: Vec<T>, value: T)
     where
-        T: Copy + PartialEq + std::fmt::Debug + IntoMemLoader<T>,
+        T: Copy + PartialEq + core::fmt::Debug + IntoMemLoader<T>,
         R: SimdRegister<T>,
         crate::math::AutoMath: Math<T>,
         for<'a> &'a mut [T]: WriteOnlyBuffer<Item = T>,
@@ -268,7 +1053,7 @@ pub(crate) mod tests {
 
     pub(crate) unsafe fn test_broadcast_value_mul<T, R>(l1: Vec<T>, value: T)
     where
-        T: Copy + PartialEq + std::fmt::Debug + IntoMemLoader<T>,
+        T: Copy + PartialEq + core::fmt::Debug + IntoMemLoader<T>,
         R: SimdRegister<T>,
         crate::math::AutoMath: Math<T>,
         for<'a> &'a mut [T]: WriteOnlyBuffer<Item = T>,
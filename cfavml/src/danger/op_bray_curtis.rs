@@ -0,0 +1,105 @@
+use crate::danger::SimdRegister;
+use crate::math::Math;
+
+#[inline(always)]
+/// A generic Bray-Curtis dissimilarity implementation over two `f64` vectors, computing
+/// `sum(|a[i] - b[i]|) / sum(a[i] + b[i])`.
+///
+/// Both sums are accumulated in a single pass, each with its own set of dense `R`
+/// registers, with the absolute difference taken as `max(x, -x)`.
+///
+/// The values of `a` and `b` are assumed to be non-negative abundances, so a zero
+/// denominator only occurs when both vectors are entirely zero. They are identical in
+/// that case and `0` is returned.
+///
+/// # Panics
+///
+/// If vectors `a` and `b` are not equal in length.
+///
+/// # Safety
+///
+/// The safety requirements of `M` definition the basic math operations and the
+/// requirements of `R` SIMD register must also be followed.
+pub unsafe fn generic_bray_curtis<R, M>(a: &[f64], b: &[f64]) -> f64
+where
+    R: SimdRegister<f64>,
+    M: Math<f64>,
+{
+    assert_eq!(a.len(), b.len(), "Buffers `a` and `b` do not match in size");
+
+    let len = a.len();
+    let offset_from = len % R::elements_per_dense();
+
+    let zero = R::zeroed_dense();
+
+    let mut diff_total = R::zeroed_dense();
+    let mut sum_total = R::zeroed_dense();
+    let mut i = 0;
+    while i < (len - offset_from) {
+        let l1 = R::load_dense(a.as_ptr().add(i));
+        let l2 = R::load_dense(b.as_ptr().add(i));
+
+        let diff = R::sub_dense(l1, l2);
+        let abs_diff = R::max_dense(diff, R::sub_dense(zero, diff));
+        diff_total = R::add_dense(diff_total, abs_diff);
+        sum_total = R::add_dense(sum_total, R::add_dense(l1, l2));
+
+        i += R::elements_per_dense();
+    }
+
+    // Handle the remainder.
+    let mut diff_total = R::sum_to_value(R::sum_to_register(diff_total));
+    let mut sum_total = R::sum_to_value(R::sum_to_register(sum_total));
+    while i < len {
+        let x = *a.get_unchecked(i);
+        let y = *b.get_unchecked(i);
+
+        diff_total = M::add(diff_total, M::abs(M::sub(x, y)));
+        sum_total = M::add(sum_total, M::add(x, y));
+
+        i += 1;
+    }
+
+    if M::cmp_eq(sum_total, M::zero()) {
+        return M::zero();
+    }
+
+    M::div(diff_total, sum_total)
+}
+
+#[cfg(test)]
+pub(crate) unsafe fn test_bray_curtis<R>()
+where
+    R: SimdRegister<f64>,
+{
+    use crate::math::AutoMath;
+
+    let reference = |a: &[f64], b: &[f64]| -> f64 {
+        let diff = a.iter().zip(b).map(|(x, y)| (x - y).abs()).sum::<f64>();
+        let sum = a.iter().zip(b).map(|(x, y)| x + y).sum::<f64>();
+        diff / sum
+    };
+
+    // Scale the samples up to abundance counts.
+    let (l1, l2) = crate::test_utils::get_sample_vectors::<f64>(1043);
+    let a = l1.iter().map(|v| (v * 50.0).floor()).collect::<Vec<_>>();
+    let b = l2.iter().map(|v| (v * 50.0).floor()).collect::<Vec<_>>();
+
+    for len in [1, 7, 131, 1043] {
+        let value = generic_bray_curtis::<R, AutoMath>(&a[..len], &b[..len]);
+        let expected_value = reference(&a[..len], &b[..len]);
+        assert!(
+            AutoMath::is_close(value, expected_value),
+            "value missmatch on len {len} {value} vs {expected_value}"
+        );
+    }
+
+    let value = generic_bray_curtis::<R, AutoMath>(&a, &a);
+    assert_eq!(value, 0.0, "value missmatch on identical vectors {value}");
+
+    let value = generic_bray_curtis::<R, AutoMath>(&[0.0; 131], &[0.0; 131]);
+    assert_eq!(value, 0.0, "value missmatch on zero vectors {value}");
+
+    let value = generic_bray_curtis::<R, AutoMath>(&[], &[]);
+    assert_eq!(value, 0.0, "value missmatch on empty vectors {value}");
+}
@@ -0,0 +1,114 @@
+use crate::danger::SimdRegister;
+use crate::math::Math;
+
+#[inline(always)]
+/// A generic Canberra distance implementation over two `f64` vectors, computing
+/// `sum(|a[i] - b[i]| / (|a[i]| + |b[i]|))`.
+///
+/// The absolute values are taken as `max(x, -x)` a dense lane at a time and each term is
+/// divided and accumulated in the `R` registers. A term where both `a[i]` and `b[i]` are
+/// zero contributes `0` rather than `NaN`, this is done by clamping the denominator to
+/// [f64::MIN_POSITIVE] as the numerator is always zero when the denominator is.
+///
+/// Denominators in the subnormal range are clamped in the same way, so their terms
+/// underestimate the exact ratio.
+///
+/// # Panics
+///
+/// If vectors `a` and `b` are not equal in length.
+///
+/// # Safety
+///
+/// The safety requirements of `M` definition the basic math operations and the
+/// requirements of `R` SIMD register must also be followed.
+pub unsafe fn generic_canberra<R, M>(a: &[f64], b: &[f64]) -> f64
+where
+    R: SimdRegister<f64>,
+    M: Math<f64>,
+{
+    assert_eq!(a.len(), b.len(), "Buffers `a` and `b` do not match in size");
+
+    let len = a.len();
+    let offset_from = len % R::elements_per_dense();
+
+    let zero = R::zeroed_dense();
+    let smallest = R::filled_dense(f64::MIN_POSITIVE);
+
+    let mut total = R::zeroed_dense();
+    let mut i = 0;
+    while i < (len - offset_from) {
+        let l1 = R::load_dense(a.as_ptr().add(i));
+        let l2 = R::load_dense(b.as_ptr().add(i));
+
+        let diff = R::sub_dense(l1, l2);
+        let numerator = R::max_dense(diff, R::sub_dense(zero, diff));
+        let denominator = R::add_dense(
+            R::max_dense(l1, R::sub_dense(zero, l1)),
+            R::max_dense(l2, R::sub_dense(zero, l2)),
+        );
+        let term = R::div_dense(numerator, R::max_dense(denominator, smallest));
+        total = R::add_dense(total, term);
+
+        i += R::elements_per_dense();
+    }
+
+    // Handle the remainder.
+    let mut total = R::sum_to_value(R::sum_to_register(total));
+    while i < len {
+        let x = *a.get_unchecked(i);
+        let y = *b.get_unchecked(i);
+
+        let numerator = M::abs(M::sub(x, y));
+        let denominator = M::add(M::abs(x), M::abs(y));
+        let term = M::div(numerator, M::cmp_max(denominator, f64::MIN_POSITIVE));
+        total = M::add(total, term);
+
+        i += 1;
+    }
+
+    total
+}
+
+#[cfg(test)]
+pub(crate) unsafe fn test_canberra<R>()
+where
+    R: SimdRegister<f64>,
+{
+    use crate::math::AutoMath;
+
+    let reference = |a: &[f64], b: &[f64]| -> f64 {
+        a.iter()
+            .zip(b)
+            .map(|(x, y)| {
+                let denominator = x.abs() + y.abs();
+                if denominator == 0.0 {
+                    0.0
+                } else {
+                    (x - y).abs() / denominator
+                }
+            })
+            .sum::<f64>()
+    };
+
+    let (l1, l2) = crate::test_utils::get_sample_vectors::<f64>(1043);
+    // Shift the samples so the signs are mixed, with a few dimensions zero in both.
+    let mut a = l1.iter().map(|v| v - 0.5).collect::<Vec<_>>();
+    let mut b = l2.iter().map(|v| v - 0.5).collect::<Vec<_>>();
+    for i in [0, 3, 64, 130, 1042] {
+        a[i] = 0.0;
+        b[i] = 0.0;
+    }
+
+    for len in [0, 1, 7, 131, 1043] {
+        let value = generic_canberra::<R, AutoMath>(&a[..len], &b[..len]);
+        let expected_value = reference(&a[..len], &b[..len]);
+        assert!(
+            AutoMath::is_close(value, expected_value),
+            "value missmatch on len {len} {value} vs {expected_value}"
+        );
+    }
+
+    // Identical vectors have no distance, including the zero dimensions.
+    let value = generic_canberra::<R, AutoMath>(&a, &a);
+    assert_eq!(value, 0.0, "value missmatch on identical vectors {value}");
+}
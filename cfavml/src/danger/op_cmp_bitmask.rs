@@ -0,0 +1,116 @@
+use crate::danger::SimdRegister;
+use crate::math::Math;
+use crate::mem_loader::{IntoMemLoader, MemLoader};
+
+/// The number of element flags packed into each word of a bitmask.
+const BITS_PER_WORD: usize = u64::BITS as usize;
+
+#[inline(always)]
+/// A generic vector element-wise check of vectors `a` and `b` checking if
+/// element of `a` is **_greater than_** element of `b`.
+///
+/// Unlike [generic_cmp_gt_vertical](crate::danger::generic_cmp_gt_vertical) the
+/// result of each element check is packed as a single bit into `result`, where
+/// element `i` is stored as bit `i % 64` of word `i / 64`. Each register of results is
+/// packed with [SimdRegister::movemask] and merged into the word it belongs to.
+///
+/// Any bits in the last word beyond the length of `a` are always `0`.
+///
+/// # Safety
+///
+/// `b` must be projectable to the length of `a`, the safety requirements of
+/// `M` definition the basic math operations and the requirements of `R` SIMD register
+/// must also be followed.
+pub unsafe fn generic_cmp_gt_bitmask<T, R, M, B1, B2>(a: B1, b: B2, result: &mut [u64])
+where
+    T: Copy,
+    R: SimdRegister<T>,
+    M: Math<T>,
+    B1: IntoMemLoader<T>,
+    B1::Loader: MemLoader<Value = T>,
+    B2: IntoMemLoader<T>,
+    B2::Loader: MemLoader<Value = T>,
+{
+    let mut a = a.into_mem_loader();
+    let len = a.projected_len();
+    let mut b = b.into_projected_mem_loader(len);
+    assert_eq!(
+        result.len(),
+        len.div_ceil(BITS_PER_WORD),
+        "Buffer `result` does not match the number of words required to mask `a`"
+    );
+
+    result.fill(0);
+
+    // Operate over single registers, the number of elements in a register always
+    // divides the word size so the bits of a register never straddle two words.
+    let offset_from = len % R::elements_per_lane();
+    let mut i = 0;
+    while i < (len - offset_from) {
+        let l1 = a.load::<R>();
+        let l2 = b.load::<R>();
+        let bits = R::movemask(R::gt(l1, l2));
+        result[i / BITS_PER_WORD] |= bits << (i % BITS_PER_WORD);
+
+        i += R::elements_per_lane();
+    }
+
+    // Handle the remainder, the last word may only be partially filled.
+    while i < len {
+        if M::cmp_gt(a.read(), b.read()) {
+            set_bit(result, i);
+        }
+
+        i += 1;
+    }
+}
+
+#[inline(always)]
+fn set_bit(result: &mut [u64], idx: usize) {
+    result[idx / BITS_PER_WORD] |= 1 << (idx % BITS_PER_WORD);
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use crate::math::AutoMath;
+
+    fn reference_gt_bitmask<T>(l1: &[T], l2: &[T]) -> Vec<u64>
+    where
+        T: Copy,
+        AutoMath: Math<T>,
+    {
+        let mut expected = vec![0; l1.len().div_ceil(BITS_PER_WORD)];
+        for (idx, (a, b)) in l1.iter().zip(l2).enumerate() {
+            let bit = AutoMath::cmp_gt(*a, *b) as u64;
+            expected[idx / BITS_PER_WORD] |= bit << (idx % BITS_PER_WORD);
+        }
+        expected
+    }
+
+    pub(crate) unsafe fn test_simple_vectors_gt_bitmask<T, R>(l1: Vec<T>, l2: Vec<T>)
+    where
+        T: Copy,
+        R: SimdRegister<T>,
+        AutoMath: Math<T>,
+    {
+        let mut result = vec![u64::MAX; l1.len().div_ceil(BITS_PER_WORD)];
+        generic_cmp_gt_bitmask::<T, R, AutoMath, _, _>(&l1, &l2, &mut result);
+
+        let expected_result = reference_gt_bitmask(&l1, &l2);
+        assert_eq!(result, expected_result, "value mismatch");
+    }
+
+    pub(crate) unsafe fn test_broadcast_value_gt_bitmask<T, R>(l1: Vec<T>, value: T)
+    where
+        T: Copy + IntoMemLoader<T>,
+        R: SimdRegister<T>,
+        AutoMath: Math<T>,
+    {
+        let mut result = vec![u64::MAX; l1.len().div_ceil(BITS_PER_WORD)];
+        generic_cmp_gt_bitmask::<T, R, AutoMath, _, _>(&l1, value, &mut result);
+
+        let expected_result = reference_gt_bitmask(&l1, &vec![value; l1.len()]);
+        assert_eq!(result, expected_result, "value mismatch");
+    }
+}
@@ -0,0 +1,172 @@
+use crate::danger::SimdRegister;
+use crate::math::Math;
+
+#[inline(always)]
+/// A generic clamp implementation bounding each element of `x` to `[lo, hi]`, writing
+/// `max(min(x[i], hi), lo)` to `result`.
+///
+/// ### NaN Handling
+///
+/// The result of a `NaN` element is unspecified, the `min` and `max` instructions of
+/// the selected CPU features differ in whether they return the `NaN` or the other
+/// operand, so a `NaN` element may be kept or replaced by one of the bounds.
+///
+/// # Panics
+///
+/// If `x` and `result` are not the same length.
+///
+/// # Safety
+///
+/// `lo` must be less than or equal to `hi`, which is checked in debug builds. The safety
+/// requirements of `M` definition the basic math operations and the requirements of
+/// `R` SIMD register must also be followed.
+pub unsafe fn generic_cmp_clamp<T, R, M>(x: &[T], lo: T, hi: T, result: &mut [T])
+where
+    T: Copy,
+    R: SimdRegister<T>,
+    M: Math<T>,
+{
+    assert_eq!(
+        x.len(),
+        result.len(),
+        "Buffers `x` and `result` do not match in size"
+    );
+
+    clamp::<T, R, M>(x.as_ptr(), lo, hi, result.as_mut_ptr(), x.len())
+}
+
+#[inline(always)]
+/// A generic clamp implementation bounding each element of `a` to `[lo, hi]` in place.
+///
+/// This is [generic_cmp_clamp] where the result aliases the input, including the
+/// handling of `NaN` elements.
+///
+/// # Safety
+///
+/// `lo` must be less than or equal to `hi`, which is checked in debug builds. The safety
+/// requirements of `M` definition the basic math operations and the requirements of
+/// `R` SIMD register must also be followed.
+pub unsafe fn generic_cmp_clamp_inplace<T, R, M>(a: &mut [T], lo: T, hi: T)
+where
+    T: Copy,
+    R: SimdRegister<T>,
+    M: Math<T>,
+{
+    let a_ptr = a.as_mut_ptr();
+    clamp::<T, R, M>(a_ptr, lo, hi, a_ptr, a.len())
+}
+
+#[inline(always)]
+unsafe fn clamp<T, R, M>(x_ptr: *const T, lo: T, hi: T, out_ptr: *mut T, len: usize)
+where
+    T: Copy,
+    R: SimdRegister<T>,
+    M: Math<T>,
+{
+    debug_assert!(
+        M::cmp_lte(lo, hi),
+        "Bound `lo` must not be greater than `hi`"
+    );
+
+    let offset_from = len % R::elements_per_dense();
+
+    // Operate over dense lanes first.
+    let lo_dense = R::filled_dense(lo);
+    let hi_dense = R::filled_dense(hi);
+    let mut i = 0;
+    while i < (len - offset_from) {
+        let l1 = R::load_dense(x_ptr.add(i));
+        let result = R::max_dense(R::min_dense(l1, hi_dense), lo_dense);
+        R::write_dense(out_ptr.add(i), result);
+
+        i += R::elements_per_dense();
+    }
+
+    // Operate over single registers next.
+    let lo_reg = R::filled(lo);
+    let hi_reg = R::filled(hi);
+    let offset_from = offset_from % R::elements_per_lane();
+    while i < (len - offset_from) {
+        let l1 = R::load(x_ptr.add(i));
+        R::write(out_ptr.add(i), R::max(R::min(l1, hi_reg), lo_reg));
+
+        i += R::elements_per_lane();
+    }
+
+    // Handle the remainder.
+    while i < len {
+        let value = x_ptr.add(i).read();
+        out_ptr.add(i).write(M::cmp_max(M::cmp_min(value, hi), lo));
+
+        i += 1;
+    }
+}
+
+#[cfg(test)]
+pub(crate) unsafe fn test_clamp<T, R>(l1: Vec<T>)
+where
+    T: Copy + PartialOrd + core::fmt::Debug,
+    R: SimdRegister<T>,
+    crate::math::AutoMath: Math<T>,
+{
+    use crate::math::AutoMath;
+
+    // The bounds are the quartiles so there are values below, above and inside them.
+    let mut sorted = l1.clone();
+    sorted.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    let lo = sorted[sorted.len() / 4];
+    let hi = sorted[(sorted.len() * 3) / 4];
+
+    for len in [1, 7, 131, l1.len()] {
+        let x = &l1[..len];
+        let expected = x
+            .iter()
+            .map(|v| AutoMath::cmp_max(AutoMath::cmp_min(*v, hi), lo))
+            .collect::<Vec<_>>();
+
+        let mut result = vec![AutoMath::zero(); len];
+        generic_cmp_clamp::<T, R, AutoMath>(x, lo, hi, &mut result);
+        assert_eq!(result, expected, "value missmatch on len {len}");
+
+        let mut inplace = x.to_vec();
+        generic_cmp_clamp_inplace::<T, R, AutoMath>(&mut inplace, lo, hi);
+        assert_eq!(inplace, expected, "value missmatch on inplace len {len}");
+    }
+}
+
+#[cfg(test)]
+pub(crate) unsafe fn test_clamp_nan<T, R>()
+where
+    T: Copy + PartialOrd + core::fmt::Debug,
+    R: SimdRegister<T>,
+    crate::math::AutoMath: Math<T>,
+{
+    use crate::math::AutoMath;
+
+    let nan = AutoMath::div(AutoMath::zero(), AutoMath::zero());
+    let lo = AutoMath::zero();
+    let hi = AutoMath::one();
+
+    // The `NaN` lands in a dense lane, a single register and the remainder.
+    let len = R::elements_per_dense() + R::elements_per_lane() + 1;
+    for idx in [1, R::elements_per_dense(), len - 1] {
+        let mut x = vec![AutoMath::add(hi, hi); len];
+        x[0] = AutoMath::sub(lo, hi);
+        x[idx] = nan;
+
+        let mut result = vec![AutoMath::zero(); len];
+        generic_cmp_clamp::<T, R, AutoMath>(&x, lo, hi, &mut result);
+
+        let value = result[idx];
+        assert!(
+            !AutoMath::cmp_eq(value, value) || (value >= lo && value <= hi),
+            "value missmatch on NaN at {idx} {value:?}"
+        );
+        assert_eq!(result[0], lo, "value missmatch below `lo`");
+        for (i, value) in result.iter().enumerate().skip(1) {
+            if i != idx {
+                assert_eq!(*value, hi, "value missmatch above `hi` at {i}");
+            }
+        }
+    }
+}
@@ -1,12 +1,20 @@
 use crate::buffer::WriteOnlyBuffer;
-use crate::danger::core_routine_boilerplate::apply_vertical_kernel;
+use crate::danger::core_routine_boilerplate::{
+    apply_columns_kernel,
+    apply_vertical_kernel,
+    count_eq_value,
+    find_eq_value,
+};
 use crate::danger::core_simd_api::SimdRegister;
 use crate::math::Math;
-use crate::mem_loader::{IntoMemLoader, MemLoader};
+use crate::mem_loader::{IntoMemLoader, MemLoader, SCRATCH_SPACE_SIZE};
 
 #[inline(always)]
 /// A generic horizontal max implementation over one vectors of a given set of dimensions.
 ///
+/// The result is unspecified when `a` contains `NaN`, as each lane follows `R::max`
+/// which differs between CPU features.
+///
 /// # Safety
 ///
 /// The safety requirements of `M` definition the basic math operations and
@@ -58,6 +66,287 @@ where
     max
 }
 
+#[inline(always)]
+/// A generic horizontal max implementation which also returns the number of elements
+/// equal to the maximum.
+///
+/// A count above `1` indicates the maximum is not unique, i.e. for detecting degenerate
+/// or saturated data. The count is computed in a second pass over `a` once the
+/// maximum is known.
+///
+/// Since `NaN` never compares equal, a `NaN` maximum produces a count of `0`.
+///
+/// # Safety
+///
+/// The safety requirements of `M` definition the basic math operations and
+/// the requirements of `R` SIMD register must also be followed.
+pub unsafe fn generic_cmp_max_with_count<T, R, M, B1>(a: B1) -> (T, usize)
+where
+    T: Copy,
+    R: SimdRegister<T>,
+    M: Math<T>,
+    B1: IntoMemLoader<T> + Copy,
+    B1::Loader: MemLoader<Value = T>,
+{
+    let max = generic_cmp_max::<T, R, M, B1>(a);
+    let count = count_eq_value::<T, R, M, B1>(a, max);
+    (max, count)
+}
+
+#[inline(always)]
+/// A generic horizontal argmax implementation, returning the index of the maximum element
+/// along with its value.
+///
+/// Ties resolve to the lowest index. The maximum is found with [generic_cmp_max], then
+/// a second pass compares each register against it and stops at the first match.
+///
+/// Returns `None` if `a` is empty, or if the maximum is `NaN` as it never compares equal.
+///
+/// # Safety
+///
+/// The safety requirements of `M` definition the basic math operations and
+/// the requirements of `R` SIMD register must also be followed.
+pub unsafe fn generic_cmp_argmax<T, R, M>(a: &[T]) -> Option<(usize, T)>
+where
+    T: Copy,
+    R: SimdRegister<T>,
+    M: Math<T>,
+{
+    if a.is_empty() {
+        return None;
+    }
+
+    let max = generic_cmp_max::<T, R, M, _>(a);
+    let index = find_eq_value::<T, R, M, _>(a, max)?;
+    Some((index, max))
+}
+
+#[inline(always)]
+/// A generic top-k implementation, writing the indices and values of the `k` largest
+/// elements of `a` into `indices` and `values` where `k` is the length of the buffers,
+/// returning the number of elements written.
+///
+/// The result is sorted by descending value with ties resolved to the lowest index, if
+/// `a` has fewer than `k` elements every element is written and the rest of the buffers
+/// are left untouched.
+///
+/// ### Selection
+///
+/// The first `k` elements are insertion sorted into the buffers, the smallest of them
+/// becomes the threshold a candidate must exceed. The remaining elements are scanned a
+/// register at a time and only registers whose max, from `R::max_to_value`, exceeds the
+/// threshold are checked element by element, inserting any which are greater and
+/// raising the threshold. Once the threshold settles most registers are skipped without
+/// leaving the SIMD registers.
+///
+/// The values of `a` are assumed to be free of `NaN`.
+///
+/// # Panics
+///
+/// If `indices` and `values` are not the same length.
+///
+/// # Safety
+///
+/// The safety requirements of `M` definition the basic math operations and
+/// the requirements of `R` SIMD register must also be followed.
+pub unsafe fn generic_cmp_top_k<T, R, M>(
+    a: &[T],
+    indices: &mut [usize],
+    values: &mut [T],
+) -> usize
+where
+    T: Copy,
+    R: SimdRegister<T>,
+    M: Math<T>,
+{
+    assert_eq!(
+        indices.len(),
+        values.len(),
+        "Buffers `indices` and `values` do not match in size"
+    );
+
+    let len = a.len();
+    let k = indices.len().min(len);
+    if k == 0 {
+        return 0;
+    }
+
+    let indices = indices.get_unchecked_mut(..k);
+    let values = values.get_unchecked_mut(..k);
+
+    let mut i = 0;
+    while i < k {
+        insert_top_k::<T, M>(indices, values, i, i, *a.get_unchecked(i));
+
+        i += 1;
+    }
+
+    // Operate over single registers, skipping any without a candidate.
+    let offset_from = (len - i) % R::elements_per_lane();
+    while i < (len - offset_from) {
+        let l1 = R::load(a.as_ptr().add(i));
+        if M::cmp_gt(R::max_to_value(l1), *values.get_unchecked(k - 1)) {
+            for j in i..i + R::elements_per_lane() {
+                let value = *a.get_unchecked(j);
+                if M::cmp_gt(value, *values.get_unchecked(k - 1)) {
+                    insert_top_k::<T, M>(indices, values, k, j, value);
+                }
+            }
+        }
+
+        i += R::elements_per_lane();
+    }
+
+    // Handle the remainder.
+    while i < len {
+        let value = *a.get_unchecked(i);
+        if M::cmp_gt(value, *values.get_unchecked(k - 1)) {
+            insert_top_k::<T, M>(indices, values, k, i, value);
+        }
+
+        i += 1;
+    }
+
+    k
+}
+
+#[inline(always)]
+/// Inserts `value` into the first `filled` sorted entries of `indices` and `values`,
+/// after any entries it does not exceed so earlier indices win ties. When the buffers
+/// are already full the smallest entry is dropped.
+unsafe fn insert_top_k<T, M>(
+    indices: &mut [usize],
+    values: &mut [T],
+    filled: usize,
+    index: usize,
+    value: T,
+) where
+    T: Copy,
+    M: Math<T>,
+{
+    let mut position = filled.min(values.len() - 1);
+    while position > 0 && M::cmp_gt(value, *values.get_unchecked(position - 1)) {
+        *values.get_unchecked_mut(position) = *values.get_unchecked(position - 1);
+        *indices.get_unchecked_mut(position) = *indices.get_unchecked(position - 1);
+        position -= 1;
+    }
+
+    *values.get_unchecked_mut(position) = value;
+    *indices.get_unchecked_mut(position) = index;
+}
+
+#[inline(always)]
+/// A generic in place max implementation against the scalar `floor`, writing
+/// `max(a[i], floor)` back into `a` and returning the number of elements raised.
+///
+/// An element counts as raised when `a[i] < floor`, the comparison mask of each register
+/// is written to a scratch buffer and counted before the max is applied.
+///
+/// A `NaN` element is never counted as it does not compare less than `floor`.
+///
+/// # Safety
+///
+/// The safety requirements of `M` definition the basic math operations and
+/// the requirements of `R` SIMD register must also be followed.
+pub unsafe fn generic_cmp_max_floor_counting<T, R, M>(a: &mut [T], floor: T) -> usize
+where
+    T: Copy,
+    R: SimdRegister<T>,
+    M: Math<T>,
+{
+    let len = a.len();
+    let data_ptr = a.as_mut_ptr();
+
+    let floor_reg = R::filled(floor);
+    let mut flags = [M::zero(); SCRATCH_SPACE_SIZE];
+
+    let offset_from = len % R::elements_per_lane();
+    let mut raised = 0;
+    let mut i = 0;
+    while i < (len - offset_from) {
+        let l1 = R::load(data_ptr.add(i));
+        R::write(flags.as_mut_ptr(), R::lt(l1, floor_reg));
+
+        raised += flags[..R::elements_per_lane()]
+            .iter()
+            .filter(|flag| !M::cmp_eq(**flag, M::zero()))
+            .count();
+
+        R::write(data_ptr.add(i), R::max(l1, floor_reg));
+
+        i += R::elements_per_lane();
+    }
+
+    while i < len {
+        let value = data_ptr.add(i);
+        if M::cmp_lt(value.read(), floor) {
+            raised += 1;
+        }
+        value.write(M::cmp_max(value.read(), floor));
+
+        i += 1;
+    }
+
+    raised
+}
+
+#[inline(always)]
+/// A generic inclusive prefix max implementation, writing `max(a[0..=i])` back into
+/// `a[i]`.
+///
+/// The running max is carried from one register to the next. A register whose maximum
+/// does not exceed the running max is written as the broadcast running max in a single
+/// store, this is the common case once a peak has been passed, i.e. for drawdowns.
+/// Otherwise the register is scanned element by element.
+///
+/// The values of `a` are assumed to be free of `NaN`.
+///
+/// # Safety
+///
+/// The safety requirements of `M` definition the basic math operations and
+/// the requirements of `R` SIMD register must also be followed.
+pub unsafe fn generic_cmp_max_cumulative_inplace<T, R, M>(a: &mut [T])
+where
+    T: Copy,
+    R: SimdRegister<T>,
+    M: Math<T>,
+{
+    let len = a.len();
+    if len == 0 {
+        return;
+    }
+
+    let data_ptr = a.as_mut_ptr();
+    let mut running = data_ptr.read();
+
+    let offset_from = len % R::elements_per_lane();
+    let mut i = 0;
+    while i < (len - offset_from) {
+        let l1 = R::load(data_ptr.add(i));
+        let block_max = R::max_to_value(l1);
+
+        if !M::cmp_gt(block_max, running) {
+            R::write(data_ptr.add(i), R::filled(running));
+        } else {
+            for j in i..i + R::elements_per_lane() {
+                let value = data_ptr.add(j);
+                running = M::cmp_max(running, value.read());
+                value.write(running);
+            }
+        }
+
+        i += R::elements_per_lane();
+    }
+
+    while i < len {
+        let value = data_ptr.add(i);
+        running = M::cmp_max(running, value.read());
+        value.write(running);
+
+        i += 1;
+    }
+}
+
 #[inline(always)]
 /// A generic vertical max implementation over two vectors of a given set of dimensions.
 ///
@@ -94,10 +383,39 @@ pub unsafe fn generic_cmp_max_vertical<T, R, M, B1, B2, B3>(
     );
 }
 
+#[inline(always)]
+/// A generic per-column max implementation over the row-major `matrix`, writing the
+/// largest value of each column to the matching element of `result`.
+///
+/// The number of columns is taken from the length of `result`. Each block of columns is
+/// seeded from the first row and kept in registers while iterating over the remaining
+/// rows, the remaining columns are handled one at a time.
+///
+/// The result is unspecified when a column contains `NaN`, as each lane follows `R::max`
+/// which differs between CPU features.
+///
+/// # Panics
+///
+/// If `result` is empty, `matrix` is empty or `matrix` is not a multiple of `result`
+/// in length.
+///
+/// # Safety
+///
+/// The safety requirements of `M` definition the basic math operations and
+/// the requirements of `R` SIMD register must also be followed.
+pub unsafe fn generic_cmp_max_columns<T, R, M>(matrix: &[T], result: &mut [T])
+where
+    T: Copy,
+    R: SimdRegister<T>,
+    M: Math<T>,
+{
+    apply_columns_kernel::<T, R>(matrix, result, R::max, M::cmp_max);
+}
+
 #[cfg(test)]
 pub(crate) unsafe fn test_max<T, R>(l1: Vec<T>, l2: Vec<T>)
 where
-    T: Copy + PartialEq + std::fmt::Debug + IntoMemLoader<T>,
+    T: Copy + PartialEq + core::fmt::Debug + IntoMemLoader<T>,
     R: SimdRegister<T>,
     crate::math::AutoMath: Math<T>,
     for<'a> &'a Vec<T>: IntoMemLoader<T>,
@@ -133,3 +451,266 @@ where
         .fold(AutoMath::min(), |a, b| AutoMath::cmp_max(a, *b));
     assert_eq!(max, expected_max, "value mismatch on horizontal");
 }
+
+#[cfg(test)]
+pub(crate) unsafe fn test_max_horizontal_edges<T, R>(l1: Vec<T>, extreme: T)
+where
+    T: Copy + PartialEq + core::fmt::Debug,
+    R: SimdRegister<T>,
+    crate::math::AutoMath: Math<T>,
+{
+    use crate::math::AutoMath;
+
+    // All negative values, so a zero initialised accumulator would be wrong.
+    let negative = l1
+        .iter()
+        .map(|v| AutoMath::sub(AutoMath::sub(AutoMath::zero(), *v), AutoMath::one()))
+        .collect::<Vec<_>>();
+    let max = generic_cmp_max::<T, R, AutoMath, _>(&negative);
+    let expected_max = negative
+        .iter()
+        .fold(AutoMath::min(), |a, b| AutoMath::cmp_max(a, *b));
+    assert_eq!(max, expected_max, "value missmatch on all negative");
+
+    // A single max in the tail after the dense lanes and single registers.
+    let main_len = R::elements_per_dense() * 2;
+    for tail in [1, 2, 3] {
+        for len in [main_len + tail, main_len + R::elements_per_lane() + tail] {
+            let mut values = negative[..len].to_vec();
+            values[len - 1] = extreme;
+
+            let max = generic_cmp_max::<T, R, AutoMath, _>(&values);
+            assert_eq!(max, extreme, "value missmatch on tail {tail} len {len}");
+        }
+    }
+}
+
+#[cfg(test)]
+pub(crate) unsafe fn test_argmax<T, R>(l1: Vec<T>, extreme: T)
+where
+    T: Copy + PartialEq + core::fmt::Debug,
+    R: SimdRegister<T>,
+    crate::math::AutoMath: Math<T>,
+{
+    use crate::math::AutoMath;
+
+    // Only a strictly greater value moves the index, so ties keep the lowest.
+    let expected = |values: &[T]| {
+        values.iter().copied().enumerate().fold(
+            None,
+            |best: Option<(usize, T)>, (i, v)| match best {
+                Some((_, b)) if !AutoMath::cmp_gt(v, b) => best,
+                _ => Some((i, v)),
+            },
+        )
+    };
+
+    let result = generic_cmp_argmax::<T, R, AutoMath>(&l1);
+    assert_eq!(result, expected(&l1), "value missmatch on argmax");
+
+    // The maximum placed in the tail beyond the dense lanes.
+    for len in [131, R::elements_per_dense() * 2 + 3] {
+        let mut values = l1[..len].to_vec();
+        values[len - 2] = extreme;
+
+        let result = generic_cmp_argmax::<T, R, AutoMath>(&values);
+        assert_eq!(
+            result,
+            Some((len - 2, extreme)),
+            "value missmatch on len {len}"
+        );
+    }
+
+    // Duplicate maximums resolve to the lowest index.
+    let mut values = l1[..131].to_vec();
+    for i in [97, 13, 130, 64] {
+        values[i] = extreme;
+    }
+    let result = generic_cmp_argmax::<T, R, AutoMath>(&values);
+    assert_eq!(result, Some((13, extreme)), "value missmatch on duplicates");
+
+    assert_eq!(generic_cmp_argmax::<T, R, AutoMath>(&[]), None);
+}
+
+#[cfg(test)]
+pub(crate) unsafe fn test_top_k<T, R>(l1: Vec<T>)
+where
+    T: Copy + PartialEq + core::fmt::Debug,
+    R: SimdRegister<T>,
+    crate::math::AutoMath: Math<T>,
+{
+    use core::cmp::Ordering;
+
+    use crate::math::AutoMath;
+
+    // The stable sort keeps equal values in index order, so ties keep the lowest.
+    let expected = |values: &[T], k: usize| {
+        let mut sorted = values.iter().copied().enumerate().collect::<Vec<_>>();
+        sorted.sort_by(|a, b| {
+            if AutoMath::cmp_gt(a.1, b.1) {
+                Ordering::Less
+            } else if AutoMath::cmp_gt(b.1, a.1) {
+                Ordering::Greater
+            } else {
+                Ordering::Equal
+            }
+        });
+        sorted.truncate(k);
+        sorted
+    };
+    let check = |values: &[T], k: usize, case: &str| {
+        let mut indices = vec![usize::MAX; k];
+        let mut top = vec![AutoMath::zero(); k];
+        let count = generic_cmp_top_k::<T, R, AutoMath>(values, &mut indices, &mut top);
+
+        let expected = expected(values, k);
+        assert_eq!(count, expected.len(), "count missmatch on {case}");
+        let result = indices[..count]
+            .iter()
+            .copied()
+            .zip(top[..count].iter().copied())
+            .collect::<Vec<_>>();
+        assert_eq!(result, expected, "value missmatch on {case}");
+    };
+
+    for len in [0, 1, 7, 131, l1.len()] {
+        let values = &l1[..len];
+        for k in [0, 1, 10, len, len + 5] {
+            check(values, k, &format!("len {len} k {k}"));
+        }
+    }
+
+    // Cycling a handful of values gives every value many duplicates.
+    let duplicates = (0..131).map(|i| l1[i % 5]).collect::<Vec<_>>();
+    for k in [1, 3, 12, 131] {
+        check(&duplicates, k, &format!("duplicates k {k}"));
+    }
+}
+
+#[cfg(test)]
+pub(crate) unsafe fn test_max_floor_counting<T, R>(l1: Vec<T>)
+where
+    T: Copy + PartialEq + core::fmt::Debug,
+    R: SimdRegister<T>,
+    crate::math::AutoMath: Math<T>,
+{
+    use crate::math::AutoMath;
+
+    // Picking floors from the data itself gives a mix of raised and kept elements.
+    for len in [l1.len(), 131, 3] {
+        for floor in [l1[0], l1[len / 2], l1[len - 1]] {
+            let values = &l1[..len];
+            let expected_raised = values
+                .iter()
+                .filter(|v| AutoMath::cmp_lt(**v, floor))
+                .count();
+
+            let mut result = values.to_vec();
+            let raised =
+                generic_cmp_max_floor_counting::<T, R, AutoMath>(&mut result, floor);
+            assert_eq!(
+                raised, expected_raised,
+                "count missmatch on len {len} floor {floor:?}"
+            );
+
+            for (i, (value, x)) in result.iter().zip(values).enumerate() {
+                let expected_value = AutoMath::cmp_max(*x, floor);
+                assert_eq!(*value, expected_value, "value missmatch on index {i}");
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+pub(crate) unsafe fn test_max_cumulative_inplace<T, R>(l1: Vec<T>)
+where
+    T: Copy + PartialEq + core::fmt::Debug,
+    R: SimdRegister<T>,
+    crate::math::AutoMath: Math<T>,
+{
+    use crate::math::AutoMath;
+
+    let expected = |values: &[T]| {
+        let mut running = values[0];
+        values
+            .iter()
+            .map(|v| {
+                running = AutoMath::cmp_max(running, *v);
+                running
+            })
+            .collect::<Vec<_>>()
+    };
+
+    let mut sorted = l1.clone();
+    sorted.sort_by(|a, b| {
+        if AutoMath::cmp_lt(*a, *b) {
+            core::cmp::Ordering::Less
+        } else if AutoMath::cmp_gt(*a, *b) {
+            core::cmp::Ordering::Greater
+        } else {
+            core::cmp::Ordering::Equal
+        }
+    });
+    // Rising teeth which each restart below the previous peak.
+    let sawtooth = sorted[..37]
+        .iter()
+        .copied()
+        .cycle()
+        .take(l1.len())
+        .collect::<Vec<_>>();
+    let mut descending = sorted.clone();
+    descending.reverse();
+
+    let inputs = [
+        ("random", l1),
+        ("monotonic", sorted),
+        ("descending", descending),
+        ("sawtooth", sawtooth),
+    ];
+    for (case, values) in inputs {
+        for len in [values.len(), 131, 3, 1] {
+            let mut result = values[..len].to_vec();
+            generic_cmp_max_cumulative_inplace::<T, R, AutoMath>(&mut result);
+            assert_eq!(
+                result,
+                expected(&values[..len]),
+                "value missmatch on {case} len {len}"
+            );
+        }
+    }
+
+    let mut empty: [T; 0] = [];
+    generic_cmp_max_cumulative_inplace::<T, R, AutoMath>(&mut empty);
+}
+
+#[cfg(test)]
+pub(crate) unsafe fn test_max_columns<T, R>(l1: Vec<T>)
+where
+    T: Copy + PartialEq + core::fmt::Debug,
+    R: SimdRegister<T>,
+    crate::math::AutoMath: Math<T>,
+    rand::distributions::Standard: rand::distributions::Distribution<T>,
+{
+    use crate::math::AutoMath;
+
+    // A wide, non-aligned matrix alongside the shared sample vector.
+    let (wide, _) = crate::test_utils::get_sample_vectors::<T>(537 * 3);
+    let cases = [(&l1, 1), (&l1, 7), (&l1, 67), (&wide, 537)];
+    for (values, dims) in cases {
+        let matrix = &values[..(values.len() / dims) * dims];
+
+        let mut result = vec![AutoMath::zero(); dims];
+        generic_cmp_max_columns::<T, R, AutoMath>(matrix, &mut result);
+
+        let expected_result = (0..dims)
+            .map(|column| {
+                matrix
+                    .iter()
+                    .skip(column)
+                    .step_by(dims)
+                    .fold(AutoMath::min(), |a, b| AutoMath::cmp_max(a, *b))
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(result, expected_result, "value mismatch on dims {dims}");
+    }
+}
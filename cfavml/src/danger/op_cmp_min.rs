@@ -1,5 +1,10 @@
 use crate::buffer::WriteOnlyBuffer;
-use crate::danger::core_routine_boilerplate::apply_vertical_kernel;
+use crate::danger::core_routine_boilerplate::{
+    apply_columns_kernel,
+    apply_vertical_kernel,
+    count_eq_value,
+    find_eq_value,
+};
 use crate::danger::core_simd_api::SimdRegister;
 use crate::math::Math;
 use crate::mem_loader::{IntoMemLoader, MemLoader};
@@ -7,6 +12,9 @@ use crate::mem_loader::{IntoMemLoader, MemLoader};
 #[inline(always)]
 /// A generic horizontal min implementation over one vectors of a given set of dimensions.
 ///
+/// The result is unspecified when `a` contains `NaN`, as each lane follows `R::min`
+/// which differs between CPU features.
+///
 /// # Safety
 ///
 /// The sizes of `a` must be equal to `dims`, the safety requirements of
@@ -59,6 +67,61 @@ where
     min
 }
 
+#[inline(always)]
+/// A generic horizontal min implementation which also returns the number of elements
+/// equal to the minimum.
+///
+/// A count above `1` indicates the minimum is not unique, i.e. for detecting degenerate
+/// or saturated data. The count is computed in a second pass over `a` once the
+/// minimum is known.
+///
+/// Since `NaN` never compares equal, a `NaN` minimum produces a count of `0`.
+///
+/// # Safety
+///
+/// The safety requirements of `M` definition the basic math operations and
+/// the requirements of `R` SIMD register must also be followed.
+pub unsafe fn generic_cmp_min_with_count<T, R, M, B1>(a: B1) -> (T, usize)
+where
+    T: Copy,
+    R: SimdRegister<T>,
+    M: Math<T>,
+    B1: IntoMemLoader<T> + Copy,
+    B1::Loader: MemLoader<Value = T>,
+{
+    let min = generic_cmp_min::<T, R, M, B1>(a);
+    let count = count_eq_value::<T, R, M, B1>(a, min);
+    (min, count)
+}
+
+#[inline(always)]
+/// A generic horizontal argmin implementation, returning the index of the minimum element
+/// along with its value.
+///
+/// Ties resolve to the lowest index. The minimum is found with [generic_cmp_min], then
+/// a second pass compares each register against it and stops at the first match.
+///
+/// Returns `None` if `a` is empty, or if the minimum is `NaN` as it never compares equal.
+///
+/// # Safety
+///
+/// The safety requirements of `M` definition the basic math operations and
+/// the requirements of `R` SIMD register must also be followed.
+pub unsafe fn generic_cmp_argmin<T, R, M>(a: &[T]) -> Option<(usize, T)>
+where
+    T: Copy,
+    R: SimdRegister<T>,
+    M: Math<T>,
+{
+    if a.is_empty() {
+        return None;
+    }
+
+    let min = generic_cmp_min::<T, R, M, _>(a);
+    let index = find_eq_value::<T, R, M, _>(a, min)?;
+    Some((index, min))
+}
+
 #[inline(always)]
 /// A generic vertical min implementation over two vectors of a given set of dimensions.
 ///
@@ -95,10 +158,39 @@ pub unsafe fn generic_cmp_min_vertical<T, R, M, B1, B2, B3>(
     );
 }
 
+#[inline(always)]
+/// A generic per-column min implementation over the row-major `matrix`, writing the
+/// smallest value of each column to the matching element of `result`.
+///
+/// The number of columns is taken from the length of `result`. Each block of columns is
+/// seeded from the first row and kept in registers while iterating over the remaining
+/// rows, the remaining columns are handled one at a time.
+///
+/// The result is unspecified when a column contains `NaN`, as each lane follows `R::min`
+/// which differs between CPU features.
+///
+/// # Panics
+///
+/// If `result` is empty, `matrix` is empty or `matrix` is not a multiple of `result`
+/// in length.
+///
+/// # Safety
+///
+/// The safety requirements of `M` definition the basic math operations and
+/// the requirements of `R` SIMD register must also be followed.
+pub unsafe fn generic_cmp_min_columns<T, R, M>(matrix: &[T], result: &mut [T])
+where
+    T: Copy,
+    R: SimdRegister<T>,
+    M: Math<T>,
+{
+    apply_columns_kernel::<T, R>(matrix, result, R::min, M::cmp_min);
+}
+
 #[cfg(test)]
 pub(crate) unsafe fn test_min<T, R>(l1: Vec<T>, l2: Vec<T>)
 where
-    T: Copy + PartialEq + std::fmt::Debug + IntoMemLoader<T>,
+    T: Copy + PartialEq + core::fmt::Debug + IntoMemLoader<T>,
     R: SimdRegister<T>,
     crate::math::AutoMath: Math<T>,
     for<'a> &'a Vec<T>: IntoMemLoader<T>,
@@ -135,3 +227,115 @@ where
         .fold(AutoMath::max(), |a, b| AutoMath::cmp_min(a, *b));
     assert_eq!(min, expected_min, "value mismatch on horizontal");
 }
+
+#[cfg(test)]
+pub(crate) unsafe fn test_min_horizontal_edges<T, R>(l1: Vec<T>, extreme: T)
+where
+    T: Copy + PartialEq + core::fmt::Debug,
+    R: SimdRegister<T>,
+    crate::math::AutoMath: Math<T>,
+{
+    use crate::math::AutoMath;
+
+    // All negative values, so a zero initialised accumulator would be wrong.
+    let negative = l1
+        .iter()
+        .map(|v| AutoMath::sub(AutoMath::sub(AutoMath::zero(), *v), AutoMath::one()))
+        .collect::<Vec<_>>();
+    let min = generic_cmp_min::<T, R, AutoMath, _>(&negative);
+    let expected_min = negative
+        .iter()
+        .fold(AutoMath::max(), |a, b| AutoMath::cmp_min(a, *b));
+    assert_eq!(min, expected_min, "value missmatch on all negative");
+
+    // A single min in the tail after the dense lanes and single registers.
+    let main_len = R::elements_per_dense() * 2;
+    for tail in [1, 2, 3] {
+        for len in [main_len + tail, main_len + R::elements_per_lane() + tail] {
+            let mut values = negative[..len].to_vec();
+            values[len - 1] = extreme;
+
+            let min = generic_cmp_min::<T, R, AutoMath, _>(&values);
+            assert_eq!(min, extreme, "value missmatch on tail {tail} len {len}");
+        }
+    }
+}
+
+#[cfg(test)]
+pub(crate) unsafe fn test_argmin<T, R>(l1: Vec<T>, extreme: T)
+where
+    T: Copy + PartialEq + core::fmt::Debug,
+    R: SimdRegister<T>,
+    crate::math::AutoMath: Math<T>,
+{
+    use crate::math::AutoMath;
+
+    // Only a strictly smaller value moves the index, so ties keep the lowest.
+    let expected = |values: &[T]| {
+        values.iter().copied().enumerate().fold(
+            None,
+            |best: Option<(usize, T)>, (i, v)| match best {
+                Some((_, b)) if !AutoMath::cmp_lt(v, b) => best,
+                _ => Some((i, v)),
+            },
+        )
+    };
+
+    let result = generic_cmp_argmin::<T, R, AutoMath>(&l1);
+    assert_eq!(result, expected(&l1), "value missmatch on argmin");
+
+    // The minimum placed in the tail beyond the dense lanes.
+    for len in [131, R::elements_per_dense() * 2 + 3] {
+        let mut values = l1[..len].to_vec();
+        values[len - 2] = extreme;
+
+        let result = generic_cmp_argmin::<T, R, AutoMath>(&values);
+        assert_eq!(
+            result,
+            Some((len - 2, extreme)),
+            "value missmatch on len {len}"
+        );
+    }
+
+    // Duplicate minimums resolve to the lowest index.
+    let mut values = l1[..131].to_vec();
+    for i in [97, 13, 130, 64] {
+        values[i] = extreme;
+    }
+    let result = generic_cmp_argmin::<T, R, AutoMath>(&values);
+    assert_eq!(result, Some((13, extreme)), "value missmatch on duplicates");
+
+    assert_eq!(generic_cmp_argmin::<T, R, AutoMath>(&[]), None);
+}
+
+#[cfg(test)]
+pub(crate) unsafe fn test_min_columns<T, R>(l1: Vec<T>)
+where
+    T: Copy + PartialEq + core::fmt::Debug,
+    R: SimdRegister<T>,
+    crate::math::AutoMath: Math<T>,
+    rand::distributions::Standard: rand::distributions::Distribution<T>,
+{
+    use crate::math::AutoMath;
+
+    // A wide, non-aligned matrix alongside the shared sample vector.
+    let (wide, _) = crate::test_utils::get_sample_vectors::<T>(537 * 3);
+    let cases = [(&l1, 1), (&l1, 7), (&l1, 67), (&wide, 537)];
+    for (values, dims) in cases {
+        let matrix = &values[..(values.len() / dims) * dims];
+
+        let mut result = vec![AutoMath::zero(); dims];
+        generic_cmp_min_columns::<T, R, AutoMath>(matrix, &mut result);
+
+        let expected_result = (0..dims)
+            .map(|column| {
+                matrix
+                    .iter()
+                    .skip(column)
+                    .step_by(dims)
+                    .fold(AutoMath::max(), |a, b| AutoMath::cmp_min(a, *b))
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(result, expected_result, "value mismatch on dims {dims}");
+    }
+}
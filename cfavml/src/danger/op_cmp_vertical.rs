@@ -75,7 +75,7 @@ pub unsafe fn generic_cmp_neq_vertical<T, R, M, B1, B2, B3>(
 }
 
 #[inline(always)]
-/// A generic vector element-wise equality check of vectors `a` and `b` checking if
+/// A generic vector element-wise comparison of vectors `a` and `b` checking if
 /// element of `a` is **_less than_** element of `b`.
 ///
 /// The result of each element check is returned as a mask of either `0` (false) or ` 1` (true).
@@ -110,7 +110,7 @@ pub unsafe fn generic_cmp_lt_vertical<T, R, M, B1, B2, B3>(
 }
 
 #[inline(always)]
-/// A generic vector element-wise equality check of vectors `a` and `b` checking if
+/// A generic vector element-wise comparison of vectors `a` and `b` checking if
 /// element of `a` is **_less than or equal to_** element of `b`.
 ///
 /// The result of each element check is returned as a mask of either `0` (false) or ` 1` (true).
@@ -145,7 +145,7 @@ pub unsafe fn generic_cmp_lte_vertical<T, R, M, B1, B2, B3>(
 }
 
 #[inline(always)]
-/// A generic vector element-wise equality check of vectors `a` and `b` checking if
+/// A generic vector element-wise comparison of vectors `a` and `b` checking if
 /// element of `a` is **_greater than_** element of `b`.
 ///
 /// The result of each element check is returned as a mask of either `0` (false) or ` 1` (true).
@@ -180,7 +180,7 @@ pub unsafe fn generic_cmp_gt_vertical<T, R, M, B1, B2, B3>(
 }
 
 #[inline(always)]
-/// A generic vector element-wise equality check of vectors `a` and `b` checking if
+/// A generic vector element-wise comparison of vectors `a` and `b` checking if
 /// element of `a` is **_greater than or equal to_** element of `b`.
 ///
 /// The result of each element check is returned as a mask of either `0` (false) or ` 1` (true).
@@ -216,7 +216,7 @@ pub unsafe fn generic_cmp_gte_vertical<T, R, M, B1, B2, B3>(
 
 #[cfg(test)]
 pub(crate) mod tests {
-    use std::iter::zip;
+    use core::iter::zip;
 
     use super::*;
     use crate::danger::SimdRegister;
@@ -224,7 +224,7 @@ pub(crate) mod tests {
 
     pub(crate) unsafe fn test_simple_vectors_eq<T, R>(l1: Vec<T>, l2: Vec<T>)
     where
-        T: Copy + PartialEq + std::fmt::Debug,
+        T: Copy + PartialEq + core::fmt::Debug,
         R: SimdRegister<T>,
         crate::math::AutoMath: Math<T>,
         for<'a> &'a mut [T]: WriteOnlyBuffer<Item = T>,
@@ -244,7 +244,7 @@ pub(crate) mod tests {
 
     pub(crate) unsafe fn test_simple_vectors_neq<T, R>(l1: Vec<T>, l2: Vec<T>)
     where
-        T: Copy + PartialEq + std::fmt::Debug,
+        T: Copy + PartialEq + core::fmt::Debug,
         R: SimdRegister<T>,
         crate::math::AutoMath: Math<T>,
         for<'a> &'a mut [T]: WriteOnlyBuffer<Item = T>,
@@ -264,7 +264,7 @@ pub(crate) mod tests {
 
     pub(crate) unsafe fn test_simple_vectors_lt<T, R>(l1: Vec<T>, l2: Vec<T>)
     where
-        T: Copy + PartialEq + std::fmt::Debug,
+        T: Copy + PartialEq + core::fmt::Debug,
         R: SimdRegister<T>,
         crate::math::AutoMath: Math<T>,
         for<'a> &'a mut [T]: WriteOnlyBuffer<Item = T>,
@@ -284,7 +284,7 @@ pub(crate) mod tests {
 
     pub(crate) unsafe fn test_simple_vectors_lte<T, R>(l1: Vec<T>, l2: Vec<T>)
     where
-        T: Copy + PartialEq + std::fmt::Debug,
+        T: Copy + PartialEq + core::fmt::Debug,
         R: SimdRegister<T>,
         crate::math::AutoMath: Math<T>,
         for<'a> &'a mut [T]: WriteOnlyBuffer<Item = T>,
@@ -304,7 +304,7 @@ pub(crate) mod tests {
 
     pub(crate) unsafe fn test_simple_vectors_gt<T, R>(l1: Vec<T>, l2: Vec<T>)
     where
-        T: Copy + PartialEq + std::fmt::Debug,
+        T: Copy + PartialEq + core::fmt::Debug,
         R: SimdRegister<T>,
         crate::math::AutoMath: Math<T>,
         for<'a> &'a mut [T]: WriteOnlyBuffer<Item = T>,
@@ -324,7 +324,7 @@ pub(crate) mod tests {
 
     pub(crate) unsafe fn test_simple_vectors_gte<T, R>(l1: Vec<T>, l2: Vec<T>)
     where
-        T: Copy + PartialEq + std::fmt::Debug,
+        T: Copy + PartialEq + core::fmt::Debug,
         R: SimdRegister<T>,
         crate::math::AutoMath: Math<T>,
         for<'a> &'a mut [T]: WriteOnlyBuffer<Item = T>,
@@ -345,7 +345,7 @@ pub(crate) mod tests {
     // Broadcast value tests
     pub(crate) unsafe fn test_broadcast_value_eq<T, R>(l1: Vec<T>, value: T)
     where
-        T: Copy + PartialEq + std::fmt::Debug + IntoMemLoader<T>,
+        T: Copy + PartialEq + core::fmt::Debug + IntoMemLoader<T>,
         R: SimdRegister<T>,
         crate::math::AutoMath: Math<T>,
         for<'a> &'a mut [T]: WriteOnlyBuffer<Item = T>,
@@ -365,7 +365,7 @@ pub(crate) mod tests {
 
     pub(crate) unsafe fn test_broadcast_value_neq<T, R>(l1: Vec<T>, value: T)
     where
-        T: Copy + PartialEq + std::fmt::Debug + IntoMemLoader<T>,
+        T: Copy + PartialEq + core::fmt::Debug + IntoMemLoader<T>,
         R: SimdRegister<T>,
         crate::math::AutoMath: Math<T>,
         for<'a> &'a mut [T]: WriteOnlyBuffer<Item = T>,
@@ -385,7 +385,7 @@ pub(crate) mod tests {
 
     pub(crate) unsafe fn test_broadcast_value_lt<T, R>(l1: Vec<T>, value: T)
     where
-        T: Copy + PartialEq + std::fmt::Debug + IntoMemLoader<T>,
+        T: Copy + PartialEq + core::fmt::Debug + IntoMemLoader<T>,
         R: SimdRegister<T>,
         crate::math::AutoMath: Math<T>,
         for<'a> &'a mut [T]: WriteOnlyBuffer<Item = T>,
@@ -405,7 +405,7 @@ pub(crate) mod tests {
 
     pub(crate) unsafe fn test_broadcast_value_lte<T, R>(l1: Vec<T>, value: T)
     where
-        T: Copy + PartialEq + std::fmt::Debug + IntoMemLoader<T>,
+        T: Copy + PartialEq + core::fmt::Debug + IntoMemLoader<T>,
         R: SimdRegister<T>,
         crate::math::AutoMath: Math<T>,
         for<'a> &'a mut [T]: WriteOnlyBuffer<Item = T>,
@@ -425,7 +425,7 @@ pub(crate) mod tests {
 
     pub(crate) unsafe fn test_broadcast_value_gt<T, R>(l1: Vec<T>, value: T)
     where
-        T: Copy + PartialEq + std::fmt::Debug + IntoMemLoader<T>,
+        T: Copy + PartialEq + core::fmt::Debug + IntoMemLoader<T>,
         R: SimdRegister<T>,
         crate::math::AutoMath: Math<T>,
         for<'a> &'a mut [T]: WriteOnlyBuffer<Item = T>,
@@ -445,7 +445,7 @@ pub(crate) mod tests {
 
     pub(crate) unsafe fn test_broadcast_value_gte<T, R>(l1: Vec<T>, value: T)
     where
-        T: Copy + PartialEq + std::fmt::Debug + IntoMemLoader<T>,
+        T: Copy + PartialEq + core::fmt::Debug + IntoMemLoader<T>,
         R: SimdRegister<T>,
         crate::math::AutoMath: Math<T>,
         for<'a> &'a mut [T]: WriteOnlyBuffer<Item = T>,
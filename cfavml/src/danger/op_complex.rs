@@ -0,0 +1,158 @@
+use crate::danger::SimdRegister;
+use crate::math::Math;
+use crate::mem_loader::{IntoMemLoader, MemLoader};
+
+#[inline(always)]
+/// A generic complex dot product implementation over planar complex vectors `a` and `b`,
+/// where the real and imaginary parts are stored in separate buffers.
+///
+/// Computes `sum(conj(a) * b)` returning the `(real, imaginary)` parts of the result.
+/// Since each part lives in its own buffer no shuffling is required and every operation
+/// maps directly onto the registers.
+///
+/// # Panics
+///
+/// If `a_re`, `a_im`, `b_re` and `b_im` are not all the same length.
+///
+/// # Safety
+///
+/// The safety requirements of `M` definition the basic math operations and
+/// the requirements of `R` SIMD register must also be followed.
+pub unsafe fn generic_complex_dot_planar<T, R, M, B1, B2, B3, B4>(
+    a_re: B1,
+    a_im: B2,
+    b_re: B3,
+    b_im: B4,
+) -> (T, T)
+where
+    T: Copy,
+    R: SimdRegister<T>,
+    M: Math<T>,
+    B1: IntoMemLoader<T>,
+    B1::Loader: MemLoader<Value = T>,
+    B2: IntoMemLoader<T>,
+    B2::Loader: MemLoader<Value = T>,
+    B3: IntoMemLoader<T>,
+    B3::Loader: MemLoader<Value = T>,
+    B4: IntoMemLoader<T>,
+    B4::Loader: MemLoader<Value = T>,
+{
+    let mut a_re = a_re.into_mem_loader();
+    let mut a_im = a_im.into_mem_loader();
+    let mut b_re = b_re.into_mem_loader();
+    let mut b_im = b_im.into_mem_loader();
+
+    let len = a_re.projected_len();
+    assert!(
+        a_im.projected_len() == len
+            && b_re.projected_len() == len
+            && b_im.projected_len() == len,
+        "Buffers `a_re`, `a_im`, `b_re` and `b_im` do not match in size"
+    );
+
+    let offset_from = len % R::elements_per_dense();
+
+    // The imaginary part is `a_re * b_im - a_im * b_re`, the subtracted products
+    // are accumulated separately so every step remains a fused multiply add.
+    let mut real = R::zeroed_dense();
+    let mut imag = R::zeroed_dense();
+    let mut imag_neg = R::zeroed_dense();
+
+    // Operate over dense lanes first.
+    let mut i = 0;
+    while i < (len - offset_from) {
+        let ar = a_re.load_dense::<R>();
+        let ai = a_im.load_dense::<R>();
+        let br = b_re.load_dense::<R>();
+        let bi = b_im.load_dense::<R>();
+
+        real = R::fmadd_dense(ar, br, real);
+        real = R::fmadd_dense(ai, bi, real);
+        imag = R::fmadd_dense(ar, bi, imag);
+        imag_neg = R::fmadd_dense(ai, br, imag_neg);
+
+        i += R::elements_per_dense();
+    }
+
+    let mut real = R::sum_to_register(real);
+    let mut imag = R::sum_to_register(imag);
+    let mut imag_neg = R::sum_to_register(imag_neg);
+
+    // Operate over single registers next.
+    let offset_from = offset_from % R::elements_per_lane();
+    while i < (len - offset_from) {
+        let ar = a_re.load::<R>();
+        let ai = a_im.load::<R>();
+        let br = b_re.load::<R>();
+        let bi = b_im.load::<R>();
+
+        real = R::fmadd(ar, br, real);
+        real = R::fmadd(ai, bi, real);
+        imag = R::fmadd(ar, bi, imag);
+        imag_neg = R::fmadd(ai, br, imag_neg);
+
+        i += R::elements_per_lane();
+    }
+
+    // Handle the remainder.
+    let mut real = R::sum_to_value(real);
+    let mut imag = M::sub(R::sum_to_value(imag), R::sum_to_value(imag_neg));
+
+    while i < len {
+        let ar = a_re.read();
+        let ai = a_im.read();
+        let br = b_re.read();
+        let bi = b_im.read();
+
+        real = M::add(real, M::add(M::mul(ar, br), M::mul(ai, bi)));
+        imag = M::add(imag, M::sub(M::mul(ar, bi), M::mul(ai, br)));
+
+        i += 1;
+    }
+
+    (real, imag)
+}
+
+#[cfg(test)]
+pub(crate) unsafe fn test_complex_dot_planar<T, R>(l1: Vec<T>, l2: Vec<T>)
+where
+    T: Copy + PartialEq + core::fmt::Debug,
+    R: SimdRegister<T>,
+    crate::math::AutoMath: Math<T>,
+{
+    use crate::math::AutoMath;
+
+    // Kept short enough for the absolute tolerance of `is_close` on `f32`,
+    // while still covering the dense, register and remainder loops.
+    let dims = (R::elements_per_dense() + R::elements_per_lane() + 3).min(l1.len() / 2);
+    let (a_re, a_im) = (&l1[..dims], &l1[dims..][..dims]);
+    let (b_re, b_im) = (&l2[..dims], &l2[dims..][..dims]);
+
+    let (real, imag) =
+        generic_complex_dot_planar::<T, R, AutoMath, _, _, _, _>(a_re, a_im, b_re, b_im);
+
+    let mut expected_real = AutoMath::zero();
+    let mut expected_imag = AutoMath::zero();
+    for i in 0..dims {
+        // conj(a) * b = (ar*br + ai*bi) + (ar*bi - ai*br)i
+        let real = AutoMath::add(
+            AutoMath::mul(a_re[i], b_re[i]),
+            AutoMath::mul(a_im[i], b_im[i]),
+        );
+        let imag = AutoMath::sub(
+            AutoMath::mul(a_re[i], b_im[i]),
+            AutoMath::mul(a_im[i], b_re[i]),
+        );
+        expected_real = AutoMath::add(expected_real, real);
+        expected_imag = AutoMath::add(expected_imag, imag);
+    }
+
+    assert!(
+        AutoMath::is_close(real, expected_real),
+        "real value missmatch {real:?} vs {expected_real:?}"
+    );
+    assert!(
+        AutoMath::is_close(imag, expected_imag),
+        "imaginary value missmatch {imag:?} vs {expected_imag:?}"
+    );
+}
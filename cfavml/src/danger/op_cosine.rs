@@ -14,6 +14,92 @@ use crate::mem_loader::{IntoMemLoader, MemLoader};
 /// The safety requirements of `M` definition the basic math operations and
 /// the requirements of `R` SIMD register must also be followed.
 pub unsafe fn generic_cosine<T, R, M, B1, B2>(a: B1, b: B2) -> T
+where
+    T: Copy,
+    R: SimdRegister<T>,
+    M: Math<T>,
+    B1: IntoMemLoader<T>,
+    B1::Loader: MemLoader<Value = T>,
+    B2: IntoMemLoader<T>,
+    B2::Loader: MemLoader<Value = T>,
+{
+    let (dot, norm_a, norm_b) = cosine_sums::<T, R, M, B1, B2>(a, b);
+    cosine::<T, M>(dot, norm_a, norm_b)
+}
+
+#[inline(always)]
+/// A generic cosine similarity implementation over two vectors of a given set of
+/// dimensions, computing `dot(a, b) / (||a|| * ||b||)`.
+///
+/// The dot product and both squared norms are accumulated in a single pass like
+/// [generic_cosine], but the similarity is returned rather than the distance. If either
+/// vector has a norm of zero the similarity is undefined and zero is returned.
+///
+/// # Panics
+///
+/// If `a` and `b` are not the same length; no projection is available on this routine.
+///
+/// # Safety
+///
+/// The safety requirements of `M` definition the basic math operations and
+/// the requirements of `R` SIMD register must also be followed.
+pub unsafe fn generic_cosine_similarity<T, R, M, B1, B2>(a: B1, b: B2) -> T
+where
+    T: Copy,
+    R: SimdRegister<T>,
+    M: Math<T>,
+    B1: IntoMemLoader<T>,
+    B1::Loader: MemLoader<Value = T>,
+    B2: IntoMemLoader<T>,
+    B2::Loader: MemLoader<Value = T>,
+{
+    let (dot, norm_a, norm_b) = cosine_sums::<T, R, M, B1, B2>(a, b);
+    if M::cmp_eq(norm_a, M::zero()) || M::cmp_eq(norm_b, M::zero()) {
+        return M::zero();
+    }
+
+    M::div(dot, M::mul(M::sqrt(norm_a), M::sqrt(norm_b)))
+}
+
+#[inline(always)]
+/// A fast approximate `f32` cosine implementation over two vectors of a given set of
+/// dimensions.
+///
+/// This computes the same sums as [generic_cosine] but replaces the exact `sqrt` and
+/// divide with an approximate inverse square root refined by Newton-Raphson iterations,
+/// the relative error of `1 / sqrt(norm_a * norm_b)` is at most `1e-6`.
+///
+/// # Panics
+///
+/// If `a` and `b` are not the same length; no projection is available on this routine.
+///
+/// # Safety
+///
+/// The safety requirements of `M` definition the basic math operations and
+/// the requirements of `R` SIMD register must also be followed.
+pub unsafe fn generic_cosine_fast<R, M, B1, B2>(a: B1, b: B2) -> f32
+where
+    R: SimdRegister<f32>,
+    M: Math<f32>,
+    B1: IntoMemLoader<f32>,
+    B1::Loader: MemLoader<Value = f32>,
+    B2: IntoMemLoader<f32>,
+    B2::Loader: MemLoader<Value = f32>,
+{
+    let (dot, norm_a, norm_b) = cosine_sums::<f32, R, M, B1, B2>(a, b);
+
+    if norm_a == 0.0 && norm_b == 0.0 {
+        0.0
+    } else if norm_a == 0.0 || norm_b == 0.0 {
+        1.0
+    } else {
+        1.0 - (dot * fast_inv_sqrt(norm_a * norm_b))
+    }
+}
+
+#[inline(always)]
+/// Computes the dot product, and squared norms of `a` and `b` in one pass.
+unsafe fn cosine_sums<T, R, M, B1, B2>(a: B1, b: B2) -> (T, T, T)
 where
     T: Copy,
     R: SimdRegister<T>,
@@ -68,7 +154,44 @@ where
         i += 1;
     }
 
-    cosine::<T, M>(dot, norm_a, norm_b)
+    (dot, norm_a, norm_b)
+}
+
+#[inline(always)]
+/// An approximate `1 / sqrt(x)` with a relative error of at most `1e-6`.
+///
+/// On x86 this uses the `rsqrtss` estimate (relative error `<= 1.5 * 2^-12`) refined
+/// with a single Newton-Raphson step, elsewhere the classic bit-level estimate
+/// (relative error `<= 1.8e-3`) is refined with two steps.
+fn fast_inv_sqrt(x: f32) -> f32 {
+    #[cfg(all(
+        any(target_arch = "x86", target_arch = "x86_64"),
+        target_feature = "sse"
+    ))]
+    {
+        #[cfg(target_arch = "x86")]
+        use core::arch::x86::*;
+        #[cfg(target_arch = "x86_64")]
+        use core::arch::x86_64::*;
+
+        let estimate = unsafe { _mm_cvtss_f32(_mm_rsqrt_ss(_mm_set_ss(x))) };
+        newton_raphson_inv_sqrt(x, estimate)
+    }
+
+    #[cfg(not(all(
+        any(target_arch = "x86", target_arch = "x86_64"),
+        target_feature = "sse"
+    )))]
+    {
+        let estimate = f32::from_bits(0x5F37_5A86 - (x.to_bits() >> 1));
+        let estimate = newton_raphson_inv_sqrt(x, estimate);
+        newton_raphson_inv_sqrt(x, estimate)
+    }
+}
+
+#[inline(always)]
+fn newton_raphson_inv_sqrt(x: f32, estimate: f32) -> f32 {
+    estimate * (1.5 - 0.5 * x * estimate * estimate)
 }
 
 #[inline(always)]
@@ -88,7 +211,7 @@ pub(crate) fn cosine<T: Copy, M: Math<T>>(dot_product: T, norm_x: T, norm_y: T)
 #[cfg(test)]
 pub(crate) unsafe fn test_cosine<T, R>(l1: Vec<T>, l2: Vec<T>)
 where
-    T: Copy + PartialEq + std::fmt::Debug,
+    T: Copy + PartialEq + core::fmt::Debug,
     R: SimdRegister<T>,
     crate::math::AutoMath: Math<T>,
 {
@@ -101,3 +224,100 @@ where
         "value missmatch {value:?} vs {expected_value:?}"
     );
 }
+
+#[cfg(test)]
+pub(crate) unsafe fn test_cosine_similarity<T, R>(l1: Vec<T>, l2: Vec<T>)
+where
+    T: Copy + PartialEq + core::fmt::Debug,
+    R: SimdRegister<T>,
+    crate::math::AutoMath: Math<T>,
+{
+    use crate::math::AutoMath;
+
+    let value = generic_cosine_similarity::<T, R, AutoMath, _, _>(&l1, &l2);
+    let norm_a = AutoMath::sqrt(crate::test_utils::simple_dot(&l1, &l1));
+    let norm_b = AutoMath::sqrt(crate::test_utils::simple_dot(&l2, &l2));
+    let expected_value = AutoMath::div(
+        crate::test_utils::simple_dot(&l1, &l2),
+        AutoMath::mul(norm_a, norm_b),
+    );
+    assert!(
+        AutoMath::is_close(value, expected_value),
+        "value missmatch {value:?} vs {expected_value:?}"
+    );
+
+    let value = generic_cosine_similarity::<T, R, AutoMath, _, _>(&l1, &l1);
+    assert!(
+        AutoMath::is_close(value, AutoMath::one()),
+        "identical vectors should have a similarity of one {value:?}"
+    );
+
+    // Vectors with no overlapping non-zero elements are orthogonal.
+    let dims = l1.len();
+    let mut a = vec![AutoMath::zero(); dims];
+    let mut b = vec![AutoMath::zero(); dims];
+    a[..dims / 2].copy_from_slice(&l1[..dims / 2]);
+    b[dims / 2..].copy_from_slice(&l2[dims / 2..]);
+    let value = generic_cosine_similarity::<T, R, AutoMath, _, _>(&a, &b);
+    assert_eq!(value, AutoMath::zero(), "orthogonal vectors should be zero");
+
+    let zeroes = vec![AutoMath::zero(); dims];
+    let value = generic_cosine_similarity::<T, R, AutoMath, _, _>(&zeroes, &l1);
+    assert_eq!(value, AutoMath::zero());
+    let value = generic_cosine_similarity::<T, R, AutoMath, _, _>(&zeroes, &zeroes);
+    assert_eq!(value, AutoMath::zero());
+}
+
+#[cfg(test)]
+pub(crate) unsafe fn test_cosine_fast<R>(l1: Vec<f32>, l2: Vec<f32>)
+where
+    R: SimdRegister<f32>,
+{
+    use crate::math::StdMath;
+
+    const TOLERANCE: f32 = 1e-5;
+
+    let value = generic_cosine_fast::<R, StdMath, _, _>(&l1, &l2);
+    let expected_value = generic_cosine::<f32, R, StdMath, _, _>(&l1, &l2);
+    assert!(
+        (value - expected_value).abs() <= TOLERANCE,
+        "value missmatch {value:?} vs {expected_value:?}"
+    );
+
+    // Near orthogonal vectors have a similarity close to zero.
+    let dims = l2.len();
+    let mut l1 = vec![0.0; dims];
+    let mut l2 = vec![0.0; dims];
+    l1[0] = 1.0;
+    l2[0] = 1e-4;
+    l2[dims - 1] = 1.0;
+    let value = generic_cosine_fast::<R, StdMath, _, _>(&l1, &l2);
+    let expected_value = generic_cosine::<f32, R, StdMath, _, _>(&l1, &l2);
+    assert!(
+        (value - expected_value).abs() <= TOLERANCE,
+        "value missmatch {value:?} vs {expected_value:?}"
+    );
+
+    let zeroes = vec![0.0; l1.len()];
+    let value = generic_cosine_fast::<R, StdMath, _, _>(&zeroes, &zeroes);
+    assert_eq!(value, 0.0);
+    let value = generic_cosine_fast::<R, StdMath, _, _>(&zeroes, &l1);
+    assert_eq!(value, 1.0);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fast_inv_sqrt_error_bound() {
+        let mut x = 1e-30_f32;
+        while x < 1e30 {
+            let expected = 1.0 / (x as f64).sqrt();
+            let error = ((fast_inv_sqrt(x) as f64 - expected) / expected).abs();
+            assert!(error <= 1e-6, "error {error} exceeds bound for {x}");
+
+            x *= 1.37;
+        }
+    }
+}
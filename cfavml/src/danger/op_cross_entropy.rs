@@ -0,0 +1,211 @@
+use crate::danger::op_vecmath::{exp_f32, ln_f32};
+use crate::danger::{generic_cmp_max, SimdRegister};
+use crate::math::Math;
+use crate::mem_loader::SCRATCH_SPACE_SIZE;
+
+#[inline(always)]
+/// A softmax cross-entropy loss implementation over a `f32` vector of `logits`,
+/// computing `-log_softmax(logits)[target]`.
+///
+/// The loss is computed with the numerically stable log-sum-exp, the max of `logits`
+/// is found with [generic_cmp_max] and subtracted from every logit before it is
+/// exponentiated, the exponentials are written a register at a time into a scratch
+/// buffer and summed in the `f32` registers of `R`. This means no exponential can
+/// overflow, and the sum is always at least `1.0`.
+///
+/// # Panics
+///
+/// If `target` is not less than `logits.len()`.
+///
+/// # Safety
+///
+/// The safety requirements of `M` definition the basic math operations and the
+/// requirements of `R` SIMD register must also be followed.
+pub unsafe fn generic_cross_entropy<R, M>(logits: &[f32], target: usize) -> f32
+where
+    R: SimdRegister<f32>,
+    M: Math<f32>,
+{
+    assert!(
+        target < logits.len(),
+        "Target class `target` is out of bounds for `logits`"
+    );
+
+    let max = generic_cmp_max::<f32, R, M, _>(logits);
+    let total = sum_exp_shifted::<R, M>(logits, max);
+
+    // `max - logits[target]` is taken first so large logits do not lose precision.
+    M::add(M::sub(max, *logits.get_unchecked(target)), ln_f32(total))
+}
+
+#[inline(always)]
+/// A numerically stable log-sum-exp implementation over the `f32` vector `x`, computing
+/// `ln(sum(exp(x[i])))`.
+///
+/// The max of `x` is found with [generic_cmp_max] and the sum is taken over
+/// `exp(x[i] - max)` in the same way as [generic_cross_entropy], so no exponential can
+/// overflow, then `max + ln(sum)` is returned. An empty vector gives negative infinity,
+/// and positive infinity anywhere in `x` gives positive infinity.
+///
+/// # Safety
+///
+/// The safety requirements of `M` definition the basic math operations and the
+/// requirements of `R` SIMD register must also be followed.
+pub unsafe fn generic_logsumexp<R, M>(x: &[f32]) -> f32
+where
+    R: SimdRegister<f32>,
+    M: Math<f32>,
+{
+    let max = generic_cmp_max::<f32, R, M, _>(x);
+    if M::cmp_eq(max, f32::INFINITY) {
+        return max;
+    }
+
+    let total = sum_exp_shifted::<R, M>(x, max);
+    M::add(max, ln_f32(total))
+}
+
+#[inline(always)]
+/// Sums `exp(x[i] - max)`, the exponentials are written a register at a time into a
+/// scratch buffer and summed in the `f32` registers of `R`.
+unsafe fn sum_exp_shifted<R, M>(x: &[f32], max: f32) -> f32
+where
+    R: SimdRegister<f32>,
+    M: Math<f32>,
+{
+    let len = x.len();
+    let offset_from = len % R::elements_per_lane();
+
+    // A dense lane of `f32` does not fit in the scratch space with AVX512, so the
+    // exponentials are computed a register at a time.
+    let mut buffer = [M::zero(); SCRATCH_SPACE_SIZE];
+
+    let mut total = R::zeroed();
+    let mut i = 0;
+    while i < (len - offset_from) {
+        let block = x.get_unchecked(i..i + R::elements_per_lane());
+        for (dst, value) in buffer.iter_mut().zip(block) {
+            *dst = exp_f32(M::sub(*value, max));
+        }
+
+        total = R::add(total, R::load(buffer.as_ptr()));
+
+        i += R::elements_per_lane();
+    }
+
+    // Handle the remainder.
+    let mut total = R::sum_to_value(total);
+    while i < len {
+        total = M::add(total, exp_f32(M::sub(*x.get_unchecked(i), max)));
+
+        i += 1;
+    }
+
+    total
+}
+
+#[cfg(test)]
+pub(crate) unsafe fn test_cross_entropy<R>()
+where
+    R: SimdRegister<f32>,
+{
+    use crate::math::AutoMath;
+
+    let reference = |logits: &[f32], target: usize| -> f64 {
+        let max = logits
+            .iter()
+            .fold(f64::NEG_INFINITY, |m, v| m.max(*v as f64));
+        let sum = logits.iter().map(|v| (*v as f64 - max).exp()).sum::<f64>();
+        max + sum.ln() - logits[target] as f64
+    };
+
+    let (l1, _) = crate::test_utils::get_sample_vectors::<f32>(1043);
+    for len in [1, 7, 131, 1043] {
+        let logits = l1[..len].iter().map(|v| v * 10.0 - 5.0).collect::<Vec<_>>();
+
+        for target in [0, len / 2, len - 1] {
+            let value = generic_cross_entropy::<R, AutoMath>(&logits, target);
+            let expected_value = reference(&logits, target);
+            assert!(
+                (value as f64 - expected_value).abs()
+                    <= 1e-4 * expected_value.abs().max(1.0),
+                "value missmatch on len {len} {value} vs {expected_value}"
+            );
+        }
+    }
+
+    // Naively exponentiating these logits overflows to infinity.
+    let logits = (0..67)
+        .map(|i| 1000.0 - i as f32 * 0.25)
+        .collect::<Vec<_>>();
+    assert!(logits[0].exp().is_infinite());
+    for target in [0, 33, 66] {
+        let value = generic_cross_entropy::<R, AutoMath>(&logits, target);
+        let expected_value = reference(&logits, target);
+        assert!(
+            value.is_finite(),
+            "cross entropy should be finite for large logits"
+        );
+        assert!(
+            (value as f64 - expected_value).abs()
+                <= 1e-4 * expected_value.abs().max(1.0),
+            "value missmatch on large logits {value} vs {expected_value}"
+        );
+    }
+
+    // A single dominant logit gives a loss of almost zero.
+    let mut logits = vec![-50.0f32; 16];
+    logits[3] = 50.0;
+    let value = generic_cross_entropy::<R, AutoMath>(&logits, 3);
+    assert!(
+        value.abs() < 1e-6,
+        "value missmatch on dominant logit {value}"
+    );
+}
+
+#[cfg(test)]
+pub(crate) unsafe fn test_logsumexp<R>()
+where
+    R: SimdRegister<f32>,
+{
+    use crate::math::AutoMath;
+
+    let reference = |x: &[f32]| -> f64 {
+        let max = x.iter().fold(f64::NEG_INFINITY, |m, v| m.max(*v as f64));
+        max + x.iter().map(|v| (*v as f64 - max).exp()).sum::<f64>().ln()
+    };
+    let check = |value: f32, expected_value: f64, case: &str| {
+        assert!(
+            (value as f64 - expected_value).abs()
+                <= 1e-4 * expected_value.abs().max(1.0),
+            "value missmatch on {case} {value} vs {expected_value}"
+        );
+    };
+
+    let (l1, _) = crate::test_utils::get_sample_vectors::<f32>(1043);
+    for len in [1, 7, 131, 1043] {
+        let x = l1[..len].iter().map(|v| v * 10.0 - 5.0).collect::<Vec<_>>();
+        let value = generic_logsumexp::<R, AutoMath>(&x);
+        check(value, reference(&x), &format!("len {len}"));
+    }
+
+    // Naively exponentiating these values overflows or underflows every element.
+    for offset in [1000.0, -1000.0] {
+        let x = (0..67)
+            .map(|i| offset - i as f32 * 0.25)
+            .collect::<Vec<_>>();
+        assert!(x.iter().map(|v| v.exp()).sum::<f32>().ln().is_infinite());
+
+        let value = generic_logsumexp::<R, AutoMath>(&x);
+        check(value, reference(&x), &format!("offset {offset}"));
+    }
+
+    let value = generic_logsumexp::<R, AutoMath>(&[3.5]);
+    assert_eq!(value, 3.5, "value missmatch on a single value");
+
+    let value = generic_logsumexp::<R, AutoMath>(&[1.0, f32::INFINITY, 2.0]);
+    assert_eq!(value, f32::INFINITY);
+
+    let value = generic_logsumexp::<R, AutoMath>(&[]);
+    assert_eq!(value, f32::NEG_INFINITY, "log-sum-exp of an empty vector");
+}
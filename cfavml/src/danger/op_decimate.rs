@@ -0,0 +1,81 @@
+use crate::buffer::WriteOnlyBuffer;
+use crate::danger::SimdRegister;
+use crate::math::Math;
+use crate::mem_loader::SCRATCH_SPACE_SIZE;
+
+#[inline(always)]
+/// A generic decimation (downsample) routine taking every `factor`th element of `a`
+/// starting from the first element and writing it to `result`.
+///
+/// Strided elements are read one at a time into a register sized scratch buffer, this
+/// is a scalar gather rather than a hardware gather instruction, before being loaded
+/// and written out as a full register. A `factor` of `1` is a straight copy.
+///
+/// # Panics
+///
+/// If `factor` is `0` or `result` is not exactly `a.len().div_ceil(factor)` in length.
+///
+/// # Safety
+///
+/// The safety requirements of `M` definition the basic math operations and the
+/// requirements of `R` SIMD register must also be followed.
+pub unsafe fn generic_decimate<T, R, M, B1>(
+    a: &[T],
+    factor: usize,
+    mut result: &mut [B1],
+) where
+    T: Copy,
+    R: SimdRegister<T>,
+    M: Math<T>,
+    for<'a> &'a mut [B1]: WriteOnlyBuffer<Item = T>,
+{
+    assert_ne!(factor, 0, "Decimation `factor` must be greater than zero");
+
+    let len = result.raw_buffer_len();
+    assert_eq!(
+        len,
+        a.len().div_ceil(factor),
+        "Buffer `result` does not match the decimated length of `a`"
+    );
+
+    let data_ptr = a.as_ptr();
+    let result_ptr = result.as_write_only_ptr();
+    let offset_from = len % R::elements_per_lane();
+
+    let mut gathered = [M::zero(); SCRATCH_SPACE_SIZE];
+
+    let mut i = 0;
+    while i < (len - offset_from) {
+        for (j, slot) in gathered[..R::elements_per_lane()].iter_mut().enumerate() {
+            *slot = data_ptr.add((i + j) * factor).read();
+        }
+
+        let l1 = R::load(gathered.as_ptr());
+        R::write(result_ptr.add(i), l1);
+
+        i += R::elements_per_lane();
+    }
+
+    while i < len {
+        result.write_at(i, data_ptr.add(i * factor).read());
+
+        i += 1;
+    }
+}
+
+#[cfg(test)]
+pub(crate) unsafe fn test_decimate<T, R>(l1: Vec<T>, factor: usize)
+where
+    T: Copy + PartialEq + core::fmt::Debug,
+    R: SimdRegister<T>,
+    crate::math::AutoMath: Math<T>,
+    for<'a> &'a mut [T]: WriteOnlyBuffer<Item = T>,
+{
+    use crate::math::AutoMath;
+
+    let mut result = vec![AutoMath::zero(); l1.len().div_ceil(factor)];
+    generic_decimate::<T, R, AutoMath, _>(&l1, factor, &mut result);
+
+    let expected = l1.iter().copied().step_by(factor).collect::<Vec<_>>();
+    assert_eq!(result, expected, "value mismatch");
+}
@@ -1,10 +1,28 @@
 use crate::danger::core_simd_api::SimdRegister;
 use crate::math::Math;
-use crate::mem_loader::{IntoMemLoader, MemLoader};
+use crate::mem_loader::{IntoMemLoader, MemLoader, SCRATCH_SPACE_SIZE};
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+/// The result of a dot product along with metadata describing how it was computed.
+///
+/// Backends accumulate products in a different order and may or may not fuse the
+/// multiply and add, which is why results can differ slightly from other libraries
+/// like NumPy.
+pub struct DotResult<T> {
+    /// The dot product of the two vectors.
+    pub value: T,
+    /// A description of the order the products were accumulated in.
+    pub reduction_order: &'static str,
+    /// If the products were accumulated with a true fused multiply add, rounding once
+    /// per product and addition rather than twice.
+    pub used_fma: bool,
+}
 
 #[inline(always)]
 /// A generic dot product implementation over two vectors of a given set of dimensions.
 ///
+/// Vectors that fit within a single register are handled by [generic_dot_single_register].
+///
 /// # Safety
 ///
 /// The sizes of `a` and `b` must be equal to `dims`, the safety requirements of
@@ -29,6 +47,11 @@ where
     );
 
     let len = a.projected_len();
+
+    if len <= R::elements_per_lane() {
+        return dot_single_register::<T, R, M, _, _>(&mut a, &mut b, len);
+    }
+
     let offset_from = len % R::elements_per_dense();
 
     let mut total = R::zeroed_dense();
@@ -69,19 +92,1998 @@ where
     total
 }
 
-#[cfg(test)]
-pub(crate) unsafe fn test_dot<T, R>(l1: Vec<T>, l2: Vec<T>)
+#[inline(always)]
+/// A generic dot product implementation over two vectors whose length is a multiple of
+/// `R::elements_per_dense()`.
+///
+/// Since every element belongs to a full dense lane, the single register and remainder
+/// loops of [generic_dot] are skipped entirely. This is intended for callers which select
+/// a kernel once for a fixed dimension, i.e. embeddings of `768` or `1536` dimensions.
+///
+/// # Panics
+///
+/// If `a` and `b` are not the same length, or the length is not a multiple of
+/// `R::elements_per_dense()`.
+///
+/// # Safety
+///
+/// The safety requirements of `M` definition the basic math operations and the
+/// requirements of `R` SIMD register must also be followed.
+pub unsafe fn generic_dot_dense_aligned<T, R, M>(a: &[T], b: &[T]) -> T
 where
-    T: Copy + PartialEq + std::fmt::Debug,
+    T: Copy,
     R: SimdRegister<T>,
-    crate::math::AutoMath: Math<T>,
+    M: Math<T>,
 {
-    use crate::math::AutoMath;
+    assert_eq!(a.len(), b.len(), "Buffers `a` and `b` do not match in size");
+    assert_eq!(
+        a.len() % R::elements_per_dense(),
+        0,
+        "Buffer length is not a multiple of the dense lane size"
+    );
 
-    let value = generic_dot::<T, R, AutoMath, _, _>(&l1, &l2);
-    let expected_value = crate::test_utils::simple_dot(&l1, &l2);
+    let len = a.len();
+    let a_ptr = a.as_ptr();
+    let b_ptr = b.as_ptr();
+
+    let mut total = R::zeroed_dense();
+
+    let mut i = 0;
+    while i < len {
+        let l1 = R::load_dense(a_ptr.add(i));
+        let l2 = R::load_dense(b_ptr.add(i));
+        total = R::fmadd_dense(l1, l2, total);
+
+        i += R::elements_per_dense();
+    }
+
+    R::sum_to_value(R::sum_to_register(total))
+}
+
+#[inline(always)]
+/// A generic dot product implementation over two vectors which fit entirely within
+/// a single `R` register, i.e. `4` or less `f64` elements with AVX2.
+///
+/// Both vectors are zero padded to a full register, multiplied with a single fused
+/// multiply add and horizontally reduced, avoiding the loop and remainder handling
+/// of [generic_dot], which routes to this kernel for these sizes automatically.
+///
+/// # Panics
+///
+/// If `a` and `b` are not the same length, or are longer than `R::elements_per_lane()`.
+///
+/// # Safety
+///
+/// The safety requirements of `M` definition the basic math operations and the
+/// requirements of `R` SIMD register must also be followed.
+pub unsafe fn generic_dot_single_register<T, R, M, B1, B2>(a: B1, b: B2) -> T
+where
+    T: Copy,
+    R: SimdRegister<T>,
+    M: Math<T>,
+    B1: IntoMemLoader<T>,
+    B1::Loader: MemLoader<Value = T>,
+    B2: IntoMemLoader<T>,
+    B2::Loader: MemLoader<Value = T>,
+{
+    let mut a = a.into_mem_loader();
+    let mut b = b.into_mem_loader();
+    assert_eq!(
+        a.projected_len(),
+        b.projected_len(),
+        "Buffers `a` and `b` do not match in size"
+    );
+
+    let len = a.projected_len();
     assert!(
-        AutoMath::is_close(value, expected_value),
-        "value missmatch {value:?} vs {expected_value:?}"
+        len <= R::elements_per_lane(),
+        "Buffers `a` and `b` do not fit within a single register"
+    );
+
+    dot_single_register::<T, R, M, _, _>(&mut a, &mut b, len)
+}
+
+#[inline(always)]
+unsafe fn dot_single_register<T, R, M, L1, L2>(a: &mut L1, b: &mut L2, len: usize) -> T
+where
+    T: Copy,
+    R: SimdRegister<T>,
+    M: Math<T>,
+    L1: MemLoader<Value = T>,
+    L2: MemLoader<Value = T>,
+{
+    let mut l1_buffer = [M::zero(); SCRATCH_SPACE_SIZE];
+    let mut l2_buffer = [M::zero(); SCRATCH_SPACE_SIZE];
+    for (x, y) in l1_buffer.iter_mut().zip(l2_buffer.iter_mut()).take(len) {
+        *x = a.read();
+        *y = b.read();
+    }
+
+    let l1 = R::load(l1_buffer.as_ptr());
+    let l2 = R::load(l2_buffer.as_ptr());
+    R::sum_to_value(R::fmadd(l1, l2, R::zeroed()))
+}
+
+#[inline(always)]
+/// A generic dot product implementation over two vectors of a given set of dimensions
+/// which returns the dot product multiplied by `scale`.
+///
+/// The scale is applied once to the reduced dot product, i.e. a learning rate applied to
+/// the dot product of a gradient.
+///
+/// # Panics
+///
+/// If `a` and `b` are not the same length.
+///
+/// # Safety
+///
+/// The safety requirements of `M` definition the basic math operations and the
+/// requirements of `R` SIMD register must also be followed.
+pub unsafe fn generic_scaled_dot<T, R, M, B1, B2>(a: B1, b: B2, scale: T) -> T
+where
+    T: Copy,
+    R: SimdRegister<T>,
+    M: Math<T>,
+    B1: IntoMemLoader<T>,
+    B1::Loader: MemLoader<Value = T>,
+    B2: IntoMemLoader<T>,
+    B2::Loader: MemLoader<Value = T>,
+{
+    M::mul(scale, generic_dot::<T, R, M, B1, B2>(a, b))
+}
+
+#[inline(always)]
+/// A generic dot product implementation between `kernel` and `len` elements of the ring
+/// buffer `buf` read from `start`, wrapping around to the start of `buf` once the end
+/// is reached.
+///
+/// A read which wraps is split into the two contiguous runs `buf[start..]` and
+/// `buf[..len - (buf.len() - start)]`, each run is reduced with [generic_dot] against the
+/// matching part of `kernel` and the two results are summed.
+///
+/// # Panics
+///
+/// If `kernel` is not `len` in length, `len` is larger than `buf` or `start` is not
+/// within `buf`.
+///
+/// # Safety
+///
+/// The safety requirements of `M` definition the basic math operations and the
+/// requirements of `R` SIMD register must also be followed.
+pub unsafe fn generic_dot_wrapping<T, R, M>(
+    buf: &[T],
+    start: usize,
+    len: usize,
+    kernel: &[T],
+) -> T
+where
+    T: Copy,
+    R: SimdRegister<T>,
+    M: Math<T>,
+{
+    assert_eq!(
+        kernel.len(),
+        len,
+        "Buffer `kernel` does not match `len` in size"
+    );
+    assert!(len <= buf.len(), "Read `len` is larger than buffer `buf`");
+    assert!(
+        start < buf.len(),
+        "Offset `start` is out of bounds for `buf`"
+    );
+
+    let head_len = len.min(buf.len() - start);
+    let (kernel_head, kernel_tail) = kernel.split_at(head_len);
+
+    let head = generic_dot::<T, R, M, _, _>(&buf[start..start + head_len], kernel_head);
+    if kernel_tail.is_empty() {
+        return head;
+    }
+
+    let tail = generic_dot::<T, R, M, _, _>(&buf[..kernel_tail.len()], kernel_tail);
+    M::add(head, tail)
+}
+
+#[inline(always)]
+/// A generic dot product implementation over two vectors of a given set of dimensions
+/// which reports the partial result to `progress` while accumulating.
+///
+/// `progress(blocks_done, partial_dot)` is called after every `every` blocks have been
+/// accumulated, where a block is one dense lane of `R::elements_per_dense()` elements.
+/// The partial result is reduced from a copy of the accumulators so reporting
+/// does not alter the final result, which matches [generic_dot].
+///
+/// # Panics
+///
+/// If `every` is `0` or vectors `a` and `b` do not match in size.
+///
+/// # Safety
+///
+/// The sizes of `a` and `b` must be equal to `dims`, the safety requirements of
+/// `M` definition the basic math operations and the requirements of `R` SIMD register
+/// must also be followed.
+pub unsafe fn generic_dot_progress<T, R, M, B1, B2, F>(
+    a: B1,
+    b: B2,
+    every: usize,
+    mut progress: F,
+) -> T
+where
+    T: Copy,
+    R: SimdRegister<T>,
+    M: Math<T>,
+    B1: IntoMemLoader<T>,
+    B1::Loader: MemLoader<Value = T>,
+    B2: IntoMemLoader<T>,
+    B2::Loader: MemLoader<Value = T>,
+    F: FnMut(usize, T),
+{
+    assert_ne!(
+        every, 0,
+        "Progress interval `every` must be greater than zero"
+    );
+
+    let mut a = a.into_mem_loader();
+    let mut b = b.into_mem_loader();
+    assert_eq!(
+        a.projected_len(),
+        b.projected_len(),
+        "Buffers `a` and `b` do not match in size"
+    );
+
+    let len = a.projected_len();
+    let offset_from = len % R::elements_per_dense();
+
+    let mut total = R::zeroed_dense();
+
+    // Operate over dense lanes first.
+    let mut blocks_done = 0;
+    let mut i = 0;
+    while i < (len - offset_from) {
+        let l1 = a.load_dense::<R>();
+        let l2 = b.load_dense::<R>();
+        total = R::fmadd_dense(l1, l2, total);
+
+        blocks_done += 1;
+        if blocks_done % every == 0 {
+            progress(blocks_done, R::sum_to_value(R::sum_to_register(total)));
+        }
+
+        i += R::elements_per_dense();
+    }
+
+    let mut total = R::sum_to_register(total);
+
+    // Operate over single registers next.
+    let offset_from = offset_from % R::elements_per_lane();
+    while i < (len - offset_from) {
+        let l1 = a.load::<R>();
+        let l2 = b.load::<R>();
+        total = R::fmadd(l1, l2, total);
+
+        i += R::elements_per_lane();
+    }
+
+    // Handle the remainder.
+    let mut total = R::sum_to_value(total);
+
+    while i < len {
+        let a = a.read();
+        let b = b.read();
+        total = M::add(total, M::mul(a, b));
+
+        i += 1;
+    }
+
+    total
+}
+
+#[inline(always)]
+/// A generic dot product implementation over two vectors which are each yielded as a
+/// sequence of chunks, where the chunk boundaries of `a` and `b` do not need to line up.
+///
+/// Both iterators are advanced in lockstep. While no elements are buffered, whole dense
+/// lanes are loaded directly from the overlapping region of the current chunks, otherwise
+/// the overlap is copied into a pair of scratch buffers until they hold a full register.
+/// This means small or misaligned chunks are still accumulated in the registers of `R`,
+/// only the final partial register is handled element by element.
+///
+/// Empty chunks are skipped.
+///
+/// # Panics
+///
+/// If the total length of the chunks yielded by `a_chunks` and `b_chunks` do not match.
+///
+/// # Safety
+///
+/// The safety requirements of `M` definition the basic math operations and the
+/// requirements of `R` SIMD register must also be followed.
+pub unsafe fn generic_dot_streaming_both<'a, T, R, M, I1, I2>(
+    mut a_chunks: I1,
+    mut b_chunks: I2,
+) -> T
+where
+    T: Copy + 'a,
+    R: SimdRegister<T>,
+    M: Math<T>,
+    I1: Iterator<Item = &'a [T]>,
+    I2: Iterator<Item = &'a [T]>,
+{
+    let dense = R::elements_per_dense();
+    let lane = R::elements_per_lane();
+
+    let mut a_buffer = [M::zero(); SCRATCH_SPACE_SIZE];
+    let mut b_buffer = [M::zero(); SCRATCH_SPACE_SIZE];
+    let mut buffered = 0;
+
+    let mut a: &[T] = &[];
+    let mut b: &[T] = &[];
+    let mut total = R::zeroed_dense();
+    let mut buffered_total = R::zeroed();
+    loop {
+        if a.is_empty() {
+            a = a_chunks.find(|chunk| !chunk.is_empty()).unwrap_or_default();
+        }
+        if b.is_empty() {
+            b = b_chunks.find(|chunk| !chunk.is_empty()).unwrap_or_default();
+        }
+        if a.is_empty() || b.is_empty() {
+            assert!(
+                a.is_empty() && b.is_empty(),
+                "Chunks of `a` and `b` do not match in total size"
+            );
+            break;
+        }
+
+        let overlap = a.len().min(b.len());
+        if buffered == 0 && overlap >= dense {
+            // Operate over dense lanes directly from the chunks while aligned.
+            let aligned = overlap - (overlap % dense);
+            let mut i = 0;
+            while i < aligned {
+                let l1 = R::load_dense(a.as_ptr().add(i));
+                let l2 = R::load_dense(b.as_ptr().add(i));
+                total = R::fmadd_dense(l1, l2, total);
+
+                i += dense;
+            }
+
+            a = a.get_unchecked(aligned..);
+            b = b.get_unchecked(aligned..);
+        } else {
+            // Realign the chunk boundaries by buffering up to a full register, a dense
+            // lane of narrow types does not fit in the scratch space.
+            let take = overlap.min(lane - buffered);
+            a_buffer[buffered..buffered + take].copy_from_slice(&a[..take]);
+            b_buffer[buffered..buffered + take].copy_from_slice(&b[..take]);
+            a = a.get_unchecked(take..);
+            b = b.get_unchecked(take..);
+            buffered += take;
+
+            if buffered == lane {
+                let l1 = R::load(a_buffer.as_ptr());
+                let l2 = R::load(b_buffer.as_ptr());
+                buffered_total = R::fmadd(l1, l2, buffered_total);
+                buffered = 0;
+            }
+        }
+    }
+
+    // Handle the remainder.
+    let total = R::add(R::sum_to_register(total), buffered_total);
+    let mut total = R::sum_to_value(total);
+    for (x, y) in a_buffer.iter().zip(b_buffer.iter()).take(buffered) {
+        total = M::add(total, M::mul(*x, *y));
+    }
+
+    total
+}
+
+#[inline(always)]
+/// A generic dot product implementation over two vectors of a given set of dimensions
+/// which returns zero early if either `a` or `b` is entirely zero.
+///
+/// The first dense block of each operand is tested for zero before computing the dot
+/// product, an operand whose first block is non-zero goes straight to the dot product.
+/// Only an operand starting with a zero block is scanned further to confirm it is
+/// entirely zero, stopping at the first block containing a non-zero value. This only
+/// helps sparse workloads where all-zero operands are common, otherwise [generic_dot]
+/// should be preferred.
+///
+/// Note that skipping an all-zero operand means `NaN` or infinite values in the other
+/// operand are not propagated, the result is always exactly zero.
+///
+/// # Panics
+///
+/// If `a` and `b` are not the same length.
+///
+/// # Safety
+///
+/// The safety requirements of `M` definition the basic math operations and the
+/// requirements of `R` SIMD register must also be followed.
+pub unsafe fn generic_dot_skip_zero<T, R, M, B1, B2>(a: B1, b: B2) -> T
+where
+    T: Copy,
+    R: SimdRegister<T>,
+    M: Math<T>,
+    B1: IntoMemLoader<T> + Copy,
+    B1::Loader: MemLoader<Value = T>,
+    B2: IntoMemLoader<T> + Copy,
+    B2::Loader: MemLoader<Value = T>,
+{
+    assert_eq!(
+        a.into_mem_loader().projected_len(),
+        b.into_mem_loader().projected_len(),
+        "Buffers `a` and `b` do not match in size"
     );
+
+    if (is_first_block_zero::<T, R, M, B1>(a) && is_all_zero::<T, R, M, B1>(a))
+        || (is_first_block_zero::<T, R, M, B2>(b) && is_all_zero::<T, R, M, B2>(b))
+    {
+        return M::zero();
+    }
+
+    generic_dot::<T, R, M, B1, B2>(a, b)
+}
+
+#[inline(always)]
+/// Returns if the first block of `a` is entirely zero, a dense lane when `a` is long
+/// enough otherwise a single register.
+///
+/// Vectors shorter than a register are always considered zero, leaving the check to
+/// [is_all_zero] which handles them as the remainder.
+unsafe fn is_first_block_zero<T, R, M, B1>(a: B1) -> bool
+where
+    T: Copy,
+    R: SimdRegister<T>,
+    M: Math<T>,
+    B1: IntoMemLoader<T>,
+    B1::Loader: MemLoader<Value = T>,
+{
+    let mut a = a.into_mem_loader();
+    let len = a.projected_len();
+
+    if len >= R::elements_per_dense() {
+        let l1 = a.load_dense::<R>();
+        let non_zero = R::max_to_register(R::neq_dense(l1, R::zeroed_dense()));
+        M::cmp_eq(R::max_to_value(non_zero), M::zero())
+    } else if len >= R::elements_per_lane() {
+        let l1 = a.load::<R>();
+        M::cmp_eq(R::max_to_value(R::neq(l1, R::zeroed())), M::zero())
+    } else {
+        true
+    }
+}
+
+#[inline(always)]
+/// Returns if every element of `a` is equal to zero, stopping at the first block
+/// which contains a non-zero element.
+unsafe fn is_all_zero<T, R, M, B1>(a: B1) -> bool
+where
+    T: Copy,
+    R: SimdRegister<T>,
+    M: Math<T>,
+    B1: IntoMemLoader<T>,
+    B1::Loader: MemLoader<Value = T>,
+{
+    let mut a = a.into_mem_loader();
+    let len = a.projected_len();
+
+    let offset_from = len % R::elements_per_dense();
+
+    // Operate over dense lanes first.
+    let zeroes = R::zeroed_dense();
+    let mut i = 0;
+    while i < (len - offset_from) {
+        let l1 = a.load_dense::<R>();
+        let non_zero = R::max_to_register(R::neq_dense(l1, zeroes));
+        if !M::cmp_eq(R::max_to_value(non_zero), M::zero()) {
+            return false;
+        }
+
+        i += R::elements_per_dense();
+    }
+
+    // Operate over single registers next.
+    let zeroes = R::zeroed();
+    let offset_from = offset_from % R::elements_per_lane();
+    while i < (len - offset_from) {
+        let l1 = a.load::<R>();
+        if !M::cmp_eq(R::max_to_value(R::neq(l1, zeroes)), M::zero()) {
+            return false;
+        }
+
+        i += R::elements_per_lane();
+    }
+
+    // Handle the remainder.
+    while i < len {
+        if !M::cmp_eq(a.read(), M::zero()) {
+            return false;
+        }
+
+        i += 1;
+    }
+
+    true
+}
+
+#[inline(always)]
+/// A generic implementation computing the dot product of `x` with itself and the dot
+/// product of `x` and `y` in a single pass, returning `(x·x, x·y)`.
+///
+/// Each load of `x` is shared between two groups of accumulators, which avoids reading
+/// `x` twice when both values are needed, i.e. when building the rows of a Gram matrix.
+///
+/// # Panics
+///
+/// If `x` and `y` are not the same length.
+///
+/// # Safety
+///
+/// The sizes of `x` and `y` must be equal to `dims`, the safety requirements of
+/// `M` definition the basic math operations and the requirements of `R` SIMD register
+/// must also be followed.
+pub unsafe fn generic_dot_self_and_cross<T, R, M, B1, B2>(x: B1, y: B2) -> (T, T)
+where
+    T: Copy,
+    R: SimdRegister<T>,
+    M: Math<T>,
+    B1: IntoMemLoader<T>,
+    B1::Loader: MemLoader<Value = T>,
+    B2: IntoMemLoader<T>,
+    B2::Loader: MemLoader<Value = T>,
+{
+    let mut x = x.into_mem_loader();
+    let mut y = y.into_mem_loader();
+    assert_eq!(
+        x.projected_len(),
+        y.projected_len(),
+        "Buffers `x` and `y` do not match in size"
+    );
+
+    let len = x.projected_len();
+    let offset_from = len % R::elements_per_dense();
+
+    let mut self_total = R::zeroed_dense();
+    let mut cross_total = R::zeroed_dense();
+
+    // Operate over dense lanes first.
+    let mut i = 0;
+    while i < (len - offset_from) {
+        let l1 = x.load_dense::<R>();
+        let l2 = y.load_dense::<R>();
+        self_total = R::fmadd_dense(l1, l1, self_total);
+        cross_total = R::fmadd_dense(l1, l2, cross_total);
+
+        i += R::elements_per_dense();
+    }
+
+    let mut self_total = R::sum_to_register(self_total);
+    let mut cross_total = R::sum_to_register(cross_total);
+
+    // Operate over single registers next.
+    let offset_from = offset_from % R::elements_per_lane();
+    while i < (len - offset_from) {
+        let l1 = x.load::<R>();
+        let l2 = y.load::<R>();
+        self_total = R::fmadd(l1, l1, self_total);
+        cross_total = R::fmadd(l1, l2, cross_total);
+
+        i += R::elements_per_lane();
+    }
+
+    // Handle the remainder.
+    let mut self_total = R::sum_to_value(self_total);
+    let mut cross_total = R::sum_to_value(cross_total);
+
+    while i < len {
+        let x = x.read();
+        let y = y.read();
+        self_total = M::add(self_total, M::mul(x, x));
+        cross_total = M::add(cross_total, M::mul(x, y));
+
+        i += 1;
+    }
+
+    (self_total, cross_total)
+}
+
+#[inline(always)]
+/// A generic implementation of the dot product between `a` and the difference of
+/// `b` and `c`, i.e. `a · (b - c)`, without materializing `b - c`.
+///
+/// # Panics
+///
+/// If `a`, `b` and `c` are not the same length.
+///
+/// # Safety
+///
+/// The safety requirements of `M` definition the basic math operations and the
+/// requirements of `R` SIMD register must also be followed.
+pub unsafe fn generic_dot_diff<T, R, M, B1, B2, B3>(a: B1, b: B2, c: B3) -> T
+where
+    T: Copy,
+    R: SimdRegister<T>,
+    M: Math<T>,
+    B1: IntoMemLoader<T>,
+    B1::Loader: MemLoader<Value = T>,
+    B2: IntoMemLoader<T>,
+    B2::Loader: MemLoader<Value = T>,
+    B3: IntoMemLoader<T>,
+    B3::Loader: MemLoader<Value = T>,
+{
+    let mut a = a.into_mem_loader();
+    let mut b = b.into_mem_loader();
+    let mut c = c.into_mem_loader();
+    assert_eq!(
+        a.projected_len(),
+        b.projected_len(),
+        "Buffers `a` and `b` do not match in size"
+    );
+    assert_eq!(
+        a.projected_len(),
+        c.projected_len(),
+        "Buffers `a` and `c` do not match in size"
+    );
+
+    let len = a.projected_len();
+    let offset_from = len % R::elements_per_dense();
+
+    let mut total = R::zeroed_dense();
+
+    // Operate over dense lanes first.
+    let mut i = 0;
+    while i < (len - offset_from) {
+        let l1 = a.load_dense::<R>();
+        let l2 = b.load_dense::<R>();
+        let l3 = c.load_dense::<R>();
+        let diff = R::sub_dense(l2, l3);
+        total = R::fmadd_dense(l1, diff, total);
+
+        i += R::elements_per_dense();
+    }
+
+    let mut total = R::sum_to_register(total);
+
+    // Operate over single registers next.
+    let offset_from = offset_from % R::elements_per_lane();
+    while i < (len - offset_from) {
+        let l1 = a.load::<R>();
+        let l2 = b.load::<R>();
+        let l3 = c.load::<R>();
+        let diff = R::sub(l2, l3);
+        total = R::fmadd(l1, diff, total);
+
+        i += R::elements_per_lane();
+    }
+
+    // Handle the remainder.
+    let mut total = R::sum_to_value(total);
+
+    while i < len {
+        let x = a.read();
+        let diff = M::sub(b.read(), c.read());
+        total = M::add(total, M::mul(x, diff));
+
+        i += 1;
+    }
+
+    total
+}
+
+#[inline(always)]
+/// A generic dot product implementation between `query` and column `col` of the
+/// row-major `matrix` which has `nrows` rows of `dims_per_row` elements.
+///
+/// The column elements are strided by `dims_per_row`, they are gathered into a register
+/// sized scratch buffer before being loaded, avoiding the need to transpose the matrix.
+///
+/// # Panics
+///
+/// If `query` is not `nrows` in length, `col` is not less than `dims_per_row` or
+/// `matrix` is not at least `nrows * dims_per_row` in length.
+///
+/// # Safety
+///
+/// The safety requirements of `M` definition the basic math operations and the
+/// requirements of `R` SIMD register must also be followed.
+pub unsafe fn generic_col_dot<T, R, M>(
+    matrix: &[T],
+    col: usize,
+    dims_per_row: usize,
+    nrows: usize,
+    query: &[T],
+) -> T
+where
+    T: Copy,
+    R: SimdRegister<T>,
+    M: Math<T>,
+{
+    assert_eq!(
+        query.len(),
+        nrows,
+        "Buffer `query` does not match the number of rows in `matrix`"
+    );
+    assert!(
+        col < dims_per_row,
+        "Column `col` is out of bounds for the row size `dims_per_row`"
+    );
+    assert!(
+        matrix.len() >= nrows * dims_per_row,
+        "Buffer `matrix` is smaller than `nrows * dims_per_row`"
+    );
+
+    let column_ptr = matrix.as_ptr().add(col);
+    let query_ptr = query.as_ptr();
+    let offset_from = nrows % R::elements_per_lane();
+
+    let mut gathered = [M::zero(); SCRATCH_SPACE_SIZE];
+    let mut total = R::zeroed();
+
+    let mut i = 0;
+    while i < (nrows - offset_from) {
+        for (j, slot) in gathered[..R::elements_per_lane()].iter_mut().enumerate() {
+            *slot = column_ptr.add((i + j) * dims_per_row).read();
+        }
+
+        let l1 = R::load(gathered.as_ptr());
+        let l2 = R::load(query_ptr.add(i));
+        total = R::fmadd(l1, l2, total);
+
+        i += R::elements_per_lane();
+    }
+
+    // Handle the remainder.
+    let mut total = R::sum_to_value(total);
+
+    while i < nrows {
+        let x = column_ptr.add(i * dims_per_row).read();
+        let y = query_ptr.add(i).read();
+        total = M::add(total, M::mul(x, y));
+
+        i += 1;
+    }
+
+    total
+}
+
+#[inline(always)]
+/// A generic dot product implementation over `n` elements of `x` and `y` with the
+/// increments `incx` and `incy`, following the BLAS `dot` semantics.
+///
+/// A negative increment walks the operand backwards, element `i` is read from
+/// `x[(n - 1 - i) * |incx|]` rather than `x[i * incx]`. An increment of `0` reads the
+/// first element every time.
+///
+/// When both increments are `1`, or both are `-1` which pairs up the same elements,
+/// this is the contiguous [generic_dot]. Otherwise the strided elements are gathered
+/// into register sized scratch buffers before being loaded, like [generic_col_dot].
+///
+/// # Panics
+///
+/// If `x` or `y` are too short to hold `n` elements at their increment, i.e.
+/// `x.len() < 1 + (n - 1) * |incx|`.
+///
+/// # Safety
+///
+/// The safety requirements of `M` definition the basic math operations and the
+/// requirements of `R` SIMD register must also be followed.
+pub unsafe fn generic_dot_strided<T, R, M>(
+    n: usize,
+    x: &[T],
+    incx: isize,
+    y: &[T],
+    incy: isize,
+) -> T
+where
+    T: Copy,
+    R: SimdRegister<T>,
+    M: Math<T>,
+{
+    if n == 0 {
+        return M::zero();
+    }
+
+    let (stride_x, stride_y) = (incx.unsigned_abs(), incy.unsigned_abs());
+    assert!(
+        x.len() > (n - 1) * stride_x,
+        "Buffer `x` is too short for `n` elements at increment `incx`"
+    );
+    assert!(
+        y.len() > (n - 1) * stride_y,
+        "Buffer `y` is too short for `n` elements at increment `incy`"
+    );
+
+    if incx == incy && stride_x == 1 {
+        return generic_dot::<T, R, M, _, _>(&x[..n], &y[..n]);
+    }
+
+    // The offset of element `i`, negative increments start from the far end.
+    let offset = |i: usize, inc: isize, stride: usize| {
+        if inc < 0 {
+            (n - 1 - i) * stride
+        } else {
+            i * stride
+        }
+    };
+
+    let x_ptr = x.as_ptr();
+    let y_ptr = y.as_ptr();
+    let offset_from = n % R::elements_per_lane();
+
+    let mut gathered_x = [M::zero(); SCRATCH_SPACE_SIZE];
+    let mut gathered_y = [M::zero(); SCRATCH_SPACE_SIZE];
+    let mut total = R::zeroed();
+
+    let mut i = 0;
+    while i < (n - offset_from) {
+        for j in 0..R::elements_per_lane() {
+            gathered_x[j] = x_ptr.add(offset(i + j, incx, stride_x)).read();
+            gathered_y[j] = y_ptr.add(offset(i + j, incy, stride_y)).read();
+        }
+
+        let l1 = R::load(gathered_x.as_ptr());
+        let l2 = R::load(gathered_y.as_ptr());
+        total = R::fmadd(l1, l2, total);
+
+        i += R::elements_per_lane();
+    }
+
+    // Handle the remainder.
+    let mut total = R::sum_to_value(total);
+
+    while i < n {
+        let a = x_ptr.add(offset(i, incx, stride_x)).read();
+        let b = y_ptr.add(offset(i, incy, stride_y)).read();
+        total = M::add(total, M::mul(a, b));
+
+        i += 1;
+    }
+
+    total
+}
+
+#[inline(always)]
+/// A generic dot product implementation over the even indexed elements of `a` and `b`,
+/// computing `sum(a[2i] * b[2i])` for interleaved data.
+///
+/// This is [generic_dot_strided] with both increments set to `2`.
+///
+/// # Panics
+///
+/// If `a` and `b` are not the same length or their length is not even.
+///
+/// # Safety
+///
+/// The safety requirements of `M` definition the basic math operations and the
+/// requirements of `R` SIMD register must also be followed.
+pub unsafe fn generic_dot_stride2<T, R, M>(a: &[T], b: &[T]) -> T
+where
+    T: Copy,
+    R: SimdRegister<T>,
+    M: Math<T>,
+{
+    assert_eq!(a.len(), b.len(), "Buffers `a` and `b` do not match in size");
+    assert_eq!(a.len() % 2, 0, "Buffers `a` and `b` must be even in length");
+
+    generic_dot_strided::<T, R, M>(a.len() / 2, a, 2, b, 2)
+}
+
+#[inline(always)]
+/// A generic Frobenius inner product implementation over two matrices of the same
+/// shape, computing `sum_ij(a_ij * b_ij)` which is also `trace(aᵀ b)`.
+///
+/// The matrices are viewed as flat vectors so this is [generic_dot] over their elements,
+/// the layout does not matter as long as both share it.
+///
+/// # Panics
+///
+/// If `a` and `b` are not the same length.
+///
+/// # Safety
+///
+/// The safety requirements of `M` definition the basic math operations and the
+/// requirements of `R` SIMD register must also be followed.
+pub unsafe fn generic_frobenius_dot<T, R, M>(a: &[T], b: &[T]) -> T
+where
+    T: Copy,
+    R: SimdRegister<T>,
+    M: Math<T>,
+{
+    assert_eq!(
+        a.len(),
+        b.len(),
+        "Matrices `a` and `b` do not match in size"
+    );
+
+    generic_dot::<T, R, M, _, _>(a, b)
+}
+
+#[inline(always)]
+/// A generic dense layer forward pass, computing `output[o] = bias[o] + dot(row_o, input)`
+/// for every row `row_o` of the row-major `weights` matrix of `out_dim` rows of `in_dim`
+/// elements.
+///
+/// The rows are reduced with [generic_matvec] before the bias is added.
+///
+/// # Panics
+///
+/// If `weights` is not `out_dim * in_dim` in length, `input` is not `in_dim` in length
+/// or `bias` and `output` are not `out_dim` in length.
+///
+/// # Safety
+///
+/// The safety requirements of `M` definition the basic math operations and the
+/// requirements of `R` SIMD register must also be followed.
+pub unsafe fn generic_dense_forward<T, R, M>(
+    weights: &[T],
+    bias: &[T],
+    input: &[T],
+    in_dim: usize,
+    out_dim: usize,
+    output: &mut [T],
+) where
+    T: Copy,
+    R: SimdRegister<T>,
+    M: Math<T>,
+{
+    assert_eq!(
+        weights.len(),
+        out_dim * in_dim,
+        "Buffer `weights` is not `out_dim * in_dim` in length"
+    );
+    assert_eq!(
+        input.len(),
+        in_dim,
+        "Buffer `input` is not `in_dim` in length"
+    );
+    assert_eq!(
+        bias.len(),
+        out_dim,
+        "Buffer `bias` is not `out_dim` in length"
+    );
+    assert_eq!(
+        output.len(),
+        out_dim,
+        "Buffer `output` is not `out_dim` in length"
+    );
+
+    generic_matvec::<T, R, M>(weights, input, output);
+    for (value, bias) in output.iter_mut().zip(bias) {
+        *value = M::add(*bias, *value);
+    }
+}
+
+#[inline(always)]
+/// A generic matrix-vector product, computing `out[r] = dot(row_r, vector)` for every
+/// row `row_r` of the row-major `matrix` which has rows of `vector.len()` elements.
+///
+/// Each row is reduced with [generic_dot]. This is [generic_dense_forward] without
+/// the bias.
+///
+/// # Panics
+///
+/// If `matrix` is not `out.len() * vector.len()` in length.
+///
+/// # Safety
+///
+/// The safety requirements of `M` definition the basic math operations and the
+/// requirements of `R` SIMD register must also be followed.
+pub unsafe fn generic_matvec<T, R, M>(matrix: &[T], vector: &[T], out: &mut [T])
+where
+    T: Copy,
+    R: SimdRegister<T>,
+    M: Math<T>,
+{
+    let dims = vector.len();
+    assert_eq!(
+        matrix.len(),
+        out.len() * dims,
+        "Buffer `matrix` is not `out.len() * vector.len()` in length"
+    );
+
+    for (r, value) in out.iter_mut().enumerate() {
+        let row = matrix.get_unchecked(r * dims..(r + 1) * dims);
+        *value = generic_dot::<T, R, M, _, _>(row, vector);
+    }
+}
+
+/// The number of `docs` rows [generic_pairwise_dot] accumulates against each query row
+/// at once.
+pub const PAIRWISE_DOT_ROWS: usize = 8;
+
+#[inline(always)]
+/// A generic pairwise dot product implementation between every row of the row-major
+/// `queries` and `docs` matrices of `dims` elements per row, computing
+/// `out[i * num_docs + j] = dot(queries_i, docs_j)`.
+///
+/// When both matrices are normalized to unit length this is the cosine similarity of
+/// every pair, without recomputing the norms of each row.
+///
+/// ### Micro-kernel
+///
+/// The rows of `docs` are processed in groups of [PAIRWISE_DOT_ROWS], each register of
+/// the query row is loaded once and multiplied against the matching register of all `8`
+/// doc rows, accumulating into `8` independent registers. This reuses every query load
+/// `8` times and keeps enough independent accumulators in flight to hide the latency of
+/// the fused multiply add. The remaining doc rows are reduced with [generic_dot].
+///
+/// # Panics
+///
+/// If `dims` is `0`, `queries` or `docs` is not a multiple of `dims` in length or `out`
+/// is not `num_queries * num_docs` in length.
+///
+/// # Safety
+///
+/// The safety requirements of `M` definition the basic math operations and the
+/// requirements of `R` SIMD register must also be followed.
+pub unsafe fn generic_pairwise_dot<T, R, M>(
+    queries: &[T],
+    docs: &[T],
+    dims: usize,
+    out: &mut [T],
+) where
+    T: Copy,
+    R: SimdRegister<T>,
+    M: Math<T>,
+{
+    assert_ne!(dims, 0, "Row size `dims` must be greater than zero");
+    assert_eq!(
+        queries.len() % dims,
+        0,
+        "Buffer `queries` is not a multiple of `dims` in length"
+    );
+    assert_eq!(
+        docs.len() % dims,
+        0,
+        "Buffer `docs` is not a multiple of `dims` in length"
+    );
+
+    let num_queries = queries.len() / dims;
+    let num_docs = docs.len() / dims;
+    assert_eq!(
+        out.len(),
+        num_queries * num_docs,
+        "Buffer `out` is not `num_queries * num_docs` in length"
+    );
+
+    let offset_from = dims % R::elements_per_lane();
+    let docs_ptr = docs.as_ptr();
+    let remaining_from = num_docs - (num_docs % PAIRWISE_DOT_ROWS);
+
+    for q in 0..num_queries {
+        let query = queries.get_unchecked(q * dims..(q + 1) * dims);
+        let query_ptr = query.as_ptr();
+        let out_row = out.get_unchecked_mut(q * num_docs..(q + 1) * num_docs);
+
+        let mut j = 0;
+        while j < remaining_from {
+            let rows_ptr = docs_ptr.add(j * dims);
+
+            let mut totals = [R::zeroed(); PAIRWISE_DOT_ROWS];
+            let mut i = 0;
+            while i < (dims - offset_from) {
+                let l1 = R::load(query_ptr.add(i));
+                for (k, total) in totals.iter_mut().enumerate() {
+                    let l2 = R::load(rows_ptr.add(k * dims + i));
+                    *total = R::fmadd(l1, l2, *total);
+                }
+
+                i += R::elements_per_lane();
+            }
+
+            for (k, total) in totals.iter().enumerate() {
+                let row_ptr = rows_ptr.add(k * dims);
+
+                // Handle the remainder.
+                let mut total = R::sum_to_value(*total);
+                for d in i..dims {
+                    let x = query_ptr.add(d).read();
+                    let y = row_ptr.add(d).read();
+                    total = M::add(total, M::mul(x, y));
+                }
+
+                *out_row.get_unchecked_mut(j + k) = total;
+            }
+
+            j += PAIRWISE_DOT_ROWS;
+        }
+
+        while j < num_docs {
+            let row = docs.get_unchecked(j * dims..(j + 1) * dims);
+            *out_row.get_unchecked_mut(j) = generic_dot::<T, R, M, _, _>(query, row);
+
+            j += 1;
+        }
+    }
+}
+
+#[inline(always)]
+/// A generic dot product implementation over the subset of dimensions of `a` and `b`
+/// selected by the bitmask `active`.
+///
+/// Dimension `i` participates when bit `i % 64` of `active[i / 64]` is set. The mask bits
+/// of each register sized block are expanded to a lane mask, fully active blocks are loaded
+/// directly, fully inactive blocks are skipped and mixed blocks have their inactive elements
+/// zeroed in a scratch buffer before accumulating.
+///
+/// # Panics
+///
+/// If `a` and `b` are not the same length or `active` does not cover every dimension.
+///
+/// # Safety
+///
+/// The safety requirements of `M` definition the basic math operations and the
+/// requirements of `R` SIMD register must also be followed.
+pub unsafe fn generic_masked_dot<T, R, M>(a: &[T], b: &[T], active: &[u64]) -> T
+where
+    T: Copy,
+    R: SimdRegister<T>,
+    M: Math<T>,
+{
+    assert_eq!(a.len(), b.len(), "Input vector sizes do not match");
+    assert!(
+        active.len() * 64 >= a.len(),
+        "Bitmask `active` does not cover all dimensions"
+    );
+
+    let len = a.len();
+    let lanes = R::elements_per_lane();
+    let offset_from = len % lanes;
+
+    // Lanes are always a power of two no larger than 64, so each block's mask bits
+    // are contained within a single word.
+    let block_mask = if lanes >= 64 {
+        u64::MAX
+    } else {
+        (1u64 << lanes) - 1
+    };
+
+    let a_ptr = a.as_ptr();
+    let b_ptr = b.as_ptr();
+
+    let mut l1_buffer = [M::zero(); SCRATCH_SPACE_SIZE];
+    let mut l2_buffer = [M::zero(); SCRATCH_SPACE_SIZE];
+    let mut total = R::zeroed();
+
+    let mut i = 0;
+    while i < (len - offset_from) {
+        let bits = (active[i / 64] >> (i % 64)) & block_mask;
+
+        if bits == block_mask {
+            let l1 = R::load(a_ptr.add(i));
+            let l2 = R::load(b_ptr.add(i));
+            total = R::fmadd(l1, l2, total);
+        } else if bits != 0 {
+            for j in 0..lanes {
+                let is_active = (bits >> j) & 1 == 1;
+                l1_buffer[j] = if is_active {
+                    *a.get_unchecked(i + j)
+                } else {
+                    M::zero()
+                };
+                l2_buffer[j] = if is_active {
+                    *b.get_unchecked(i + j)
+                } else {
+                    M::zero()
+                };
+            }
+
+            let l1 = R::load(l1_buffer.as_ptr());
+            let l2 = R::load(l2_buffer.as_ptr());
+            total = R::fmadd(l1, l2, total);
+        }
+
+        i += lanes;
+    }
+
+    // Handle the remainder.
+    let mut total = R::sum_to_value(total);
+
+    while i < len {
+        if (active[i / 64] >> (i % 64)) & 1 == 1 {
+            total = M::add(total, M::mul(*a.get_unchecked(i), *b.get_unchecked(i)));
+        }
+
+        i += 1;
+    }
+
+    total
+}
+
+#[inline(always)]
+/// A generic dot product implementation over two vectors of a given set of dimensions
+/// returning the result as a double-double `(hi, lo)` pair.
+///
+/// Every product and addition is performed with error-free transformations
+/// (`TwoProduct` via a fused multiply add, and `TwoSum`) so the unevaluated sum
+/// `hi + lo` is as accurate as if the dot product was computed in twice the working
+/// precision. Callers needing a single value can simply take `hi`.
+///
+/// # Panics
+///
+/// If `a` and `b` are not the same length; no projection is available on this routine.
+///
+/// # Safety
+///
+/// The `R::fmadd` operation _must_ be a true fused multiply add and `M` must not
+/// re-associate floating point operations (i.e. no fast-math) for the result to be
+/// error-free, the safety requirements of `M` definition the basic math operations and
+/// the requirements of `R` SIMD register must also be followed.
+pub unsafe fn generic_dot_dd<T, R, M, B1, B2>(a: B1, b: B2) -> (T, T)
+where
+    T: Copy,
+    R: SimdRegister<T>,
+    M: Math<T>,
+    B1: IntoMemLoader<T>,
+    B1::Loader: MemLoader<Value = T>,
+    B2: IntoMemLoader<T>,
+    B2::Loader: MemLoader<Value = T>,
+{
+    let mut a = a.into_mem_loader();
+    let mut b = b.into_mem_loader();
+    assert_eq!(
+        a.projected_len(),
+        b.projected_len(),
+        "Buffers `a` and `b` do not match in size"
+    );
+
+    let len = a.projected_len();
+    let offset_from = len % R::elements_per_lane();
+
+    let mut hi = R::zeroed();
+    let mut lo = R::zeroed();
+
+    // Operate over single registers, the error terms double the number of live
+    // registers so dense lanes would spill on most archs.
+    let mut i = 0;
+    while i < (len - offset_from) {
+        let l1 = a.load::<R>();
+        let l2 = b.load::<R>();
+        (hi, lo) = dd_fmadd_register::<T, R>(l1, l2, hi, lo);
+
+        i += R::elements_per_lane();
+    }
+
+    // Handle the remainder by padding it out to a full register with zeroes, this
+    // keeps the fused product available for the tail without needing a scalar FMA.
+    if i < len {
+        let mut l1_buffer = [M::zero(); SCRATCH_SPACE_SIZE];
+        let mut l2_buffer = [M::zero(); SCRATCH_SPACE_SIZE];
+
+        let mut offset = 0;
+        while i < len {
+            l1_buffer[offset] = a.read();
+            l2_buffer[offset] = b.read();
+
+            offset += 1;
+            i += 1;
+        }
+
+        let l1 = R::load(l1_buffer.as_ptr());
+        let l2 = R::load(l2_buffer.as_ptr());
+        (hi, lo) = dd_fmadd_register::<T, R>(l1, l2, hi, lo);
+    }
+
+    // Roll up the lanes of the register pair, still tracking the rounding errors.
+    let mut hi_buffer = [M::zero(); SCRATCH_SPACE_SIZE];
+    let mut lo_buffer = [M::zero(); SCRATCH_SPACE_SIZE];
+    R::write(hi_buffer.as_mut_ptr(), hi);
+    R::write(lo_buffer.as_mut_ptr(), lo);
+
+    let mut total_hi = M::zero();
+    let mut total_lo = M::zero();
+    for lane in 0..R::elements_per_lane() {
+        let (sum, err) = two_sum::<T, M>(total_hi, hi_buffer[lane]);
+        total_hi = sum;
+        total_lo = M::add(total_lo, M::add(err, lo_buffer[lane]));
+    }
+
+    // Renormalize so `hi` holds the correctly rounded leading component.
+    let hi = M::add(total_hi, total_lo);
+    let lo = M::sub(total_lo, M::sub(hi, total_hi));
+    (hi, lo)
+}
+
+#[inline(always)]
+/// Accumulates `l1 * l2` into the double-double register pair `(hi, lo)`.
+unsafe fn dd_fmadd_register<T, R>(
+    l1: R::Register,
+    l2: R::Register,
+    hi: R::Register,
+    lo: R::Register,
+) -> (R::Register, R::Register)
+where
+    T: Copy,
+    R: SimdRegister<T>,
+{
+    // TwoProduct: `product + product_err == l1 * l2` exactly.
+    let product = R::mul(l1, l2);
+    let product_err = R::fmadd(l1, l2, R::sub(R::zeroed(), product));
+
+    // TwoSum: `sum + sum_err == hi + product` exactly.
+    let sum = R::add(hi, product);
+    let virtual_product = R::sub(sum, hi);
+    let sum_err = R::add(
+        R::sub(hi, R::sub(sum, virtual_product)),
+        R::sub(product, virtual_product),
+    );
+
+    (sum, R::add(lo, R::add(sum_err, product_err)))
+}
+
+#[inline(always)]
+/// Error-free transformation of `a + b` into `(sum, err)` where `sum + err == a + b`.
+fn two_sum<T: Copy, M: Math<T>>(a: T, b: T) -> (T, T) {
+    let sum = M::add(a, b);
+    let virtual_b = M::sub(sum, a);
+    let err = M::add(M::sub(a, M::sub(sum, virtual_b)), M::sub(b, virtual_b));
+    (sum, err)
+}
+
+#[cfg(test)]
+pub(crate) unsafe fn test_dot<T, R>(l1: Vec<T>, l2: Vec<T>)
+where
+    T: Copy + PartialEq + core::fmt::Debug,
+    R: SimdRegister<T>,
+    crate::math::AutoMath: Math<T>,
+{
+    use crate::math::AutoMath;
+
+    let value = generic_dot::<T, R, AutoMath, _, _>(&l1, &l2);
+    let expected_value = crate::test_utils::simple_dot(&l1, &l2);
+    assert!(
+        AutoMath::is_close(value, expected_value),
+        "value missmatch {value:?} vs {expected_value:?}"
+    );
+}
+
+#[cfg(test)]
+pub(crate) unsafe fn test_dot_dense_aligned<T, R>(l1: Vec<T>, l2: Vec<T>)
+where
+    T: Copy + PartialEq + core::fmt::Debug,
+    R: SimdRegister<T>,
+    crate::math::AutoMath: Math<T>,
+{
+    use crate::math::AutoMath;
+
+    let dense = R::elements_per_dense();
+    for len in [0, dense, (l1.len() / dense) * dense] {
+        let (a, b) = (&l1[..len], &l2[..len]);
+
+        let value = generic_dot_dense_aligned::<T, R, AutoMath>(a, b);
+        let expected_value = generic_dot::<T, R, AutoMath, _, _>(a, b);
+        assert!(
+            AutoMath::is_close(value, expected_value),
+            "value missmatch on len {len} {value:?} vs {expected_value:?}"
+        );
+    }
+}
+
+#[cfg(test)]
+pub(crate) unsafe fn test_dot_single_register<T, R>(l1: Vec<T>, l2: Vec<T>)
+where
+    T: Copy + PartialEq + core::fmt::Debug,
+    R: SimdRegister<T>,
+    crate::math::AutoMath: Math<T>,
+{
+    use crate::math::AutoMath;
+
+    for dims in 0..=R::elements_per_lane() {
+        let a = &l1[..dims];
+        let b = &l2[..dims];
+        let expected_value = crate::test_utils::simple_dot(a, b);
+
+        let value = generic_dot_single_register::<T, R, AutoMath, _, _>(a, b);
+        assert!(
+            AutoMath::is_close(value, expected_value),
+            "value missmatch for {dims} dims {value:?} vs {expected_value:?}"
+        );
+
+        let value = generic_dot::<T, R, AutoMath, _, _>(a, b);
+        assert!(
+            AutoMath::is_close(value, expected_value),
+            "value missmatch for {dims} dims {value:?} vs {expected_value:?}"
+        );
+    }
+}
+
+#[cfg(test)]
+pub(crate) unsafe fn test_scaled_dot<T, R>(l1: Vec<T>, l2: Vec<T>, scale: T)
+where
+    T: Copy + PartialEq + core::fmt::Debug,
+    R: SimdRegister<T>,
+    crate::math::AutoMath: Math<T>,
+{
+    use crate::math::AutoMath;
+
+    let value = generic_scaled_dot::<T, R, AutoMath, _, _>(&l1, &l2, scale);
+    let expected_value = AutoMath::mul(scale, crate::test_utils::simple_dot(&l1, &l2));
+    assert!(
+        AutoMath::is_close(value, expected_value),
+        "value missmatch {value:?} vs {expected_value:?}"
+    );
+}
+
+#[cfg(test)]
+pub(crate) unsafe fn test_dot_wrapping<T, R>(l1: Vec<T>, l2: Vec<T>)
+where
+    T: Copy + PartialEq + core::fmt::Debug,
+    R: SimdRegister<T>,
+    crate::math::AutoMath: Math<T>,
+{
+    use crate::math::AutoMath;
+
+    let buf = &l1;
+    let cap = buf.len();
+    // Reads ending before, exactly on and wrapping past the end of the buffer.
+    let reads = [
+        (0, cap / 2),
+        (cap / 3, cap - cap / 3),
+        (cap - 5, 67),
+        (1, cap),
+        (7, 0),
+    ];
+    for (start, len) in reads {
+        let kernel = &l2[..len];
+        let value = generic_dot_wrapping::<T, R, AutoMath>(buf, start, len, kernel);
+
+        let unwrapped = (0..len).map(|i| buf[(start + i) % cap]).collect::<Vec<_>>();
+        let expected_value = crate::test_utils::simple_dot(&unwrapped, kernel);
+        assert!(
+            AutoMath::is_close(value, expected_value),
+            "value missmatch on read of {len} from {start} {value:?} vs {expected_value:?}"
+        );
+    }
+}
+
+#[cfg(test)]
+pub(crate) unsafe fn test_dot_progress<T, R>(one: T)
+where
+    T: Copy + PartialEq + core::fmt::Debug,
+    R: SimdRegister<T>,
+    crate::math::AutoMath: Math<T>,
+{
+    use crate::math::AutoMath;
+
+    // Ones keep every partial result exact so they can be compared directly.
+    let len = R::elements_per_dense() * 7 + R::elements_per_lane() + 3;
+    let l1 = vec![one; len];
+    let l2 = vec![one; len];
+
+    let mut reported = Vec::new();
+    let value = generic_dot_progress::<T, R, AutoMath, _, _, _>(
+        &l1,
+        &l2,
+        2,
+        |blocks_done, partial| reported.push((blocks_done, partial)),
+    );
+
+    let blocks_done = reported
+        .iter()
+        .map(|(blocks, _)| *blocks)
+        .collect::<Vec<_>>();
+    assert_eq!(
+        blocks_done,
+        vec![2, 4, 6],
+        "progress should be reported every 2 blocks"
+    );
+    for (blocks, partial) in reported {
+        let processed = blocks * R::elements_per_dense();
+        let expected = crate::test_utils::simple_dot(&l1[..processed], &l2[..processed]);
+        assert_eq!(
+            partial, expected,
+            "partial value missmatch after {blocks} blocks"
+        );
+    }
+
+    let expected_value = generic_dot::<T, R, AutoMath, _, _>(&l1, &l2);
+    assert!(
+        AutoMath::is_close(value, expected_value),
+        "value missmatch {value:?} vs {expected_value:?}"
+    );
+}
+
+#[cfg(test)]
+pub(crate) unsafe fn test_dot_streaming_both<T, R>(l1: Vec<T>, l2: Vec<T>)
+where
+    T: Copy + PartialEq + core::fmt::Debug,
+    R: SimdRegister<T>,
+    crate::math::AutoMath: Math<T>,
+{
+    use crate::math::AutoMath;
+
+    // Splits `data` into chunks cycling through `sizes`, zero sized chunks are kept.
+    fn split<'a, T>(data: &'a [T], sizes: &[usize]) -> Vec<&'a [T]> {
+        let mut chunks = Vec::new();
+        let mut rest = data;
+        for size in sizes.iter().cycle() {
+            if rest.is_empty() {
+                break;
+            }
+            let (chunk, remaining) = rest.split_at((*size).min(rest.len()));
+            chunks.push(chunk);
+            rest = remaining;
+        }
+        chunks
+    }
+
+    let expected_value = generic_dot::<T, R, AutoMath, _, _>(&l1, &l2);
+    // The offset dense sized chunks keep whole dense lanes of elements buffered, which
+    // is more than the scratch space holds for narrow types.
+    let chunkings: [(&[usize], &[usize]); 6] = [
+        (&[l1.len()], &[l1.len()]),
+        (&[7], &[13]),
+        (&[1], &[l1.len()]),
+        (&[1, 2, 3, 5, 8, 13, 21, 34], &[64, 0, 3]),
+        (&[R::elements_per_dense() + 1], &[R::elements_per_lane(), 0]),
+        (
+            &[R::elements_per_dense() - 1],
+            &[R::elements_per_dense() + 1],
+        ),
+    ];
+    for (a_sizes, b_sizes) in chunkings {
+        let a_chunks = split(&l1, a_sizes);
+        let b_chunks = split(&l2, b_sizes);
+
+        let value = generic_dot_streaming_both::<T, R, AutoMath, _, _>(
+            a_chunks.into_iter(),
+            b_chunks.into_iter(),
+        );
+        assert!(
+            AutoMath::is_close(value, expected_value),
+            "value missmatch on chunks {a_sizes:?} {b_sizes:?} {value:?} vs {expected_value:?}"
+        );
+    }
+
+    let value = generic_dot_streaming_both::<T, R, AutoMath, _, _>(
+        core::iter::empty(),
+        [&l2[..0]].into_iter(),
+    );
+    assert_eq!(
+        value,
+        AutoMath::zero(),
+        "empty streams should have a dot of zero"
+    );
+}
+
+#[cfg(test)]
+pub(crate) unsafe fn test_dot_skip_zero<T, R>(l1: Vec<T>, l2: Vec<T>)
+where
+    T: Copy + PartialEq + core::fmt::Debug,
+    R: SimdRegister<T>,
+    crate::math::AutoMath: Math<T>,
+{
+    use crate::math::AutoMath;
+
+    let zeroes = vec![AutoMath::zero(); l1.len()];
+    let value = generic_dot_skip_zero::<T, R, AutoMath, _, _>(&zeroes, &l2);
+    assert_eq!(value, AutoMath::zero(), "all-zero `a` should produce zero");
+    let value = generic_dot_skip_zero::<T, R, AutoMath, _, _>(&l1, &zeroes);
+    assert_eq!(value, AutoMath::zero(), "all-zero `b` should produce zero");
+
+    let value = generic_dot_skip_zero::<T, R, AutoMath, _, _>(&l1, &l2);
+    let expected_value = generic_dot::<T, R, AutoMath, _, _>(&l1, &l2);
+    assert!(
+        AutoMath::is_close(value, expected_value),
+        "value missmatch {value:?} vs {expected_value:?}"
+    );
+
+    // A single non-zero element in the remainder must still be picked up.
+    let mut sparse = zeroes.clone();
+    let last = sparse.len() - 1;
+    sparse[last] = AutoMath::one();
+    let value = generic_dot_skip_zero::<T, R, AutoMath, _, _>(&sparse, &l2);
+    assert_eq!(value, l2[last], "value missmatch");
+
+    // Operands shorter than a register have no first block to test.
+    let value = generic_dot_skip_zero::<T, R, AutoMath, _, _>(&zeroes[..1], &l2[..1]);
+    assert_eq!(value, AutoMath::zero(), "short all-zero `a` should be zero");
+    let value = generic_dot_skip_zero::<T, R, AutoMath, _, _>(&l1[..1], &l2[..1]);
+    let expected_value = AutoMath::mul(l1[0], l2[0]);
+    assert_eq!(value, expected_value, "value missmatch on short dot");
+}
+
+#[cfg(test)]
+pub(crate) unsafe fn test_dot_self_and_cross<T, R>(l1: Vec<T>, l2: Vec<T>)
+where
+    T: Copy + PartialEq + core::fmt::Debug,
+    R: SimdRegister<T>,
+    crate::math::AutoMath: Math<T>,
+{
+    use crate::math::AutoMath;
+
+    let (self_dot, cross_dot) =
+        generic_dot_self_and_cross::<T, R, AutoMath, _, _>(&l1, &l2);
+
+    let expected_self = generic_dot::<T, R, AutoMath, _, _>(&l1, &l1);
+    let expected_cross = generic_dot::<T, R, AutoMath, _, _>(&l1, &l2);
+    assert!(
+        AutoMath::is_close(self_dot, expected_self),
+        "self value missmatch {self_dot:?} vs {expected_self:?}"
+    );
+    assert!(
+        AutoMath::is_close(cross_dot, expected_cross),
+        "cross value missmatch {cross_dot:?} vs {expected_cross:?}"
+    );
+}
+
+#[cfg(test)]
+pub(crate) unsafe fn test_dot_diff<T, R>(l1: Vec<T>, l2: Vec<T>)
+where
+    T: Copy + PartialEq + core::fmt::Debug,
+    R: SimdRegister<T>,
+    crate::math::AutoMath: Math<T>,
+{
+    use crate::math::AutoMath;
+
+    let c = l2.iter().rev().copied().collect::<Vec<_>>();
+
+    for len in [0, 1, 7, 131, l1.len()] {
+        let (a, b, c) = (&l1[..len], &l2[..len], &c[..len]);
+        let value = generic_dot_diff::<T, R, AutoMath, _, _, _>(a, b, c);
+
+        let expected_value = a.iter().zip(b.iter().zip(c)).fold(
+            AutoMath::zero(),
+            |total, (x, (y, z))| {
+                AutoMath::add(total, AutoMath::mul(*x, AutoMath::sub(*y, *z)))
+            },
+        );
+        assert!(
+            AutoMath::is_close(value, expected_value),
+            "value missmatch for {len} dims {value:?} vs {expected_value:?}"
+        );
+    }
+}
+
+#[cfg(test)]
+pub(crate) unsafe fn test_col_dot<T, R>(l1: Vec<T>, l2: Vec<T>)
+where
+    T: Copy + PartialEq + core::fmt::Debug,
+    R: SimdRegister<T>,
+    crate::math::AutoMath: Math<T>,
+{
+    use crate::math::AutoMath;
+
+    for dims_per_row in [1, 7, 64] {
+        let nrows = (l1.len() / dims_per_row).min(131);
+        let query = &l2[..nrows];
+
+        for col in [0, dims_per_row / 2, dims_per_row - 1] {
+            let value =
+                generic_col_dot::<T, R, AutoMath>(&l1, col, dims_per_row, nrows, query);
+
+            let column = l1
+                .iter()
+                .copied()
+                .skip(col)
+                .step_by(dims_per_row)
+                .take(nrows)
+                .collect::<Vec<_>>();
+            let expected_value = crate::test_utils::simple_dot(&column, query);
+            assert!(
+                AutoMath::is_close(value, expected_value),
+                "value missmatch {value:?} vs {expected_value:?}"
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+pub(crate) unsafe fn test_dot_strided<T, R>(l1: Vec<T>, l2: Vec<T>)
+where
+    T: Copy + PartialEq + core::fmt::Debug,
+    R: SimdRegister<T>,
+    crate::math::AutoMath: Math<T>,
+{
+    use crate::math::AutoMath;
+
+    let gather = |values: &[T], n: usize, inc: isize| {
+        if inc == 0 {
+            return vec![values[0]; n];
+        }
+
+        let mut gathered = values
+            .iter()
+            .copied()
+            .step_by(inc.unsigned_abs())
+            .take(n)
+            .collect::<Vec<_>>();
+        if inc < 0 {
+            gathered.reverse();
+        }
+        gathered
+    };
+
+    let cases: [(isize, isize); 6] =
+        [(1, 1), (2, 3), (-1, 2), (3, -2), (-1, -1), (0, 1)];
+    for (incx, incy) in cases {
+        let max_stride = incx.unsigned_abs().max(incy.unsigned_abs()).max(1);
+        for n in [0, 1, 131, l1.len() / max_stride] {
+            if n > l1.len() / max_stride {
+                continue;
+            }
+
+            let value = generic_dot_strided::<T, R, AutoMath>(n, &l1, incx, &l2, incy);
+
+            let expected_value = if n == 0 {
+                AutoMath::zero()
+            } else {
+                // Equal increments pair up the same elements, which the kernel sums
+                // in ascending order like the contiguous dot.
+                let (incx, incy) = if incx == incy {
+                    (incx.abs(), incy.abs())
+                } else {
+                    (incx, incy)
+                };
+                let (x, y) = (gather(&l1, n, incx), gather(&l2, n, incy));
+                crate::test_utils::simple_dot(&x, &y)
+            };
+            assert!(
+                AutoMath::is_close(value, expected_value),
+                "value missmatch on n {n} incx {incx} incy {incy} {value:?}"
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+pub(crate) unsafe fn test_dot_stride2<T, R>(l1: Vec<T>, l2: Vec<T>)
+where
+    T: Copy + PartialEq + core::fmt::Debug,
+    R: SimdRegister<T>,
+    crate::math::AutoMath: Math<T>,
+{
+    use crate::math::AutoMath;
+
+    for len in [0, 2, 14, 130, l1.len() & !1] {
+        let (a, b) = (&l1[..len], &l2[..len]);
+        let value = generic_dot_stride2::<T, R, AutoMath>(a, b);
+
+        let expected_value = a
+            .iter()
+            .zip(b)
+            .step_by(2)
+            .fold(AutoMath::zero(), |acc, (x, y)| {
+                AutoMath::add(acc, AutoMath::mul(*x, *y))
+            });
+        assert!(
+            AutoMath::is_close(value, expected_value),
+            "value missmatch on len {len} {value:?} vs {expected_value:?}"
+        );
+    }
+}
+
+#[cfg(test)]
+pub(crate) unsafe fn test_frobenius_dot<T, R>(l1: Vec<T>, l2: Vec<T>)
+where
+    T: Copy + PartialEq + core::fmt::Debug,
+    R: SimdRegister<T>,
+    crate::math::AutoMath: Math<T>,
+{
+    use crate::math::AutoMath;
+
+    let value = generic_frobenius_dot::<T, R, AutoMath>(&l1, &l2);
+    let expected_value = generic_dot::<T, R, AutoMath, _, _>(&l1, &l2);
+    assert_eq!(value, expected_value, "value missmatch against flat dot");
+
+    // Compare small row-major matrices against `trace(aᵀ b)`.
+    for (rows, cols) in [(1, 1), (3, 5), (8, 8), (13, 7)] {
+        let (a, b) = (&l1[..rows * cols], &l2[..rows * cols]);
+        let value = generic_frobenius_dot::<T, R, AutoMath>(a, b);
+
+        let mut expected_value = AutoMath::zero();
+        for j in 0..cols {
+            // Diagonal element `j` of `aᵀ b` is the dot of column `j` of `a` and `b`.
+            for i in 0..rows {
+                let product = AutoMath::mul(a[(i * cols) + j], b[(i * cols) + j]);
+                expected_value = AutoMath::add(expected_value, product);
+            }
+        }
+        assert!(
+            AutoMath::is_close(value, expected_value),
+            "value missmatch on {rows}x{cols} {value:?} vs {expected_value:?}"
+        );
+    }
+}
+
+#[cfg(test)]
+pub(crate) unsafe fn test_dense_forward<T, R>(l1: Vec<T>, l2: Vec<T>)
+where
+    T: Copy + PartialEq + core::fmt::Debug,
+    R: SimdRegister<T>,
+    crate::math::AutoMath: Math<T>,
+{
+    use crate::math::AutoMath;
+
+    for (in_dim, out_dim) in [(0, 3), (1, 1), (7, 5), (64, 16), (131, 3)] {
+        if in_dim * out_dim > l1.len() {
+            continue;
+        }
+
+        let weights = &l1[..in_dim * out_dim];
+        let input = &l2[..in_dim];
+        let bias = &l2[in_dim..in_dim + out_dim];
+
+        let mut output = vec![AutoMath::zero(); out_dim];
+        generic_dense_forward::<T, R, AutoMath>(
+            weights,
+            bias,
+            input,
+            in_dim,
+            out_dim,
+            &mut output,
+        );
+
+        for o in 0..out_dim {
+            let row = &weights[o * in_dim..(o + 1) * in_dim];
+            let expected_value =
+                AutoMath::add(bias[o], crate::test_utils::simple_dot(row, input));
+            assert!(
+                AutoMath::is_close(output[o], expected_value),
+                "value missmatch on shape {out_dim}x{in_dim} {:?} vs {expected_value:?}",
+                output[o],
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+pub(crate) unsafe fn test_matvec<T, R>(l1: Vec<T>, l2: Vec<T>)
+where
+    T: Copy + PartialEq + core::fmt::Debug,
+    R: SimdRegister<T>,
+    crate::math::AutoMath: Math<T>,
+{
+    use crate::math::AutoMath;
+
+    // Column counts which are and are not a multiple of the register width.
+    for (dims, nrows) in [(0, 3), (1, 1), (7, 5), (64, 16), (131, 3)] {
+        if dims * nrows > l1.len() {
+            continue;
+        }
+
+        let matrix = &l1[..dims * nrows];
+        let vector = &l2[..dims];
+
+        let mut out = vec![AutoMath::one(); nrows];
+        generic_matvec::<T, R, AutoMath>(matrix, vector, &mut out);
+
+        for r in 0..nrows {
+            let row = &matrix[r * dims..(r + 1) * dims];
+            let expected_value = crate::test_utils::simple_dot(row, vector);
+            assert!(
+                AutoMath::is_close(out[r], expected_value),
+                "value missmatch on shape {nrows}x{dims} {:?} vs {expected_value:?}",
+                out[r],
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+pub(crate) unsafe fn test_pairwise_dot<T, R>(l1: Vec<T>, l2: Vec<T>)
+where
+    T: Copy + PartialEq + core::fmt::Debug,
+    R: SimdRegister<T>,
+    crate::math::AutoMath: Math<T>,
+{
+    use crate::math::AutoMath;
+
+    // Doc counts either side of the micro-kernel group size.
+    let shapes = [(1, 1, 1), (7, 3, 9), (16, 2, 8), (33, 3, 30)];
+    for (dims, num_queries, num_docs) in shapes {
+        if dims * num_queries > l1.len() || dims * num_docs > l2.len() {
+            continue;
+        }
+
+        let queries = &l1[..dims * num_queries];
+        let docs = &l2[..dims * num_docs];
+
+        let mut out = vec![AutoMath::zero(); num_queries * num_docs];
+        generic_pairwise_dot::<T, R, AutoMath>(queries, docs, dims, &mut out);
+
+        for (i, query) in queries.chunks(dims).enumerate() {
+            for (j, doc) in docs.chunks(dims).enumerate() {
+                let value = out[i * num_docs + j];
+                let expected_value = crate::test_utils::simple_dot(query, doc);
+                assert!(
+                    AutoMath::is_close(value, expected_value),
+                    "value missmatch on ({i}, {j}) with {dims} dims {value:?} vs {expected_value:?}"
+                );
+            }
+        }
+    }
+
+    // No doc rows leaves nothing to write.
+    let mut out = Vec::new();
+    generic_pairwise_dot::<T, R, AutoMath>(&l1[..8], &[], 4, &mut out);
+}
+
+#[cfg(test)]
+pub(crate) unsafe fn test_masked_dot<T, R>(l1: Vec<T>, l2: Vec<T>)
+where
+    T: Copy + PartialEq + core::fmt::Debug,
+    R: SimdRegister<T>,
+    crate::math::AutoMath: Math<T>,
+{
+    use crate::math::AutoMath;
+
+    for len in [0, 1, 7, 131, 533] {
+        let a = &l1[..len];
+        let b = &l2[..len];
+        let num_words = len.div_ceil(64);
+
+        let masks: [Vec<u64>; 4] = [
+            vec![0; num_words],
+            vec![u64::MAX; num_words],
+            vec![0xAAAA_AAAA_AAAA_AAAA; num_words],
+            (0..num_words as u64)
+                .map(|w| 0x0F0F_00FF_F0F0_FF00u64.rotate_left(w as u32 * 7))
+                .collect(),
+        ];
+
+        for active in masks.iter() {
+            let value = generic_masked_dot::<T, R, AutoMath>(a, b, active);
+
+            let (a_active, b_active): (Vec<T>, Vec<T>) = a
+                .iter()
+                .zip(b)
+                .enumerate()
+                .filter(|(i, _)| (active[i / 64] >> (i % 64)) & 1 == 1)
+                .map(|(_, (x, y))| (*x, *y))
+                .unzip();
+            let expected_value = crate::test_utils::simple_dot(&a_active, &b_active);
+            assert!(
+                AutoMath::is_close(value, expected_value),
+                "value missmatch on len {len} {value:?} vs {expected_value:?}"
+            );
+        }
+    }
+}
+
+#[cfg(all(
+    test,
+    any(
+        all(target_feature = "avx2", target_feature = "fma"),
+        all(target_feature = "avx512f", feature = "nightly"),
+        target_feature = "neon",
+    )
+))]
+pub(crate) unsafe fn test_dot_dd<T, R>(epsilon: T)
+where
+    T: Copy + PartialEq + core::fmt::Debug,
+    R: SimdRegister<T>,
+    crate::math::StdMath: Math<T>,
+{
+    use crate::math::StdMath;
+
+    // `(1 + eps) * (1 - eps) == 1 - eps^2` which is not representable, so a plain
+    // dot product rounds every product to `1` and the final cancellation yields `0`.
+    let n = 67;
+    let mut adversarial_l1 = vec![StdMath::add(StdMath::one(), epsilon); n];
+    let mut adversarial_l2 = vec![StdMath::sub(StdMath::one(), epsilon); n];
+    let mut count = StdMath::zero();
+    for _ in 0..n {
+        count = StdMath::add(count, StdMath::one());
+    }
+    adversarial_l1.push(count);
+    adversarial_l2.push(StdMath::sub(StdMath::zero(), StdMath::one()));
+
+    let eps_squared = StdMath::mul(epsilon, epsilon);
+    let expected_value = StdMath::sub(StdMath::zero(), StdMath::mul(count, eps_squared));
+
+    let (hi, lo) =
+        generic_dot_dd::<T, R, StdMath, _, _>(&adversarial_l1, &adversarial_l2);
+    assert_eq!(hi, expected_value, "value missmatch on leading component");
+    assert_eq!(lo, StdMath::zero(), "value missmatch on trailing component");
+
+    // Small integer values have an exact dot product, so no error term should remain.
+    let l1 = vec![StdMath::add(StdMath::one(), StdMath::one()); n];
+    let l2 = vec![StdMath::add(StdMath::one(), l1[0]); n];
+    let (hi, lo) = generic_dot_dd::<T, R, StdMath, _, _>(&l1, &l2);
+    let expected_value = StdMath::mul(count, StdMath::mul(l1[0], l2[0]));
+    assert_eq!(hi, expected_value, "value missmatch on leading component");
+    assert_eq!(lo, StdMath::zero(), "value missmatch on trailing component");
 }
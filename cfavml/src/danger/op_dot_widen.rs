@@ -0,0 +1,233 @@
+use crate::danger::{DenseLane, SimdRegister};
+use crate::math::Math;
+use crate::mem_loader::SCRATCH_SPACE_SIZE;
+
+#[inline(always)]
+/// A dot product implementation over two `f32` vectors, widening every load to `f64`
+/// and accumulating in `f64`.
+///
+/// Each block of `f32` values is converted into a `f64` scratch buffer before being
+/// loaded into the `f64` registers of `R`, the product of two `f32` values is exactly
+/// representable as a `f64` so only the accumulation introduces any rounding error.
+/// This trades throughput for accuracy, as only half as many elements fit in a register.
+///
+/// # Panics
+///
+/// If `a` and `b` are not the same length.
+///
+/// # Safety
+///
+/// The safety requirements of `M` definition the basic math operations and the
+/// requirements of `R` SIMD register must also be followed.
+pub unsafe fn generic_dot_f32_widen<R, M>(a: &[f32], b: &[f32]) -> f64
+where
+    R: SimdRegister<f64>,
+    M: Math<f64>,
+{
+    assert_eq!(a.len(), b.len(), "Input vector sizes do not match");
+
+    let len = a.len();
+    let offset_from = len % R::elements_per_dense();
+
+    let mut l1_buffer = [M::zero(); SCRATCH_SPACE_SIZE];
+    let mut l2_buffer = [M::zero(); SCRATCH_SPACE_SIZE];
+
+    // Operate over dense lanes first.
+    let mut total = R::zeroed_dense();
+    let mut i = 0;
+    while i < (len - offset_from) {
+        let block = i..i + R::elements_per_dense();
+        widen_into(&mut l1_buffer, a.get_unchecked(block.clone()));
+        widen_into(&mut l2_buffer, b.get_unchecked(block));
+
+        let l1 = R::load_dense(l1_buffer.as_ptr());
+        let l2 = R::load_dense(l2_buffer.as_ptr());
+        total = R::fmadd_dense(l1, l2, total);
+
+        i += R::elements_per_dense();
+    }
+
+    // Operate over single registers next.
+    let mut total = R::sum_to_register(total);
+    let offset_from = offset_from % R::elements_per_lane();
+    while i < (len - offset_from) {
+        let block = i..i + R::elements_per_lane();
+        widen_into(&mut l1_buffer, a.get_unchecked(block.clone()));
+        widen_into(&mut l2_buffer, b.get_unchecked(block));
+
+        let l1 = R::load(l1_buffer.as_ptr());
+        let l2 = R::load(l2_buffer.as_ptr());
+        total = R::fmadd(l1, l2, total);
+
+        i += R::elements_per_lane();
+    }
+
+    // Handle the remainder.
+    let mut total = R::sum_to_value(total);
+    while i < len {
+        let x = *a.get_unchecked(i) as f64;
+        let y = *b.get_unchecked(i) as f64;
+        total = M::add(total, M::mul(x, y));
+
+        i += 1;
+    }
+
+    total
+}
+
+#[inline(always)]
+/// A dot product implementation over two `f32` vectors, accumulating in `f32` with
+/// Kahan compensation and correcting the result in `f64`.
+///
+/// Every lane keeps a running sum and the rounding error lost from it, once all dense
+/// lanes are processed each lane's sum and compensation are combined in `f64`.
+/// This keeps the full throughput of the `f32` registers, but unlike
+/// [generic_dot_f32_widen] each product is still rounded to `f32`.
+///
+/// # Panics
+///
+/// If `a` and `b` are not the same length.
+///
+/// # Safety
+///
+/// The requirements of `R` SIMD register must be followed, and `R` must not
+/// re-associate floating point operations (i.e. no fast-math) otherwise the
+/// compensation can be optimized away.
+pub unsafe fn generic_dot_f32_compensated<R>(a: &[f32], b: &[f32]) -> f64
+where
+    R: SimdRegister<f32>,
+{
+    assert_eq!(a.len(), b.len(), "Input vector sizes do not match");
+
+    let len = a.len();
+    let offset_from = len % R::elements_per_dense();
+
+    let a_ptr = a.as_ptr();
+    let b_ptr = b.as_ptr();
+
+    let mut sum = R::zeroed_dense();
+    let mut compensation = R::zeroed_dense();
+
+    let mut i = 0;
+    while i < (len - offset_from) {
+        let l1 = R::load_dense(a_ptr.add(i));
+        let l2 = R::load_dense(b_ptr.add(i));
+
+        let y = R::sub_dense(R::mul_dense(l1, l2), compensation);
+        let t = R::add_dense(sum, y);
+        compensation = R::sub_dense(R::sub_dense(t, sum), y);
+        sum = t;
+
+        i += R::elements_per_dense();
+    }
+
+    // Correct each lane in `f64`.
+    let mut total = correct_dense::<R>(sum, compensation);
+
+    // Handle the remainder, the product of two `f32` values is exact in `f64`.
+    while i < len {
+        total += *a.get_unchecked(i) as f64 * *b.get_unchecked(i) as f64;
+
+        i += 1;
+    }
+
+    total
+}
+
+#[inline(always)]
+fn widen_into(dst: &mut [f64], src: &[f32]) {
+    for (dst, src) in dst.iter_mut().zip(src) {
+        *dst = *src as f64;
+    }
+}
+
+#[inline(always)]
+unsafe fn correct_dense<R>(
+    sum: DenseLane<R::Register>,
+    compensation: DenseLane<R::Register>,
+) -> f64
+where
+    R: SimdRegister<f32>,
+{
+    let sums = [sum.a, sum.b, sum.c, sum.d, sum.e, sum.f, sum.g, sum.h];
+    let compensations = [
+        compensation.a,
+        compensation.b,
+        compensation.c,
+        compensation.d,
+        compensation.e,
+        compensation.f,
+        compensation.g,
+        compensation.h,
+    ];
+
+    let mut sum_buffer = [0.0f32; SCRATCH_SPACE_SIZE];
+    let mut compensation_buffer = [0.0f32; SCRATCH_SPACE_SIZE];
+
+    let mut total = 0.0f64;
+    for (sum, compensation) in sums.into_iter().zip(compensations) {
+        R::write(sum_buffer.as_mut_ptr(), sum);
+        R::write(compensation_buffer.as_mut_ptr(), compensation);
+
+        for j in 0..R::elements_per_lane() {
+            total += sum_buffer[j] as f64 - compensation_buffer[j] as f64;
+        }
+    }
+
+    total
+}
+
+#[cfg(test)]
+pub(crate) unsafe fn test_dot_f32_widen<R>()
+where
+    R: SimdRegister<f32> + SimdRegister<f64>,
+{
+    use crate::math::AutoMath;
+
+    let (l1, l2) = crate::test_utils::get_sample_vectors::<f32>(1 << 20);
+    let expected_value = l1
+        .iter()
+        .zip(l2.iter())
+        .map(|(x, y)| *x as f64 * *y as f64)
+        .sum::<f64>();
+
+    let widened = generic_dot_f32_widen::<R, AutoMath>(&l1, &l2);
+    let widened_error = ((widened - expected_value) / expected_value).abs();
+    assert!(
+        widened_error < 1e-10,
+        "value missmatch on widened dot {widened} vs {expected_value}"
+    );
+
+    let compensated = generic_dot_f32_compensated::<R>(&l1, &l2);
+    let compensated_error = ((compensated - expected_value) / expected_value).abs();
+    assert!(
+        compensated_error < 1e-5,
+        "value missmatch on compensated dot {compensated} vs {expected_value}"
+    );
+
+    // Widening every load is the more accurate of the two.
+    assert!(
+        widened_error <= compensated_error,
+        "widened dot should be at least as accurate, {widened_error} vs {compensated_error}"
+    );
+
+    for dims in [0, 1, 7, 131, 533] {
+        let (a, b) = (&l1[..dims], &l2[..dims]);
+        let expected_value = a
+            .iter()
+            .zip(b)
+            .map(|(x, y)| *x as f64 * *y as f64)
+            .sum::<f64>();
+
+        let widened = generic_dot_f32_widen::<R, AutoMath>(a, b);
+        let compensated = generic_dot_f32_compensated::<R>(a, b);
+        assert!(
+            (widened - expected_value).abs() <= 1e-6 * expected_value.abs().max(1.0),
+            "value missmatch on dims {dims} {widened} vs {expected_value}"
+        );
+        assert!(
+            (compensated - expected_value).abs() <= 1e-4 * expected_value.abs().max(1.0),
+            "value missmatch on dims {dims} {compensated} vs {expected_value}"
+        );
+    }
+}
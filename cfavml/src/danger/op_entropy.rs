@@ -0,0 +1,128 @@
+use crate::danger::op_vecmath::log2_f64;
+use crate::danger::SimdRegister;
+use crate::math::Math;
+use crate::mem_loader::SCRATCH_SPACE_SIZE;
+
+/// The maximum relative error of [generic_entropy] over a probability vector.
+///
+/// Each `log2(p)` is within a relative error of `1e-15`, the bound leaves room for the
+/// rounding of the accumulation over long vectors.
+pub const ENTROPY_MAX_RELATIVE_ERROR: f64 = 1e-12;
+
+#[inline(always)]
+/// A generic Shannon entropy implementation over the `f64` probability vector `p`,
+/// computing `-sum(p[i] * log2(p[i]))` in bits.
+///
+/// Each `log2(p[i])` is evaluated a dense lane at a time into a scratch buffer, with
+/// zero probability lanes masked to `0` so `0 * log2(0)` contributes nothing rather than
+/// `NaN`, then multiplied with `p` and accumulated with a fused multiply add in the `f64`
+/// registers of `R`. The relative error is below [ENTROPY_MAX_RELATIVE_ERROR].
+///
+/// The values of `p` are assumed to be within `[0, 1]`, `p` is not required to sum
+/// to `1`.
+///
+/// # Safety
+///
+/// The safety requirements of `M` definition the basic math operations and the
+/// requirements of `R` SIMD register must also be followed.
+pub unsafe fn generic_entropy<R, M>(p: &[f64]) -> f64
+where
+    R: SimdRegister<f64>,
+    M: Math<f64>,
+{
+    let len = p.len();
+    let offset_from = len % R::elements_per_dense();
+
+    let mut logs = [M::zero(); SCRATCH_SPACE_SIZE];
+
+    let mut total = R::zeroed_dense();
+    let mut i = 0;
+    while i < (len - offset_from) {
+        let block = p.get_unchecked(i..i + R::elements_per_dense());
+        for (dst, probability) in logs.iter_mut().zip(block) {
+            *dst = entropy_log2::<M>(*probability);
+        }
+
+        let l1 = R::load_dense(block.as_ptr());
+        total = R::fmadd_dense(l1, R::load_dense(logs.as_ptr()), total);
+
+        i += R::elements_per_dense();
+    }
+
+    // Handle the remainder.
+    let mut total = R::sum_to_value(R::sum_to_register(total));
+    while i < len {
+        let probability = *p.get_unchecked(i);
+        total = M::add(total, M::mul(probability, entropy_log2::<M>(probability)));
+
+        i += 1;
+    }
+
+    M::sub(M::zero(), total)
+}
+
+#[inline(always)]
+/// The `log2(p)` of a probability, masked to `0` when `p` is zero.
+fn entropy_log2<M>(probability: f64) -> f64
+where
+    M: Math<f64>,
+{
+    if M::cmp_eq(probability, M::zero()) {
+        M::zero()
+    } else {
+        log2_f64(probability)
+    }
+}
+
+#[cfg(test)]
+pub(crate) unsafe fn test_entropy<R>()
+where
+    R: SimdRegister<f64>,
+{
+    use crate::math::AutoMath;
+
+    let reference = |p: &[f64]| -> f64 {
+        -p.iter()
+            .filter(|v| **v != 0.0)
+            .map(|v| v * v.log2())
+            .sum::<f64>()
+    };
+    let check = |value: f64, expected_value: f64, case: &str| {
+        assert!(
+            (value - expected_value).abs()
+                <= ENTROPY_MAX_RELATIVE_ERROR * expected_value.abs().max(1.0),
+            "value missmatch on {case} {value} vs {expected_value}"
+        );
+    };
+
+    let (l1, _) = crate::test_utils::get_sample_vectors::<f64>(1043);
+    for len in [1, 7, 131, 1043] {
+        // Every third probability is zero to exercise the masking.
+        let weights = l1[..len]
+            .iter()
+            .enumerate()
+            .map(|(i, v)| if i % 3 == 1 { 0.0 } else { *v + 0.01 })
+            .collect::<Vec<_>>();
+        let total = weights.iter().sum::<f64>();
+        let p = weights.iter().map(|v| v / total).collect::<Vec<_>>();
+
+        let value = generic_entropy::<R, AutoMath>(&p);
+        check(value, reference(&p), &format!("len {len}"));
+    }
+
+    // A one-hot distribution carries no information.
+    let mut p = vec![0.0; 131];
+    p[67] = 1.0;
+    let value = generic_entropy::<R, AutoMath>(&p);
+    assert_eq!(value, 0.0, "value missmatch on one-hot {value}");
+
+    // A uniform distribution over `n` outcomes has an entropy of `log2(n)`.
+    for n in [2, 64, 1043] {
+        let p = vec![1.0 / n as f64; n];
+        let value = generic_entropy::<R, AutoMath>(&p);
+        check(value, (n as f64).log2(), &format!("uniform {n}"));
+    }
+
+    let value = generic_entropy::<R, AutoMath>(&[]);
+    assert_eq!(value, 0.0, "entropy of an empty vector should be zero");
+}
@@ -1,6 +1,7 @@
 use crate::danger::core_simd_api::SimdRegister;
+use crate::danger::{generic_pairwise_dot, generic_squared_norm};
 use crate::math::Math;
-use crate::mem_loader::{IntoMemLoader, MemLoader};
+use crate::mem_loader::{IntoMemLoader, MemLoader, SCRATCH_SPACE_SIZE};
 
 #[inline(always)]
 /// A generic squared Euclidean distance implementation over two vectors of a given set of dimensions.
@@ -72,10 +73,327 @@ where
     total
 }
 
+#[inline(always)]
+/// A generic weighted squared Euclidean distance implementation over two vectors,
+/// computing `sum(w[i] * (a[i] - b[i])^2)`, i.e. the squared Mahalanobis distance with
+/// a diagonal covariance where `w` holds the inverse variances.
+///
+/// The distance is accumulated in a single pass over all three vectors, each difference
+/// is multiplied by its weight and fused multiply added with the difference again.
+///
+/// # Panics
+///
+/// If `a`, `b` and `w` are not the same length.
+///
+/// # Safety
+///
+/// The safety requirements of `M` definition the basic math operations and the
+/// requirements of `R` SIMD register must also be followed.
+pub unsafe fn generic_weighted_squared_euclidean<T, R, M, B1, B2, B3>(
+    a: B1,
+    b: B2,
+    w: B3,
+) -> T
+where
+    T: Copy,
+    R: SimdRegister<T>,
+    M: Math<T>,
+    B1: IntoMemLoader<T>,
+    B1::Loader: MemLoader<Value = T>,
+    B2: IntoMemLoader<T>,
+    B2::Loader: MemLoader<Value = T>,
+    B3: IntoMemLoader<T>,
+    B3::Loader: MemLoader<Value = T>,
+{
+    let mut a = a.into_mem_loader();
+    let mut b = b.into_mem_loader();
+    let mut w = w.into_mem_loader();
+    assert_eq!(
+        a.projected_len(),
+        b.projected_len(),
+        "Buffers `a` and `b` do not match in size"
+    );
+    assert_eq!(
+        a.projected_len(),
+        w.projected_len(),
+        "Buffers `a` and `w` do not match in size"
+    );
+
+    let len = a.projected_len();
+    let offset_from = len % R::elements_per_lane();
+
+    // Three dense lanes of inputs would exhaust the available registers, so a pair
+    // of registers is used instead.
+    let mut total_a = R::zeroed();
+    let mut total_b = R::zeroed();
+
+    let mut i = 0;
+    let offset_from_pair = len % (R::elements_per_lane() * 2);
+    while i < (len - offset_from_pair) {
+        let diff1 = R::sub(a.load::<R>(), b.load::<R>());
+        let w1 = w.load::<R>();
+        let diff2 = R::sub(a.load::<R>(), b.load::<R>());
+        let w2 = w.load::<R>();
+        total_a = R::fmadd(R::mul(diff1, w1), diff1, total_a);
+        total_b = R::fmadd(R::mul(diff2, w2), diff2, total_b);
+
+        i += R::elements_per_lane() * 2;
+    }
+
+    let mut total = R::add(total_a, total_b);
+    while i < (len - offset_from) {
+        let diff = R::sub(a.load::<R>(), b.load::<R>());
+        let weight = w.load::<R>();
+        total = R::fmadd(R::mul(diff, weight), diff, total);
+
+        i += R::elements_per_lane();
+    }
+
+    // Handle the remainder.
+    let mut total = R::sum_to_value(total);
+    while i < len {
+        let diff = M::sub(a.read(), b.read());
+        let weight = w.read();
+        total = M::add(total, M::mul(M::mul(diff, weight), diff));
+
+        i += 1;
+    }
+
+    total
+}
+
+#[inline(always)]
+/// A generic squared Euclidean distance implementation between `query` and a batch of
+/// `4` vectors stored batch-interleaved in `docs4`, writing the distance to each vector
+/// in `out`.
+///
+/// `docs4[d * 4 + k]` holds dimension `d` of vector `k`, so each register covers the same
+/// dimensions of all `4` vectors and the `4` distances are accumulated in parallel, with
+/// the matching `query` elements broadcast across the lanes of each vector.
+///
+/// # Panics
+///
+/// If `query` is not `dims` in length or `docs4` is not `dims * 4` in length.
+///
+/// # Safety
+///
+/// The safety requirements of `M` definition the basic math operations and the
+/// requirements of `R` SIMD register must also be followed.
+pub unsafe fn generic_squared_euclidean_batch4<T, R, M>(
+    query: &[T],
+    docs4: &[T],
+    dims: usize,
+    out: &mut [T; 4],
+) where
+    T: Copy,
+    R: SimdRegister<T>,
+    M: Math<T>,
+{
+    assert_eq!(
+        query.len(),
+        dims,
+        "Buffer `query` does not match `dims` in size"
+    );
+    assert_eq!(
+        docs4.len(),
+        dims * 4,
+        "Buffer `docs4` does not match `dims * 4` in size"
+    );
+
+    let len = docs4.len();
+    let lanes = R::elements_per_lane();
+    let query_ptr = query.as_ptr();
+    let docs_ptr = docs4.as_ptr();
+
+    // Registers narrower than the batch cover a different subset of the vectors
+    // depending on their offset, so each subset gets its own accumulator.
+    let num_accumulators = if lanes < 4 { 4 / lanes } else { 1 };
+    let mut totals = [R::zeroed(); 4];
+    let mut broadcast = [M::zero(); SCRATCH_SPACE_SIZE];
+
+    let mut i = 0;
+    while i + lanes <= len {
+        for (j, slot) in broadcast[..lanes].iter_mut().enumerate() {
+            *slot = query_ptr.add((i + j) / 4).read();
+        }
+
+        let l1 = R::load(broadcast.as_ptr());
+        let l2 = R::load(docs_ptr.add(i));
+        let diff = R::sub(l1, l2);
+
+        let total = &mut totals[(i / lanes) % num_accumulators];
+        *total = R::fmadd(diff, diff, *total);
+
+        i += lanes;
+    }
+
+    *out = [M::zero(); 4];
+
+    let mut reduced = [M::zero(); SCRATCH_SPACE_SIZE];
+    for (k, total) in totals[..num_accumulators].iter().enumerate() {
+        R::write(reduced.as_mut_ptr(), *total);
+
+        for (j, value) in reduced[..lanes].iter().enumerate() {
+            let doc = (k * lanes + j) % 4;
+            out[doc] = M::add(out[doc], *value);
+        }
+    }
+
+    // Handle the remainder.
+    while i < len {
+        let diff = M::sub(query_ptr.add(i / 4).read(), docs_ptr.add(i).read());
+        out[i % 4] = M::add(out[i % 4], M::mul(diff, diff));
+
+        i += 1;
+    }
+}
+
+#[inline(always)]
+/// A generic squared Euclidean distance implementation between `query` and every row of
+/// the row-major `matrix`, which has rows of `query.len()` elements, writing the
+/// distance to row `r` to `out[r]`.
+///
+/// Each row is reduced against `query` with [generic_squared_euclidean], the query is
+/// small enough to stay resident in cache while the rows of `matrix` are streamed in.
+///
+/// # Panics
+///
+/// If `query` is empty, `matrix` is not a multiple of `query.len()` in length or `out`
+/// is not `matrix.len() / query.len()` in length.
+///
+/// # Safety
+///
+/// The safety requirements of `M` definition the basic math operations and the
+/// requirements of `R` SIMD register must also be followed.
+pub unsafe fn generic_squared_euclidean_batch<T, R, M>(
+    query: &[T],
+    matrix: &[T],
+    out: &mut [T],
+) where
+    T: Copy,
+    R: SimdRegister<T>,
+    M: Math<T>,
+{
+    let dims = query.len();
+    assert_ne!(dims, 0, "Buffer `query` must not be empty");
+    assert_eq!(
+        matrix.len() % dims,
+        0,
+        "Buffer `matrix` is not a multiple of `query` in length"
+    );
+    assert_eq!(
+        out.len(),
+        matrix.len() / dims,
+        "Buffer `out` does not match the number of rows in `matrix`"
+    );
+
+    for (row, distance) in matrix.chunks_exact(dims).zip(out.iter_mut()) {
+        *distance = generic_squared_euclidean::<T, R, M, _, _>(query, row);
+    }
+}
+
+#[inline(always)]
+/// A generic pairwise squared Euclidean distance implementation between every row of the
+/// row-major `a` and `b` matrices of `dims` elements per row, computing
+/// `out[i * num_b + j] = squared_euclidean(a_i, b_j)`.
+///
+/// ### Gram expansion
+///
+/// The distances are expanded to `||a_i||^2 + ||b_j||^2 - 2 * dot(a_i, b_j)`, the Gram
+/// matrix of dot products is written to `out` with [generic_pairwise_dot] then the
+/// squared row norms from [generic_squared_norm] are combined with it in the `R`
+/// registers. The norms of `b` are held in a scratch buffer so are computed in blocks
+/// of up to `64` rows, the norms of `a` are recomputed for each block.
+///
+/// Cancellation can leave a small rounding error for floating point rows which are
+/// almost identical, negative results are clamped to zero.
+///
+/// # Panics
+///
+/// If `dims` is `0`, `a` or `b` is not a multiple of `dims` in length or `out`
+/// is not `num_a * num_b` in length.
+///
+/// # Safety
+///
+/// The safety requirements of `M` definition the basic math operations and the
+/// requirements of `R` SIMD register must also be followed.
+pub unsafe fn generic_pairwise_squared_euclidean<T, R, M>(
+    a: &[T],
+    b: &[T],
+    dims: usize,
+    out: &mut [T],
+) where
+    T: Copy,
+    R: SimdRegister<T>,
+    M: Math<T>,
+{
+    assert_ne!(dims, 0, "Row size `dims` must be greater than zero");
+    assert_eq!(
+        a.len() % dims,
+        0,
+        "Buffer `a` is not a multiple of `dims` in length"
+    );
+    assert_eq!(
+        b.len() % dims,
+        0,
+        "Buffer `b` is not a multiple of `dims` in length"
+    );
+
+    let num_b = b.len() / dims;
+    assert_eq!(
+        out.len(),
+        (a.len() / dims) * num_b,
+        "Buffer `out` does not match `num_a * num_b` in size"
+    );
+
+    generic_pairwise_dot::<T, R, M>(a, b, dims, out);
+
+    let lanes = R::elements_per_lane();
+    let zero = R::zeroed();
+    let mut b_norms = [M::zero(); SCRATCH_SPACE_SIZE];
+
+    let mut start = 0;
+    while start < num_b {
+        let block = (num_b - start).min(SCRATCH_SPACE_SIZE);
+        for (j, norm) in b_norms[..block].iter_mut().enumerate() {
+            let row = b.get_unchecked((start + j) * dims..(start + j + 1) * dims);
+            *norm = generic_squared_norm::<T, R, M, _>(row);
+        }
+
+        for (i, row) in a.chunks_exact(dims).enumerate() {
+            let a_norm = generic_squared_norm::<T, R, M, _>(row);
+            let out_ptr = out.as_mut_ptr().add(i * num_b + start);
+
+            let a_norm_reg = R::filled(a_norm);
+            let mut j = 0;
+            while j + lanes <= block {
+                let gram = R::load(out_ptr.add(j));
+                let norms = R::add(a_norm_reg, R::load(b_norms.as_ptr().add(j)));
+                let distance = R::sub(norms, R::add(gram, gram));
+                R::write(out_ptr.add(j), R::max(distance, zero));
+
+                j += lanes;
+            }
+
+            // Handle the remainder.
+            while j < block {
+                let gram = out_ptr.add(j).read();
+                let norms = M::add(a_norm, b_norms[j]);
+                let distance = M::sub(norms, M::add(gram, gram));
+                out_ptr.add(j).write(M::cmp_max(distance, M::zero()));
+
+                j += 1;
+            }
+        }
+
+        start += block;
+    }
+}
+
 #[cfg(test)]
 pub(crate) unsafe fn test_euclidean<T, R>(l1: Vec<T>, l2: Vec<T>)
 where
-    T: Copy + PartialEq + std::fmt::Debug,
+    T: Copy + PartialEq + core::fmt::Debug,
     R: SimdRegister<T>,
     crate::math::AutoMath: Math<T>,
 {
@@ -87,4 +405,176 @@ where
         AutoMath::is_close(value, expected_value),
         "value missmatch {value:?} vs {expected_value:?}"
     );
+
+    // A vector has no distance to itself, regardless of the tail length.
+    for len in [l1.len(), 131, 3] {
+        let a = &l1[..len];
+        let value = generic_squared_euclidean::<T, R, AutoMath, _, _>(a, a);
+        assert_eq!(value, AutoMath::zero(), "value missmatch on len {len}");
+    }
+}
+
+#[cfg(test)]
+pub(crate) unsafe fn test_weighted_squared_euclidean<T, R>(l1: Vec<T>, l2: Vec<T>)
+where
+    T: Copy + PartialEq + core::fmt::Debug,
+    R: SimdRegister<T>,
+    crate::math::AutoMath: Math<T>,
+{
+    use crate::math::AutoMath;
+
+    let reference = |a: &[T], b: &[T], w: &[T]| -> T {
+        a.iter()
+            .zip(b)
+            .zip(w)
+            .fold(AutoMath::zero(), |acc, ((a, b), w)| {
+                let diff = AutoMath::sub(*a, *b);
+                AutoMath::add(acc, AutoMath::mul(AutoMath::mul(diff, *w), diff))
+            })
+    };
+
+    // The weights are taken from the tail of `l2` so they differ from `b`.
+    let weights = l2.iter().rev().copied().collect::<Vec<_>>();
+    for len in [1, 7, 131, l1.len()] {
+        let (a, b, w) = (&l1[..len], &l2[..len], &weights[..len]);
+
+        let value =
+            generic_weighted_squared_euclidean::<T, R, AutoMath, _, _, _>(a, b, w);
+        let expected_value = reference(a, b, w);
+        assert!(
+            AutoMath::is_close(value, expected_value),
+            "value missmatch on len {len} {value:?} vs {expected_value:?}"
+        );
+    }
+
+    // Unit weights give the plain squared Euclidean distance.
+    let ones = vec![AutoMath::one(); l1.len()];
+    let value =
+        generic_weighted_squared_euclidean::<T, R, AutoMath, _, _, _>(&l1, &l2, &ones);
+    let expected_value = generic_squared_euclidean::<T, R, AutoMath, _, _>(&l1, &l2);
+    assert!(
+        AutoMath::is_close(value, expected_value),
+        "value missmatch with unit weights {value:?} vs {expected_value:?}"
+    );
+}
+
+#[cfg(test)]
+pub(crate) unsafe fn test_euclidean_batch4<T, R>(l1: Vec<T>, l2: Vec<T>)
+where
+    T: Copy + PartialEq + core::fmt::Debug,
+    R: SimdRegister<T>,
+    crate::math::AutoMath: Math<T>,
+{
+    use crate::math::AutoMath;
+
+    for dims in [0, 1, 3, 67] {
+        let query = &l1[..dims];
+        let docs = [
+            &l2[..dims],
+            &l2[dims..dims * 2],
+            &l2[dims * 2..dims * 3],
+            &l1[dims..dims * 2],
+        ];
+
+        let mut docs4 = Vec::with_capacity(dims * 4);
+        for d in 0..dims {
+            docs4.extend(docs.iter().map(|doc| doc[d]));
+        }
+
+        let mut out = [AutoMath::one(); 4];
+        generic_squared_euclidean_batch4::<T, R, AutoMath>(
+            query, &docs4, dims, &mut out,
+        );
+
+        for (value, doc) in out.into_iter().zip(docs) {
+            let expected_value =
+                generic_squared_euclidean::<T, R, AutoMath, _, _>(query, doc);
+            assert!(
+                AutoMath::is_close(value, expected_value),
+                "value missmatch for {dims} dims {value:?} vs {expected_value:?}"
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+pub(crate) unsafe fn test_squared_euclidean_batch<T, R>(l1: Vec<T>, l2: Vec<T>)
+where
+    T: Copy + PartialEq + core::fmt::Debug,
+    R: SimdRegister<T>,
+    crate::math::AutoMath: Math<T>,
+{
+    use crate::math::AutoMath;
+
+    for dims in [1, 3, 67, 128] {
+        let query = &l1[..dims];
+        let nrows = (l2.len() / dims).min(64);
+        let matrix = &l2[..nrows * dims];
+
+        let mut out = vec![AutoMath::one(); nrows];
+        generic_squared_euclidean_batch::<T, R, AutoMath>(query, matrix, &mut out);
+
+        for (row, value) in matrix.chunks_exact(dims).zip(out.iter()) {
+            let expected_value =
+                query.iter().zip(row).fold(AutoMath::zero(), |acc, (a, b)| {
+                    let diff = AutoMath::sub(*a, *b);
+                    AutoMath::add(acc, AutoMath::mul(diff, diff))
+                });
+            assert!(
+                AutoMath::is_close(*value, expected_value),
+                "value missmatch for {dims} dims {value:?} vs {expected_value:?}"
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+pub(crate) unsafe fn test_pairwise_squared_euclidean<T, R>(l1: Vec<T>, l2: Vec<T>)
+where
+    T: Copy + PartialEq + core::fmt::Debug,
+    R: SimdRegister<T>,
+    crate::math::AutoMath: Math<T>,
+{
+    use crate::math::AutoMath;
+
+    // Row counts of `b` either side of the norm block size.
+    let shapes = [(1, 1, 1), (7, 3, 9), (16, 2, 8), (5, 2, 130)];
+    for (dims, num_a, num_b) in shapes {
+        if dims * num_a > l1.len() || dims * num_b > l2.len() {
+            continue;
+        }
+
+        let a = &l1[..dims * num_a];
+        let b = &l2[..dims * num_b];
+
+        let mut out = vec![AutoMath::zero(); num_a * num_b];
+        generic_pairwise_squared_euclidean::<T, R, AutoMath>(a, b, dims, &mut out);
+
+        for (i, a_row) in a.chunks(dims).enumerate() {
+            for (j, b_row) in b.chunks(dims).enumerate() {
+                let value = out[i * num_b + j];
+                let expected_value = crate::test_utils::simple_euclidean(a_row, b_row);
+                assert!(
+                    AutoMath::is_close(value, expected_value),
+                    "value missmatch on ({i}, {j}) with {dims} dims {value:?} vs {expected_value:?}"
+                );
+            }
+        }
+    }
+
+    // The distance of a row to itself is clamped to be non-negative.
+    let rows = &l1[..21];
+    let mut out = vec![AutoMath::zero(); 9];
+    generic_pairwise_squared_euclidean::<T, R, AutoMath>(rows, rows, 7, &mut out);
+    for (i, distances) in out.chunks(3).enumerate() {
+        let value = distances[i];
+        assert!(
+            AutoMath::is_close(value, AutoMath::zero()),
+            "value missmatch on self distance {value:?}"
+        );
+        assert!(
+            AutoMath::cmp_gte(value, AutoMath::zero()),
+            "self distance should not be negative {value:?}"
+        );
+    }
 }
@@ -0,0 +1,135 @@
+use crate::danger::SimdRegister;
+use crate::math::Math;
+
+#[inline(always)]
+/// A generic in place fast Walsh-Hadamard transform implementation over `a`, using the
+/// natural (Hadamard) ordering and no normalization.
+///
+/// The transform is a butterfly network of `log2(len)` stages, each stage pairing
+/// elements `h` apart and replacing them with their sum and difference. Stages where
+/// `h` spans at least one register are done a register at a time in `R`, the lower
+/// stages pair elements within the same register so are done element by element.
+///
+/// Applying the transform twice scales the input by `len`.
+///
+/// # Panics
+///
+/// If the length of `a` is not a power of two.
+///
+/// # Safety
+///
+/// The safety requirements of `M` definition the basic math operations and the
+/// requirements of `R` SIMD register must also be followed.
+pub unsafe fn generic_hadamard_transform_inplace<T, R, M>(a: &mut [T])
+where
+    T: Copy,
+    R: SimdRegister<T>,
+    M: Math<T>,
+{
+    let len = a.len();
+    assert!(
+        len.is_power_of_two(),
+        "Buffer `a` must be a power of two in length"
+    );
+
+    let data_ptr = a.as_mut_ptr();
+
+    let mut h = 1;
+    while h < len {
+        let mut i = 0;
+        while i < len {
+            if h >= R::elements_per_lane() {
+                let mut j = i;
+                while j < i + h {
+                    let l1 = R::load(data_ptr.add(j));
+                    let l2 = R::load(data_ptr.add(j + h));
+                    R::write(data_ptr.add(j), R::add(l1, l2));
+                    R::write(data_ptr.add(j + h), R::sub(l1, l2));
+
+                    j += R::elements_per_lane();
+                }
+            } else {
+                for j in i..i + h {
+                    let x = data_ptr.add(j).read();
+                    let y = data_ptr.add(j + h).read();
+                    data_ptr.add(j).write(M::add(x, y));
+                    data_ptr.add(j + h).write(M::sub(x, y));
+                }
+            }
+
+            i += h * 2;
+        }
+
+        h *= 2;
+    }
+}
+
+#[cfg(test)]
+pub(crate) unsafe fn test_hadamard_transform_inplace<T, R>(l1: Vec<T>)
+where
+    T: Copy + PartialEq + core::fmt::Debug,
+    R: SimdRegister<T>,
+    crate::math::AutoMath: Math<T>,
+{
+    use crate::math::AutoMath;
+
+    // The entry of the Hadamard matrix at `(i, j)` is negative when `i & j` has an odd
+    // number of set bits.
+    let reference = |values: &[T]| {
+        (0..values.len())
+            .map(|i| {
+                values
+                    .iter()
+                    .enumerate()
+                    .fold(AutoMath::zero(), |total, (j, v)| {
+                        if (i & j).count_ones() % 2 == 0 {
+                            AutoMath::add(total, *v)
+                        } else {
+                            AutoMath::sub(total, *v)
+                        }
+                    })
+            })
+            .collect::<Vec<_>>()
+    };
+
+    for len in [1, 2, 8, 16] {
+        let mut result = l1[..len].to_vec();
+        generic_hadamard_transform_inplace::<T, R, AutoMath>(&mut result);
+
+        let expected_result = reference(&l1[..len]);
+        for (i, (value, expected)) in result.iter().zip(&expected_result).enumerate() {
+            assert!(
+                AutoMath::is_close(*value, *expected),
+                "value missmatch on len {len} index {i} {value:?} vs {expected:?}"
+            );
+        }
+    }
+
+    // Longer lengths reach the register stages, the scalar butterflies perform the same
+    // operations so the results must match exactly.
+    let butterflies = |values: &mut [T]| {
+        let mut h = 1;
+        while h < values.len() {
+            for i in (0..values.len()).step_by(h * 2) {
+                for j in i..i + h {
+                    let (x, y) = (values[j], values[j + h]);
+                    values[j] = AutoMath::add(x, y);
+                    values[j + h] = AutoMath::sub(x, y);
+                }
+            }
+            h *= 2;
+        }
+    };
+    for len in [128, 1024] {
+        if len > l1.len() {
+            continue;
+        }
+
+        let mut result = l1[..len].to_vec();
+        generic_hadamard_transform_inplace::<T, R, AutoMath>(&mut result);
+
+        let mut expected_result = l1[..len].to_vec();
+        butterflies(&mut expected_result);
+        assert_eq!(result, expected_result, "value missmatch on len {len}");
+    }
+}
@@ -0,0 +1,159 @@
+use crate::danger::{DenseLane, SimdRegister};
+use crate::math::Math;
+use crate::mem_loader::{IntoMemLoader, MemLoader};
+
+/// A per-element transform applied by [generic_map_sum] before accumulating.
+///
+/// The transform is provided for both a full SIMD register and a single value, so
+/// it can be applied to the main body and the remainder of a vector respectively.
+/// Both methods must compute the same transform for the result to be consistent.
+pub trait SumMap<T: Copy> {
+    /// Applies the transform to every element of the register.
+    ///
+    /// # Safety
+    ///
+    /// The requirements of `R` SIMD register must be followed.
+    unsafe fn map_register<R: SimdRegister<T>>(&self, reg: R::Register) -> R::Register;
+
+    /// Applies the transform to a single value.
+    fn map_value<M: Math<T>>(&self, value: T) -> T;
+}
+
+#[derive(Debug, Copy, Clone)]
+/// Squares each element, `x * x`, producing the sum of squares.
+pub struct MapSquare;
+
+impl<T: Copy> SumMap<T> for MapSquare {
+    #[inline(always)]
+    unsafe fn map_register<R: SimdRegister<T>>(&self, reg: R::Register) -> R::Register {
+        R::mul(reg, reg)
+    }
+
+    #[inline(always)]
+    fn map_value<M: Math<T>>(&self, value: T) -> T {
+        M::mul(value, value)
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+/// Multiplies each element by a constant factor, `x * factor`.
+pub struct MapScale<T>(pub T);
+
+impl<T: Copy> SumMap<T> for MapScale<T> {
+    #[inline(always)]
+    unsafe fn map_register<R: SimdRegister<T>>(&self, reg: R::Register) -> R::Register {
+        R::mul(reg, R::filled(self.0))
+    }
+
+    #[inline(always)]
+    fn map_value<M: Math<T>>(&self, value: T) -> T {
+        M::mul(value, self.0)
+    }
+}
+
+#[inline(always)]
+/// A generic horizontal sum implementation which applies the transform `map`
+/// to each element of `a` before it is accumulated.
+///
+/// This allows custom reductions to be built without re-implementing the dense lane,
+/// register and remainder handling, i.e. [MapSquare] produces the sum of squares.
+///
+/// # Safety
+///
+/// The safety requirements of `M` definition the basic math operations and
+/// the requirements of `R` SIMD register must also be followed.
+pub unsafe fn generic_map_sum<T, R, M, B1, F>(a: B1, map: &F) -> T
+where
+    T: Copy,
+    R: SimdRegister<T>,
+    M: Math<T>,
+    B1: IntoMemLoader<T>,
+    B1::Loader: MemLoader<Value = T>,
+    F: SumMap<T>,
+{
+    let mut a = a.into_mem_loader();
+
+    let len = a.projected_len();
+    let offset_from = len % R::elements_per_dense();
+
+    let mut sum = R::zeroed_dense();
+
+    // Operate over dense lanes first.
+    let mut i = 0;
+    while i < (len - offset_from) {
+        let l1 = a.load_dense::<R>();
+        sum = R::add_dense(sum, map_dense::<T, R, F>(map, l1));
+
+        i += R::elements_per_dense();
+    }
+
+    let mut sum = R::sum_to_register(sum);
+
+    // Operate over single registers next.
+    let offset_from = offset_from % R::elements_per_lane();
+    while i < (len - offset_from) {
+        let l1 = a.load::<R>();
+        sum = R::add(sum, map.map_register::<R>(l1));
+
+        i += R::elements_per_lane();
+    }
+
+    // Handle the remainder.
+    let mut sum = R::sum_to_value(sum);
+
+    while i < len {
+        sum = M::add(sum, map.map_value::<M>(a.read()));
+
+        i += 1;
+    }
+
+    sum
+}
+
+#[inline(always)]
+unsafe fn map_dense<T, R, F>(
+    map: &F,
+    lane: DenseLane<R::Register>,
+) -> DenseLane<R::Register>
+where
+    T: Copy,
+    R: SimdRegister<T>,
+    F: SumMap<T>,
+{
+    DenseLane {
+        a: map.map_register::<R>(lane.a),
+        b: map.map_register::<R>(lane.b),
+        c: map.map_register::<R>(lane.c),
+        d: map.map_register::<R>(lane.d),
+        e: map.map_register::<R>(lane.e),
+        f: map.map_register::<R>(lane.f),
+        g: map.map_register::<R>(lane.g),
+        h: map.map_register::<R>(lane.h),
+    }
+}
+
+#[cfg(test)]
+pub(crate) unsafe fn test_map_sum<T, R>(l1: Vec<T>)
+where
+    T: Copy + PartialEq + core::fmt::Debug,
+    R: SimdRegister<T>,
+    crate::math::AutoMath: Math<T>,
+{
+    use crate::danger::{generic_squared_norm, generic_sum};
+    use crate::math::AutoMath;
+
+    let value = generic_map_sum::<T, R, AutoMath, _, _>(&l1, &MapSquare);
+    let expected_value = generic_squared_norm::<T, R, AutoMath, _>(&l1);
+    assert!(
+        AutoMath::is_close(value, expected_value),
+        "value missmatch {value:?} vs {expected_value:?}"
+    );
+
+    let two = AutoMath::add(AutoMath::one(), AutoMath::one());
+    let value = generic_map_sum::<T, R, AutoMath, _, _>(&l1, &MapScale(two));
+    let expected_value = AutoMath::mul(generic_sum::<T, R, AutoMath, _>(&l1), two);
+    assert!(
+        AutoMath::is_close(value, expected_value),
+        "value missmatch {value:?} vs {expected_value:?}"
+    );
+}
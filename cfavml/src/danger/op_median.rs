@@ -0,0 +1,151 @@
+use crate::danger::{generic_cmp_max, generic_cmp_min, SimdRegister};
+use crate::math::Math;
+use crate::mem_loader::SCRATCH_SPACE_SIZE;
+
+/// The number of equal width bins the range of the input is split into by
+/// [generic_approx_median].
+pub const APPROX_MEDIAN_BINS: usize = 2048;
+/// The maximum error of [generic_approx_median] relative to the range of the input,
+/// `max(a) - min(a)`.
+///
+/// The estimate is the midpoint of a bin so it is within half a bin width of the exact
+/// median, this allows a full bin width to cover values rounded into a neighbouring bin.
+pub const APPROX_MEDIAN_MAX_RANGE_ERROR: f64 = 1.0 / APPROX_MEDIAN_BINS as f64;
+
+#[inline(always)]
+/// A generic approximate median implementation over a `f64` vector.
+///
+/// ### Approximation
+///
+/// The range of `a` is found with [generic_cmp_min] and [generic_cmp_max] and split into
+/// [APPROX_MEDIAN_BINS] equal width bins, the bin of each element is computed a dense lane
+/// at a time in the `R` registers and counted into a histogram. The median is estimated
+/// as the midpoint of the bin holding the middle element, or the mean of the two bin
+/// midpoints for an even number of elements, giving an absolute error of at most
+/// `APPROX_MEDIAN_MAX_RANGE_ERROR * (max(a) - min(a))`.
+///
+/// An empty vector returns `NaN`. The values of `a` are assumed to be free of `NaN` and
+/// have a finite range.
+///
+/// # Safety
+///
+/// The safety requirements of `M` definition the basic math operations and the
+/// requirements of `R` SIMD register must also be followed.
+pub unsafe fn generic_approx_median<R, M>(a: &[f64]) -> f64
+where
+    R: SimdRegister<f64>,
+    M: Math<f64>,
+{
+    let len = a.len();
+    if len == 0 {
+        return M::div(M::zero(), M::zero());
+    }
+
+    let min = generic_cmp_min::<f64, R, M, _>(a);
+    let max = generic_cmp_max::<f64, R, M, _>(a);
+    let range = M::sub(max, min);
+    if range <= 0.0 {
+        return min;
+    }
+
+    let scale = M::div(APPROX_MEDIAN_BINS as f64, range);
+    let offset_from = len % R::elements_per_dense();
+
+    let mut counts = [0usize; APPROX_MEDIAN_BINS];
+    let mut positions = [M::zero(); SCRATCH_SPACE_SIZE];
+
+    let min_dense = R::filled_dense(min);
+    let scale_dense = R::filled_dense(scale);
+
+    let mut i = 0;
+    while i < (len - offset_from) {
+        let l1 = R::load_dense(a.as_ptr().add(i));
+        let position = R::mul_dense(R::sub_dense(l1, min_dense), scale_dense);
+        R::write_dense(positions.as_mut_ptr(), position);
+
+        for position in positions.get_unchecked(..R::elements_per_dense()) {
+            counts[bin_of(*position)] += 1;
+        }
+
+        i += R::elements_per_dense();
+    }
+
+    // Handle the remainder.
+    while i < len {
+        let position = M::mul(M::sub(*a.get_unchecked(i), min), scale);
+        counts[bin_of(position)] += 1;
+
+        i += 1;
+    }
+
+    let width = M::div(range, APPROX_MEDIAN_BINS as f64);
+    let midpoint = |bin: usize| M::add(min, M::mul(M::add(bin as f64, 0.5), width));
+
+    let lower_bin = bin_of_rank(&counts, (len - 1) / 2);
+    let upper_bin = bin_of_rank(&counts, len / 2);
+    if lower_bin == upper_bin {
+        midpoint(lower_bin)
+    } else {
+        M::mul(M::add(midpoint(lower_bin), midpoint(upper_bin)), 0.5)
+    }
+}
+
+#[inline(always)]
+/// Converts a position scaled to the bin range into its bin, the maximum value lands
+/// exactly on the upper edge so is clamped into the last bin.
+fn bin_of(position: f64) -> usize {
+    (position as usize).min(APPROX_MEDIAN_BINS - 1)
+}
+
+#[inline(always)]
+/// Finds the bin containing the element of the given `rank` in sorted order.
+fn bin_of_rank(counts: &[usize], rank: usize) -> usize {
+    let mut seen = 0;
+    for (bin, count) in counts.iter().enumerate() {
+        seen += count;
+        if seen > rank {
+            return bin;
+        }
+    }
+    counts.len() - 1
+}
+
+#[cfg(test)]
+pub(crate) unsafe fn test_approx_median<R>()
+where
+    R: SimdRegister<f64>,
+{
+    use crate::math::AutoMath;
+
+    let exact_median = |values: &[f64]| -> f64 {
+        let mut sorted = values.to_vec();
+        sorted.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        let len = sorted.len();
+        (sorted[(len - 1) / 2] + sorted[len / 2]) / 2.0
+    };
+
+    let (l1, _) = crate::test_utils::get_sample_vectors::<f64>(1043);
+    // The squared values skew the distribution towards the lower end of the range.
+    let skewed = l1.iter().map(|v| v * v * 100.0 - 20.0).collect::<Vec<_>>();
+    for values in [&l1, &skewed] {
+        for len in [1, 2, 7, 64, 131, 1042, 1043] {
+            let values = &values[..len];
+            let value = generic_approx_median::<R, AutoMath>(values);
+            let expected_value = exact_median(values);
+
+            let range = values.iter().fold(f64::NEG_INFINITY, |m, v| m.max(*v))
+                - values.iter().fold(f64::INFINITY, |m, v| m.min(*v));
+            assert!(
+                (value - expected_value).abs() <= APPROX_MEDIAN_MAX_RANGE_ERROR * range,
+                "value missmatch on len {len} {value} vs {expected_value}"
+            );
+        }
+    }
+
+    // A constant vector has no range so the median is exact.
+    let value = generic_approx_median::<R, AutoMath>(&[2.5; 67]);
+    assert_eq!(value, 2.5, "value missmatch on constant vector");
+
+    let value = generic_approx_median::<R, AutoMath>(&[]);
+    assert!(value.is_nan(), "median of an empty vector should be NaN");
+}
@@ -0,0 +1,209 @@
+use crate::danger::op_vecmath::{exp2_f64, log2_f64};
+use crate::danger::{generic_squared_euclidean, SimdRegister};
+use crate::math::Math;
+use crate::mem_loader::SCRATCH_SPACE_SIZE;
+
+/// The maximum relative error of [generic_minkowski] for a `p` other than `1`, `2`
+/// or infinity.
+///
+/// Each `|a[i] - b[i]|^p` is evaluated as `2^(p * log2(|a[i] - b[i]|))` with both
+/// approximations within a relative error of `1e-15`, the error of the power grows with
+/// the magnitude of `p * log2(|a[i] - b[i]|)` so the bound leaves room for large `p`.
+pub const MINKOWSKI_MAX_RELATIVE_ERROR: f64 = 1e-12;
+
+#[inline(always)]
+/// A generic Minkowski distance implementation over two `f64` vectors, computing
+/// `sum(|a[i] - b[i]|^p)^(1 / p)`.
+///
+/// A `p` of `1`, `2` or infinity is routed to the Manhattan, Euclidean and Chebyshev
+/// distances respectively, which are exact to the accumulation.
+///
+/// For any other `p` the absolute differences are computed a dense lane at a time into a
+/// scratch buffer, raised to `p` element by element and accumulated in the `R`
+/// registers. The relative error is below [MINKOWSKI_MAX_RELATIVE_ERROR].
+///
+/// # Panics
+///
+/// If vectors `a` and `b` are not equal in length, or `p` is not positive.
+///
+/// # Safety
+///
+/// The safety requirements of `M` definition the basic math operations and the
+/// requirements of `R` SIMD register must also be followed.
+pub unsafe fn generic_minkowski<R, M>(a: &[f64], b: &[f64], p: f64) -> f64
+where
+    R: SimdRegister<f64>,
+    M: Math<f64>,
+{
+    assert_eq!(a.len(), b.len(), "Buffers `a` and `b` do not match in size");
+    assert!(p > 0.0, "Order `p` must be positive");
+
+    if p == 1.0 {
+        return manhattan::<R, M>(a, b);
+    } else if p == 2.0 {
+        return M::sqrt(generic_squared_euclidean::<f64, R, M, _, _>(a, b));
+    } else if p == f64::INFINITY {
+        return chebyshev::<R, M>(a, b);
+    }
+
+    let len = a.len();
+    let offset_from = len % R::elements_per_dense();
+
+    let zero = R::zeroed_dense();
+    let mut powers = [M::zero(); SCRATCH_SPACE_SIZE];
+
+    let mut total = R::zeroed_dense();
+    let mut i = 0;
+    while i < (len - offset_from) {
+        let l1 = R::load_dense(a.as_ptr().add(i));
+        let l2 = R::load_dense(b.as_ptr().add(i));
+
+        let diff = R::sub_dense(l1, l2);
+        let abs_diff = R::max_dense(diff, R::sub_dense(zero, diff));
+        R::write_dense(powers.as_mut_ptr(), abs_diff);
+
+        for value in powers.get_unchecked_mut(..R::elements_per_dense()) {
+            *value = pow_abs::<M>(*value, p);
+        }
+        total = R::add_dense(total, R::load_dense(powers.as_ptr()));
+
+        i += R::elements_per_dense();
+    }
+
+    // Handle the remainder.
+    let mut total = R::sum_to_value(R::sum_to_register(total));
+    while i < len {
+        let diff = M::abs(M::sub(*a.get_unchecked(i), *b.get_unchecked(i)));
+        total = M::add(total, pow_abs::<M>(diff, p));
+
+        i += 1;
+    }
+
+    if M::cmp_eq(total, M::zero()) {
+        return M::zero();
+    }
+
+    exp2_f64(M::div(log2_f64(total), p))
+}
+
+#[inline(always)]
+/// Raises the absolute difference `diff` to `p`, a zero difference stays zero rather
+/// than going through `log2(0)`.
+fn pow_abs<M>(diff: f64, p: f64) -> f64
+where
+    M: Math<f64>,
+{
+    if M::cmp_eq(diff, M::zero()) {
+        M::zero()
+    } else {
+        exp2_f64(M::mul(p, log2_f64(diff)))
+    }
+}
+
+#[inline(always)]
+/// The Manhattan distance `sum(|a[i] - b[i]|)`, the Minkowski distance with a `p` of `1`.
+unsafe fn manhattan<R, M>(a: &[f64], b: &[f64]) -> f64
+where
+    R: SimdRegister<f64>,
+    M: Math<f64>,
+{
+    let len = a.len();
+    let offset_from = len % R::elements_per_dense();
+
+    let zero = R::zeroed_dense();
+
+    let mut total = R::zeroed_dense();
+    let mut i = 0;
+    while i < (len - offset_from) {
+        let diff = R::sub_dense(
+            R::load_dense(a.as_ptr().add(i)),
+            R::load_dense(b.as_ptr().add(i)),
+        );
+        total = R::add_dense(total, R::max_dense(diff, R::sub_dense(zero, diff)));
+
+        i += R::elements_per_dense();
+    }
+
+    let mut total = R::sum_to_value(R::sum_to_register(total));
+    while i < len {
+        let diff = M::sub(*a.get_unchecked(i), *b.get_unchecked(i));
+        total = M::add(total, M::abs(diff));
+
+        i += 1;
+    }
+
+    total
+}
+
+#[inline(always)]
+/// The Chebyshev distance `max(|a[i] - b[i]|)`, the limit of the Minkowski distance as
+/// `p` goes to infinity.
+unsafe fn chebyshev<R, M>(a: &[f64], b: &[f64]) -> f64
+where
+    R: SimdRegister<f64>,
+    M: Math<f64>,
+{
+    let len = a.len();
+    let offset_from = len % R::elements_per_dense();
+
+    let zero = R::zeroed_dense();
+
+    let mut max = R::zeroed_dense();
+    let mut i = 0;
+    while i < (len - offset_from) {
+        let diff = R::sub_dense(
+            R::load_dense(a.as_ptr().add(i)),
+            R::load_dense(b.as_ptr().add(i)),
+        );
+        max = R::max_dense(max, R::max_dense(diff, R::sub_dense(zero, diff)));
+
+        i += R::elements_per_dense();
+    }
+
+    let mut max = R::max_to_value(R::max_to_register(max));
+    while i < len {
+        let diff = M::sub(*a.get_unchecked(i), *b.get_unchecked(i));
+        max = M::cmp_max(max, M::abs(diff));
+
+        i += 1;
+    }
+
+    max
+}
+
+#[cfg(test)]
+pub(crate) unsafe fn test_minkowski<R>()
+where
+    R: SimdRegister<f64>,
+{
+    use crate::math::AutoMath;
+
+    let reference = |a: &[f64], b: &[f64], p: f64| -> f64 {
+        let diffs = a.iter().zip(b).map(|(x, y)| (x - y).abs());
+        if p == f64::INFINITY {
+            return diffs.fold(0.0, f64::max);
+        }
+        diffs.map(|d| d.powf(p)).sum::<f64>().powf(1.0 / p)
+    };
+
+    let (l1, l2) = crate::test_utils::get_sample_vectors::<f64>(1043);
+    let b = l2.iter().map(|v| v * 4.0 - 2.0).collect::<Vec<_>>();
+    for p in [3.0, 0.5, 1.5, 1.0, 2.0, f64::INFINITY] {
+        for len in [1, 7, 131, 1043] {
+            let (a, b) = (&l1[..len], &b[..len]);
+            let value = generic_minkowski::<R, AutoMath>(a, b, p);
+            let expected_value = reference(a, b, p);
+            assert!(
+                (value - expected_value).abs()
+                    <= MINKOWSKI_MAX_RELATIVE_ERROR * expected_value.abs().max(1.0),
+                "value missmatch on p {p} len {len} {value} vs {expected_value}"
+            );
+        }
+    }
+
+    // Identical vectors have no distance for any order.
+    for p in [3.0, 1.0, 2.0, f64::INFINITY] {
+        let value = generic_minkowski::<R, AutoMath>(&l1, &l1, p);
+        assert_eq!(value, 0.0, "value missmatch on identical p {p}");
+    }
+}
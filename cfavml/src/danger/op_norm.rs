@@ -58,10 +58,227 @@ where
     total
 }
 
+#[inline(always)]
+/// A generic L2 norm clipping implementation, scaling `a` in place by `max_norm / norm`
+/// if the L2 norm of `a` exceeds `max_norm`, otherwise `a` is left unchanged.
+///
+/// The L2 norm of `a` _before_ clipping is returned.
+///
+/// # Safety
+///
+/// The safety requirements of `M` definition the basic math operations and the
+/// requirements of `R` SIMD register must also be followed.
+pub unsafe fn generic_clip_norm<T, R, M>(a: &mut [T], max_norm: T) -> T
+where
+    T: Copy,
+    R: SimdRegister<T>,
+    M: Math<T>,
+{
+    let norm = M::sqrt(generic_squared_norm::<T, R, M, _>(&*a));
+    if !M::cmp_gt(norm, max_norm) {
+        return norm;
+    }
+
+    scale_inplace::<T, R, M>(a, M::div(max_norm, norm));
+
+    norm
+}
+
+#[inline(always)]
+/// A generic L2 normalization implementation, scaling `a` in place to unit length.
+///
+/// The L2 norm of `a` _before_ normalizing is returned. If the norm is zero or not
+/// finite `a` is left unchanged, as scaling by its reciprocal would fill `a` with `NaN`
+/// or zeroes, callers can detect this from the returned norm.
+///
+/// # Safety
+///
+/// The safety requirements of `M` definition the basic math operations and the
+/// requirements of `R` SIMD register must also be followed.
+pub unsafe fn generic_normalize_inplace<T, R, M>(a: &mut [T]) -> T
+where
+    T: Copy,
+    R: SimdRegister<T>,
+    M: Math<T>,
+{
+    let norm = M::sqrt(generic_squared_norm::<T, R, M, _>(&*a));
+    // `norm - norm` is only zero when the norm is finite, infinity and `NaN` give `NaN`.
+    let is_finite = M::cmp_eq(M::sub(norm, norm), M::zero());
+    if M::cmp_eq(norm, M::zero()) || !is_finite {
+        return norm;
+    }
+
+    scale_inplace::<T, R, M>(a, M::div(M::one(), norm));
+
+    norm
+}
+
+#[inline(always)]
+/// A generic per-row L2 norm implementation over the row-major `matrix` with rows of
+/// `dims` elements, writing the norm of row `r` to `out[r]`.
+///
+/// Each row is reduced with [generic_squared_norm] before taking the square root.
+///
+/// # Panics
+///
+/// If `dims` is zero, `matrix` is not a multiple of `dims` in length or `out` is not
+/// `matrix.len() / dims` in length.
+///
+/// # Safety
+///
+/// The safety requirements of `M` definition the basic math operations and the
+/// requirements of `R` SIMD register must also be followed.
+pub unsafe fn generic_row_norms<T, R, M>(matrix: &[T], dims: usize, out: &mut [T])
+where
+    T: Copy,
+    R: SimdRegister<T>,
+    M: Math<T>,
+{
+    assert_ne!(dims, 0, "Row size `dims` must be greater than zero");
+    assert_eq!(
+        matrix.len() % dims,
+        0,
+        "Buffer `matrix` is not a multiple of `dims` in length"
+    );
+    assert_eq!(
+        out.len(),
+        matrix.len() / dims,
+        "Buffer `out` does not match the number of rows in `matrix`"
+    );
+
+    for (row, norm) in matrix.chunks_exact(dims).zip(out.iter_mut()) {
+        *norm = M::sqrt(generic_squared_norm::<T, R, M, _>(row));
+    }
+}
+
+#[inline(always)]
+/// A generic per-column min-max normalization implementation over the row-major
+/// `matrix` with rows of `dims` elements, rescaling each column in place to `[0, 1]`
+/// with `(x - min) / (max - min)`.
+///
+/// The minimum and maximum of a register of columns are reduced over every row before
+/// a second pass rescales them, the remaining columns are handled one at a time.
+/// Columns with a zero range have every value set to `0`.
+///
+/// # Panics
+///
+/// If `dims` is zero or `matrix` is not a multiple of `dims` in length.
+///
+/// # Safety
+///
+/// The safety requirements of `M` definition the basic math operations and the
+/// requirements of `R` SIMD register must also be followed.
+pub unsafe fn generic_minmax_normalize_columns_inplace<T, R, M>(
+    matrix: &mut [T],
+    dims: usize,
+) where
+    T: Copy,
+    R: SimdRegister<T>,
+    M: Math<T>,
+{
+    assert_ne!(dims, 0, "Row size `dims` must be greater than zero");
+    assert_eq!(
+        matrix.len() % dims,
+        0,
+        "Buffer `matrix` is not a multiple of `dims` in length"
+    );
+
+    let num_rows = matrix.len() / dims;
+    if num_rows == 0 {
+        return;
+    }
+    let matrix_ptr = matrix.as_mut_ptr();
+
+    // Operate over single registers of columns first.
+    let offset_from = dims % R::elements_per_lane();
+    let mut i = 0;
+    while i < (dims - offset_from) {
+        let mut min = R::load(matrix_ptr.add(i));
+        let mut max = min;
+        for row in 1..num_rows {
+            let l1 = R::load(matrix_ptr.add((row * dims) + i));
+            min = R::min(min, l1);
+            max = R::max(max, l1);
+        }
+
+        // A zero range has `1` added so the column rescales to `0 / 1`.
+        let range = R::sub(max, min);
+        let range = R::add(range, R::eq(range, R::zeroed()));
+        for row in 0..num_rows {
+            let ptr = matrix_ptr.add((row * dims) + i);
+            R::write(ptr, R::div(R::sub(R::load(ptr), min), range));
+        }
+
+        i += R::elements_per_lane();
+    }
+
+    // Handle the remainder.
+    while i < dims {
+        let mut min = matrix_ptr.add(i).read();
+        let mut max = min;
+        for row in 1..num_rows {
+            let value = matrix_ptr.add((row * dims) + i).read();
+            min = M::cmp_min(min, value);
+            max = M::cmp_max(max, value);
+        }
+
+        let mut range = M::sub(max, min);
+        if M::cmp_eq(range, M::zero()) {
+            range = M::one();
+        }
+        for row in 0..num_rows {
+            let ptr = matrix_ptr.add((row * dims) + i);
+            ptr.write(M::div(M::sub(ptr.read(), min), range));
+        }
+
+        i += 1;
+    }
+}
+
+#[inline(always)]
+unsafe fn scale_inplace<T, R, M>(a: &mut [T], scale: T)
+where
+    T: Copy,
+    R: SimdRegister<T>,
+    M: Math<T>,
+{
+    let len = a.len();
+    let data_ptr = a.as_mut_ptr();
+    let offset_from = len % R::elements_per_dense();
+
+    // Operate over dense lanes first.
+    let scale_dense = R::filled_dense(scale);
+    let mut i = 0;
+    while i < (len - offset_from) {
+        let l1 = R::load_dense(data_ptr.add(i));
+        R::write_dense(data_ptr.add(i), R::mul_dense(l1, scale_dense));
+
+        i += R::elements_per_dense();
+    }
+
+    // Operate over single registers next.
+    let scale_reg = R::filled(scale);
+    let offset_from = offset_from % R::elements_per_lane();
+    while i < (len - offset_from) {
+        let l1 = R::load(data_ptr.add(i));
+        R::write(data_ptr.add(i), R::mul(l1, scale_reg));
+
+        i += R::elements_per_lane();
+    }
+
+    // Handle the remainder.
+    while i < len {
+        let value = data_ptr.add(i);
+        value.write(M::mul(value.read(), scale));
+
+        i += 1;
+    }
+}
+
 #[cfg(test)]
 pub(crate) unsafe fn test_squared_norm<T, R>(l1: Vec<T>)
 where
-    T: Copy + PartialEq + std::fmt::Debug,
+    T: Copy + PartialEq + core::fmt::Debug,
     R: SimdRegister<T>,
     crate::math::AutoMath: Math<T>,
 {
@@ -74,3 +291,222 @@ where
         "value missmatch {value:?} vs {expected_value:?}"
     );
 }
+
+#[cfg(test)]
+pub(crate) unsafe fn test_clip_norm<T, R>(l1: Vec<T>)
+where
+    T: Copy + PartialEq + core::fmt::Debug,
+    R: SimdRegister<T>,
+    crate::math::AutoMath: Math<T>,
+{
+    use crate::math::AutoMath;
+
+    let two = AutoMath::add(AutoMath::one(), AutoMath::one());
+    let norm = AutoMath::sqrt(crate::test_utils::simple_dot(&l1, &l1));
+
+    // Unclipped, the vector must be left completely untouched.
+    let mut unclipped = l1.clone();
+    let value =
+        generic_clip_norm::<T, R, AutoMath>(&mut unclipped, AutoMath::mul(norm, two));
+    assert!(
+        AutoMath::is_close(value, norm),
+        "norm missmatch {value:?} vs {norm:?}"
+    );
+    assert_eq!(
+        unclipped, l1,
+        "vector should not be modified when under the max norm"
+    );
+
+    // Clipped, the resulting norm must be (within rounding) the max norm.
+    let max_norm = AutoMath::div(norm, two);
+    let mut clipped = l1.clone();
+    let value = generic_clip_norm::<T, R, AutoMath>(&mut clipped, max_norm);
+    assert!(
+        AutoMath::is_close(value, norm),
+        "norm missmatch {value:?} vs {norm:?}"
+    );
+
+    let clipped_norm = AutoMath::sqrt(crate::test_utils::simple_dot(&clipped, &clipped));
+    assert!(
+        AutoMath::cmp_lte(clipped_norm, max_norm)
+            || AutoMath::is_close(clipped_norm, max_norm),
+        "clipped norm exceeds max norm {clipped_norm:?} vs {max_norm:?}"
+    );
+    for (original, scaled) in l1.iter().zip(clipped.iter()) {
+        let expected = AutoMath::mul(*original, AutoMath::div(max_norm, norm));
+        assert!(
+            AutoMath::is_close(*scaled, expected),
+            "value missmatch {scaled:?} vs {expected:?}"
+        );
+    }
+}
+
+#[cfg(test)]
+pub(crate) unsafe fn test_normalize_inplace<T, R>(l1: Vec<T>)
+where
+    T: Copy + PartialEq + core::fmt::Debug,
+    R: SimdRegister<T>,
+    crate::math::AutoMath: Math<T>,
+{
+    use crate::math::AutoMath;
+
+    let norm = AutoMath::sqrt(crate::test_utils::simple_dot(&l1, &l1));
+
+    let mut normalized = l1.clone();
+    let value = generic_normalize_inplace::<T, R, AutoMath>(&mut normalized);
+    assert!(
+        AutoMath::is_close(value, norm),
+        "norm missmatch {value:?} vs {norm:?}"
+    );
+
+    let unit_norm =
+        AutoMath::sqrt(crate::test_utils::simple_dot(&normalized, &normalized));
+    assert!(
+        AutoMath::is_close(unit_norm, AutoMath::one()),
+        "normalized vector is not unit length {unit_norm:?}"
+    );
+    for (original, scaled) in l1.iter().zip(normalized.iter()) {
+        let expected = AutoMath::div(*original, norm);
+        assert!(
+            AutoMath::is_close(*scaled, expected),
+            "value missmatch {scaled:?} vs {expected:?}"
+        );
+    }
+
+    // A zero vector has no direction and must be left untouched.
+    let mut zeroes = vec![AutoMath::zero(); l1.len()];
+    let value = generic_normalize_inplace::<T, R, AutoMath>(&mut zeroes);
+    assert_eq!(value, AutoMath::zero(), "zero vector norm should be zero");
+    assert!(
+        zeroes.iter().all(|v| *v == AutoMath::zero()),
+        "zero vector should not be modified"
+    );
+
+    // A length leaving a register and scalar tail normalizes the same way.
+    let mut normalized = l1[..131].to_vec();
+    let norm = AutoMath::sqrt(crate::test_utils::simple_dot(&normalized, &normalized));
+    generic_normalize_inplace::<T, R, AutoMath>(&mut normalized);
+    for (original, scaled) in l1.iter().zip(normalized.iter()) {
+        let expected = AutoMath::div(*original, norm);
+        assert!(
+            AutoMath::is_close(*scaled, expected),
+            "value missmatch on tail {scaled:?} vs {expected:?}"
+        );
+    }
+
+    // A non-finite norm would poison the vector so it must be left untouched.
+    let infinity = AutoMath::div(AutoMath::one(), AutoMath::zero());
+    let nan = AutoMath::div(AutoMath::zero(), AutoMath::zero());
+    for special in [infinity, nan] {
+        let mut values = l1.clone();
+        values[l1.len() - 1] = special;
+        let original = values.clone();
+
+        generic_normalize_inplace::<T, R, AutoMath>(&mut values);
+        // `NaN` never compares equal, so it is matched by also failing to equal itself.
+        let is_nan = |v: T| !AutoMath::cmp_eq(v, v);
+        let unchanged = values
+            .iter()
+            .zip(&original)
+            .all(|(v, o)| *v == *o || (is_nan(*v) && is_nan(*o)));
+        assert!(unchanged, "vector with {special:?} should not be modified");
+    }
+}
+
+#[cfg(test)]
+pub(crate) unsafe fn test_row_norms<T, R>(l1: Vec<T>)
+where
+    T: Copy + PartialEq + core::fmt::Debug,
+    R: SimdRegister<T>,
+    crate::math::AutoMath: Math<T>,
+{
+    use crate::math::AutoMath;
+
+    for dims in [1, 7, 64, 149] {
+        let nrows = (l1.len() / dims).min(64);
+        let matrix = &l1[..nrows * dims];
+
+        let mut out = vec![AutoMath::zero(); nrows];
+        generic_row_norms::<T, R, AutoMath>(matrix, dims, &mut out);
+
+        for (row, value) in matrix.chunks_exact(dims).zip(out.iter()) {
+            let expected_value = AutoMath::sqrt(crate::test_utils::simple_dot(row, row));
+            assert!(
+                AutoMath::is_close(*value, expected_value),
+                "value missmatch on dims {dims} {value:?} vs {expected_value:?}"
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+pub(crate) unsafe fn test_minmax_normalize_columns_inplace<T, R>(l1: Vec<T>)
+where
+    T: Copy + PartialEq + core::fmt::Debug,
+    R: SimdRegister<T>,
+    crate::math::AutoMath: Math<T>,
+{
+    use crate::math::AutoMath;
+
+    for dims in [1, 7, 64, 149] {
+        let nrows = (l1.len() / dims).min(64);
+        let mut matrix = l1[..nrows * dims].to_vec();
+        // The last column is constant and has no range to rescale.
+        let constant = matrix[0];
+        for row in matrix.chunks_exact_mut(dims) {
+            row[dims - 1] = constant;
+        }
+        let original = matrix.clone();
+
+        generic_minmax_normalize_columns_inplace::<T, R, AutoMath>(&mut matrix, dims);
+
+        for column in 0..dims {
+            let values = original.iter().skip(column).step_by(dims);
+            let min = values
+                .clone()
+                .fold(AutoMath::max(), |m, v| AutoMath::cmp_min(m, *v));
+            let max = values.fold(AutoMath::min(), |m, v| AutoMath::cmp_max(m, *v));
+            let range = AutoMath::sub(max, min);
+
+            let mut column_min = AutoMath::max();
+            let mut column_max = AutoMath::min();
+            for row in 0..nrows {
+                let value = matrix[(row * dims) + column];
+                let expected_value = if column == dims - 1 {
+                    AutoMath::zero()
+                } else {
+                    AutoMath::div(
+                        AutoMath::sub(original[(row * dims) + column], min),
+                        range,
+                    )
+                };
+                assert!(
+                    AutoMath::is_close(value, expected_value),
+                    "value missmatch on dims {dims} {value:?} vs {expected_value:?}"
+                );
+
+                column_min = AutoMath::cmp_min(column_min, value);
+                column_max = AutoMath::cmp_max(column_max, value);
+            }
+
+            if column == dims - 1 {
+                assert_eq!(
+                    column_max,
+                    AutoMath::zero(),
+                    "constant column should be zero"
+                );
+            } else {
+                assert_eq!(
+                    column_min,
+                    AutoMath::zero(),
+                    "column {column} min should be 0"
+                );
+                assert_eq!(
+                    column_max,
+                    AutoMath::one(),
+                    "column {column} max should be 1"
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,190 @@
+use crate::danger::SimdRegister;
+use crate::math::Math;
+use crate::mem_loader::{IntoMemLoader, MemLoader};
+
+#[inline(always)]
+/// A generic Pearson correlation coefficient implementation over two vectors of a
+/// given set of dimensions.
+///
+/// The sums of `a`, `b`, `a * a`, `b * b` and `a * b` are all accumulated in a single pass
+/// over the same loaded registers and combined with the standard formula:
+///
+/// ```ignore
+/// r = (n * sum_ab - sum_a * sum_b) / sqrt((n * sum_aa - sum_a²) * (n * sum_bb - sum_b²))
+/// ```
+///
+/// If either vector has zero variance (including empty vectors) the correlation is
+/// undefined and zero is returned.
+///
+/// This is only meaningful for floating point types.
+///
+/// # Panics
+///
+/// If `a` and `b` are not the same length.
+///
+/// # Safety
+///
+/// The safety requirements of `M` definition the basic math operations and
+/// the requirements of `R` SIMD register must also be followed.
+pub unsafe fn generic_pearson<T, R, M, B1, B2>(a: B1, b: B2) -> T
+where
+    T: Copy,
+    R: SimdRegister<T>,
+    M: Math<T>,
+    B1: IntoMemLoader<T>,
+    B1::Loader: MemLoader<Value = T>,
+    B2: IntoMemLoader<T>,
+    B2::Loader: MemLoader<Value = T>,
+{
+    let mut a = a.into_mem_loader();
+    let mut b = b.into_mem_loader();
+    assert_eq!(
+        a.projected_len(),
+        b.projected_len(),
+        "Buffers `a` and `b` do not match in size"
+    );
+
+    let len = a.projected_len();
+    let offset_from = len % R::elements_per_lane();
+
+    // Five accumulator groups are already enough to exhaust the available
+    // registers, so only single registers are used rather than dense lanes.
+    let mut sum_a = R::zeroed();
+    let mut sum_b = R::zeroed();
+    let mut sum_aa = R::zeroed();
+    let mut sum_bb = R::zeroed();
+    let mut sum_ab = R::zeroed();
+    let mut count = R::zeroed();
+    let ones = R::filled(M::one());
+
+    let mut i = 0;
+    while i < (len - offset_from) {
+        let l1 = a.load::<R>();
+        let l2 = b.load::<R>();
+        sum_a = R::add(sum_a, l1);
+        sum_b = R::add(sum_b, l2);
+        sum_aa = R::fmadd(l1, l1, sum_aa);
+        sum_bb = R::fmadd(l2, l2, sum_bb);
+        sum_ab = R::fmadd(l1, l2, sum_ab);
+        count = R::add(count, ones);
+
+        i += R::elements_per_lane();
+    }
+
+    // Handle the remainder.
+    let mut sum_a = R::sum_to_value(sum_a);
+    let mut sum_b = R::sum_to_value(sum_b);
+    let mut sum_aa = R::sum_to_value(sum_aa);
+    let mut sum_bb = R::sum_to_value(sum_bb);
+    let mut sum_ab = R::sum_to_value(sum_ab);
+    let mut n = R::sum_to_value(count);
+
+    while i < len {
+        let x = a.read();
+        let y = b.read();
+        sum_a = M::add(sum_a, x);
+        sum_b = M::add(sum_b, y);
+        sum_aa = M::add(sum_aa, M::mul(x, x));
+        sum_bb = M::add(sum_bb, M::mul(y, y));
+        sum_ab = M::add(sum_ab, M::mul(x, y));
+        n = M::add(n, M::one());
+
+        i += 1;
+    }
+
+    let covariance = M::sub(M::mul(n, sum_ab), M::mul(sum_a, sum_b));
+    let variance_a = M::sub(M::mul(n, sum_aa), M::mul(sum_a, sum_a));
+    let variance_b = M::sub(M::mul(n, sum_bb), M::mul(sum_b, sum_b));
+
+    if !M::cmp_gt(variance_a, M::zero()) || !M::cmp_gt(variance_b, M::zero()) {
+        return M::zero();
+    }
+
+    M::div(covariance, M::sqrt(M::mul(variance_a, variance_b)))
+}
+
+#[cfg(test)]
+pub(crate) unsafe fn test_pearson<T, R>(l1: Vec<T>, l2: Vec<T>)
+where
+    T: Copy + PartialEq + core::fmt::Debug,
+    R: SimdRegister<T>,
+    crate::math::AutoMath: Math<T>,
+{
+    use crate::math::AutoMath;
+
+    let value = generic_pearson::<T, R, AutoMath, _, _>(&l1, &l2);
+    let expected_value = simple_pearson(&l1, &l2);
+    assert!(
+        AutoMath::is_close(value, expected_value),
+        "value missmatch {value:?} vs {expected_value:?}"
+    );
+
+    // b = 2a + 1 is perfectly correlated with a.
+    let two = AutoMath::add(AutoMath::one(), AutoMath::one());
+    let correlated = l1
+        .iter()
+        .map(|v| AutoMath::add(AutoMath::mul(*v, two), AutoMath::one()))
+        .collect::<Vec<_>>();
+    let value = generic_pearson::<T, R, AutoMath, _, _>(&l1, &correlated);
+    assert!(
+        AutoMath::is_close(value, AutoMath::one()),
+        "correlated value should be 1, got {value:?}"
+    );
+
+    // b = -2a is perfectly anti-correlated with a.
+    let minus_two = AutoMath::sub(AutoMath::zero(), two);
+    let anti_correlated = l1
+        .iter()
+        .map(|v| AutoMath::mul(*v, minus_two))
+        .collect::<Vec<_>>();
+    let value = generic_pearson::<T, R, AutoMath, _, _>(&l1, &anti_correlated);
+    let minus_one = AutoMath::sub(AutoMath::zero(), AutoMath::one());
+    assert!(
+        AutoMath::is_close(value, minus_one),
+        "anti-correlated value should be -1, got {value:?}"
+    );
+
+    // A constant vector has zero variance.
+    let constant = vec![two; l2.len()];
+    let value = generic_pearson::<T, R, AutoMath, _, _>(&constant, &l2);
+    assert_eq!(value, AutoMath::zero(), "zero variance should return zero");
+    let value = generic_pearson::<T, R, AutoMath, _, _>(&[] as &[T], &[] as &[T]);
+    assert_eq!(value, AutoMath::zero(), "empty vectors should return zero");
+}
+
+#[cfg(test)]
+fn simple_pearson<T>(a: &[T], b: &[T]) -> T
+where
+    T: Copy,
+    crate::math::AutoMath: Math<T>,
+{
+    use crate::math::AutoMath;
+
+    let n = a
+        .iter()
+        .fold(AutoMath::zero(), |n, _| AutoMath::add(n, AutoMath::one()));
+    let mean_a = AutoMath::div(
+        a.iter().fold(AutoMath::zero(), |s, v| AutoMath::add(s, *v)),
+        n,
+    );
+    let mean_b = AutoMath::div(
+        b.iter().fold(AutoMath::zero(), |s, v| AutoMath::add(s, *v)),
+        n,
+    );
+
+    let mut covariance = AutoMath::zero();
+    let mut variance_a = AutoMath::zero();
+    let mut variance_b = AutoMath::zero();
+    for (x, y) in a.iter().zip(b) {
+        let dx = AutoMath::sub(*x, mean_a);
+        let dy = AutoMath::sub(*y, mean_b);
+        covariance = AutoMath::add(covariance, AutoMath::mul(dx, dy));
+        variance_a = AutoMath::add(variance_a, AutoMath::mul(dx, dx));
+        variance_b = AutoMath::add(variance_b, AutoMath::mul(dy, dy));
+    }
+
+    AutoMath::div(
+        covariance,
+        AutoMath::sqrt(AutoMath::mul(variance_a, variance_b)),
+    )
+}
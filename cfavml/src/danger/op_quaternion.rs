@@ -0,0 +1,256 @@
+use crate::danger::SimdRegister;
+use crate::math::Math;
+use crate::mem_loader::SCRATCH_SPACE_SIZE;
+
+#[inline(always)]
+/// A generic element-wise quaternion multiply implementation over the interleaved
+/// quaternion vectors `a` and `b`, writing the Hamilton product `a[i] * b[i]` of each
+/// pair of quaternions to `out`.
+///
+/// Each quaternion is stored as `[w, x, y, z]` with a stride of `4`. A register worth of
+/// quaternions is deinterleaved into planar scratch buffers, one per component, so the
+/// product maps directly onto the registers without needing any lane shuffles, then the
+/// result is interleaved back into `out`.
+///
+/// This is only meaningful for floating point types.
+///
+/// # Panics
+///
+/// If `a`, `b` and `out` are not all the same length or the length is not a multiple
+/// of `4`.
+///
+/// # Safety
+///
+/// The safety requirements of `M` definition the basic math operations and
+/// the requirements of `R` SIMD register must also be followed.
+pub unsafe fn generic_quat_mul<T, R, M>(a: &[T], b: &[T], out: &mut [T])
+where
+    T: Copy,
+    R: SimdRegister<T>,
+    M: Math<T>,
+{
+    let len = a.len();
+    assert!(
+        b.len() == len && out.len() == len,
+        "Buffers `a`, `b` and `out` do not match in size"
+    );
+    assert_eq!(
+        len % 4,
+        0,
+        "Buffer length must be a multiple of 4 to hold `[w, x, y, z]` quaternions"
+    );
+
+    let num_quats = len / 4;
+    let offset_from = num_quats % R::elements_per_lane();
+
+    let mut a_planes = [[M::zero(); SCRATCH_SPACE_SIZE]; 4];
+    let mut b_planes = [[M::zero(); SCRATCH_SPACE_SIZE]; 4];
+    let mut out_planes = [[M::zero(); SCRATCH_SPACE_SIZE]; 4];
+
+    let mut i = 0;
+    while i < (num_quats - offset_from) {
+        for j in 0..R::elements_per_lane() {
+            let base = (i + j) * 4;
+            for (c, (a_plane, b_plane)) in
+                a_planes.iter_mut().zip(b_planes.iter_mut()).enumerate()
+            {
+                a_plane[j] = *a.get_unchecked(base + c);
+                b_plane[j] = *b.get_unchecked(base + c);
+            }
+        }
+
+        let aw = R::load(a_planes[0].as_ptr());
+        let ax = R::load(a_planes[1].as_ptr());
+        let ay = R::load(a_planes[2].as_ptr());
+        let az = R::load(a_planes[3].as_ptr());
+        let bw = R::load(b_planes[0].as_ptr());
+        let bx = R::load(b_planes[1].as_ptr());
+        let by = R::load(b_planes[2].as_ptr());
+        let bz = R::load(b_planes[3].as_ptr());
+
+        // The positive products are fused, the negative products are subtracted last.
+        let w = R::sub(
+            R::sub(R::mul(aw, bw), R::mul(ax, bx)),
+            R::fmadd(ay, by, R::mul(az, bz)),
+        );
+        let x = R::sub(
+            R::fmadd(aw, bx, R::fmadd(ax, bw, R::mul(ay, bz))),
+            R::mul(az, by),
+        );
+        let y = R::sub(
+            R::fmadd(aw, by, R::fmadd(ay, bw, R::mul(az, bx))),
+            R::mul(ax, bz),
+        );
+        let z = R::sub(
+            R::fmadd(aw, bz, R::fmadd(ax, by, R::mul(az, bw))),
+            R::mul(ay, bx),
+        );
+
+        R::write(out_planes[0].as_mut_ptr(), w);
+        R::write(out_planes[1].as_mut_ptr(), x);
+        R::write(out_planes[2].as_mut_ptr(), y);
+        R::write(out_planes[3].as_mut_ptr(), z);
+
+        for j in 0..R::elements_per_lane() {
+            let base = (i + j) * 4;
+            for (c, plane) in out_planes.iter().enumerate() {
+                *out.get_unchecked_mut(base + c) = plane[j];
+            }
+        }
+
+        i += R::elements_per_lane();
+    }
+
+    // Handle the remainder.
+    while i < num_quats {
+        let base = i * 4;
+        let product = quat_mul::<T, M>(
+            a.get_unchecked(base..base + 4),
+            b.get_unchecked(base..base + 4),
+        );
+        out.get_unchecked_mut(base..base + 4)
+            .copy_from_slice(&product);
+
+        i += 1;
+    }
+}
+
+#[inline(always)]
+/// The Hamilton product of the single quaternions `a` and `b`.
+fn quat_mul<T, M>(a: &[T], b: &[T]) -> [T; 4]
+where
+    T: Copy,
+    M: Math<T>,
+{
+    let [aw, ax, ay, az] = [a[0], a[1], a[2], a[3]];
+    let [bw, bx, by, bz] = [b[0], b[1], b[2], b[3]];
+
+    let w = M::sub(
+        M::sub(M::mul(aw, bw), M::mul(ax, bx)),
+        M::add(M::mul(ay, by), M::mul(az, bz)),
+    );
+    let x = M::sub(
+        M::add(M::add(M::mul(aw, bx), M::mul(ax, bw)), M::mul(ay, bz)),
+        M::mul(az, by),
+    );
+    let y = M::sub(
+        M::add(M::add(M::mul(aw, by), M::mul(ay, bw)), M::mul(az, bx)),
+        M::mul(ax, bz),
+    );
+    let z = M::sub(
+        M::add(M::add(M::mul(aw, bz), M::mul(ax, by)), M::mul(az, bw)),
+        M::mul(ay, bx),
+    );
+
+    [w, x, y, z]
+}
+
+#[cfg(test)]
+pub(crate) unsafe fn test_quat_mul<T, R>(l1: Vec<T>, l2: Vec<T>)
+where
+    T: Copy + PartialEq + core::fmt::Debug,
+    R: SimdRegister<T>,
+    crate::math::AutoMath: Math<T>,
+{
+    use crate::math::AutoMath;
+
+    let max_quats = l1.len() / 4;
+    for num_quats in [0, 1, 3, R::elements_per_lane() + 1, max_quats] {
+        let len = num_quats * 4;
+        let (a, b) = (&l1[..len], &l2[..len]);
+
+        let mut out = vec![AutoMath::zero(); len];
+        generic_quat_mul::<T, R, AutoMath>(a, b, &mut out);
+
+        for (i, (q1, q2)) in a.chunks_exact(4).zip(b.chunks_exact(4)).enumerate() {
+            let expected = simple_quat_mul(q1, q2);
+            for (value, expected_value) in out[i * 4..i * 4 + 4].iter().zip(expected) {
+                assert!(
+                    AutoMath::is_close(*value, expected_value),
+                    "value missmatch on quaternion {i} {value:?} vs {expected_value:?}"
+                );
+            }
+        }
+    }
+
+    // Multiplying by the identity quaternion on either side is a no-op.
+    let len = max_quats * 4;
+    let identity = [
+        AutoMath::one(),
+        AutoMath::zero(),
+        AutoMath::zero(),
+        AutoMath::zero(),
+    ]
+    .repeat(max_quats);
+
+    let mut out = vec![AutoMath::zero(); len];
+    generic_quat_mul::<T, R, AutoMath>(&l1[..len], &identity, &mut out);
+    assert_eq!(out, l1[..len], "value missmatch on right identity");
+
+    let mut out = vec![AutoMath::zero(); len];
+    generic_quat_mul::<T, R, AutoMath>(&identity, &l1[..len], &mut out);
+    assert_eq!(out, l1[..len], "value missmatch on left identity");
+
+    // `i * j = k` and `j * i = -k`, quaternion multiplication does not commute.
+    let zero = AutoMath::zero();
+    let one = AutoMath::one();
+    let minus_one = AutoMath::sub(zero, one);
+    let mut out = [zero; 8];
+    generic_quat_mul::<T, R, AutoMath>(
+        &[zero, one, zero, zero, zero, zero, one, zero],
+        &[zero, zero, one, zero, zero, one, zero, zero],
+        &mut out,
+    );
+    assert_eq!(
+        out[..4],
+        [zero, zero, zero, one],
+        "value missmatch on i * j"
+    );
+    assert_eq!(
+        out[4..],
+        [zero, zero, zero, minus_one],
+        "value missmatch on j * i"
+    );
+}
+
+#[cfg(test)]
+fn simple_quat_mul<T>(a: &[T], b: &[T]) -> [T; 4]
+where
+    T: Copy,
+    crate::math::AutoMath: Math<T>,
+{
+    use crate::math::AutoMath;
+
+    // Expands `(aw + ax*i + ay*j + az*k) * (bw + bx*i + by*j + bz*k)` term by term
+    // using a table of `(index_a, index_b, index_out, sign)` from the rules
+    // `i^2 = j^2 = k^2 = ijk = -1`.
+    const TERMS: [(usize, usize, usize, bool); 16] = [
+        (0, 0, 0, false),
+        (0, 1, 1, false),
+        (0, 2, 2, false),
+        (0, 3, 3, false),
+        (1, 0, 1, false),
+        (1, 1, 0, true),
+        (1, 2, 3, false),
+        (1, 3, 2, true),
+        (2, 0, 2, false),
+        (2, 1, 3, true),
+        (2, 2, 0, true),
+        (2, 3, 1, false),
+        (3, 0, 3, false),
+        (3, 1, 2, false),
+        (3, 2, 1, true),
+        (3, 3, 0, true),
+    ];
+
+    let mut result = [AutoMath::zero(); 4];
+    for (ia, ib, io, negative) in TERMS {
+        let product = AutoMath::mul(a[ia], b[ib]);
+        result[io] = if negative {
+            AutoMath::sub(result[io], product)
+        } else {
+            AutoMath::add(result[io], product)
+        };
+    }
+    result
+}
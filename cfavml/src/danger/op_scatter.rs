@@ -0,0 +1,98 @@
+use crate::math::Math;
+
+#[inline(always)]
+/// A generic scatter add, accumulating each element of `values` into `result` at
+/// the position given by the matching element of `indices`, i.e. for building histograms.
+///
+/// There is no scatter instruction on most targets, and duplicate indices within the
+/// same register would cause lanes to overwrite each other's updates rather than
+/// accumulate them. To handle duplicates correctly every update is applied one at a
+/// time in the order of `indices`.
+///
+/// # Panics
+///
+/// If `indices` and `values` are not the same length, or any index is out of bounds
+/// of `result`.
+///
+/// # Safety
+///
+/// The safety requirements of `M` definition the basic math operations must also be followed.
+pub unsafe fn generic_scatter_add<T, M>(indices: &[u32], values: &[T], result: &mut [T])
+where
+    T: Copy,
+    M: Math<T>,
+{
+    assert_eq!(
+        indices.len(),
+        values.len(),
+        "Buffers `indices` and `values` do not match in size"
+    );
+
+    for (&idx, &value) in indices.iter().zip(values) {
+        let slot = &mut result[idx as usize];
+        *slot = M::add(*slot, value);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math::AutoMath;
+
+    #[test]
+    fn test_scatter_add_duplicate_indices() {
+        let indices = [0, 3, 3, 1, 3, 0, 2, 3];
+        let values = [1.0, 2.0, 4.0, 8.0, 16.0, 32.0, 64.0, 128.0];
+
+        let mut result = [0.0f64; 5];
+        unsafe { generic_scatter_add::<f64, AutoMath>(&indices, &values, &mut result) };
+        assert_eq!(result, [33.0, 8.0, 64.0, 150.0, 0.0]);
+
+        // Values are exact so any ordering of the updates must produce the same result.
+        let mut reversed_indices = indices;
+        let mut reversed_values = values;
+        reversed_indices.reverse();
+        reversed_values.reverse();
+
+        let mut reversed_result = [0.0f64; 5];
+        unsafe {
+            generic_scatter_add::<f64, AutoMath>(
+                &reversed_indices,
+                &reversed_values,
+                &mut reversed_result,
+            )
+        };
+        assert_eq!(reversed_result, result);
+    }
+
+    #[test]
+    fn test_scatter_add_accumulates_existing() {
+        let (values, _) = crate::test_utils::get_sample_vectors::<u32>(533);
+        let indices = values.iter().map(|v| v % 7).collect::<Vec<_>>();
+
+        let mut result = vec![1u32; 7];
+        unsafe { generic_scatter_add::<u32, AutoMath>(&indices, &values, &mut result) };
+
+        let mut expected = vec![1u32; 7];
+        for (idx, value) in indices.iter().zip(values.iter()) {
+            expected[*idx as usize] = expected[*idx as usize].wrapping_add(*value);
+        }
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_scatter_add_index_out_of_bounds() {
+        let mut result = [0.0f32; 2];
+        unsafe {
+            generic_scatter_add::<f32, AutoMath>(&[0, 2], &[1.0, 1.0], &mut result)
+        };
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_scatter_add_length_mismatch() {
+        let mut result = [0.0f32; 2];
+        unsafe { generic_scatter_add::<f32, AutoMath>(&[0, 1], &[1.0], &mut result) };
+    }
+}
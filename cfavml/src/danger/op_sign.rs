@@ -0,0 +1,175 @@
+use crate::buffer::WriteOnlyBuffer;
+use crate::math::Math;
+use crate::mem_loader::{IntoMemLoader, MemLoader};
+
+#[inline(always)]
+/// A generic vector element-wise copysign, combining the magnitude of each element
+/// of `a` with the sign of each element of `b` and writing it to `result`.
+///
+/// This is a scalar routine, there is no register level sign manipulation in
+/// [SimdRegister](crate::danger::SimdRegister) so no per-backend variants are exported.
+///
+/// # Safety
+///
+/// The sizes of `a`, `b` and `result` must be equal, the safety requirements of
+/// `M` definition the basic math operations must also be followed.
+pub unsafe fn generic_copysign_vertical<T, M, B1, B2, B3>(
+    a: B1,
+    b: B2,
+    mut result: &mut [B3],
+) where
+    T: Copy,
+    M: Math<T>,
+    B1: IntoMemLoader<T>,
+    B1::Loader: MemLoader<Value = T>,
+    B2: IntoMemLoader<T>,
+    B2::Loader: MemLoader<Value = T>,
+    for<'a> &'a mut [B3]: WriteOnlyBuffer<Item = T>,
+{
+    let len = result.raw_buffer_len();
+
+    let mut a = a.into_projected_mem_loader(len);
+    let mut b = b.into_projected_mem_loader(len);
+
+    let mut i = 0;
+    while i < len {
+        result.write_at(i, M::copysign(a.read(), b.read()));
+
+        i += 1;
+    }
+}
+
+#[inline(always)]
+/// A generic in place signum, replacing each element of `a` with its sign.
+///
+/// Floats produce `±1.0` for every value including signed zeros, and `NaN` is kept as `NaN`,
+/// integers produce `-1`, `0` or `1`.
+///
+/// Like [generic_copysign_vertical] this is a scalar routine.
+///
+/// # Safety
+///
+/// The safety requirements of `M` definition the basic math operations must also be followed.
+pub unsafe fn generic_signum_inplace<T, M>(a: &mut [T])
+where
+    T: Copy,
+    M: Math<T>,
+{
+    for value in a.iter_mut() {
+        *value = M::signum(*value);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math::StdMath;
+
+    const SPECIAL_VALUES: [f64; 10] = [
+        0.0,
+        -0.0,
+        1.5,
+        -1.5,
+        f64::INFINITY,
+        f64::NEG_INFINITY,
+        f64::NAN,
+        -f64::NAN,
+        f64::MIN_POSITIVE,
+        -f64::MAX,
+    ];
+
+    fn same_bits(a: &[f64], b: &[f64]) -> bool {
+        a.iter().zip(b).all(|(a, b)| a.to_bits() == b.to_bits())
+    }
+
+    #[test]
+    fn test_copysign_special_values() {
+        let mut mag = Vec::new();
+        let mut sign = Vec::new();
+        for a in SPECIAL_VALUES {
+            for b in SPECIAL_VALUES {
+                mag.push(a);
+                sign.push(b);
+            }
+        }
+
+        let mut result = vec![0.0; mag.len()];
+        unsafe {
+            generic_copysign_vertical::<f64, StdMath, _, _, _>(&mag, &sign, &mut result)
+        };
+
+        let expected = mag
+            .iter()
+            .zip(sign.iter())
+            .map(|(a, b)| a.copysign(*b))
+            .collect::<Vec<_>>();
+        assert!(same_bits(&result, &expected), "value mismatch");
+    }
+
+    #[test]
+    fn test_copysign_broadcast_sign() {
+        let mut result = vec![0.0; SPECIAL_VALUES.len()];
+        unsafe {
+            generic_copysign_vertical::<f64, StdMath, _, _, _>(
+                &SPECIAL_VALUES,
+                -0.0,
+                &mut result,
+            )
+        };
+
+        let expected = SPECIAL_VALUES.map(|v| v.copysign(-0.0));
+        assert!(same_bits(&result, &expected), "value mismatch");
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_copysign_length_mismatch() {
+        let mut result = vec![0.0f32; 3];
+        unsafe {
+            generic_copysign_vertical::<f32, StdMath, _, _, _>(
+                &[1.0, 2.0, 3.0],
+                &[1.0, 2.0],
+                &mut result,
+            )
+        };
+    }
+
+    #[test]
+    fn test_signum_special_values() {
+        let mut values = SPECIAL_VALUES;
+        unsafe { generic_signum_inplace::<f64, StdMath>(&mut values) };
+
+        for (result, value) in values.iter().zip(SPECIAL_VALUES) {
+            let expected = value.signum();
+            assert!(
+                result.to_bits() == expected.to_bits()
+                    || (result.is_nan() && expected.is_nan()),
+                "value mismatch for {value}: {result} != {expected}",
+            );
+        }
+
+        let mut values = [0.0f32, -0.0, 3.0, -3.0, f32::NAN];
+        unsafe { generic_signum_inplace::<f32, StdMath>(&mut values) };
+        assert_eq!(values[..4], [1.0, -1.0, 1.0, -1.0]);
+        assert!(values[4].is_nan());
+    }
+
+    #[test]
+    fn test_sign_ops_ints() {
+        let mag = [0i32, 3, -3, i32::MAX, -7];
+        let sign = [-1i32, -1, 1, -2, 0];
+        let mut result = [0i32; 5];
+        unsafe {
+            generic_copysign_vertical::<i32, StdMath, _, _, _>(&mag, &sign, &mut result)
+        };
+        assert_eq!(result, [0, -3, 3, -i32::MAX, 7]);
+
+        let mut values = [0i32, 5, -5];
+        unsafe { generic_signum_inplace::<i32, StdMath>(&mut values) };
+        assert_eq!(values, [0, 1, -1]);
+
+        let mut values = [0u8, 5, 255];
+        unsafe { generic_signum_inplace::<u8, StdMath>(&mut values) };
+        assert_eq!(values, [0, 1, 1]);
+    }
+}
@@ -1,10 +1,51 @@
-use crate::danger::core_simd_api::SimdRegister;
+use core::mem;
+
+use crate::danger::core_simd_api::{DenseLane, SimdRegister};
 use crate::math::Math;
-use crate::mem_loader::{IntoMemLoader, MemLoader};
+use crate::mem_loader::{IntoMemLoader, MemLoader, MemoryHint, SCRATCH_SPACE_SIZE};
+
+/// The length below which [generic_sum] skips the SIMD registers entirely and
+/// sums the elements one at a time.
+///
+/// For short vectors setting up the accumulators and reducing them back to a single
+/// value can cost more than the scalar loop.
+pub const SUM_SCALAR_THRESHOLD: usize = 16;
+
+/// How far ahead of the current dense lane [generic_sum_hint] prefetches, in bytes,
+/// when given [MemoryHint::HugePage].
+const HUGE_PAGE_PREFETCH_DISTANCE: usize = 2048;
+
+/// The assumed cache line size in bytes when issuing prefetches.
+const CACHE_LINE_SIZE: usize = 64;
+
+/// The number of elements [generic_adaptive_sum] sums in `f32` before checking the
+/// running magnitude.
+pub const ADAPTIVE_SUM_BLOCK_SIZE: usize = 1024;
+
+/// The magnitude at which [generic_adaptive_sum] promotes its running total to `f64`.
+///
+/// At `2^20` the spacing between adjacent `f32` values is `0.125`, so adding typical
+/// values to a running total this large starts to discard a noticeable part of each value.
+pub const ADAPTIVE_SUM_PROMOTE_THRESHOLD: f32 = 1048576.0;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+/// Describes whether a reduction produced a usable value, returned alongside the value so
+/// it can be checked lazily rather than validating the input in a separate pass.
+pub struct ReductionStatus {
+    /// If the result is finite, this is `false` if the input contained a non-finite value
+    /// or the reduction overflowed.
+    pub is_finite: bool,
+    /// If the input contained a `NaN` value.
+    pub saw_nan: bool,
+}
 
 #[inline(always)]
 /// A generic horizontal sum implementation over one vectors of a given set of dimensions.
 ///
+/// Vectors shorter than [SUM_SCALAR_THRESHOLD] are summed with a scalar loop, otherwise
+/// the elements left over after the last full register are zero padded and added as
+/// one more register rather than one at a time.
+///
 /// # Safety
 ///
 /// The sizes of `a` must be equal to `dims`, the safety requirements of
@@ -21,6 +62,15 @@ where
     let mut a = a.into_mem_loader();
 
     let len = a.projected_len();
+
+    if len < SUM_SCALAR_THRESHOLD {
+        let mut sum = M::zero();
+        for _ in 0..len {
+            sum = M::add(sum, a.read());
+        }
+        return sum;
+    }
+
     let offset_from = len % R::elements_per_dense();
 
     let mut sum = R::zeroed_dense();
@@ -45,11 +95,506 @@ where
         i += R::elements_per_lane();
     }
 
+    // Handle the remainder, zero padded to a full register so the padding lanes
+    // do not contribute to the sum.
+    if i < len {
+        let mut buffer = [M::zero(); SCRATCH_SPACE_SIZE];
+        for value in buffer.iter_mut().take(len - i) {
+            *value = a.read();
+        }
+        sum = R::add(sum, R::load(buffer.as_ptr()));
+    }
+
+    R::sum_to_value(sum)
+}
+
+#[inline(always)]
+/// An adaptive precision horizontal sum over a `f32` vector, accumulating in `f32` and
+/// promoting to `f64` once the running total grows large enough to lose precision.
+///
+/// ### Switch Heuristic
+///
+/// The vector is summed in blocks of [ADAPTIVE_SUM_BLOCK_SIZE] elements, each block is
+/// summed with the `f32` SIMD registers via [generic_sum]. Block totals are added to a
+/// running `f32` total until either the running total or a block total reaches a magnitude
+/// of [ADAPTIVE_SUM_PROMOTE_THRESHOLD], from then on the running total is kept in `f64`
+/// and each remaining block total is added in `f64`.
+///
+/// Well-behaved data never leaves `f32`, while large sums only accumulate the rounding
+/// error of a single block in `f32` before being promoted.
+///
+/// # Safety
+///
+/// The safety requirements of `M` definition the basic math operations and the
+/// requirements of `R` SIMD register must also be followed.
+pub unsafe fn generic_adaptive_sum<R, M>(a: &[f32]) -> f64
+where
+    R: SimdRegister<f32>,
+    M: Math<f32>,
+{
+    let mut blocks = a.chunks(ADAPTIVE_SUM_BLOCK_SIZE);
+
+    let mut total = M::zero();
+    for block in blocks.by_ref() {
+        let block_total = generic_sum::<f32, R, M, _>(block);
+        total = M::add(total, block_total);
+
+        if M::abs(total) >= ADAPTIVE_SUM_PROMOTE_THRESHOLD
+            || M::abs(block_total) >= ADAPTIVE_SUM_PROMOTE_THRESHOLD
+        {
+            break;
+        }
+    }
+
+    // Any remaining blocks are accumulated in `f64`.
+    let mut total = total as f64;
+    for block in blocks {
+        total += generic_sum::<f32, R, M, _>(block) as f64;
+    }
+
+    total
+}
+
+/// The number of dense lanes accumulated by [generic_sum_i32_widen] before the `i32`
+/// registers are widened into the `i64` total, this bounds the wrap count of each lane.
+const WIDEN_BLOCK_LANES: usize = 1 << 12;
+
+#[inline(always)]
+/// A horizontal sum over an `i32` vector, accumulating in the `i32` registers of `R`
+/// and widening to `i64` once per block.
+///
+/// The `i32` lanes are added with wrapping arithmetic, alongside a second set of
+/// registers counting how many times each lane wrapped, which is detected from the
+/// comparisons of `R`. Every [WIDEN_BLOCK_LANES] dense lanes, or at the end of the
+/// vector, each lane is widened to `lane + wraps * 2^32` and added to the `i64` total.
+/// Summing in plain `i32` would wrap once a lane passes `i32::MAX`, which only takes
+/// two large values, whereas the `i64` total can only overflow after more than `2^32`
+/// elements.
+///
+/// # Safety
+///
+/// The safety requirements of `M` definition the basic math operations and the
+/// requirements of `R` SIMD register must also be followed.
+pub unsafe fn generic_sum_i32_widen<R, M>(a: &[i32]) -> i64
+where
+    R: SimdRegister<i32>,
+    M: Math<i64>,
+{
+    let len = a.len();
+    let offset_from = len % R::elements_per_dense();
+    let a_ptr = a.as_ptr();
+
+    let mut total = M::zero();
+
+    // Operate over dense lanes first.
+    let zeroed = R::zeroed_dense();
+    let mut sum = zeroed;
+    let mut wraps = zeroed;
+    let mut lanes = 0;
+    let mut i = 0;
+    while i < (len - offset_from) {
+        let l1 = R::load_dense(a_ptr.add(i));
+        let next = R::add_dense(sum, l1);
+        // A lane wrapped up if it decreased after adding a positive value, a lane which
+        // adds a negative value always decreases unless it wrapped down.
+        let wrapped = R::sub_dense(R::lt_dense(next, sum), R::lt_dense(l1, zeroed));
+        wraps = R::add_dense(wraps, wrapped);
+        sum = next;
+
+        lanes += 1;
+        if lanes == WIDEN_BLOCK_LANES {
+            total = M::add(total, widen_wrapped_dense::<R, M>(sum, wraps));
+            sum = zeroed;
+            wraps = zeroed;
+            lanes = 0;
+        }
+
+        i += R::elements_per_dense();
+    }
+    total = M::add(total, widen_wrapped_dense::<R, M>(sum, wraps));
+
+    // Operate over single registers next.
+    let zeroed = R::zeroed();
+    let mut sum = zeroed;
+    let mut wraps = zeroed;
+    let offset_from = offset_from % R::elements_per_lane();
+    while i < (len - offset_from) {
+        let l1 = R::load(a_ptr.add(i));
+        let next = R::add(sum, l1);
+        wraps = R::add(wraps, R::sub(R::lt(next, sum), R::lt(l1, zeroed)));
+        sum = next;
+
+        i += R::elements_per_lane();
+    }
+    total = M::add(total, widen_wrapped::<R, M>(sum, wraps));
+
+    // Handle the remainder.
+    while i < len {
+        total = M::add(total, *a.get_unchecked(i) as i64);
+
+        i += 1;
+    }
+
+    total
+}
+
+#[inline(always)]
+/// Widens each register of wrapping `i32` sums in a dense lane, see [widen_wrapped].
+unsafe fn widen_wrapped_dense<R, M>(
+    sum: DenseLane<R::Register>,
+    wraps: DenseLane<R::Register>,
+) -> i64
+where
+    R: SimdRegister<i32>,
+    M: Math<i64>,
+{
+    let sums = [sum.a, sum.b, sum.c, sum.d, sum.e, sum.f, sum.g, sum.h];
+    let wraps = [
+        wraps.a, wraps.b, wraps.c, wraps.d, wraps.e, wraps.f, wraps.g, wraps.h,
+    ];
+
+    let mut total = M::zero();
+    for (sum, wraps) in sums.into_iter().zip(wraps) {
+        total = M::add(total, widen_wrapped::<R, M>(sum, wraps));
+    }
+
+    total
+}
+
+#[inline(always)]
+/// Widens a register of wrapping `i32` sums, along with the number of times each lane
+/// wrapped, into a single `i64` total.
+unsafe fn widen_wrapped<R, M>(sum: R::Register, wraps: R::Register) -> i64
+where
+    R: SimdRegister<i32>,
+    M: Math<i64>,
+{
+    let mut sum_buffer = [0; SCRATCH_SPACE_SIZE];
+    let mut wraps_buffer = [0; SCRATCH_SPACE_SIZE];
+    R::write(sum_buffer.as_mut_ptr(), sum);
+    R::write(wraps_buffer.as_mut_ptr(), wraps);
+
+    let mut total = M::zero();
+    for (sum, wraps) in sum_buffer
+        .iter()
+        .zip(wraps_buffer.iter())
+        .take(R::elements_per_lane())
+    {
+        let lane = M::add(*sum as i64, M::mul(*wraps as i64, 1 << 32));
+        total = M::add(total, lane);
+    }
+
+    total
+}
+
+#[inline(always)]
+/// A generic horizontal sum implementation which selects an inner loop tuned for the
+/// memory described by `hint`.
+///
+/// [MemoryHint::Default] is identical to [generic_sum]. [MemoryHint::HugePage] sums the
+/// elements before the first register aligned address one at a time so every dense lane
+/// load is aligned, and prefetches `2KB` ahead of each load.
+///
+/// # Safety
+///
+/// The safety requirements of `M` definition the basic math operations and the
+/// requirements of `R` SIMD register must also be followed.
+pub unsafe fn generic_sum_hint<T, R, M>(a: &[T], hint: MemoryHint) -> T
+where
+    T: Copy,
+    R: SimdRegister<T>,
+    M: Math<T>,
+{
+    match hint {
+        MemoryHint::Default => generic_sum::<T, R, M, _>(a),
+        MemoryHint::HugePage => sum_aligned_prefetch::<T, R, M>(a),
+    }
+}
+
+#[inline(always)]
+unsafe fn sum_aligned_prefetch<T, R, M>(a: &[T]) -> T
+where
+    T: Copy,
+    R: SimdRegister<T>,
+    M: Math<T>,
+{
+    let len = a.len();
+    let ptr = a.as_ptr();
+
+    // Peel off the elements before the first aligned address.
+    let head = ptr.align_offset(mem::align_of::<R::Register>()).min(len);
+    let mut head_sum = M::zero();
+    for value in &a[..head] {
+        head_sum = M::add(head_sum, *value);
+    }
+
+    let dense_bytes = R::elements_per_dense() * mem::size_of::<T>();
+    let offset_from = (len - head) % R::elements_per_dense();
+
+    let mut sum = R::zeroed_dense();
+
+    // Operate over dense lanes first.
+    let mut i = head;
+    while i < (len - offset_from) {
+        let block = ptr.add(i);
+        let ahead = block.cast::<u8>().wrapping_add(HUGE_PAGE_PREFETCH_DISTANCE);
+        let mut line = 0;
+        while line < dense_bytes {
+            prefetch_read(ahead.wrapping_add(line));
+            line += CACHE_LINE_SIZE;
+        }
+
+        let l1 = R::load_dense(block);
+        sum = R::add_dense(sum, l1);
+
+        i += R::elements_per_dense();
+    }
+
+    let mut sum = R::sum_to_register(sum);
+
+    // Operate over single registers next.
+    let offset_from = offset_from % R::elements_per_lane();
+    while i < (len - offset_from) {
+        let l1 = R::load(ptr.add(i));
+        sum = R::add(sum, l1);
+
+        i += R::elements_per_lane();
+    }
+
+    // Handle the remainder.
+    let mut sum = M::add(head_sum, R::sum_to_value(sum));
+
+    while i < len {
+        sum = M::add(sum, ptr.add(i).read());
+
+        i += 1;
+    }
+
+    sum
+}
+
+#[inline(always)]
+/// Hints to the CPU that the cache line containing `ptr` will be read soon.
+///
+/// This never faults, even if `ptr` is outside of any allocation, and is a no-op on
+/// architectures without a stable prefetch intrinsic.
+unsafe fn prefetch_read(ptr: *const u8) {
+    #[cfg(target_arch = "x86_64")]
+    core::arch::x86_64::_mm_prefetch::<{ core::arch::x86_64::_MM_HINT_T0 }>(ptr.cast());
+    #[cfg(target_arch = "x86")]
+    core::arch::x86::_mm_prefetch::<{ core::arch::x86::_MM_HINT_T0 }>(ptr.cast());
+    #[cfg(not(any(target_arch = "x86", target_arch = "x86_64")))]
+    let _ = ptr;
+}
+
+#[inline(always)]
+/// A generic horizontal sum implementation over a vector which is already a multiple
+/// of `R::elements_per_dense()` in length and aligned to `R::Register`.
+///
+/// Unlike [generic_sum], this routine only runs the dense lane loop with aligned loads
+/// and skips all tail handling, which trims the hot path for pre-padded buffers.
+///
+/// # Safety
+///
+/// The length of `a` must be a multiple of `R::elements_per_dense()` and the data
+/// pointer must be aligned to `R::Register`, these preconditions are only checked
+/// via debug assertions. The safety requirements of `M` definition the basic math
+/// operations and the requirements of `R` SIMD register must also be followed.
+pub unsafe fn generic_sum_exact<T, R, M>(a: &[T]) -> T
+where
+    T: Copy,
+    R: SimdRegister<T>,
+    M: Math<T>,
+{
+    debug_assert_eq!(
+        a.len() % R::elements_per_dense(),
+        0,
+        "Buffer `a` is not a multiple of the dense lane size"
+    );
+    debug_assert_eq!(
+        a.as_ptr() as usize % mem::align_of::<R::Register>(),
+        0,
+        "Buffer `a` is not aligned to the SIMD register"
+    );
+
+    let len = a.len();
+    let ptr = a.as_ptr();
+
+    let mut sum = R::zeroed_dense();
+
+    let mut i = 0;
+    while i < len {
+        let l1 = R::load_dense_aligned(ptr.add(i));
+        sum = R::add_dense(sum, l1);
+
+        i += R::elements_per_dense();
+    }
+
+    R::sum_to_value(R::sum_to_register(sum))
+}
+
+#[inline(always)]
+/// A generic compensated horizontal sum implementation, using Kahan-Babuška-Neumaier
+/// summation to recover the rounding error lost by each addition.
+///
+/// Every lane of the dense accumulator keeps a running sum and a parallel compensation,
+/// the exact rounding error of each lane addition is found with the branch-free TwoSum
+/// so no comparison of magnitudes is needed in the registers. Once all dense lanes are
+/// processed, the lane sums are folded together with the scalar Neumaier correction
+/// along with the remainder, and the compensation is added back last.
+///
+/// This is only meaningful for floating point types, and costs roughly four times the
+/// additions of [generic_sum]. The scalar fold compares magnitudes with [Math::abs],
+/// which overflows on the minimum value of signed integers, so `T` must be a float.
+///
+/// # Safety
+///
+/// The safety requirements of `M` definition the basic math operations and the
+/// requirements of `R` SIMD register must also be followed, and `R` must not re-associate
+/// floating point operations (i.e. no fast-math) otherwise the compensation can be
+/// optimized away.
+pub unsafe fn generic_sum_compensated<T, R, M>(a: &[T]) -> T
+where
+    T: Copy,
+    R: SimdRegister<T>,
+    M: Math<T>,
+{
+    let len = a.len();
+    let data_ptr = a.as_ptr();
+    let offset_from = len % R::elements_per_dense();
+
+    let mut sum = R::zeroed_dense();
+    let mut compensation = R::zeroed_dense();
+
+    let mut i = 0;
+    while i < (len - offset_from) {
+        let x = R::load_dense(data_ptr.add(i));
+
+        let t = R::add_dense(sum, x);
+        let z = R::sub_dense(t, sum);
+        let error =
+            R::add_dense(R::sub_dense(sum, R::sub_dense(t, z)), R::sub_dense(x, z));
+        compensation = R::add_dense(compensation, error);
+        sum = t;
+
+        i += R::elements_per_dense();
+    }
+
+    // Fold the lanes together with the Neumaier correction, a register at a time as a
+    // dense lane of narrow types does not fit in the scratch space.
+    let sums = [sum.a, sum.b, sum.c, sum.d, sum.e, sum.f, sum.g, sum.h];
+    let compensations = [
+        compensation.a,
+        compensation.b,
+        compensation.c,
+        compensation.d,
+        compensation.e,
+        compensation.f,
+        compensation.g,
+        compensation.h,
+    ];
+
+    let mut sum_buffer = [M::zero(); SCRATCH_SPACE_SIZE];
+    let mut compensation_buffer = [M::zero(); SCRATCH_SPACE_SIZE];
+
+    let mut total = M::zero();
+    let mut correction = M::zero();
+    for (sum, compensation) in sums.into_iter().zip(compensations) {
+        R::write(sum_buffer.as_mut_ptr(), sum);
+        R::write(compensation_buffer.as_mut_ptr(), compensation);
+
+        for (sum, compensation) in sum_buffer
+            .iter()
+            .zip(compensation_buffer.iter())
+            .take(R::elements_per_lane())
+        {
+            neumaier_add::<T, M>(&mut total, &mut correction, *sum);
+            correction = M::add(correction, *compensation);
+        }
+    }
+
+    // Handle the remainder.
+    while i < len {
+        neumaier_add::<T, M>(&mut total, &mut correction, *a.get_unchecked(i));
+
+        i += 1;
+    }
+
+    M::add(total, correction)
+}
+
+#[inline(always)]
+/// Adds `value` to `total`, accumulating the rounding error into `correction`.
+fn neumaier_add<T, M>(total: &mut T, correction: &mut T, value: T)
+where
+    T: Copy,
+    M: Math<T>,
+{
+    let t = M::add(*total, value);
+    let error = if M::cmp_gte(M::abs(*total), M::abs(value)) {
+        M::add(M::sub(*total, t), value)
+    } else {
+        M::add(M::sub(value, t), *total)
+    };
+    *correction = M::add(*correction, error);
+    *total = t;
+}
+
+#[inline(always)]
+/// A generic horizontal sum implementation which multiplies each element by `scale`,
+/// computing `sum(a[i] * scale)`.
+///
+/// The scale is folded into the accumulation with a fused multiply add per register,
+/// `acc = a * scale + acc`, which saves a separate multiply pass over `a` when the
+/// register supports FMA. Fixed size arrays can be passed to have the loop bounds known
+/// at compile time.
+///
+/// # Safety
+///
+/// The safety requirements of `M` definition the basic math operations and the
+/// requirements of `R` SIMD register must also be followed.
+pub unsafe fn generic_sum_scaled<T, R, M, B1>(a: B1, scale: T) -> T
+where
+    T: Copy,
+    R: SimdRegister<T>,
+    M: Math<T>,
+    B1: IntoMemLoader<T>,
+    B1::Loader: MemLoader<Value = T>,
+{
+    let mut a = a.into_mem_loader();
+
+    let len = a.projected_len();
+    let offset_from = len % R::elements_per_dense();
+
+    let scale_dense = R::filled_dense(scale);
+    let mut sum = R::zeroed_dense();
+
+    // Operate over dense lanes first.
+    let mut i = 0;
+    while i < (len - offset_from) {
+        let l1 = a.load_dense::<R>();
+        sum = R::fmadd_dense(l1, scale_dense, sum);
+
+        i += R::elements_per_dense();
+    }
+
+    let mut sum = R::sum_to_register(sum);
+
+    // Operate over single registers next.
+    let scale_reg = R::filled(scale);
+    let offset_from = offset_from % R::elements_per_lane();
+    while i < (len - offset_from) {
+        let l1 = a.load::<R>();
+        sum = R::fmadd(l1, scale_reg, sum);
+
+        i += R::elements_per_lane();
+    }
+
     // Handle the remainder.
     let mut sum = R::sum_to_value(sum);
 
     while i < len {
-        sum = M::add(sum, a.read());
+        sum = M::add(sum, M::mul(a.read(), scale));
 
         i += 1;
     }
@@ -57,10 +602,168 @@ where
     sum
 }
 
+#[inline(always)]
+/// A generic horizontal sum implementation which also reports a [ReductionStatus]
+/// describing whether the sum is finite and whether the input contained `NaN`.
+///
+/// The status is derived from the sum itself rather than checking each element, as a
+/// `NaN` or infinity in the input always propagates to the sum. The sum is finite only if
+/// `sum - sum` is zero, and if it is finite or infinite no element can have been `NaN`.
+/// Only when the sum is `NaN` is the input scanned, to tell a `NaN` element apart from
+/// infinities of opposite sign cancelling. This means finite inputs cost no more than
+/// [generic_sum].
+///
+/// # Safety
+///
+/// The safety requirements of `M` definition the basic math operations and the
+/// requirements of `R` SIMD register must also be followed.
+pub unsafe fn generic_sum_with_status<T, R, M>(a: &[T]) -> (T, ReductionStatus)
+where
+    T: Copy,
+    R: SimdRegister<T>,
+    M: Math<T>,
+{
+    let sum = generic_sum::<T, R, M, _>(a);
+
+    let is_finite = M::cmp_eq(M::sub(sum, sum), M::zero());
+    let saw_nan = !M::cmp_eq(sum, sum) && a.iter().any(|v| !M::cmp_eq(*v, *v));
+
+    (sum, ReductionStatus { is_finite, saw_nan })
+}
+
+#[inline(always)]
+/// A generic inclusive prefix sum implementation, writing `sum(x[..=i])` to `result[i]`.
+///
+/// Each register is scanned in `log2(lanes)` steps, the register is written to a
+/// scratch buffer behind a register of zeros and reloaded offset by `1, 2, 4, ...`
+/// elements, which shifts the lanes up with zeros shifted in, and added back. The
+/// running total of the previous registers is then broadcast and added before the
+/// register is written out.
+///
+/// Floating point results are not bitwise identical to a serial scan, as the lanes of a
+/// register are summed before the running total is added.
+///
+/// # Panics
+///
+/// If `x` and `result` do not match in length.
+///
+/// # Safety
+///
+/// The safety requirements of `M` definition the basic math operations and the
+/// requirements of `R` SIMD register must also be followed.
+pub unsafe fn generic_cumulative_sum<T, R, M>(x: &[T], result: &mut [T])
+where
+    T: Copy,
+    R: SimdRegister<T>,
+    M: Math<T>,
+{
+    assert_eq!(
+        x.len(),
+        result.len(),
+        "Buffers `x` and `result` do not match in size"
+    );
+
+    let len = x.len();
+    let lanes = R::elements_per_lane();
+    let offset_from = len % lanes;
+    let x_ptr = x.as_ptr();
+    let result_ptr = result.as_mut_ptr();
+
+    // The first register worth of elements stays zeroed, the register being scanned is
+    // written directly after it.
+    let mut shifted = [M::zero(); SCRATCH_SPACE_SIZE * 2];
+    let shifted_ptr = shifted.as_mut_ptr();
+
+    let mut carry = M::zero();
+    let mut i = 0;
+    while i < (len - offset_from) {
+        let mut acc = R::load(x_ptr.add(i));
+
+        let mut shift = 1;
+        while shift < lanes {
+            R::write(shifted_ptr.add(lanes), acc);
+            acc = R::add(acc, R::load(shifted_ptr.add(lanes - shift)));
+
+            shift *= 2;
+        }
+
+        R::write(result_ptr.add(i), R::add(acc, R::filled(carry)));
+        carry = result_ptr.add(i + lanes - 1).read();
+
+        i += lanes;
+    }
+
+    // Handle the remainder.
+    while i < len {
+        carry = M::add(carry, x_ptr.add(i).read());
+        result_ptr.add(i).write(carry);
+
+        i += 1;
+    }
+}
+
+#[inline(always)]
+/// A generic vertical sum implementation, adding the sum of each column of the
+/// row-major `matrix` into the matching element of `output`.
+///
+/// The number of columns is taken from the length of `output`. Each block of columns
+/// is seeded from `output` and kept in registers while iterating over every row, so
+/// successive calls accumulate into the same running total without a temporary buffer.
+///
+/// # Panics
+///
+/// If `output` is empty or `matrix` is not a multiple of `output` in length.
+///
+/// # Safety
+///
+/// The safety requirements of `M` definition the basic math operations and the
+/// requirements of `R` SIMD register must also be followed.
+pub unsafe fn generic_sum_vertical_accumulate<T, R, M>(matrix: &[T], output: &mut [T])
+where
+    T: Copy,
+    R: SimdRegister<T>,
+    M: Math<T>,
+{
+    let dims = output.len();
+    assert_ne!(dims, 0, "Buffer `output` must not be empty");
+    assert_eq!(
+        matrix.len() % dims,
+        0,
+        "Buffer `matrix` is not a multiple of `output` in length"
+    );
+
+    let num_rows = matrix.len() / dims;
+    let matrix_ptr = matrix.as_ptr();
+    let output_ptr = output.as_mut_ptr();
+
+    let offset_from = dims % R::elements_per_lane();
+    let mut i = 0;
+    while i < (dims - offset_from) {
+        let mut total = R::load(output_ptr.add(i));
+        for row in 0..num_rows {
+            total = R::add(total, R::load(matrix_ptr.add((row * dims) + i)));
+        }
+        R::write(output_ptr.add(i), total);
+
+        i += R::elements_per_lane();
+    }
+
+    // Handle the remainder.
+    while i < dims {
+        let mut total = *output_ptr.add(i);
+        for row in 0..num_rows {
+            total = M::add(total, *matrix_ptr.add((row * dims) + i));
+        }
+        *output_ptr.add(i) = total;
+
+        i += 1;
+    }
+}
+
 #[cfg(test)]
 pub(crate) unsafe fn test_sum<T, R>(l1: Vec<T>)
 where
-    T: Copy + PartialEq + std::fmt::Debug,
+    T: Copy + PartialEq + core::fmt::Debug,
     R: SimdRegister<T>,
     crate::math::AutoMath: Math<T>,
 {
@@ -74,4 +777,460 @@ where
         AutoMath::is_close(sum, expected_sum),
         "value missmatch on horizontal {sum:?} vs {expected_sum:?}"
     );
+
+    // A dense aligned embedding size and a length leaving a register and scalar tail.
+    for len in [768, 131] {
+        if len > l1.len() {
+            continue;
+        }
+
+        let l1 = &l1[..len];
+        let sum = generic_sum::<T, R, AutoMath, _>(l1);
+        let expected_sum = l1
+            .iter()
+            .fold(AutoMath::zero(), |a, b| AutoMath::add(a, *b));
+        assert!(
+            AutoMath::is_close(sum, expected_sum),
+            "value missmatch on len {len} {sum:?} vs {expected_sum:?}"
+        );
+    }
+
+    // Every remainder length after the full registers.
+    let base = R::elements_per_dense() + R::elements_per_lane();
+    for tail in 0..R::elements_per_lane() {
+        let len = base + tail;
+        if len > l1.len() {
+            continue;
+        }
+
+        let l1 = &l1[..len];
+        let sum = generic_sum::<T, R, AutoMath, _>(l1);
+        let expected_sum = l1
+            .iter()
+            .fold(AutoMath::zero(), |a, b| AutoMath::add(a, *b));
+        assert!(
+            AutoMath::is_close(sum, expected_sum),
+            "value missmatch on tail {tail} {sum:?} vs {expected_sum:?}"
+        );
+    }
+}
+
+#[cfg(test)]
+pub(crate) unsafe fn test_cumulative_sum<T, R>(l1: Vec<T>)
+where
+    T: Copy + PartialEq + core::fmt::Debug,
+    R: SimdRegister<T>,
+    crate::math::AutoMath: Math<T>,
+{
+    use crate::math::AutoMath;
+
+    for len in [1, 32, 131] {
+        let x = &l1[..len];
+        let mut result = vec![AutoMath::zero(); len];
+        generic_cumulative_sum::<T, R, AutoMath>(x, &mut result);
+
+        let mut total = AutoMath::zero();
+        for (i, value) in result.iter().enumerate() {
+            total = AutoMath::add(total, x[i]);
+            assert!(
+                AutoMath::is_close(*value, total),
+                "value missmatch on len {len} index {i} {value:?} vs {total:?}"
+            );
+        }
+
+        let sum = generic_sum::<T, R, AutoMath, _>(x);
+        assert!(
+            AutoMath::is_close(result[len - 1], sum),
+            "value missmatch on len {len} against the horizontal sum {sum:?}"
+        );
+    }
+
+    // Over the full vector the rounding of the running total drifts from a serial scan,
+    // so each step is checked against its element instead.
+    let mut result = vec![AutoMath::zero(); l1.len()];
+    generic_cumulative_sum::<T, R, AutoMath>(&l1, &mut result);
+    assert_eq!(result[0], l1[0], "value missmatch on first element");
+    for i in 1..l1.len() {
+        let step = AutoMath::sub(result[i], result[i - 1]);
+        assert!(
+            AutoMath::is_close(step, l1[i]),
+            "value missmatch on step {i} {step:?} vs {:?}",
+            l1[i]
+        );
+    }
+}
+
+#[cfg(test)]
+pub(crate) unsafe fn test_sum_vertical_accumulate<T, R>(l1: Vec<T>)
+where
+    T: Copy + PartialEq + core::fmt::Debug,
+    R: SimdRegister<T>,
+    crate::math::AutoMath: Math<T>,
+{
+    use crate::math::AutoMath;
+
+    // A single column, a length leaving a register and scalar tail, and a dense width.
+    for dims in [1, 67, 128] {
+        let num_rows = l1.len() / dims;
+        let split = (num_rows / 2) * dims;
+        let matrix = &l1[..num_rows * dims];
+
+        let mut output = vec![AutoMath::zero(); dims];
+        generic_sum_vertical_accumulate::<T, R, AutoMath>(&matrix[..split], &mut output);
+        generic_sum_vertical_accumulate::<T, R, AutoMath>(&matrix[split..], &mut output);
+
+        for (column, value) in output.iter().enumerate() {
+            let expected_value = matrix
+                .iter()
+                .skip(column)
+                .step_by(dims)
+                .fold(AutoMath::zero(), |a, b| AutoMath::add(a, *b));
+            assert!(
+                AutoMath::is_close(*value, expected_value),
+                "value missmatch on dims {dims} column {column} {value:?}"
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+pub(crate) unsafe fn test_sum_threshold<T, R>(l1: Vec<T>)
+where
+    T: Copy + PartialEq + core::fmt::Debug,
+    R: SimdRegister<T>,
+    crate::math::AutoMath: Math<T>,
+{
+    use crate::math::AutoMath;
+
+    // Cover lengths either side of the scalar threshold.
+    for len in 1..=SUM_SCALAR_THRESHOLD * 8 {
+        let l1 = &l1[..len];
+        let sum = generic_sum::<T, R, AutoMath, _>(l1);
+        let expected_sum = l1
+            .iter()
+            .fold(AutoMath::zero(), |a, b| AutoMath::add(a, *b));
+        assert!(
+            AutoMath::is_close(sum, expected_sum),
+            "value missmatch on horizontal with len {len} {sum:?} vs {expected_sum:?}"
+        );
+    }
+}
+
+#[cfg(test)]
+pub(crate) unsafe fn test_sum_hint<T, R>(value: T)
+where
+    T: Copy + PartialEq + core::fmt::Debug,
+    R: SimdRegister<T>,
+    crate::math::AutoMath: Math<T>,
+{
+    use crate::math::AutoMath;
+
+    let buffer = [value; 1043];
+    // Shift the start so the aligned path has to peel off a head.
+    for offset in [0, 1, 3] {
+        for len in [0, 1, 7, 257, 1040] {
+            let l1 = &buffer[offset..][..len];
+            let expected_sum = generic_sum::<T, R, AutoMath, _>(l1);
+
+            for hint in [MemoryHint::Default, MemoryHint::HugePage] {
+                let sum = generic_sum_hint::<T, R, AutoMath>(l1, hint);
+                assert_eq!(
+                    sum, expected_sum,
+                    "value missmatch with {hint:?} at offset {offset} len {len}"
+                );
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+pub(crate) unsafe fn test_adaptive_sum<R>()
+where
+    R: SimdRegister<f32>,
+{
+    use crate::math::AutoMath;
+
+    // Small sums never leave `f32`.
+    let l1 = vec![0.5f32; 1043];
+    let sum = generic_adaptive_sum::<R, AutoMath>(&l1);
+    assert_eq!(sum, 521.5, "value missmatch on small adaptive sum");
+
+    // The promoting case sums millions of elements, which is far too slow under miri.
+    if cfg!(miri) {
+        return;
+    }
+
+    // Large enough to trigger the switch to `f64` early on.
+    let l1 = (0..4_000_000)
+        .map(|i| 1.1f32 + (i % 7) as f32)
+        .collect::<Vec<_>>();
+    let expected_sum = l1.iter().map(|v| *v as f64).sum::<f64>();
+
+    let sum = generic_adaptive_sum::<R, AutoMath>(&l1);
+    let relative_error = ((sum - expected_sum) / expected_sum).abs();
+    assert!(
+        relative_error < 1e-6,
+        "value missmatch on adaptive sum {sum} vs {expected_sum}"
+    );
+
+    // Staying in `f32` the whole time would be far less accurate.
+    let naive_sum = l1.iter().fold(0.0f32, |a, b| a + b) as f64;
+    let naive_error = ((naive_sum - expected_sum) / expected_sum).abs();
+    assert!(
+        naive_error > relative_error,
+        "naive sum should be less accurate, {naive_error} vs {relative_error}"
+    );
+}
+
+#[cfg(test)]
+pub(crate) unsafe fn test_sum_i32_widen<R>()
+where
+    R: SimdRegister<i32>,
+{
+    use crate::math::AutoMath;
+
+    let (l1, _) = crate::test_utils::get_sample_vectors::<i32>(1043);
+    for len in [0, 1, 7, 131, 1043] {
+        let a = &l1[..len];
+        let sum = generic_sum_i32_widen::<R, AutoMath>(a);
+        let expected_sum = a.iter().map(|v| *v as i64).sum::<i64>();
+        assert_eq!(sum, expected_sum, "value missmatch on len {len}");
+    }
+
+    // Any two of these values would already wrap an `i32` accumulator.
+    for value in [i32::MAX, i32::MIN] {
+        let l1 = vec![value; 1043];
+        let sum = generic_sum_i32_widen::<R, AutoMath>(&l1);
+        assert_eq!(sum, value as i64 * 1043, "value missmatch on {value}");
+    }
+
+    // Cross a block boundary so the lanes are widened mid way through the vector,
+    // with lanes that wrap both up and down.
+    if !cfg!(miri) {
+        let len = WIDEN_BLOCK_LANES * R::elements_per_dense() + 131;
+        for pattern in [[i32::MAX, i32::MAX - 7, i32::MIN], [i32::MIN, i32::MIN, 5]] {
+            let l1 = pattern.into_iter().cycle().take(len).collect::<Vec<_>>();
+            let sum = generic_sum_i32_widen::<R, AutoMath>(&l1);
+            let expected_sum = l1.iter().map(|v| *v as i64).sum::<i64>();
+            assert_eq!(sum, expected_sum, "value missmatch across blocks");
+        }
+    }
+}
+
+#[cfg(test)]
+pub(crate) unsafe fn test_sum_exact<T, R>(value: T)
+where
+    T: Copy + PartialEq + core::fmt::Debug,
+    R: SimdRegister<T>,
+    crate::math::AutoMath: Math<T>,
+{
+    use crate::math::AutoMath;
+
+    // 512 elements is a multiple of every dense lane size we support.
+    let len = 512;
+    let align = mem::align_of::<R::Register>();
+    let buffer = vec![value; len + align];
+    let offset = buffer.as_ptr().align_offset(align);
+    let l1 = &buffer[offset..][..len];
+
+    let sum = generic_sum_exact::<T, R, AutoMath>(l1);
+    let expected_sum = generic_sum::<T, R, AutoMath, _>(l1);
+    assert!(
+        AutoMath::is_close(sum, expected_sum),
+        "value missmatch on horizontal {sum:?} vs {expected_sum:?}"
+    );
+}
+
+#[cfg(test)]
+pub(crate) unsafe fn test_sum_with_status<T, R>(l1: Vec<T>, nan: T, infinity: T)
+where
+    T: Copy + PartialEq + core::fmt::Debug,
+    R: SimdRegister<T>,
+    crate::math::AutoMath: Math<T>,
+{
+    use crate::math::AutoMath;
+
+    let finite = ReductionStatus {
+        is_finite: true,
+        saw_nan: false,
+    };
+
+    let (sum, status) = generic_sum_with_status::<T, R, AutoMath>(&l1);
+    let expected_sum = generic_sum::<T, R, AutoMath, _>(&l1);
+    assert_eq!(sum, expected_sum, "value missmatch on finite input");
+    assert_eq!(status, finite, "status missmatch on finite input");
+
+    let (_, status) = generic_sum_with_status::<T, R, AutoMath>(&[]);
+    assert_eq!(status, finite, "status missmatch on empty input");
+
+    // Check the special values land in the dense, single register and remainder loops.
+    for idx in [0, l1.len() / 2, l1.len() - 1] {
+        let mut values = l1.clone();
+        values[idx] = nan;
+        let (sum, status) = generic_sum_with_status::<T, R, AutoMath>(&values);
+        assert!(
+            !AutoMath::cmp_eq(sum, sum),
+            "sum should be NaN with a NaN element at {idx}"
+        );
+        assert_eq!(
+            status,
+            ReductionStatus {
+                is_finite: false,
+                saw_nan: true,
+            },
+            "status missmatch with a NaN element at {idx}"
+        );
+
+        let mut values = l1.clone();
+        values[idx] = infinity;
+        let (sum, status) = generic_sum_with_status::<T, R, AutoMath>(&values);
+        assert_eq!(
+            sum, infinity,
+            "value missmatch with an infinite element at {idx}"
+        );
+        assert_eq!(
+            status,
+            ReductionStatus {
+                is_finite: false,
+                saw_nan: false,
+            },
+            "status missmatch with an infinite element at {idx}"
+        );
+    }
+
+    // Infinities of opposite sign sum to `NaN` without the input containing `NaN`.
+    let mut values = l1.clone();
+    values[0] = infinity;
+    values[l1.len() - 1] = AutoMath::sub(AutoMath::zero(), infinity);
+    let (sum, status) = generic_sum_with_status::<T, R, AutoMath>(&values);
+    assert!(
+        !AutoMath::cmp_eq(sum, sum),
+        "sum should be NaN with cancelling infinities"
+    );
+    assert_eq!(
+        status,
+        ReductionStatus {
+            is_finite: false,
+            saw_nan: false,
+        },
+        "status missmatch with cancelling infinities"
+    );
+
+    // Finite elements which overflow the sum.
+    let values = vec![AutoMath::max(); 64];
+    let (sum, status) = generic_sum_with_status::<T, R, AutoMath>(&values);
+    assert_eq!(sum, infinity, "value missmatch on overflowing input");
+    assert_eq!(
+        status,
+        ReductionStatus {
+            is_finite: false,
+            saw_nan: false,
+        },
+        "status missmatch on overflowing input"
+    );
+}
+
+#[cfg(test)]
+pub(crate) unsafe fn test_sum_scaled<T, R>(l1: Vec<T>, scale: T)
+where
+    T: Copy + PartialEq + core::fmt::Debug,
+    R: SimdRegister<T>,
+    crate::math::AutoMath: Math<T>,
+{
+    use crate::math::AutoMath;
+
+    // Lengths which are not a multiple of the dense lane size exercise the tail.
+    for len in [0, 1, 31, 33, 67, l1.len()] {
+        let l1 = &l1[..len];
+        let sum = generic_sum_scaled::<T, R, AutoMath, _>(l1, scale);
+        let expected_sum = l1.iter().fold(AutoMath::zero(), |a, b| {
+            AutoMath::add(a, AutoMath::mul(*b, scale))
+        });
+        assert!(
+            AutoMath::is_close(sum, expected_sum),
+            "value missmatch on scaled sum with len {len} {sum:?} vs {expected_sum:?}"
+        );
+    }
+
+    // Fixed size arrays have their length known at compile time.
+    let fixed: [T; 67] = l1[..67].try_into().unwrap();
+    let sum = generic_sum_scaled::<T, R, AutoMath, _>(&fixed, scale);
+    let expected_sum = generic_sum_scaled::<T, R, AutoMath, _>(&l1[..67], scale);
+    assert_eq!(sum, expected_sum, "value missmatch on fixed scaled sum");
+}
+
+#[cfg(test)]
+pub(crate) unsafe fn test_sum_compensated<T, R>(l1: Vec<T>, big: T, ulp: T)
+where
+    T: Copy + PartialEq + core::fmt::Debug,
+    R: SimdRegister<T>,
+    crate::math::AutoMath: Math<T>,
+{
+    use crate::math::AutoMath;
+
+    let neumaier_sum = |values: &[T]| {
+        let mut total = AutoMath::zero();
+        let mut correction = AutoMath::zero();
+        for value in values {
+            neumaier_add::<T, AutoMath>(&mut total, &mut correction, *value);
+        }
+        AutoMath::add(total, correction)
+    };
+
+    let sum = generic_sum_compensated::<T, R, AutoMath>(&l1);
+    let expected_sum = neumaier_sum(&l1);
+    assert!(
+        AutoMath::is_close(sum, expected_sum),
+        "value missmatch on horizontal {sum:?} vs {expected_sum:?}"
+    );
+
+    // Enough elements for two dense lanes and a remainder, a dense lane of `f32` is
+    // larger than the scratch space on AVX512.
+    let len = R::elements_per_dense() * 2 + 3;
+    let values = l1.iter().copied().cycle().take(len).collect::<Vec<_>>();
+    let sum = generic_sum_compensated::<T, R, AutoMath>(&values);
+    let expected_sum = neumaier_sum(&values);
+    assert!(
+        AutoMath::is_close(sum, expected_sum),
+        "value missmatch on len {len} {sum:?} vs {expected_sum:?}"
+    );
+
+    // Adding `1` to `big` is lost to rounding, so a naive sum drops most of the ones.
+    // Lane counts are powers of two so with a period of `3` every lane mixes all terms.
+    let pattern = [big, AutoMath::one(), AutoMath::sub(AutoMath::zero(), big)];
+    let adversarial = pattern
+        .iter()
+        .copied()
+        .cycle()
+        .take(1043)
+        .collect::<Vec<_>>();
+    let ones = adversarial
+        .iter()
+        .filter(|v| **v == AutoMath::one())
+        .fold(AutoMath::zero(), |a, b| AutoMath::add(a, *b));
+    let exact_sum = AutoMath::add(big, ones);
+
+    let reference = neumaier_sum(&adversarial);
+
+    let error = |value: T| {
+        let diff = AutoMath::sub(value, reference);
+        AutoMath::abs(diff)
+    };
+
+    let sum = generic_sum_compensated::<T, R, AutoMath>(&adversarial);
+    assert!(
+        AutoMath::cmp_lte(error(sum), ulp),
+        "value missmatch on compensated sum {sum:?} vs {reference:?}"
+    );
+    assert!(
+        AutoMath::cmp_lte(error(exact_sum), ulp),
+        "scalar reference should match the exact sum {reference:?} vs {exact_sum:?}"
+    );
+
+    let plain = generic_sum::<T, R, AutoMath, _>(&adversarial);
+    let many_ulps = (0..16).fold(AutoMath::zero(), |a, _| AutoMath::add(a, ulp));
+    assert!(
+        AutoMath::cmp_gt(error(plain), many_ulps),
+        "plain sum should lose the small terms {plain:?} vs {reference:?}"
+    );
 }
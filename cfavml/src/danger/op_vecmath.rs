@@ -0,0 +1,998 @@
+use crate::danger::{generic_cmp_max, SimdRegister};
+use crate::math::Math;
+use crate::mem_loader::SCRATCH_SPACE_SIZE;
+
+/// The maximum relative error of [generic_exp_inplace] over inputs which neither
+/// overflow nor underflow.
+pub const EXP_MAX_RELATIVE_ERROR: f32 = 3e-7;
+/// The maximum relative error of [generic_log_inplace] over positive inputs.
+pub const LOG_MAX_RELATIVE_ERROR: f32 = 3e-7;
+/// The maximum relative error of [generic_pow_vertical] over positive bases where the
+/// result neither overflows nor underflows.
+///
+/// The error of `log2(base)` is scaled by `exponent * log2(base)` when raised back out,
+/// the bound covers results up to the `f64` overflow threshold.
+pub const POW_MAX_RELATIVE_ERROR: f64 = 1e-12;
+/// The maximum relative error of [generic_softplus_inplace] over inputs above `-86`.
+///
+/// This is the error of the inner `e^x` carried through `log1p`, which does not amplify
+/// it, plus the rounding of the remaining operations. Below `-86` the result is
+/// subnormal and loses precision.
+pub const SOFTPLUS_MAX_RELATIVE_ERROR: f32 = 1e-6;
+/// The maximum absolute error of [generic_gelu_inplace] against the exact tanh form of
+/// GELU for inputs within `[-1, 1]`, outside of this range the bound scales with `|x|`.
+///
+/// The tanh form itself differs from the exact `erf` form of GELU by up to `4.8e-4`,
+/// the largest difference being around `x = -2.7`.
+pub const GELU_MAX_ABSOLUTE_ERROR: f32 = 1e-6;
+/// The maximum absolute error of each probability from [generic_softmax].
+///
+/// The `2^x` polynomial has a relative error below `1e-15`, rounding
+/// `(x - max) * log2(e)` adds a relative error which grows with `|x - max|` but stays
+/// around `1e-13` before the exponential is flushed to zero.
+pub const SOFTMAX_MAX_ABSOLUTE_ERROR: f64 = 1e-12;
+
+/// Inputs above this value overflow to infinity in the `exp` routines.
+///
+/// This is slightly below `ln(f32::MAX)`, as `2^128` is not representable when scaling
+/// the reduced result.
+const EXP_OVERFLOW: f32 = 88.376_26;
+/// Inputs below this value underflow to zero in the `exp` routines, subnormal results
+/// are flushed to zero.
+const EXP_UNDERFLOW: f32 = -87.336_54;
+const LOG2_E: f32 = core::f32::consts::LOG2_E;
+const LN_2: f32 = core::f32::consts::LN_2;
+// `ln(2)` split into a high part exactly representable with few mantissa bits and
+// the remaining low part, so the range reduction does not lose precision.
+const LN_2_HI: f32 = 0.693_359_4;
+const LN_2_LO: f32 = -2.121_944_4e-4;
+// `-2 * sqrt(2 / pi)` and the cubic coefficient of the tanh form of GELU.
+const GELU_SCALE: f32 = -1.595_769_2;
+const GELU_CUBIC: f32 = 0.044_715;
+// The degree 6 Taylor polynomial of `e^r`, highest order first.
+const EXP_COEFFS: [f32; 7] = [
+    1.0 / 720.0,
+    1.0 / 120.0,
+    1.0 / 24.0,
+    1.0 / 6.0,
+    0.5,
+    1.0,
+    1.0,
+];
+// The series of `atanh(s) / s` in `s^2`, highest order first.
+const LOG_COEFFS: [f32; 5] = [1.0 / 9.0, 1.0 / 7.0, 1.0 / 5.0, 1.0 / 3.0, 1.0];
+// The series of `atanh(s) / s` in `s^2` for `log1p`, which only needs `s <= 1 / 3` so
+// has no range reduction and instead carries enough terms to reach `f32` precision.
+const LOG1P_COEFFS: [f32; 8] = [
+    1.0 / 15.0,
+    1.0 / 13.0,
+    1.0 / 11.0,
+    1.0 / 9.0,
+    1.0 / 7.0,
+    1.0 / 5.0,
+    1.0 / 3.0,
+    1.0,
+];
+// The `f64` series of `atanh(s) / s` in `s^2`, highest order first, enough terms to
+// reach double precision over the reduced range.
+const LOG_COEFFS_F64: [f64; 11] = [
+    1.0 / 21.0,
+    1.0 / 19.0,
+    1.0 / 17.0,
+    1.0 / 15.0,
+    1.0 / 13.0,
+    1.0 / 11.0,
+    1.0 / 9.0,
+    1.0 / 7.0,
+    1.0 / 5.0,
+    1.0 / 3.0,
+    1.0,
+];
+
+// The degree 14 Taylor polynomial of `e^r` for the `f64` reduced range, highest order
+// first.
+const EXP2_COEFFS_F64: [f64; 15] = [
+    1.0 / 87_178_291_200.0,
+    1.0 / 6_227_020_800.0,
+    1.0 / 479_001_600.0,
+    1.0 / 39_916_800.0,
+    1.0 / 3_628_800.0,
+    1.0 / 362_880.0,
+    1.0 / 40_320.0,
+    1.0 / 5_040.0,
+    1.0 / 720.0,
+    1.0 / 120.0,
+    1.0 / 24.0,
+    1.0 / 6.0,
+    0.5,
+    1.0,
+    1.0,
+];
+
+#[inline(always)]
+/// A generic element-wise `e^x` implementation over a `f32` vector, writing the result
+/// back into `a`.
+///
+/// ### Approximation
+///
+/// Each element is reduced to `n * ln(2) + r` where `|r| <= ln(2) / 2`, `e^r` is evaluated
+/// with a degree 6 Taylor polynomial in the `R` registers and scaled by `2^n` via the
+/// exponent bits. The relative error is below [EXP_MAX_RELATIVE_ERROR], inputs above
+/// `88.376` overflow to infinity and inputs below `-87.336` are flushed to zero.
+///
+/// # Safety
+///
+/// The safety requirements of `M` definition the basic math operations and the
+/// requirements of `R` SIMD register must also be followed.
+pub unsafe fn generic_exp_inplace<R, M>(a: &mut [f32])
+where
+    R: SimdRegister<f32>,
+    M: Math<f32>,
+{
+    let len = a.len();
+    let data_ptr = a.as_mut_ptr();
+    let offset_from = len % R::elements_per_lane();
+
+    let mut reduced = [M::zero(); SCRATCH_SPACE_SIZE];
+    let mut scales = [M::zero(); SCRATCH_SPACE_SIZE];
+
+    let mut i = 0;
+    while i < (len - offset_from) {
+        for j in 0..R::elements_per_lane() {
+            let (r, scale) = exp_reduce(data_ptr.add(i + j).read());
+            reduced[j] = r;
+            scales[j] = scale;
+        }
+
+        let r = R::load(reduced.as_ptr());
+        let poly = horner_register::<R>(&EXP_COEFFS, r);
+        R::write(data_ptr.add(i), R::mul(poly, R::load(scales.as_ptr())));
+
+        i += R::elements_per_lane();
+    }
+
+    // Handle the remainder.
+    while i < len {
+        let value = data_ptr.add(i);
+        value.write(exp_f32(value.read()));
+
+        i += 1;
+    }
+}
+
+#[inline(always)]
+/// A generic element-wise natural logarithm implementation over a `f32` vector, writing
+/// the result back into `a`.
+///
+/// ### Approximation
+///
+/// Each element is split into `m * 2^e` where `m` is within `[sqrt(2) / 2, sqrt(2)]`,
+/// `ln(m)` is evaluated with the series `2 * atanh((m - 1) / (m + 1))` in the `R` registers
+/// and `e * ln(2)` is added. The relative error is below [LOG_MAX_RELATIVE_ERROR], zero
+/// gives negative infinity and negative values give NaN.
+///
+/// # Safety
+///
+/// The safety requirements of `M` definition the basic math operations and the
+/// requirements of `R` SIMD register must also be followed.
+pub unsafe fn generic_log_inplace<R, M>(a: &mut [f32])
+where
+    R: SimdRegister<f32>,
+    M: Math<f32>,
+{
+    let len = a.len();
+    let data_ptr = a.as_mut_ptr();
+    let offset_from = len % R::elements_per_lane();
+
+    let mut reduced = [M::zero(); SCRATCH_SPACE_SIZE];
+    let mut exponents = [M::zero(); SCRATCH_SPACE_SIZE];
+
+    let mut i = 0;
+    while i < (len - offset_from) {
+        for j in 0..R::elements_per_lane() {
+            let (s, exponent) = log_reduce(data_ptr.add(i + j).read());
+            reduced[j] = s;
+            exponents[j] = exponent;
+        }
+
+        let s = R::load(reduced.as_ptr());
+        let poly = horner_register::<R>(&LOG_COEFFS, R::mul(s, s));
+        let result = R::fmadd(R::add(s, s), poly, R::load(exponents.as_ptr()));
+        R::write(data_ptr.add(i), result);
+
+        i += R::elements_per_lane();
+    }
+
+    // Handle the remainder.
+    while i < len {
+        let value = data_ptr.add(i);
+        value.write(ln_f32(value.read()));
+
+        i += 1;
+    }
+}
+
+#[inline(always)]
+/// A generic element-wise softplus implementation over a `f32` vector, computing
+/// `ln(1 + e^x)` and writing the result back into `a`.
+///
+/// ### Approximation
+///
+/// The stable form `max(x, 0) + log1p(e^-|x|)` is used so large inputs do not overflow.
+/// `e^-|x|` is reduced as in [generic_exp_inplace], then `log1p(y)` is evaluated as
+/// `2 * atanh(y / (2 + y))` with the `atanh` series in the `R` registers, which keeps
+/// the precision of small `y` where `ln(1 + y)` would round to zero. The relative error
+/// is below [SOFTPLUS_MAX_RELATIVE_ERROR].
+///
+/// Inputs below `-87.336` give zero as `e^x` is flushed to zero, infinities map to
+/// themselves or zero and `NaN` stays `NaN`.
+///
+/// # Safety
+///
+/// The safety requirements of `M` definition the basic math operations and the
+/// requirements of `R` SIMD register must also be followed.
+pub unsafe fn generic_softplus_inplace<R, M>(a: &mut [f32])
+where
+    R: SimdRegister<f32>,
+    M: Math<f32>,
+{
+    let len = a.len();
+    let data_ptr = a.as_mut_ptr();
+    let offset_from = len % R::elements_per_lane();
+
+    let mut reduced = [M::zero(); SCRATCH_SPACE_SIZE];
+    let mut scales = [M::zero(); SCRATCH_SPACE_SIZE];
+
+    let zero = R::zeroed();
+    let two = R::filled(2.0);
+    let mut i = 0;
+    while i < (len - offset_from) {
+        for j in 0..R::elements_per_lane() {
+            let (r, scale) = exp_reduce(neg_abs(data_ptr.add(i + j).read()));
+            reduced[j] = r;
+            scales[j] = scale;
+        }
+
+        let r = R::load(reduced.as_ptr());
+        let y = R::mul(
+            horner_register::<R>(&EXP_COEFFS, r),
+            R::load(scales.as_ptr()),
+        );
+        let s = R::div(y, R::add(y, two));
+        let poly = horner_register::<R>(&LOG1P_COEFFS, R::mul(s, s));
+
+        let positive = R::max(R::load(data_ptr.add(i)), zero);
+        R::write(data_ptr.add(i), R::fmadd(R::add(s, s), poly, positive));
+
+        i += R::elements_per_lane();
+    }
+
+    // Handle the remainder.
+    while i < len {
+        let value = data_ptr.add(i);
+        value.write(softplus_f32(value.read()));
+
+        i += 1;
+    }
+}
+
+#[inline(always)]
+/// A generic element-wise GELU activation implementation over a `f32` vector, using the
+/// tanh form `0.5 * x * (1 + tanh(sqrt(2 / pi) * (x + 0.044715 * x^3)))` and writing the
+/// result back into `a`.
+///
+/// ### Approximation
+///
+/// As `0.5 * (1 + tanh(u))` is `1 / (1 + e^(-2 * u))` the activation is evaluated as
+/// `x / (1 + e^(-2 * u))`, with `e^(-2 * u)` reduced as in [generic_exp_inplace] and its
+/// polynomial and the division done in the `R` registers. The absolute error against the
+/// tanh form is below [GELU_MAX_ABSOLUTE_ERROR] for `|x| <= 1` and scales with `|x|`
+/// beyond it.
+///
+/// Large negative inputs give `-0.0` and positive infinity maps to itself, negative
+/// infinity and `NaN` give `NaN`.
+///
+/// # Safety
+///
+/// The safety requirements of `M` definition the basic math operations and the
+/// requirements of `R` SIMD register must also be followed.
+pub unsafe fn generic_gelu_inplace<R, M>(a: &mut [f32])
+where
+    R: SimdRegister<f32>,
+    M: Math<f32>,
+{
+    let len = a.len();
+    let data_ptr = a.as_mut_ptr();
+    let offset_from = len % R::elements_per_lane();
+
+    let mut reduced = [M::zero(); SCRATCH_SPACE_SIZE];
+    let mut scales = [M::zero(); SCRATCH_SPACE_SIZE];
+
+    let one = R::filled(M::one());
+    let mut i = 0;
+    while i < (len - offset_from) {
+        for j in 0..R::elements_per_lane() {
+            let (r, scale) = exp_reduce(gelu_exponent(data_ptr.add(i + j).read()));
+            reduced[j] = r;
+            scales[j] = scale;
+        }
+
+        let r = R::load(reduced.as_ptr());
+        let e = R::mul(
+            horner_register::<R>(&EXP_COEFFS, r),
+            R::load(scales.as_ptr()),
+        );
+        let x = R::load(data_ptr.add(i));
+        R::write(data_ptr.add(i), R::div(x, R::add(one, e)));
+
+        i += R::elements_per_lane();
+    }
+
+    // Handle the remainder.
+    while i < len {
+        let value = data_ptr.add(i);
+        let x = value.read();
+        value.write(x / (1.0 + exp_f32(gelu_exponent(x))));
+
+        i += 1;
+    }
+}
+
+#[inline(always)]
+/// A generic element-wise square root implementation over a `f32` vector, writing
+/// the result back into `a`.
+///
+/// With the `std` feature enabled the result is correctly rounded, and compiled with the
+/// target features of the export, the loop is lowered to the native square root
+/// instruction, i.e. `_mm256_sqrt_ps` with AVX2. Without `std` the approximation of `M`
+/// is used instead.
+///
+/// # Safety
+///
+/// The safety requirements of `M` definition the basic math operations must be followed.
+pub unsafe fn generic_sqrt_inplace<M>(a: &mut [f32])
+where
+    M: Math<f32>,
+{
+    for value in a.iter_mut() {
+        *value = M::sqrt(*value);
+    }
+}
+
+#[inline(always)]
+/// A generic element-wise `base[i] ^ exponent[i]` implementation over `f64` vectors,
+/// writing the result to `result`.
+///
+/// ### Approximation
+///
+/// Each power is evaluated as `2 ^ (exponent[i] * log2(base[i]))`, the `log2(base[i])`
+/// of a dense lane is computed into a scratch buffer, scaled by the exponents in the `R`
+/// registers and raised back out element by element. The relative error is below
+/// [POW_MAX_RELATIVE_ERROR] for positive bases.
+///
+/// A zero exponent always gives `1`, a zero base gives `0` for a positive exponent and
+/// infinity for a negative exponent, and a negative base gives `NaN` regardless of
+/// whether the exponent is an integer.
+///
+/// # Panics
+///
+/// If the lengths of `base`, `exponent` and `result` do not match.
+///
+/// # Safety
+///
+/// The safety requirements of `M` definition the basic math operations and the
+/// requirements of `R` SIMD register must also be followed.
+pub unsafe fn generic_pow_vertical<R, M>(
+    base: &[f64],
+    exponent: &[f64],
+    result: &mut [f64],
+) where
+    R: SimdRegister<f64>,
+    M: Math<f64>,
+{
+    assert_eq!(
+        base.len(),
+        exponent.len(),
+        "Buffers `base` and `exponent` do not match in size"
+    );
+    assert_eq!(
+        result.len(),
+        base.len(),
+        "Buffer `result` does not match the size of `base`"
+    );
+
+    let len = base.len();
+    let offset_from = len % R::elements_per_dense();
+
+    let mut logs = [M::zero(); SCRATCH_SPACE_SIZE];
+
+    let mut i = 0;
+    while i < (len - offset_from) {
+        let block = base.get_unchecked(i..i + R::elements_per_dense());
+        for (dst, value) in logs.iter_mut().zip(block) {
+            *dst = log2_f64(*value);
+        }
+
+        let l1 = R::load_dense(exponent.as_ptr().add(i));
+        let scaled = R::mul_dense(l1, R::load_dense(logs.as_ptr()));
+        R::write_dense(logs.as_mut_ptr(), scaled);
+
+        for (j, scaled) in logs.iter().take(R::elements_per_dense()).enumerate() {
+            let power = pow_from_log2::<M>(*exponent.get_unchecked(i + j), *scaled);
+            *result.get_unchecked_mut(i + j) = power;
+        }
+
+        i += R::elements_per_dense();
+    }
+
+    // Handle the remainder.
+    while i < len {
+        let exponent = *exponent.get_unchecked(i);
+        let scaled = M::mul(exponent, log2_f64(*base.get_unchecked(i)));
+        *result.get_unchecked_mut(i) = pow_from_log2::<M>(exponent, scaled);
+
+        i += 1;
+    }
+}
+
+#[inline(always)]
+/// Raises `2 ^ scaled` where `scaled = exponent * log2(base)`, a zero exponent gives `1`
+/// even when `scaled` is `NaN` from an infinite or `NaN` base.
+fn pow_from_log2<M>(exponent: f64, scaled: f64) -> f64
+where
+    M: Math<f64>,
+{
+    if M::cmp_eq(exponent, M::zero()) {
+        M::one()
+    } else {
+        exp2_f64(scaled)
+    }
+}
+
+#[inline(always)]
+/// A generic numerically stable softmax implementation over a `f64` vector, writing
+/// `exp(x[i] - max(x)) / sum(exp(x - max(x)))` to `result`.
+///
+/// ### Approximation
+///
+/// The max of `x` is found with [generic_cmp_max] and subtracted from a dense lane at a
+/// time in the `R` registers, so no exponential can overflow and the sum is always at
+/// least `1`. Each exponential is then evaluated element by element into a scratch
+/// buffer as `2 ^ ((x[i] - max) * log2(e))`, a scalar polynomial rather than a
+/// vectorized one, before being summed and normalized in the `R` registers. Each
+/// probability is within an absolute error of [SOFTMAX_MAX_ABSOLUTE_ERROR].
+///
+/// The values of `x` are assumed to be finite, an empty vector is left untouched.
+///
+/// # Panics
+///
+/// If `x` and `result` are not the same length.
+///
+/// # Safety
+///
+/// The safety requirements of `M` definition the basic math operations and the
+/// requirements of `R` SIMD register must also be followed.
+pub unsafe fn generic_softmax<R, M>(x: &[f64], result: &mut [f64])
+where
+    R: SimdRegister<f64>,
+    M: Math<f64>,
+{
+    assert_eq!(
+        x.len(),
+        result.len(),
+        "Buffers `x` and `result` do not match in size"
+    );
+
+    let max = generic_cmp_max::<f64, R, M, _>(x);
+    softmax::<R, M>(x.as_ptr(), max, result.as_mut_ptr(), x.len())
+}
+
+#[inline(always)]
+/// A generic numerically stable softmax implementation over a `f64` vector, writing the
+/// result back into `a`.
+///
+/// This is [generic_softmax] where the result aliases the input.
+///
+/// # Safety
+///
+/// The safety requirements of `M` definition the basic math operations and the
+/// requirements of `R` SIMD register must also be followed.
+pub unsafe fn generic_softmax_inplace<R, M>(a: &mut [f64])
+where
+    R: SimdRegister<f64>,
+    M: Math<f64>,
+{
+    let max = generic_cmp_max::<f64, R, M, _>(&*a);
+    let a_ptr = a.as_mut_ptr();
+    softmax::<R, M>(a_ptr, max, a_ptr, a.len())
+}
+
+#[inline(always)]
+unsafe fn softmax<R, M>(x_ptr: *const f64, max: f64, out_ptr: *mut f64, len: usize)
+where
+    R: SimdRegister<f64>,
+    M: Math<f64>,
+{
+    if len == 0 {
+        return;
+    }
+
+    let offset_from = len % R::elements_per_dense();
+
+    let mut buffer = [M::zero(); SCRATCH_SPACE_SIZE];
+
+    // The exponentials are written to `out_ptr` and summed.
+    let max_dense = R::filled_dense(max);
+    let log2_e_dense = R::filled_dense(core::f64::consts::LOG2_E);
+    let mut total = R::zeroed_dense();
+    let mut i = 0;
+    while i < (len - offset_from) {
+        let l1 = R::load_dense(x_ptr.add(i));
+        let scaled = R::mul_dense(R::sub_dense(l1, max_dense), log2_e_dense);
+        R::write_dense(buffer.as_mut_ptr(), scaled);
+
+        for value in buffer.get_unchecked_mut(..R::elements_per_dense()) {
+            *value = exp2_f64(*value);
+        }
+
+        let exp = R::load_dense(buffer.as_ptr());
+        total = R::add_dense(total, exp);
+        R::write_dense(out_ptr.add(i), exp);
+
+        i += R::elements_per_dense();
+    }
+
+    let mut total = R::sum_to_value(R::sum_to_register(total));
+    while i < len {
+        let scaled = M::mul(M::sub(x_ptr.add(i).read(), max), core::f64::consts::LOG2_E);
+        let exp = exp2_f64(scaled);
+        total = M::add(total, exp);
+        out_ptr.add(i).write(exp);
+
+        i += 1;
+    }
+
+    // Normalize the exponentials by their sum.
+    let total_dense = R::filled_dense(total);
+    let mut i = 0;
+    while i < (len - offset_from) {
+        let l1 = R::load_dense(out_ptr.add(i));
+        R::write_dense(out_ptr.add(i), R::div_dense(l1, total_dense));
+
+        i += R::elements_per_dense();
+    }
+
+    // Handle the remainder.
+    while i < len {
+        out_ptr.add(i).write(M::div(out_ptr.add(i).read(), total));
+
+        i += 1;
+    }
+}
+
+#[inline(always)]
+/// Approximates `e^x` for a single value, see [generic_exp_inplace].
+pub(crate) fn exp_f32(x: f32) -> f32 {
+    let (r, scale) = exp_reduce(x);
+    horner(&EXP_COEFFS, r) * scale
+}
+
+#[inline(always)]
+/// Approximates `ln(x)` for a single value, see [generic_log_inplace].
+pub(crate) fn ln_f32(x: f32) -> f32 {
+    let (s, exponent) = log_reduce(x);
+    exponent + 2.0 * s * horner(&LOG_COEFFS, s * s)
+}
+
+#[inline(always)]
+/// Approximates `ln(1 + e^x)` for a single value, see [generic_softplus_inplace].
+fn softplus_f32(x: f32) -> f32 {
+    let y = exp_f32(neg_abs(x));
+    let s = y / (y + 2.0);
+    let positive = if x > 0.0 { x } else { 0.0 };
+    positive + 2.0 * s * horner(&LOG1P_COEFFS, s * s)
+}
+
+#[inline(always)]
+/// Returns `-2 * sqrt(2 / pi) * (x + 0.044715 * x^3)`, the exponent of the GELU sigmoid.
+fn gelu_exponent(x: f32) -> f32 {
+    GELU_SCALE * x * (1.0 + GELU_CUBIC * x * x)
+}
+
+#[inline(always)]
+/// Returns `-|x|` without relying on `std`.
+fn neg_abs(x: f32) -> f32 {
+    if x > 0.0 {
+        -x
+    } else {
+        x
+    }
+}
+
+#[inline(always)]
+/// Approximates `log2(x)` for a single `f64` value with a relative error below `1e-15`,
+/// using the same reduction as [generic_log_inplace].
+pub(crate) fn log2_f64(x: f64) -> f64 {
+    let (s, exponent) = log_reduce_f64(x);
+    exponent + 2.0 * s * horner_f64(&LOG_COEFFS_F64, s * s) * core::f64::consts::LOG2_E
+}
+
+#[inline(always)]
+/// Approximates `2^x` for a single `f64` value with a relative error below `1e-15`.
+///
+/// `x` is split into the nearest integer `n` and `|r| <= 0.5`, `2^r` is evaluated as
+/// `e^(r * ln(2))` and scaled by `2^n` via the exponent bits. Inputs at or above `1024`
+/// overflow to infinity and inputs below `-1021` are flushed to zero.
+pub(crate) fn exp2_f64(x: f64) -> f64 {
+    if x.is_nan() {
+        return x;
+    } else if x >= 1024.0 {
+        return f64::INFINITY;
+    } else if x < -1021.0 {
+        return 0.0;
+    }
+
+    let n = if x < 0.0 {
+        (x - 0.5) as i64
+    } else {
+        (x + 0.5) as i64
+    };
+    let r = x - n as f64;
+
+    // The scale is split into `2^(n - 1) * 2` as `2^1024` is not representable.
+    let scale = f64::from_bits(((n + 1022) as u64) << 52);
+    horner_f64(&EXP2_COEFFS_F64, r * core::f64::consts::LN_2) * scale * 2.0
+}
+
+#[inline(always)]
+/// Reduces `x` to `(r, 2^n)` where `x = n * ln(2) + r`.
+fn exp_reduce(x: f32) -> (f32, f32) {
+    if x > EXP_OVERFLOW {
+        return (0.0, f32::INFINITY);
+    } else if x < EXP_UNDERFLOW {
+        return (0.0, 0.0);
+    }
+
+    let t = x * LOG2_E;
+    let n = if t < 0.0 {
+        (t - 0.5) as i32
+    } else {
+        (t + 0.5) as i32
+    };
+    let r = (x - n as f32 * LN_2_HI) - n as f32 * LN_2_LO;
+
+    (r, f32::from_bits(((n + 127) as u32) << 23))
+}
+
+#[inline(always)]
+/// Reduces `x` to `(s, e * ln(2))` where `x = m * 2^e` and `s = (m - 1) / (m + 1)`.
+///
+/// Values without a finite logarithm return an `s` of zero alongside the result.
+fn log_reduce(x: f32) -> (f32, f32) {
+    if x.is_nan() || x < 0.0 {
+        return (0.0, f32::NAN);
+    } else if x == 0.0 {
+        return (0.0, f32::NEG_INFINITY);
+    } else if x == f32::INFINITY {
+        return (0.0, f32::INFINITY);
+    }
+
+    // Subnormals are scaled by `2^23` to recover a normalized mantissa.
+    let (x, bias) = if x < f32::MIN_POSITIVE {
+        (x * 8_388_608.0, -23)
+    } else {
+        (x, 0)
+    };
+
+    let bits = x.to_bits();
+    let mut exponent = ((bits >> 23) & 0xFF) as i32 - 127 + bias;
+    let mut m = f32::from_bits((bits & 0x007F_FFFF) | 0x3F80_0000);
+    if m > core::f32::consts::SQRT_2 {
+        m *= 0.5;
+        exponent += 1;
+    }
+
+    ((m - 1.0) / (m + 1.0), exponent as f32 * LN_2)
+}
+
+#[inline(always)]
+/// Reduces `x` to `(s, e)` where `x = m * 2^e` and `s = (m - 1) / (m + 1)`.
+///
+/// Values without a finite logarithm return an `s` of zero alongside the result.
+fn log_reduce_f64(x: f64) -> (f64, f64) {
+    if x.is_nan() || x < 0.0 {
+        return (0.0, f64::NAN);
+    } else if x == 0.0 {
+        return (0.0, f64::NEG_INFINITY);
+    } else if x == f64::INFINITY {
+        return (0.0, f64::INFINITY);
+    }
+
+    // Subnormals are scaled by `2^52` to recover a normalized mantissa.
+    let (x, bias) = if x < f64::MIN_POSITIVE {
+        (x * 4_503_599_627_370_496.0, -52)
+    } else {
+        (x, 0)
+    };
+
+    let bits = x.to_bits();
+    let mut exponent = ((bits >> 52) & 0x7FF) as i64 - 1023 + bias;
+    let mut m = f64::from_bits((bits & 0x000F_FFFF_FFFF_FFFF) | 0x3FF0_0000_0000_0000);
+    if m > core::f64::consts::SQRT_2 {
+        m *= 0.5;
+        exponent += 1;
+    }
+
+    ((m - 1.0) / (m + 1.0), exponent as f64)
+}
+
+#[inline(always)]
+fn horner(coeffs: &[f32], x: f32) -> f32 {
+    coeffs[1..].iter().fold(coeffs[0], |p, c| p * x + c)
+}
+
+#[inline(always)]
+fn horner_f64(coeffs: &[f64], x: f64) -> f64 {
+    coeffs[1..].iter().fold(coeffs[0], |p, c| p * x + c)
+}
+
+#[inline(always)]
+unsafe fn horner_register<R>(coeffs: &[f32], x: R::Register) -> R::Register
+where
+    R: SimdRegister<f32>,
+{
+    let mut p = R::filled(coeffs[0]);
+    for c in &coeffs[1..] {
+        p = R::fmadd(p, x, R::filled(*c));
+    }
+    p
+}
+
+#[cfg(test)]
+pub(crate) unsafe fn test_exp_inplace<R>()
+where
+    R: SimdRegister<f32>,
+{
+    use crate::math::AutoMath;
+
+    let values = (0..1043)
+        .map(|i| -87.0 + i as f32 * (175.0 / 1043.0))
+        .collect::<Vec<_>>();
+
+    let mut output = values.clone();
+    generic_exp_inplace::<R, AutoMath>(&mut output);
+    for (x, value) in values.iter().zip(output.iter()) {
+        let expected_value = (*x as f64).exp();
+        let error = ((*value as f64 - expected_value) / expected_value).abs();
+        assert!(
+            error <= EXP_MAX_RELATIVE_ERROR as f64,
+            "value missmatch on exp({x}) {value} vs {expected_value}"
+        );
+    }
+
+    let mut special = [
+        0.0,
+        f32::NEG_INFINITY,
+        -100.0,
+        100.0,
+        f32::INFINITY,
+        f32::NAN,
+    ];
+    generic_exp_inplace::<R, AutoMath>(&mut special);
+    assert_eq!(special[..5], [1.0, 0.0, 0.0, f32::INFINITY, f32::INFINITY]);
+    assert!(special[5].is_nan());
+}
+
+#[cfg(test)]
+pub(crate) unsafe fn test_log_inplace<R>()
+where
+    R: SimdRegister<f32>,
+{
+    use crate::math::AutoMath;
+
+    // Spans `2^-120` to `2^120`, never landing exactly on `1.0` where `ln(x)` is zero.
+    let values = (0..1043)
+        .map(|i| 2f32.powf(-120.0 + i as f32 * (240.0 / 1043.0)))
+        .collect::<Vec<_>>();
+
+    let mut output = values.clone();
+    generic_log_inplace::<R, AutoMath>(&mut output);
+    for (x, value) in values.iter().zip(output.iter()) {
+        let expected_value = (*x as f64).ln();
+        let error = ((*value as f64 - expected_value) / expected_value).abs();
+        assert!(
+            error <= LOG_MAX_RELATIVE_ERROR as f64,
+            "value missmatch on log({x}) {value} vs {expected_value}"
+        );
+    }
+
+    let mut special = [1.0, 0.0, -1.0, f32::INFINITY, f32::NAN, 1e-42];
+    generic_log_inplace::<R, AutoMath>(&mut special);
+    assert_eq!(special[..2], [0.0, f32::NEG_INFINITY]);
+    assert!(special[2].is_nan());
+    assert_eq!(special[3], f32::INFINITY);
+    assert!(special[4].is_nan());
+    let expected_value = (1e-42f32 as f64).ln();
+    assert!(
+        ((special[5] as f64 - expected_value) / expected_value).abs()
+            <= LOG_MAX_RELATIVE_ERROR as f64,
+        "value missmatch on subnormal log {} vs {expected_value}",
+        special[5],
+    );
+}
+
+#[cfg(test)]
+pub(crate) unsafe fn test_softplus_inplace<R>()
+where
+    R: SimdRegister<f32>,
+{
+    use crate::math::AutoMath;
+
+    let reference = |x: f64| x.max(0.0) + (-x.abs()).exp().ln_1p();
+
+    // Spans large negative inputs, where the result is tiny, to large positive inputs.
+    let values = (0..1043)
+        .map(|i| -86.0 + i as f32 * (186.0 / 1043.0))
+        .collect::<Vec<_>>();
+
+    let mut output = values.clone();
+    generic_softplus_inplace::<R, AutoMath>(&mut output);
+    for (x, value) in values.iter().zip(output.iter()) {
+        let expected_value = reference(*x as f64);
+        let error = ((*value as f64 - expected_value) / expected_value).abs();
+        assert!(
+            error <= SOFTPLUS_MAX_RELATIVE_ERROR as f64,
+            "value missmatch on softplus({x}) {value} vs {expected_value}"
+        );
+    }
+
+    // The special values are repeated so they pass through the registers and remainder.
+    let special = [0.0, -100.0, 1e4, f32::NEG_INFINITY, f32::INFINITY, f32::NAN];
+    let mut output = special.repeat(11);
+    generic_softplus_inplace::<R, AutoMath>(&mut output);
+    for values in output.chunks_exact(special.len()) {
+        let error =
+            ((values[0] - core::f32::consts::LN_2) / core::f32::consts::LN_2).abs();
+        assert!(
+            error <= SOFTPLUS_MAX_RELATIVE_ERROR,
+            "value missmatch on softplus(0) {}",
+            values[0]
+        );
+        assert_eq!(values[1..5], [0.0, 1e4, 0.0, f32::INFINITY]);
+        assert!(values[5].is_nan());
+    }
+}
+
+#[cfg(test)]
+pub(crate) unsafe fn test_gelu_inplace<R>()
+where
+    R: SimdRegister<f32>,
+{
+    use crate::math::AutoMath;
+
+    let reference = |x: f64| {
+        let u = (2.0 / core::f64::consts::PI).sqrt() * (x + 0.044715 * x * x * x);
+        0.5 * x * (1.0 + u.tanh())
+    };
+
+    // Most of the inputs land in `[-10, 10]`, the rest reach out to `1000`.
+    let values = (0..1043)
+        .map(|i| -10.0 + i as f32 * (20.0 / 1043.0))
+        .chain([-1000.0, -87.0, 0.0, 40.0, 1000.0])
+        .collect::<Vec<_>>();
+
+    let mut output = values.clone();
+    generic_gelu_inplace::<R, AutoMath>(&mut output);
+    for (x, value) in values.iter().zip(output.iter()) {
+        let expected_value = reference(*x as f64);
+        let error = (*value as f64 - expected_value).abs();
+        assert!(
+            error <= GELU_MAX_ABSOLUTE_ERROR as f64 * (x.abs() as f64).max(1.0),
+            "value missmatch on gelu({x}) {value} vs {expected_value}"
+        );
+    }
+
+    let mut special = [f32::INFINITY, f32::NEG_INFINITY, f32::NAN];
+    generic_gelu_inplace::<R, AutoMath>(&mut special);
+    assert_eq!(special[0], f32::INFINITY);
+    assert!(special[1].is_nan() && special[2].is_nan());
+}
+
+#[cfg(test)]
+pub(crate) unsafe fn test_pow_vertical<R>()
+where
+    R: SimdRegister<f64>,
+{
+    use crate::math::AutoMath;
+
+    // Bases span `0.05` to `20` and exponents span `-8` to `8`.
+    let base = (0..1043)
+        .map(|i| 0.05 + i as f64 * (19.95 / 1043.0))
+        .collect::<Vec<_>>();
+    let exponent = (0..1043)
+        .map(|i| -8.0 + ((i * 7) % 1043) as f64 * (16.0 / 1043.0))
+        .collect::<Vec<_>>();
+
+    for len in [1, 7, 131, 1043] {
+        let mut result = vec![0.0; len];
+        generic_pow_vertical::<R, AutoMath>(&base[..len], &exponent[..len], &mut result);
+
+        for (i, value) in result.iter().enumerate() {
+            let (b, e) = (base[i], exponent[i]);
+            let expected_value = b.powf(e);
+            let error = ((value - expected_value) / expected_value).abs();
+            assert!(
+                error <= POW_MAX_RELATIVE_ERROR,
+                "value missmatch on len {len} {b}^{e} {value} vs {expected_value}"
+            );
+        }
+    }
+
+    let base = [0.0, 0.0, 0.0, 1.0, f64::INFINITY, f64::NAN, 2.0, 4.0, -2.0];
+    let exponent = [2.0, -1.0, 0.0, 1e10, 2.0, 0.0, -1100.0, 0.5, 2.0];
+    let mut result = [0.0; 9];
+    generic_pow_vertical::<R, AutoMath>(&base, &exponent, &mut result);
+    assert_eq!(
+        result[..8],
+        [0.0, f64::INFINITY, 1.0, 1.0, f64::INFINITY, 1.0, 0.0, 2.0]
+    );
+    assert!(result[8].is_nan(), "negative bases should give NaN");
+}
+
+#[cfg(test)]
+pub(crate) unsafe fn test_softmax<R>()
+where
+    R: SimdRegister<f64>,
+{
+    use crate::math::AutoMath;
+
+    let reference = |x: &[f64]| -> Vec<f64> {
+        let max = x.iter().fold(f64::NEG_INFINITY, |m, v| m.max(*v));
+        let exps = x.iter().map(|v| (v - max).exp()).collect::<Vec<_>>();
+        let sum = exps.iter().sum::<f64>();
+        exps.iter().map(|v| v / sum).collect()
+    };
+    let check = |x: &[f64], case: &str| {
+        let expected = reference(x);
+
+        let mut result = vec![0.0; x.len()];
+        generic_softmax::<R, AutoMath>(x, &mut result);
+        for (value, expected_value) in result.iter().zip(expected.iter()) {
+            assert!(
+                (value - expected_value).abs() <= SOFTMAX_MAX_ABSOLUTE_ERROR,
+                "value missmatch on {case} {value} vs {expected_value}"
+            );
+        }
+
+        let sum = result.iter().sum::<f64>();
+        assert!(
+            (sum - 1.0).abs() <= 1e-12,
+            "probabilities on {case} sum to {sum}"
+        );
+
+        let mut inplace = x.to_vec();
+        generic_softmax_inplace::<R, AutoMath>(&mut inplace);
+        assert_eq!(inplace, result, "value missmatch on inplace {case}");
+    };
+
+    // Logits span `-20` to `20`.
+    let (l1, _) = crate::test_utils::get_sample_vectors::<f64>(1043);
+    let logits = l1.iter().map(|v| v * 40.0 - 20.0).collect::<Vec<_>>();
+    for len in [1, 7, 131, 1043] {
+        check(&logits[..len], &format!("len {len}"));
+    }
+
+    // Without subtracting the max every exponential here would overflow.
+    let large = logits.iter().map(|v| v + 1000.0).collect::<Vec<_>>();
+    assert!(large.iter().all(|v| v.exp().is_infinite()));
+    check(&large, "large logits");
+
+    // Equal logits give a uniform distribution.
+    let mut result = vec![0.0; 64];
+    generic_softmax::<R, AutoMath>(&[3.5; 64], &mut result);
+    assert_eq!(
+        result,
+        vec![1.0 / 64.0; 64],
+        "value missmatch on uniform logits"
+    );
+}
@@ -0,0 +1,305 @@
+use crate::danger::{generic_dot, generic_sum, SimdRegister};
+use crate::math::Math;
+use crate::mem_loader::{IntoMemLoader, MemLoader};
+
+#[inline(always)]
+/// A generic weighted mean implementation over the values `x` and their weights `w`,
+/// computing `sum(w * x) / sum(w)`.
+///
+/// The weighted sum and the total weight are accumulated in a single pass, the weighted
+/// sum with a fused multiply add and the total weight with an add over the same loaded
+/// weight register.
+///
+/// If the total weight is zero (including empty vectors) the mean is undefined and
+/// `NaN` is returned.
+///
+/// A zero weight only excludes a finite value from the mean, `0 * inf` and `0 * NaN`
+/// are both `NaN` so a non-finite value poisons the mean regardless of its weight.
+///
+/// This is only meaningful for floating point types, the `0 / 0` returned for a zero
+/// total weight panics on integers.
+///
+/// # Panics
+///
+/// If `x` and `w` are not the same length.
+///
+/// # Safety
+///
+/// The safety requirements of `M` definition the basic math operations and
+/// the requirements of `R` SIMD register must also be followed.
+pub unsafe fn generic_weighted_mean<T, R, M, B1, B2>(x: B1, w: B2) -> T
+where
+    T: Copy,
+    R: SimdRegister<T>,
+    M: Math<T>,
+    B1: IntoMemLoader<T>,
+    B1::Loader: MemLoader<Value = T>,
+    B2: IntoMemLoader<T>,
+    B2::Loader: MemLoader<Value = T>,
+{
+    let mut x = x.into_mem_loader();
+    let mut w = w.into_mem_loader();
+    assert_eq!(
+        x.projected_len(),
+        w.projected_len(),
+        "Buffers `x` and `w` do not match in size"
+    );
+
+    let len = x.projected_len();
+    let offset_from = len % R::elements_per_lane();
+
+    // Two accumulator groups of dense lanes would exhaust the available registers,
+    // so a pair of registers is used for each group instead.
+    let mut weighted_a = R::zeroed();
+    let mut weighted_b = R::zeroed();
+    let mut weights_a = R::zeroed();
+    let mut weights_b = R::zeroed();
+
+    let mut i = 0;
+    let offset_from_pair = len % (R::elements_per_lane() * 2);
+    while i < (len - offset_from_pair) {
+        let x1 = x.load::<R>();
+        let w1 = w.load::<R>();
+        let x2 = x.load::<R>();
+        let w2 = w.load::<R>();
+        weighted_a = R::fmadd(w1, x1, weighted_a);
+        weighted_b = R::fmadd(w2, x2, weighted_b);
+        weights_a = R::add(weights_a, w1);
+        weights_b = R::add(weights_b, w2);
+
+        i += R::elements_per_lane() * 2;
+    }
+
+    let mut weighted = R::add(weighted_a, weighted_b);
+    let mut weights = R::add(weights_a, weights_b);
+    while i < (len - offset_from) {
+        let l1 = x.load::<R>();
+        let l2 = w.load::<R>();
+        weighted = R::fmadd(l2, l1, weighted);
+        weights = R::add(weights, l2);
+
+        i += R::elements_per_lane();
+    }
+
+    // Handle the remainder.
+    let mut weighted = R::sum_to_value(weighted);
+    let mut weights = R::sum_to_value(weights);
+    while i < len {
+        let value = x.read();
+        let weight = w.read();
+        weighted = M::add(weighted, M::mul(weight, value));
+        weights = M::add(weights, weight);
+
+        i += 1;
+    }
+
+    if M::cmp_eq(weights, M::zero()) {
+        // `0 / 0`, i.e. `NaN` for floating point types.
+        return M::div(M::zero(), M::zero());
+    }
+
+    M::div(weighted, weights)
+}
+
+#[inline(always)]
+/// A generic weighted sum implementation over the values `x` and their weights `w`,
+/// computing `sum(w * x)`.
+///
+/// This is the numerator of [generic_weighted_mean] and is computed with [generic_dot],
+/// it is provided separately as the semantic entry point for aggregations like moving
+/// averages where the weights are already normalized.
+///
+/// # Panics
+///
+/// If `x` and `w` are not the same length.
+///
+/// # Safety
+///
+/// The safety requirements of `M` definition the basic math operations and
+/// the requirements of `R` SIMD register must also be followed.
+pub unsafe fn generic_weighted_sum<T, R, M, B1, B2>(x: B1, w: B2) -> T
+where
+    T: Copy,
+    R: SimdRegister<T>,
+    M: Math<T>,
+    B1: IntoMemLoader<T>,
+    B1::Loader: MemLoader<Value = T>,
+    B2: IntoMemLoader<T>,
+    B2::Loader: MemLoader<Value = T>,
+{
+    generic_dot::<T, R, M, B1, B2>(x, w)
+}
+
+#[inline(always)]
+/// A generic weighted mean implementation built from [generic_weighted_sum], computing
+/// `sum(w * x) / sum(w)` with the total weight taken from [generic_sum].
+///
+/// This is a convenience over the weighted sum for callers which already use it, it
+/// reads the weights twice so [generic_weighted_mean] is preferred otherwise.
+///
+/// If the total weight is zero (including empty vectors) the mean is undefined and
+/// `NaN` is returned.
+///
+/// This is only meaningful for floating point types, the `0 / 0` returned for a zero
+/// total weight panics on integers.
+///
+/// # Panics
+///
+/// If `x` and `w` are not the same length.
+///
+/// # Safety
+///
+/// The safety requirements of `M` definition the basic math operations and
+/// the requirements of `R` SIMD register must also be followed.
+pub unsafe fn generic_weighted_sum_mean<T, R, M, B1, B2>(x: B1, w: B2) -> T
+where
+    T: Copy,
+    R: SimdRegister<T>,
+    M: Math<T>,
+    B1: IntoMemLoader<T>,
+    B1::Loader: MemLoader<Value = T>,
+    B2: IntoMemLoader<T> + Copy,
+    B2::Loader: MemLoader<Value = T>,
+{
+    let weighted = generic_weighted_sum::<T, R, M, B1, B2>(x, w);
+    let weights = generic_sum::<T, R, M, B2>(w);
+
+    if M::cmp_eq(weights, M::zero()) {
+        // `0 / 0`, i.e. `NaN` for floating point types.
+        return M::div(M::zero(), M::zero());
+    }
+
+    M::div(weighted, weights)
+}
+
+#[cfg(test)]
+pub(crate) unsafe fn test_weighted_mean<T, R>(l1: Vec<T>, l2: Vec<T>)
+where
+    T: Copy + PartialEq + core::fmt::Debug,
+    R: SimdRegister<T>,
+    crate::math::AutoMath: Math<T>,
+{
+    use crate::math::AutoMath;
+
+    for len in [1, 7, 131, l1.len()] {
+        let (x, w) = (&l1[..len], &l2[..len]);
+
+        let value = generic_weighted_mean::<T, R, AutoMath, _, _>(x, w);
+        let expected_value = simple_weighted_mean(x, w);
+        assert!(
+            AutoMath::is_close(value, expected_value),
+            "value missmatch on len {len} {value:?} vs {expected_value:?}"
+        );
+    }
+
+    // Zero weights exclude their finite values from the mean entirely.
+    let big = (0..20).fold(AutoMath::one(), |a, _| AutoMath::add(a, a));
+    let mut w = l2.clone();
+    let mut x = l1.clone();
+    for i in (0..w.len()).step_by(3) {
+        w[i] = AutoMath::zero();
+        x[i] = big;
+    }
+    let value = generic_weighted_mean::<T, R, AutoMath, _, _>(&x, &w);
+    let expected_value = simple_weighted_mean(&x, &w);
+    assert!(
+        AutoMath::is_close(value, expected_value),
+        "value missmatch with zero weights {value:?} vs {expected_value:?}"
+    );
+
+    // Equal weights give the plain mean.
+    let ones = vec![AutoMath::one(); l1.len()];
+    let value = generic_weighted_mean::<T, R, AutoMath, _, _>(&l1, &ones);
+    let expected_value = simple_weighted_mean(&l1, &ones);
+    assert!(
+        AutoMath::is_close(value, expected_value),
+        "value missmatch with equal weights {value:?} vs {expected_value:?}"
+    );
+
+    // A total weight of zero is undefined.
+    let zeroes = vec![AutoMath::zero(); l1.len()];
+    let value = generic_weighted_mean::<T, R, AutoMath, _, _>(&l1, &zeroes);
+    assert!(!AutoMath::cmp_eq(value, value), "zero total weight should return NaN");
+    let value = generic_weighted_mean::<T, R, AutoMath, _, _>(&[] as &[T], &[] as &[T]);
+    assert!(!AutoMath::cmp_eq(value, value), "empty vectors should return NaN");
+}
+
+#[cfg(test)]
+pub(crate) unsafe fn test_weighted_sum<T, R>(l1: Vec<T>, l2: Vec<T>)
+where
+    T: Copy + PartialEq + core::fmt::Debug,
+    R: SimdRegister<T>,
+    crate::math::AutoMath: Math<T>,
+    f64: From<T>,
+{
+    use crate::math::AutoMath;
+
+    // The kernel accumulates across lanes, so the reference is kept in `f64`.
+    let reference = |x: &[T], w: &[T]| -> f64 {
+        x.iter().zip(w).fold(0.0, |acc, (value, weight)| {
+            acc + f64::from(*weight) * f64::from(*value)
+        })
+    };
+    let is_close = |value: T, expected: f64| {
+        (f64::from(value) - expected).abs() <= expected.abs().max(1.0) * 1e-5
+    };
+
+    for len in [1, 7, 131, l1.len()] {
+        let (x, w) = (&l1[..len], &l2[..len]);
+
+        let value = generic_weighted_sum::<T, R, AutoMath, _, _>(x, w);
+        let expected_value = reference(x, w);
+        assert!(
+            is_close(value, expected_value),
+            "value missmatch on len {len} {value:?} vs {expected_value:?}"
+        );
+
+        let value = generic_weighted_sum_mean::<T, R, AutoMath, _, _>(x, w);
+        let weights = w.iter().fold(0.0, |acc, weight| acc + f64::from(*weight));
+        let expected_value = expected_value / weights;
+        assert!(
+            is_close(value, expected_value),
+            "value missmatch on mean len {len} {value:?} vs {expected_value:?}"
+        );
+    }
+
+    // Unit weights give the plain sum, and the plain mean.
+    let ones = vec![AutoMath::one(); l1.len()];
+    let value = generic_weighted_sum::<T, R, AutoMath, _, _>(&l1, &ones);
+    let expected_value = reference(&l1, &ones);
+    assert!(
+        is_close(value, expected_value),
+        "value missmatch with unit weights {value:?} vs {expected_value:?}"
+    );
+    let value = generic_weighted_sum_mean::<T, R, AutoMath, _, _>(&l1, &ones);
+    let expected_value = expected_value / l1.len() as f64;
+    assert!(
+        is_close(value, expected_value),
+        "value missmatch on mean with unit weights {value:?} vs {expected_value:?}"
+    );
+
+    // Zero weights exclude every value.
+    let zeroes = vec![AutoMath::zero(); l1.len()];
+    let value = generic_weighted_sum::<T, R, AutoMath, _, _>(&l1, &zeroes);
+    assert_eq!(value, AutoMath::zero(), "value missmatch with zero weights");
+    let value = generic_weighted_sum_mean::<T, R, AutoMath, _, _>(&l1, &zeroes);
+    assert!(!AutoMath::cmp_eq(value, value), "zero total weight should return NaN");
+}
+
+#[cfg(test)]
+fn simple_weighted_mean<T>(x: &[T], w: &[T]) -> T
+where
+    T: Copy,
+    crate::math::AutoMath: Math<T>,
+{
+    use crate::math::AutoMath;
+
+    let mut weighted = AutoMath::zero();
+    let mut weights = AutoMath::zero();
+    for (value, weight) in x.iter().zip(w) {
+        weighted = AutoMath::add(weighted, AutoMath::mul(*weight, *value));
+        weights = AutoMath::add(weights, *weight);
+    }
+
+    AutoMath::div(weighted, weights)
+}
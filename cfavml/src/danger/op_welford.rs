@@ -0,0 +1,316 @@
+use crate::danger::SimdRegister;
+use crate::math::Math;
+
+#[inline(always)]
+/// A generic vertical Welford update, folding each row of the row-major `matrix` with
+/// `dims` columns into the running per-column `mean` and `m2` state.
+///
+/// `count` is the number of rows folded into the state so far and is updated to include
+/// the rows of `matrix`, the state starts as `count = 0` with `mean` and `m2` zeroed.
+/// Calling this routine with successive batches produces the same state as a single call
+/// with all rows, once done the per-column variance is `m2 / count` (or `m2 / (count - 1)`
+/// for the sample variance).
+///
+/// Each block of columns is kept in registers while iterating over every row, so the
+/// state is only loaded and written once per call.
+///
+/// This is only meaningful for floating point types.
+///
+/// # Panics
+///
+/// If `dims` is `0`, `mean` or `m2` are not `dims` in length, or `matrix` is not
+/// a multiple of `dims` in length.
+///
+/// # Safety
+///
+/// The safety requirements of `M` definition the basic math operations and
+/// the requirements of `R` SIMD register must also be followed.
+pub unsafe fn generic_welford_vertical<T, R, M>(
+    matrix: &[T],
+    dims: usize,
+    count: &mut T,
+    mean: &mut [T],
+    m2: &mut [T],
+) where
+    T: Copy,
+    R: SimdRegister<T>,
+    M: Math<T>,
+{
+    assert_ne!(dims, 0, "Number of `dims` must be greater than zero");
+    assert_eq!(mean.len(), dims, "Buffer `mean` does not match `dims`");
+    assert_eq!(m2.len(), dims, "Buffer `m2` does not match `dims`");
+    assert_eq!(
+        matrix.len() % dims,
+        0,
+        "Buffer `matrix` is not a multiple of `dims` in length"
+    );
+
+    let num_rows = matrix.len() / dims;
+    let matrix_ptr = matrix.as_ptr();
+    let mean_ptr = mean.as_mut_ptr();
+    let m2_ptr = m2.as_mut_ptr();
+    let initial_count = *count;
+
+    // Operate over single registers of columns first.
+    let offset_from = dims % R::elements_per_lane();
+    let mut i = 0;
+    while i < (dims - offset_from) {
+        let mut mu = R::load(mean_ptr.add(i));
+        let mut s = R::load(m2_ptr.add(i));
+
+        let mut n = initial_count;
+        for row in 0..num_rows {
+            n = M::add(n, M::one());
+
+            let x = R::load(matrix_ptr.add((row * dims) + i));
+            let delta = R::sub(x, mu);
+            mu = R::add(mu, R::div(delta, R::filled(n)));
+            s = R::fmadd(delta, R::sub(x, mu), s);
+        }
+
+        R::write(mean_ptr.add(i), mu);
+        R::write(m2_ptr.add(i), s);
+
+        i += R::elements_per_lane();
+    }
+
+    // Handle the remainder.
+    while i < dims {
+        let mut mu = *mean_ptr.add(i);
+        let mut s = *m2_ptr.add(i);
+
+        let mut n = initial_count;
+        for row in 0..num_rows {
+            n = M::add(n, M::one());
+
+            let x = *matrix_ptr.add((row * dims) + i);
+            let delta = M::sub(x, mu);
+            mu = M::add(mu, M::div(delta, n));
+            s = M::add(s, M::mul(delta, M::sub(x, mu)));
+        }
+
+        *mean_ptr.add(i) = mu;
+        *m2_ptr.add(i) = s;
+
+        i += 1;
+    }
+
+    for _ in 0..num_rows {
+        *count = M::add(*count, M::one());
+    }
+}
+
+#[inline(always)]
+/// A generic mean and population variance implementation over the vector `x`,
+/// returning `(mean, variance)` from a single traversal.
+///
+/// ### Shifted Sums
+///
+/// The sum and sum of squares are accumulated over `x[i] - x[0]` rather than `x[i]`,
+/// then combined as `(sum_sq - sum² / n) / n`. Shifting by a value inside the data
+/// avoids the catastrophic cancellation of the naive `sum_sq / n - mean²` when the mean
+/// is large relative to the spread, while keeping to one pass over memory. A centered
+/// two pass form is more accurate when `x[0]` lies far from the mean, at the cost of
+/// reading `x` twice.
+///
+/// The variance is clamped to be non-negative against rounding. An empty vector returns
+/// `NaN` for both the mean and variance.
+///
+/// This is only meaningful for floating point types.
+///
+/// # Safety
+///
+/// The safety requirements of `M` definition the basic math operations and
+/// the requirements of `R` SIMD register must also be followed.
+pub unsafe fn generic_mean_variance<T, R, M>(x: &[T]) -> (T, T)
+where
+    T: Copy,
+    R: SimdRegister<T>,
+    M: Math<T>,
+{
+    let len = x.len();
+    if len == 0 {
+        let nan = M::div(M::zero(), M::zero());
+        return (nan, nan);
+    }
+
+    let data_ptr = x.as_ptr();
+    let shift = data_ptr.read();
+    let offset_from = len % R::elements_per_dense();
+
+    // Operate over dense lanes first.
+    let shift_dense = R::filled_dense(shift);
+    let ones_dense = R::filled_dense(M::one());
+    let mut sum_dense = R::zeroed_dense();
+    let mut sum_sq_dense = R::zeroed_dense();
+    let mut count_dense = R::zeroed_dense();
+    let mut i = 0;
+    while i < (len - offset_from) {
+        let delta = R::sub_dense(R::load_dense(data_ptr.add(i)), shift_dense);
+        sum_dense = R::add_dense(sum_dense, delta);
+        sum_sq_dense = R::fmadd_dense(delta, delta, sum_sq_dense);
+        count_dense = R::add_dense(count_dense, ones_dense);
+
+        i += R::elements_per_dense();
+    }
+
+    // Operate over single registers next.
+    let shift_reg = R::filled(shift);
+    let ones_reg = R::filled(M::one());
+    let mut sum_reg = R::sum_to_register(sum_dense);
+    let mut sum_sq_reg = R::sum_to_register(sum_sq_dense);
+    let mut count_reg = R::sum_to_register(count_dense);
+    let offset_from = offset_from % R::elements_per_lane();
+    while i < (len - offset_from) {
+        let delta = R::sub(R::load(data_ptr.add(i)), shift_reg);
+        sum_reg = R::add(sum_reg, delta);
+        sum_sq_reg = R::fmadd(delta, delta, sum_sq_reg);
+        count_reg = R::add(count_reg, ones_reg);
+
+        i += R::elements_per_lane();
+    }
+
+    // Handle the remainder.
+    let mut sum = R::sum_to_value(sum_reg);
+    let mut sum_sq = R::sum_to_value(sum_sq_reg);
+    let mut n = R::sum_to_value(count_reg);
+    while i < len {
+        let delta = M::sub(data_ptr.add(i).read(), shift);
+        sum = M::add(sum, delta);
+        sum_sq = M::add(sum_sq, M::mul(delta, delta));
+        n = M::add(n, M::one());
+
+        i += 1;
+    }
+
+    let mean = M::add(shift, M::div(sum, n));
+    let variance = M::div(M::sub(sum_sq, M::div(M::mul(sum, sum), n)), n);
+
+    (mean, M::cmp_max(variance, M::zero()))
+}
+
+#[cfg(test)]
+pub(crate) unsafe fn test_welford<T, R>(l1: Vec<T>)
+where
+    T: Copy + PartialEq + core::fmt::Debug,
+    R: SimdRegister<T>,
+    crate::math::AutoMath: Math<T>,
+{
+    use crate::math::AutoMath;
+
+    let dims = R::elements_per_lane() * 2 + 3;
+    let num_rows = l1.len() / dims;
+    let matrix = &l1[..num_rows * dims];
+
+    // Fold the rows in two uneven batches.
+    let split = (num_rows / 3) * dims;
+    let mut count = AutoMath::zero();
+    let mut mean = vec![AutoMath::zero(); dims];
+    let mut m2 = vec![AutoMath::zero(); dims];
+    generic_welford_vertical::<T, R, AutoMath>(
+        &matrix[..split],
+        dims,
+        &mut count,
+        &mut mean,
+        &mut m2,
+    );
+    generic_welford_vertical::<T, R, AutoMath>(
+        &matrix[split..],
+        dims,
+        &mut count,
+        &mut mean,
+        &mut m2,
+    );
+
+    let mut expected_count = AutoMath::zero();
+    for _ in 0..num_rows {
+        expected_count = AutoMath::add(expected_count, AutoMath::one());
+    }
+    assert_eq!(count, expected_count, "count missmatch");
+
+    for column in 0..dims {
+        let values = matrix.iter().skip(column).step_by(dims).copied();
+        let expected_mean = AutoMath::div(
+            values
+                .clone()
+                .fold(AutoMath::zero(), |a, b| AutoMath::add(a, b)),
+            expected_count,
+        );
+        let expected_m2 = values.fold(AutoMath::zero(), |a, b| {
+            let diff = AutoMath::sub(b, expected_mean);
+            AutoMath::add(a, AutoMath::mul(diff, diff))
+        });
+
+        assert!(
+            AutoMath::is_close(mean[column], expected_mean),
+            "mean missmatch on column {column} {:?} vs {expected_mean:?}",
+            mean[column],
+        );
+        assert!(
+            AutoMath::is_close(
+                AutoMath::div(m2[column], count),
+                AutoMath::div(expected_m2, expected_count)
+            ),
+            "variance missmatch on column {column} {:?} vs {expected_m2:?}",
+            m2[column],
+        );
+    }
+}
+
+#[cfg(test)]
+pub(crate) unsafe fn test_mean_variance<T, R>(l1: Vec<T>)
+where
+    T: Copy + PartialEq + core::fmt::Debug,
+    R: SimdRegister<T>,
+    crate::math::AutoMath: Math<T>,
+{
+    use crate::math::AutoMath;
+
+    let mut offset = AutoMath::zero();
+    for _ in 0..10 {
+        offset = AutoMath::add(offset, AutoMath::one());
+    }
+    // The offset values check the shift keeps the variance stable for a larger mean.
+    let shifted = l1
+        .iter()
+        .map(|v| AutoMath::add(*v, offset))
+        .collect::<Vec<_>>();
+
+    for values in [&l1, &shifted] {
+        for len in [1, 7, 131, values.len()] {
+            let x = &values[..len];
+            let (mean, variance) = generic_mean_variance::<T, R, AutoMath>(x);
+
+            // The centered two pass form is used as the reference.
+            let mut n = AutoMath::zero();
+            let mut total = AutoMath::zero();
+            for v in x {
+                n = AutoMath::add(n, AutoMath::one());
+                total = AutoMath::add(total, *v);
+            }
+            let expected_mean = AutoMath::div(total, n);
+            let expected_variance = AutoMath::div(
+                x.iter().fold(AutoMath::zero(), |acc, v| {
+                    let delta = AutoMath::sub(*v, expected_mean);
+                    AutoMath::add(acc, AutoMath::mul(delta, delta))
+                }),
+                n,
+            );
+
+            assert!(
+                AutoMath::is_close(mean, expected_mean),
+                "mean missmatch on len {len} {mean:?} vs {expected_mean:?}"
+            );
+            assert!(
+                AutoMath::is_close(variance, expected_variance),
+                "variance missmatch on len {len} {variance:?} vs {expected_variance:?}"
+            );
+        }
+    }
+
+    let (mean, variance) = generic_mean_variance::<T, R, AutoMath>(&[]);
+    assert!(
+        !AutoMath::cmp_eq(mean, mean) && !AutoMath::cmp_eq(variance, variance),
+        "mean and variance of an empty vector should be NaN"
+    );
+}
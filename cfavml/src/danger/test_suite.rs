@@ -1,4 +1,4 @@
-use std::fmt::Debug;
+use core::fmt::Debug;
 
 use super::*;
 use crate::buffer::WriteOnlyBuffer;
@@ -23,35 +23,1033 @@ macro_rules! test_cosine_extra {
     };
 }
 
+// Cosine similarity divides by the norms, which only makes sense for float types.
+macro_rules! test_cosine_similarity_extra {
+    ($t:ident, $im:ident) => {
+        paste::paste! {
+            #[test]
+            fn [<test_ $im:lower _ $t _cosine_similarity>]() {
+                let (l1, l2) = crate::test_utils::get_sample_vectors::<$t>(DATA_SIZE);
+                unsafe {
+                    crate::danger::op_cosine::test_cosine_similarity::<$t, $im>(l1, l2)
+                };
+            }
+        }
+    };
+}
+
+// The double-double dot product is only error-free on impls with a true FMA.
+#[cfg(any(
+    all(target_feature = "avx2", target_feature = "fma"),
+    all(target_feature = "avx512f", feature = "nightly"),
+    target_feature = "neon",
+))]
+macro_rules! test_dot_dd_extra {
+    ($t:ident, $im:ident) => {
+        paste::paste! {
+            #[test]
+            fn [<test_ $im:lower _ $t _dot_dd>]() {
+                unsafe { crate::danger::op_dot::test_dot_dd::<$t, $im>($t::EPSILON) };
+            }
+        }
+    };
+}
+
+// The fast cosine approximation is only provided for f32.
+macro_rules! test_cosine_fast_extra {
+    ($im:ident) => {
+        paste::paste! {
+            #[test]
+            fn [<test_ $im:lower _f32_cosine_fast>]() {
+                let (l1, l2) = crate::test_utils::get_sample_vectors::<f32>(DATA_SIZE);
+                unsafe { crate::danger::op_cosine::test_cosine_fast::<$im>(l1, l2) };
+            }
+        }
+    };
+}
+
+// The widened and compensated dot products are only provided for f32 inputs.
+macro_rules! test_dot_f32_widen_extra {
+    ($im:ident) => {
+        paste::paste! {
+            #[test]
+            fn [<test_ $im:lower _f32_dot_widen>]() {
+                unsafe { crate::danger::op_dot_widen::test_dot_f32_widen::<$im>() };
+            }
+        }
+    };
+}
+
+// The adaptive sum is only provided for f32 inputs.
+macro_rules! test_adaptive_sum_extra {
+    ($im:ident) => {
+        paste::paste! {
+            #[test]
+            fn [<test_ $im:lower _f32_adaptive_sum>]() {
+                unsafe { crate::danger::op_sum::test_adaptive_sum::<$im>() };
+            }
+        }
+    };
+}
+
+// The widened sum is only provided for i32 inputs.
+macro_rules! test_sum_i32_widen_extra {
+    ($im:ident) => {
+        paste::paste! {
+            #[test]
+            fn [<test_ $im:lower _i32_sum_widen>]() {
+                unsafe { crate::danger::op_sum::test_sum_i32_widen::<$im>() };
+            }
+        }
+    };
+}
+
+// The approximate median is only provided for f64 vectors.
+macro_rules! test_approx_median_extra {
+    ($im:ident) => {
+        paste::paste! {
+            #[test]
+            fn [<test_ $im:lower _f64_approx_median>]() {
+                unsafe { crate::danger::op_median::test_approx_median::<$im>() };
+            }
+        }
+    };
+}
+
+// The entropy is only provided for f64 probabilities.
+macro_rules! test_entropy_extra {
+    ($im:ident) => {
+        paste::paste! {
+            #[test]
+            fn [<test_ $im:lower _f64_entropy>]() {
+                unsafe { crate::danger::op_entropy::test_entropy::<$im>() };
+            }
+        }
+    };
+}
+
+// Canberra distance is only implemented over f64 vectors.
+macro_rules! test_canberra_extra {
+    ($im:ident) => {
+        paste::paste! {
+            #[test]
+            fn [<test_ $im:lower _f64_canberra>]() {
+                unsafe { crate::danger::op_canberra::test_canberra::<$im>() };
+            }
+        }
+    };
+}
+
+// Bray-Curtis dissimilarity is only implemented over f64 abundances.
+macro_rules! test_bray_curtis_extra {
+    ($im:ident) => {
+        paste::paste! {
+            #[test]
+            fn [<test_ $im:lower _f64_bray_curtis>]() {
+                unsafe { crate::danger::op_bray_curtis::test_bray_curtis::<$im>() };
+            }
+
+            #[test]
+            #[should_panic]
+            fn [<test_ $im:lower _f64_bray_curtis_length_missmatch>]() {
+                let (l1, l2) = ([1.0; 3], [1.0; 2]);
+                let _ = unsafe { crate::danger::generic_bray_curtis::<$im, AutoMath>(&l1, &l2) };
+            }
+        }
+    };
+}
+
+// Minkowski distance is only implemented over f64 vectors.
+macro_rules! test_minkowski_extra {
+    ($im:ident) => {
+        paste::paste! {
+            #[test]
+            fn [<test_ $im:lower _f64_minkowski>]() {
+                unsafe { crate::danger::op_minkowski::test_minkowski::<$im>() };
+            }
+
+            #[test]
+            #[should_panic]
+            fn [<test_ $im:lower _f64_minkowski_length_missmatch>]() {
+                let (l1, l2) = ([1.0; 3], [1.0; 2]);
+                let _ = unsafe {
+                    crate::danger::generic_minkowski::<$im, AutoMath>(&l1, &l2, 3.0)
+                };
+            }
+        }
+    };
+}
+
+// The cross entropy loss and log-sum-exp are only provided for f32 inputs.
+macro_rules! test_cross_entropy_extra {
+    ($im:ident) => {
+        paste::paste! {
+            #[test]
+            fn [<test_ $im:lower _f32_cross_entropy>]() {
+                unsafe { crate::danger::op_cross_entropy::test_cross_entropy::<$im>() };
+            }
+
+            #[test]
+            #[should_panic]
+            fn [<test_ $im:lower _f32_cross_entropy_target_out_of_bounds>]() {
+                let logits = [0.5f32, 1.5, -2.0];
+                unsafe { crate::danger::generic_cross_entropy::<$im, AutoMath>(&logits, 3) };
+            }
+
+            #[test]
+            fn [<test_ $im:lower _f32_logsumexp>]() {
+                unsafe { crate::danger::op_cross_entropy::test_logsumexp::<$im>() };
+            }
+        }
+    };
+}
+
+// The element-wise math functions are only provided for f32 inputs.
+macro_rules! test_vecmath_extra {
+    ($im:ident) => {
+        paste::paste! {
+            #[test]
+            fn [<test_ $im:lower _f32_exp_inplace>]() {
+                unsafe { crate::danger::op_vecmath::test_exp_inplace::<$im>() };
+            }
+
+            #[test]
+            fn [<test_ $im:lower _f32_log_inplace>]() {
+                unsafe { crate::danger::op_vecmath::test_log_inplace::<$im>() };
+            }
+
+            #[test]
+            fn [<test_ $im:lower _f32_softplus_inplace>]() {
+                unsafe { crate::danger::op_vecmath::test_softplus_inplace::<$im>() };
+            }
+
+            #[test]
+            fn [<test_ $im:lower _f32_gelu_inplace>]() {
+                unsafe { crate::danger::op_vecmath::test_gelu_inplace::<$im>() };
+            }
+        }
+    };
+}
+
+// The softmax is only implemented over f64 vectors.
+macro_rules! test_softmax_extra {
+    ($im:ident) => {
+        paste::paste! {
+            #[test]
+            fn [<test_ $im:lower _f64_softmax>]() {
+                unsafe { crate::danger::op_vecmath::test_softmax::<$im>() };
+            }
+
+            #[test]
+            #[should_panic]
+            fn [<test_ $im:lower _f64_softmax_length_missmatch>]() {
+                let (l1, mut l2) = ([2.0; 3], [0.0; 2]);
+                unsafe { crate::danger::generic_softmax::<$im, AutoMath>(&l1, &mut l2) };
+            }
+        }
+    };
+}
+
+// The vector power is only implemented over f64 vectors.
+macro_rules! test_pow_extra {
+    ($im:ident) => {
+        paste::paste! {
+            #[test]
+            fn [<test_ $im:lower _f64_pow_vertical>]() {
+                unsafe { crate::danger::op_vecmath::test_pow_vertical::<$im>() };
+            }
+
+            #[test]
+            #[should_panic]
+            fn [<test_ $im:lower _f64_pow_vertical_length_missmatch>]() {
+                let (l1, l2, mut l3) = ([2.0; 3], [1.0; 2], [0.0; 3]);
+                unsafe {
+                    crate::danger::generic_pow_vertical::<$im, AutoMath>(&l1, &l2, &mut l3)
+                };
+            }
+        }
+    };
+}
+
+// Clipping, normalizing and row norms rely on a true `sqrt`, which only makes sense for float types.
+macro_rules! test_clip_norm_extra {
+    ($t:ident, $im:ident) => {
+        paste::paste! {
+            #[test]
+            fn [<test_ $im:lower _ $t _clip_norm>]() {
+                let (l1, _) = crate::test_utils::get_sample_vectors::<$t>(DATA_SIZE);
+                unsafe { crate::danger::op_norm::test_clip_norm::<$t, $im>(l1) };
+            }
+
+            #[test]
+            fn [<test_ $im:lower _ $t _normalize_inplace>]() {
+                let (l1, _) = crate::test_utils::get_sample_vectors::<$t>(DATA_SIZE);
+                unsafe { crate::danger::op_norm::test_normalize_inplace::<$t, $im>(l1) };
+            }
+
+            #[test]
+            fn [<test_ $im:lower _ $t _row_norms>]() {
+                let (l1, _) = crate::test_utils::get_sample_vectors::<$t>(DATA_SIZE);
+                unsafe { crate::danger::op_norm::test_row_norms::<$t, $im>(l1) };
+            }
+
+            #[test]
+            #[should_panic]
+            fn [<test_ $im:lower _ $t _row_norms_ragged_matrix>]() {
+                let (l1, _) = crate::test_utils::get_sample_vectors::<$t>(10);
+                let mut out = vec![0 as $t; 3];
+                unsafe {
+                    crate::danger::generic_row_norms::<$t, $im, AutoMath>(&l1, 3, &mut out)
+                };
+            }
+
+            #[test]
+            fn [<test_ $im:lower _ $t _minmax_normalize_columns_inplace>]() {
+                let (l1, _) = crate::test_utils::get_sample_vectors::<$t>(DATA_SIZE);
+                unsafe {
+                    crate::danger::op_norm::test_minmax_normalize_columns_inplace::<
+                        $t,
+                        $im,
+                    >(l1)
+                };
+            }
+        }
+    };
+}
+
+macro_rules! test_pearson_extra {
+    ($t:ident, $im:ident) => {
+        paste::paste! {
+            #[test]
+            fn [<test_ $im:lower _ $t _pearson>]() {
+                let (l1, l2) = crate::test_utils::get_sample_vectors::<$t>(DATA_SIZE);
+                unsafe { crate::danger::op_pearson::test_pearson::<$t, $im>(l1, l2) };
+            }
+        }
+    };
+}
+
+macro_rules! test_weighted_mean_extra {
+    ($t:ident, $im:ident) => {
+        paste::paste! {
+            #[test]
+            fn [<test_ $im:lower _ $t _weighted_mean>]() {
+                let (l1, l2) = crate::test_utils::get_sample_vectors::<$t>(DATA_SIZE);
+                unsafe {
+                    crate::danger::op_weighted_mean::test_weighted_mean::<$t, $im>(l1, l2)
+                };
+            }
+
+            #[test]
+            fn [<test_ $im:lower _ $t _weighted_sum>]() {
+                let (l1, l2) = crate::test_utils::get_sample_vectors::<$t>(DATA_SIZE);
+                unsafe {
+                    crate::danger::op_weighted_mean::test_weighted_sum::<$t, $im>(l1, l2)
+                };
+            }
+
+            #[test]
+            #[should_panic]
+            fn [<test_ $im:lower _ $t _weighted_sum_length_missmatch>]() {
+                let (l1, l2) = crate::test_utils::get_sample_vectors::<$t>(DATA_SIZE);
+                unsafe {
+                    crate::danger::generic_weighted_sum::<$t, $im, AutoMath, _, _>(
+                        &l1,
+                        &l2[1..],
+                    )
+                };
+            }
+        }
+    };
+}
+
+macro_rules! test_quat_mul_extra {
+    ($t:ident, $im:ident) => {
+        paste::paste! {
+            #[test]
+            fn [<test_ $im:lower _ $t _quat_mul>]() {
+                let (l1, l2) = crate::test_utils::get_sample_vectors::<$t>(DATA_SIZE);
+                unsafe { crate::danger::op_quaternion::test_quat_mul::<$t, $im>(l1, l2) };
+            }
+
+            #[test]
+            #[should_panic]
+            fn [<test_ $im:lower _ $t _quat_mul_stride_missmatch>]() {
+                let (l1, l2) = crate::test_utils::get_sample_vectors::<$t>(6);
+                let mut out = vec![0 as $t; 6];
+                unsafe {
+                    crate::danger::generic_quat_mul::<$t, $im, AutoMath>(&l1, &l2, &mut out)
+                };
+            }
+        }
+    };
+}
+
+macro_rules! test_sum_with_status_extra {
+    ($t:ident, $im:ident) => {
+        paste::paste! {
+            #[test]
+            fn [<test_ $im:lower _ $t _sum_with_status>]() {
+                let (l1, _) = crate::test_utils::get_sample_vectors::<$t>(DATA_SIZE);
+                unsafe {
+                    crate::danger::op_sum::test_sum_with_status::<$t, $im>(
+                        l1,
+                        $t::NAN,
+                        $t::INFINITY,
+                    )
+                };
+            }
+        }
+    };
+}
+
+macro_rules! test_axpy_extra {
+    ($t:ident, $im:ident) => {
+        paste::paste! {
+            #[test]
+            fn [<test_ $im:lower _ $t _axpy>]() {
+                let (l1, l2) = crate::test_utils::get_sample_vectors::<$t>(DATA_SIZE);
+                for alpha in [0.0, 0.5, -0.25, -3.0] {
+                    unsafe {
+                        op_arithmetic_vertical::tests::test_simple_axpy::<$t, $im>(
+                            l1.clone(),
+                            l2.clone(),
+                            alpha,
+                        )
+                    };
+                }
+            }
+
+            #[test]
+            #[should_panic]
+            fn [<test_ $im:lower _ $t _axpy_length_missmatch>]() {
+                let l1 = vec![1.0; 3];
+                let mut l2 = vec![1.0; 2];
+                unsafe {
+                    crate::danger::generic_axpy::<$t, $im, AutoMath>(2.0, &l1, &mut l2)
+                };
+            }
+
+            #[test]
+            fn [<test_ $im:lower _ $t _scaled_dot>]() {
+                let (l1, l2) = crate::test_utils::get_sample_vectors::<$t>(DATA_SIZE);
+                for scale in [0.0, 0.5, -0.25] {
+                    unsafe {
+                        crate::danger::op_dot::test_scaled_dot::<$t, $im>(
+                            l1.clone(),
+                            l2.clone(),
+                            scale,
+                        )
+                    };
+                }
+            }
+        }
+    };
+}
+
+macro_rules! test_sum_scaled_extra {
+    ($t:ident, $im:ident) => {
+        paste::paste! {
+            #[test]
+            fn [<test_ $im:lower _ $t _sum_scaled>]() {
+                let (l1, _) = crate::test_utils::get_sample_vectors::<$t>(DATA_SIZE);
+                for scale in [0.0, 0.5, -0.25] {
+                    unsafe {
+                        crate::danger::op_sum::test_sum_scaled::<$t, $im>(l1.clone(), scale)
+                    };
+                }
+            }
+        }
+    };
+}
+
+macro_rules! test_pairwise_squared_euclidean_extra {
+    ($t:ident, $im:ident) => {
+        paste::paste! {
+            #[test]
+            fn [<test_ $im:lower _ $t _pairwise_squared_euclidean>]() {
+                let (l1, l2) = crate::test_utils::get_sample_vectors::<$t>(DATA_SIZE);
+                unsafe {
+                    crate::danger::op_euclidean::test_pairwise_squared_euclidean::<$t, $im>(
+                        l1, l2,
+                    )
+                };
+            }
+
+            #[test]
+            #[should_panic]
+            fn [<test_ $im:lower _ $t _pairwise_squared_euclidean_shape_missmatch>]() {
+                let (l1, l2) = crate::test_utils::get_sample_vectors::<$t>(12);
+                let mut out = vec![0.0; 5];
+                unsafe {
+                    crate::danger::generic_pairwise_squared_euclidean::<$t, $im, AutoMath>(
+                        &l1[..8], &l2, 4, &mut out,
+                    )
+                };
+            }
+        }
+    };
+}
+
+macro_rules! test_sum_compensated_extra {
+    ($t:ident, $im:ident) => {
+        paste::paste! {
+            #[test]
+            fn [<test_ $im:lower _ $t _sum_compensated>]() {
+                let (l1, _) = crate::test_utils::get_sample_vectors::<$t>(DATA_SIZE);
+                // The spacing between values around `big` is `4`.
+                let big = 4.0 / $t::EPSILON;
+                unsafe {
+                    crate::danger::op_sum::test_sum_compensated::<$t, $im>(l1, big, 4.0)
+                };
+            }
+        }
+    };
+}
+
+macro_rules! test_linear_combine_extra {
+    ($t:ident, $im:ident) => {
+        paste::paste! {
+            #[test]
+            fn [<test_ $im:lower _ $t _linear_combine>]() {
+                let (l1, l2) = crate::test_utils::get_sample_vectors::<$t>(DATA_SIZE);
+                for (a, b) in [(0.0, 1.0), (0.5, -0.25), (-3.0, 2.0)] {
+                    unsafe {
+                        op_arithmetic_vertical::tests::test_simple_linear_combine::<$t, $im>(
+                            l1.clone(),
+                            l2.clone(),
+                            a,
+                            b,
+                        )
+                    };
+                }
+            }
+
+            #[test]
+            #[should_panic]
+            fn [<test_ $im:lower _ $t _linear_combine_length_missmatch>]() {
+                let l1 = vec![1.0; 3];
+                let l2 = vec![1.0; 3];
+                let mut out = vec![0.0; 2];
+                unsafe {
+                    crate::danger::generic_linear_combine::<$t, $im, AutoMath>(
+                        &l1, 2.0, &l2, 0.5, &mut out,
+                    )
+                };
+            }
+        }
+    };
+}
+
+macro_rules! test_cmp_horizontal_extra {
+    ($t:ident, $im:ident) => {
+        paste::paste! {
+            #[test]
+            fn [<test_ $im:lower _ $t _max_horizontal_edges>]() {
+                let (l1, _) = crate::test_utils::get_sample_vectors::<$t>(DATA_SIZE);
+                unsafe {
+                    crate::danger::op_cmp_max::test_max_horizontal_edges::<$t, $im>(l1, 10.0)
+                };
+            }
+
+            #[test]
+            fn [<test_ $im:lower _ $t _min_horizontal_edges>]() {
+                let (l1, _) = crate::test_utils::get_sample_vectors::<$t>(DATA_SIZE);
+                unsafe {
+                    crate::danger::op_cmp_min::test_min_horizontal_edges::<$t, $im>(l1, -10.0)
+                };
+            }
+
+            #[test]
+            fn [<test_ $im:lower _ $t _argmax>]() {
+                let (l1, _) = crate::test_utils::get_sample_vectors::<$t>(DATA_SIZE);
+                unsafe { crate::danger::op_cmp_max::test_argmax::<$t, $im>(l1, 10.0) };
+            }
+
+            #[test]
+            fn [<test_ $im:lower _ $t _argmin>]() {
+                let (l1, _) = crate::test_utils::get_sample_vectors::<$t>(DATA_SIZE);
+                unsafe { crate::danger::op_cmp_min::test_argmin::<$t, $im>(l1, -10.0) };
+            }
+        }
+    };
+}
+
+macro_rules! test_recip_inplace_extra {
+    ($t:ident, $im:ident) => {
+        paste::paste! {
+            #[test]
+            fn [<test_ $im:lower _ $t _recip_inplace>]() {
+                let (l1, _) = crate::test_utils::get_sample_vectors::<$t>(DATA_SIZE);
+                let special = vec![0.0, -0.0, -2.5, -1e-3, 4.0];
+                unsafe {
+                    op_arithmetic_vertical::tests::test_simple_recip_inplace::<$t, $im>(
+                        l1, special,
+                    )
+                };
+            }
+        }
+    };
+}
+
+macro_rules! test_momentum_update_extra {
+    ($t:ident, $im:ident) => {
+        paste::paste! {
+            #[test]
+            fn [<test_ $im:lower _ $t _momentum_update>]() {
+                let (l1, l2) = crate::test_utils::get_sample_vectors::<$t>(DATA_SIZE);
+                for (momentum, learning_rate) in [(0.0, 0.1), (0.9, 0.01), (0.5, -0.25)] {
+                    unsafe {
+                        op_arithmetic_vertical::tests::test_simple_momentum_update::<$t, $im>(
+                            l1.clone(),
+                            l2.clone(),
+                            momentum,
+                            learning_rate,
+                        )
+                    };
+                }
+            }
+
+            #[test]
+            #[should_panic]
+            fn [<test_ $im:lower _ $t _momentum_update_length_missmatch>]() {
+                let mut velocity = vec![0.0; 3];
+                let gradient = vec![1.0; 3];
+                let mut params = vec![1.0; 2];
+                unsafe {
+                    crate::danger::generic_momentum_update::<$t, $im, AutoMath>(
+                        &mut velocity,
+                        &gradient,
+                        0.9,
+                        0.1,
+                        &mut params,
+                    )
+                };
+            }
+        }
+    };
+}
+
+macro_rules! test_welford_extra {
+    ($t:ident, $im:ident) => {
+        paste::paste! {
+            #[test]
+            fn [<test_ $im:lower _ $t _welford_vertical>]() {
+                let (l1, _) = crate::test_utils::get_sample_vectors::<$t>(DATA_SIZE);
+                unsafe { crate::danger::op_welford::test_welford::<$t, $im>(l1) };
+            }
+
+            #[test]
+            fn [<test_ $im:lower _ $t _mean_variance>]() {
+                let (l1, _) = crate::test_utils::get_sample_vectors::<$t>(DATA_SIZE);
+                unsafe { crate::danger::op_welford::test_mean_variance::<$t, $im>(l1) };
+            }
+        }
+    };
+}
+
 // In cases like f32 and f64 where we have comparison we need to ensure that
 // all implementations behave equivalently and consistently.
 macro_rules! test_nan_sanity {
     ($t:ident, $im:ident) => {
         paste::paste! {
             #[test]
-            fn [<test_ $im:lower _ $t _float_sanity>]() {
-                let l1 = vec![1.0, 0.0, $t::NAN, $t::INFINITY, $t::NEG_INFINITY];
-                let l2 = vec![1.0, 1.0, 1.0, 1.0, 1.0];
+            fn [<test_ $im:lower _ $t _float_sanity>]() {
+                let l1 = vec![1.0, 0.0, $t::NAN, $t::INFINITY, $t::NEG_INFINITY];
+                let l2 = vec![1.0, 1.0, 1.0, 1.0, 1.0];
+
+                test_cmp_value_all::<$t, $im>(l1.clone(), 0.0);
+                test_cmp_vector_all::<$t, $im>(l1, l2);
+            }
+
+            #[test]
+            fn [<test_ $im:lower _ $t _clamp_nan>]() {
+                unsafe { crate::danger::op_cmp_clamp::test_clamp_nan::<$t, $im>() };
+            }
+        }
+    };
+}
+
+macro_rules! test_suite {
+    ($t:ident, $im:ident) => {
+        paste::paste! {
+            #[test]
+            fn [<test_ $im:lower _ $t _suite>]() {
+                unsafe { crate::danger::impl_test::test_suite_impl::<$t, $im>() }
+            }
+
+            #[test]
+            fn [<test_ $im:lower _ $t _cumulative_sum>]() {
+                let (l1, _) = crate::test_utils::get_sample_vectors::<$t>(DATA_SIZE);
+                unsafe { crate::danger::op_sum::test_cumulative_sum::<$t, $im>(l1) };
+            }
+
+            #[test]
+            #[should_panic]
+            fn [<test_ $im:lower _ $t _cumulative_sum_length_missmatch>]() {
+                let (l1, mut l2) = (vec![1 as $t; 10], vec![0 as $t; 9]);
+                unsafe {
+                    crate::danger::generic_cumulative_sum::<$t, $im, AutoMath>(
+                        &l1,
+                        &mut l2,
+                    )
+                };
+            }
+
+            #[test]
+            fn [<test_ $im:lower _ $t _clamp>]() {
+                let (l1, _) = crate::test_utils::get_sample_vectors::<$t>(DATA_SIZE);
+                unsafe { crate::danger::op_cmp_clamp::test_clamp::<$t, $im>(l1) };
+            }
+
+            #[test]
+            #[should_panic]
+            fn [<test_ $im:lower _ $t _clamp_length_missmatch>]() {
+                let (l1, mut l2) = (vec![1 as $t; 10], vec![0 as $t; 9]);
+                unsafe {
+                    crate::danger::generic_cmp_clamp::<$t, $im, AutoMath>(
+                        &l1,
+                        0 as $t,
+                        2 as $t,
+                        &mut l2,
+                    )
+                };
+            }
+
+            #[test]
+            fn [<test_ $im:lower _ $t _sum_vertical_accumulate>]() {
+                let (l1, _) = crate::test_utils::get_sample_vectors::<$t>(DATA_SIZE);
+                unsafe { crate::danger::op_sum::test_sum_vertical_accumulate::<$t, $im>(l1) };
+            }
+
+            #[test]
+            #[should_panic]
+            fn [<test_ $im:lower _ $t _sum_vertical_accumulate_length_missmatch>]() {
+                let (l1, mut l2) = (vec![1 as $t; 10], vec![0 as $t; 3]);
+                unsafe {
+                    crate::danger::generic_sum_vertical_accumulate::<$t, $im, AutoMath>(
+                        &l1,
+                        &mut l2,
+                    )
+                };
+            }
+
+            #[test]
+            fn [<test_ $im:lower _ $t _hadamard_transform_inplace>]() {
+                let (l1, _) = crate::test_utils::get_sample_vectors::<$t>(DATA_SIZE);
+                unsafe {
+                    crate::danger::op_hadamard::test_hadamard_transform_inplace::<$t, $im>(l1)
+                };
+            }
+
+            #[test]
+            #[should_panic]
+            fn [<test_ $im:lower _ $t _hadamard_transform_inplace_length_missmatch>]() {
+                let mut l1 = vec![1 as $t; 12];
+                unsafe {
+                    crate::danger::generic_hadamard_transform_inplace::<$t, $im, AutoMath>(
+                        &mut l1,
+                    )
+                };
+            }
+
+            #[test]
+            fn [<test_ $im:lower _ $t _max_cumulative_inplace>]() {
+                let (l1, _) = crate::test_utils::get_sample_vectors::<$t>(DATA_SIZE);
+                unsafe {
+                    crate::danger::op_cmp_max::test_max_cumulative_inplace::<$t, $im>(l1)
+                };
+            }
+
+            #[test]
+            fn [<test_ $im:lower _ $t _max_columns>]() {
+                let (l1, _) = crate::test_utils::get_sample_vectors::<$t>(DATA_SIZE);
+                unsafe { crate::danger::op_cmp_max::test_max_columns::<$t, $im>(l1) };
+            }
+
+            #[test]
+            #[should_panic]
+            fn [<test_ $im:lower _ $t _max_columns_length_missmatch>]() {
+                let (l1, mut l2) = (vec![1 as $t; 10], vec![0 as $t; 3]);
+                unsafe {
+                    crate::danger::generic_cmp_max_columns::<$t, $im, AutoMath>(
+                        &l1,
+                        &mut l2,
+                    )
+                };
+            }
+
+            #[test]
+            fn [<test_ $im:lower _ $t _max_floor_counting>]() {
+                let (l1, _) = crate::test_utils::get_sample_vectors::<$t>(DATA_SIZE);
+                unsafe { crate::danger::op_cmp_max::test_max_floor_counting::<$t, $im>(l1) };
+            }
+
+            #[test]
+            fn [<test_ $im:lower _ $t _top_k>]() {
+                let (l1, _) = crate::test_utils::get_sample_vectors::<$t>(DATA_SIZE);
+                unsafe { crate::danger::op_cmp_max::test_top_k::<$t, $im>(l1) };
+            }
+
+            #[test]
+            #[should_panic]
+            fn [<test_ $im:lower _ $t _top_k_length_missmatch>]() {
+                let (l1, _) = crate::test_utils::get_sample_vectors::<$t>(DATA_SIZE);
+                let mut indices = vec![0; 4];
+                let mut values = vec![0 as $t; 3];
+                let _ = unsafe {
+                    crate::danger::generic_cmp_top_k::<$t, $im, AutoMath>(
+                        &l1,
+                        &mut indices,
+                        &mut values,
+                    )
+                };
+            }
+
+            #[test]
+            fn [<test_ $im:lower _ $t _dot>]() {
+                let (l1, l2) = crate::test_utils::get_sample_vectors::<$t>(DATA_SIZE);
+                unsafe { crate::danger::op_dot::test_dot::<$t, $im>(l1, l2) };
+            }
+
+            #[test]
+            #[should_panic]
+            fn [<test_ $im:lower _ $t _dot_length_missmatch>]() {
+                let (l1, l2) = crate::test_utils::get_sample_vectors::<$t>(DATA_SIZE);
+                let l2 = &l2[..DATA_SIZE - 1];
+                let _ = unsafe {
+                    crate::danger::generic_dot::<$t, $im, AutoMath, _, _>(&l1, l2)
+                };
+            }
+
+            #[test]
+            fn [<test_ $im:lower _ $t _complex_dot_planar>]() {
+                let (l1, l2) = crate::test_utils::get_sample_vectors::<$t>(DATA_SIZE);
+                unsafe { crate::danger::op_complex::test_complex_dot_planar::<$t, $im>(l1, l2) };
+            }
+
+            #[test]
+            fn [<test_ $im:lower _ $t _dot_self_and_cross>]() {
+                let (l1, l2) = crate::test_utils::get_sample_vectors::<$t>(DATA_SIZE);
+                unsafe { crate::danger::op_dot::test_dot_self_and_cross::<$t, $im>(l1, l2) };
+            }
+
+            #[test]
+            fn [<test_ $im:lower _ $t _dot_single_register>]() {
+                let (l1, l2) = crate::test_utils::get_sample_vectors::<$t>(DATA_SIZE);
+                unsafe { crate::danger::op_dot::test_dot_single_register::<$t, $im>(l1, l2) };
+            }
+
+            #[test]
+            #[should_panic]
+            fn [<test_ $im:lower _ $t _dot_single_register_too_long>]() {
+                let l1 = vec![1 as $t; <$im as SimdRegister<$t>>::elements_per_lane() + 1];
+                unsafe {
+                    crate::danger::generic_dot_single_register::<$t, $im, AutoMath, _, _>(&l1, &l1)
+                };
+            }
+
+            #[test]
+            fn [<test_ $im:lower _ $t _dot_dense_aligned>]() {
+                let (l1, l2) = crate::test_utils::get_sample_vectors::<$t>(DATA_SIZE);
+                unsafe { crate::danger::op_dot::test_dot_dense_aligned::<$t, $im>(l1, l2) };
+            }
+
+            #[test]
+            #[should_panic]
+            fn [<test_ $im:lower _ $t _dot_dense_aligned_misaligned>]() {
+                let l1 = vec![1 as $t; <$im as SimdRegister<$t>>::elements_per_dense() + 1];
+                unsafe {
+                    crate::danger::generic_dot_dense_aligned::<$t, $im, AutoMath>(&l1, &l1)
+                };
+            }
+
+            #[test]
+            fn [<test_ $im:lower _ $t _dot_diff>]() {
+                let (l1, l2) = crate::test_utils::get_sample_vectors::<$t>(DATA_SIZE);
+                unsafe { crate::danger::op_dot::test_dot_diff::<$t, $im>(l1, l2) };
+            }
+
+            #[test]
+            #[should_panic]
+            fn [<test_ $im:lower _ $t _dot_diff_length_missmatch>]() {
+                let l1 = vec![1 as $t, 2 as $t, 3 as $t];
+                let l2 = vec![1 as $t, 2 as $t];
+                unsafe {
+                    crate::danger::generic_dot_diff::<$t, $im, AutoMath, _, _, _>(&l1, &l1, &l2)
+                };
+            }
+
+            #[test]
+            fn [<test_ $im:lower _ $t _col_dot>]() {
+                let (l1, l2) = crate::test_utils::get_sample_vectors::<$t>(DATA_SIZE);
+                unsafe { crate::danger::op_dot::test_col_dot::<$t, $im>(l1, l2) };
+            }
+
+            #[test]
+            fn [<test_ $im:lower _ $t _dot_strided>]() {
+                let (l1, l2) = crate::test_utils::get_sample_vectors::<$t>(DATA_SIZE);
+                unsafe { crate::danger::op_dot::test_dot_strided::<$t, $im>(l1, l2) };
+            }
+
+            #[test]
+            #[should_panic]
+            fn [<test_ $im:lower _ $t _dot_strided_length_missmatch>]() {
+                let (l1, l2) = (vec![1 as $t; 8], vec![1 as $t; 8]);
+                let _ = unsafe {
+                    crate::danger::generic_dot_strided::<$t, $im, AutoMath>(
+                        4, &l1, 3, &l2, 1,
+                    )
+                };
+            }
 
-                test_cmp_value_all::<$t, $im>(l1.clone(), 0.0);
-                test_cmp_vector_all::<$t, $im>(l1, l2);
+            #[test]
+            fn [<test_ $im:lower _ $t _dot_stride2>]() {
+                let (l1, l2) = crate::test_utils::get_sample_vectors::<$t>(DATA_SIZE);
+                unsafe { crate::danger::op_dot::test_dot_stride2::<$t, $im>(l1, l2) };
             }
-        }
-    };
-}
 
-macro_rules! test_suite {
-    ($t:ident, $im:ident) => {
-        paste::paste! {
             #[test]
-            fn [<test_ $im:lower _ $t _suite>]() {
-                unsafe { crate::danger::impl_test::test_suite_impl::<$t, $im>() }
+            #[should_panic]
+            fn [<test_ $im:lower _ $t _dot_stride2_odd_length>]() {
+                let (l1, l2) = (vec![1 as $t; 7], vec![1 as $t; 7]);
+                let _ = unsafe {
+                    crate::danger::generic_dot_stride2::<$t, $im, AutoMath>(&l1, &l2)
+                };
             }
 
             #[test]
-            fn [<test_ $im:lower _ $t _dot>]() {
+            fn [<test_ $im:lower _ $t _frobenius_dot>]() {
                 let (l1, l2) = crate::test_utils::get_sample_vectors::<$t>(DATA_SIZE);
-                unsafe { crate::danger::op_dot::test_dot::<$t, $im>(l1, l2) };
+                unsafe { crate::danger::op_dot::test_frobenius_dot::<$t, $im>(l1, l2) };
+            }
+
+            #[test]
+            #[should_panic]
+            fn [<test_ $im:lower _ $t _frobenius_dot_length_missmatch>]() {
+                let (l1, l2) = (vec![1 as $t; 12], vec![1 as $t; 9]);
+                let _ = unsafe {
+                    crate::danger::generic_frobenius_dot::<$t, $im, AutoMath>(&l1, &l2)
+                };
+            }
+
+            #[test]
+            fn [<test_ $im:lower _ $t _dense_forward>]() {
+                let (l1, l2) = crate::test_utils::get_sample_vectors::<$t>(DATA_SIZE);
+                unsafe { crate::danger::op_dot::test_dense_forward::<$t, $im>(l1, l2) };
+            }
+
+            #[test]
+            #[should_panic]
+            fn [<test_ $im:lower _ $t _dense_forward_shape_missmatch>]() {
+                let (l1, l2) = crate::test_utils::get_sample_vectors::<$t>(12);
+                let mut output = vec![0 as $t; 3];
+                unsafe {
+                    crate::danger::generic_dense_forward::<$t, $im, AutoMath>(
+                        &l1, &l2[..3], &l2[..5], 5, 3, &mut output,
+                    )
+                };
+            }
+
+            #[test]
+            fn [<test_ $im:lower _ $t _matvec>]() {
+                let (l1, l2) = crate::test_utils::get_sample_vectors::<$t>(DATA_SIZE);
+                unsafe { crate::danger::op_dot::test_matvec::<$t, $im>(l1, l2) };
+            }
+
+            #[test]
+            #[should_panic]
+            fn [<test_ $im:lower _ $t _matvec_shape_missmatch>]() {
+                let (l1, l2) = crate::test_utils::get_sample_vectors::<$t>(12);
+                let mut out = vec![0 as $t; 3];
+                unsafe {
+                    crate::danger::generic_matvec::<$t, $im, AutoMath>(
+                        &l1[..11],
+                        &l2[..4],
+                        &mut out,
+                    )
+                };
+            }
+
+            #[test]
+            fn [<test_ $im:lower _ $t _dot_wrapping>]() {
+                let (l1, l2) = crate::test_utils::get_sample_vectors::<$t>(DATA_SIZE);
+                unsafe { crate::danger::op_dot::test_dot_wrapping::<$t, $im>(l1, l2) };
+            }
+
+            #[test]
+            #[should_panic]
+            fn [<test_ $im:lower _ $t _dot_wrapping_length_missmatch>]() {
+                let (l1, l2) = crate::test_utils::get_sample_vectors::<$t>(12);
+                unsafe {
+                    crate::danger::generic_dot_wrapping::<$t, $im, AutoMath>(
+                        &l1[..8], 4, 9, &l2[..9],
+                    )
+                };
+            }
+
+            #[test]
+            fn [<test_ $im:lower _ $t _pairwise_dot>]() {
+                let (l1, l2) = crate::test_utils::get_sample_vectors::<$t>(DATA_SIZE);
+                unsafe { crate::danger::op_dot::test_pairwise_dot::<$t, $im>(l1, l2) };
+            }
+
+            #[test]
+            #[should_panic]
+            fn [<test_ $im:lower _ $t _pairwise_dot_shape_missmatch>]() {
+                let (l1, l2) = crate::test_utils::get_sample_vectors::<$t>(12);
+                let mut out = vec![0 as $t; 5];
+                unsafe {
+                    crate::danger::generic_pairwise_dot::<$t, $im, AutoMath>(
+                        &l1[..8], &l2, 4, &mut out,
+                    )
+                };
+            }
+
+            #[test]
+            fn [<test_ $im:lower _ $t _masked_dot>]() {
+                let (l1, l2) = crate::test_utils::get_sample_vectors::<$t>(DATA_SIZE);
+                unsafe { crate::danger::op_dot::test_masked_dot::<$t, $im>(l1, l2) };
+            }
+
+            #[test]
+            #[should_panic]
+            fn [<test_ $im:lower _ $t _masked_dot_short_bitmask>]() {
+                let (l1, l2) = crate::test_utils::get_sample_vectors::<$t>(130);
+                unsafe {
+                    crate::danger::generic_masked_dot::<$t, $im, AutoMath>(&l1, &l2, &[u64::MAX; 2])
+                };
+            }
+
+            #[test]
+            fn [<test_ $im:lower _ $t _dot_skip_zero>]() {
+                let (l1, l2) = crate::test_utils::get_sample_vectors::<$t>(DATA_SIZE);
+                unsafe { crate::danger::op_dot::test_dot_skip_zero::<$t, $im>(l1, l2) };
+            }
+
+            #[test]
+            fn [<test_ $im:lower _ $t _dot_progress>]() {
+                unsafe { crate::danger::op_dot::test_dot_progress::<$t, $im>(1 as $t) };
+            }
+
+            #[test]
+            fn [<test_ $im:lower _ $t _dot_streaming_both>]() {
+                let (l1, l2) = crate::test_utils::get_sample_vectors::<$t>(DATA_SIZE);
+                unsafe {
+                    crate::danger::op_dot::test_dot_streaming_both::<$t, $im>(l1, l2)
+                };
+            }
+
+            #[test]
+            #[should_panic]
+            fn [<test_ $im:lower _ $t _dot_streaming_both_size_missmatch>]() {
+                let (l1, l2) = crate::test_utils::get_sample_vectors::<$t>(DATA_SIZE);
+                unsafe {
+                    crate::danger::generic_dot_streaming_both::<$t, $im, AutoMath, _, _>(
+                        l1.chunks(7),
+                        l2[1..].chunks(5),
+                    )
+                };
             }
 
             #[test]
@@ -60,12 +1058,87 @@ macro_rules! test_suite {
                 unsafe { crate::danger::op_norm::test_squared_norm::<$t, $im>(l1) };
             }
 
+            #[test]
+            fn [<test_ $im:lower _ $t _euclidean_batch4>]() {
+                let (l1, l2) = crate::test_utils::get_sample_vectors::<$t>(DATA_SIZE);
+                unsafe { crate::danger::op_euclidean::test_euclidean_batch4::<$t, $im>(l1, l2) };
+            }
+
+            #[test]
+            fn [<test_ $im:lower _ $t _squared_euclidean_batch>]() {
+                let (l1, l2) = crate::test_utils::get_sample_vectors::<$t>(DATA_SIZE);
+                unsafe {
+                    crate::danger::op_euclidean::test_squared_euclidean_batch::<$t, $im>(
+                        l1, l2,
+                    )
+                };
+            }
+
+            #[test]
+            #[should_panic]
+            fn [<test_ $im:lower _ $t _squared_euclidean_batch_length_missmatch>]() {
+                let query = vec![1 as $t; 3];
+                let matrix = vec![1 as $t; 12];
+                let mut out = vec![0 as $t; 3];
+                unsafe {
+                    crate::danger::generic_squared_euclidean_batch::<$t, $im, AutoMath>(
+                        &query,
+                        &matrix,
+                        &mut out,
+                    )
+                };
+            }
+
+            #[test]
+            #[should_panic]
+            fn [<test_ $im:lower _ $t _euclidean_batch4_length_missmatch>]() {
+                let query = vec![1 as $t; 3];
+                let docs4 = vec![1 as $t; 11];
+                let mut out = [0 as $t; 4];
+                unsafe {
+                    crate::danger::generic_squared_euclidean_batch4::<$t, $im, AutoMath>(
+                        &query,
+                        &docs4,
+                        3,
+                        &mut out,
+                    )
+                };
+            }
+
             #[test]
             fn [<test_ $im:lower _ $t _euclidean>]() {
                 let (l1, l2) = crate::test_utils::get_sample_vectors::<$t>(DATA_SIZE);
                 unsafe { crate::danger::op_euclidean::test_euclidean::<$t, $im>(l1, l2) };
             }
 
+            #[test]
+            fn [<test_ $im:lower _ $t _weighted_squared_euclidean>]() {
+                let (l1, l2) = crate::test_utils::get_sample_vectors::<$t>(DATA_SIZE);
+                unsafe {
+                    crate::danger::op_euclidean::test_weighted_squared_euclidean::<
+                        $t,
+                        $im,
+                    >(l1, l2)
+                };
+            }
+
+            #[test]
+            #[should_panic]
+            fn [<test_ $im:lower _ $t _weighted_squared_euclidean_length_missmatch>]() {
+                let (l1, l2) = crate::test_utils::get_sample_vectors::<$t>(DATA_SIZE);
+                let w = vec![1 as $t; DATA_SIZE - 1];
+                unsafe {
+                    crate::danger::generic_weighted_squared_euclidean::<
+                        $t,
+                        $im,
+                        AutoMath,
+                        _,
+                        _,
+                        _,
+                    >(&l1, &l2, &w)
+                };
+            }
+
             #[test]
             fn [<test_ $im:lower _ $t _max>]() {
                 let (l1, l2) = crate::test_utils::get_sample_vectors::<$t>(DATA_SIZE);
@@ -78,24 +1151,136 @@ macro_rules! test_suite {
                 unsafe { crate::danger::op_cmp_min::test_min::<$t, $im>(l1, l2) };
             }
 
+            #[test]
+            fn [<test_ $im:lower _ $t _min_columns>]() {
+                let (l1, _) = crate::test_utils::get_sample_vectors::<$t>(DATA_SIZE);
+                unsafe { crate::danger::op_cmp_min::test_min_columns::<$t, $im>(l1) };
+            }
+
+            #[test]
+            #[should_panic]
+            fn [<test_ $im:lower _ $t _min_columns_length_missmatch>]() {
+                let (l1, mut l2) = (vec![1 as $t; 10], vec![0 as $t; 3]);
+                unsafe {
+                    crate::danger::generic_cmp_min_columns::<$t, $im, AutoMath>(
+                        &l1,
+                        &mut l2,
+                    )
+                };
+            }
+
             #[test]
             fn [<test_ $im:lower _ $t _sum>]() {
                 let l1 = vec![1 as $t; DATA_SIZE];
                 unsafe { crate::danger::op_sum::test_sum::<$t, $im>(l1) };
             }
 
+            #[test]
+            fn [<test_ $im:lower _ $t _sum_threshold>]() {
+                let (l1, _) = crate::test_utils::get_sample_vectors::<$t>(DATA_SIZE);
+                unsafe { crate::danger::op_sum::test_sum_threshold::<$t, $im>(l1) };
+            }
+
+            #[test]
+            fn [<test_ $im:lower _ $t _map_sum>]() {
+                let (l1, _) = crate::test_utils::get_sample_vectors::<$t>(DATA_SIZE);
+                unsafe { crate::danger::op_map_sum::test_map_sum::<$t, $im>(l1) };
+            }
+
+            #[test]
+            fn [<test_ $im:lower _ $t _sum_hint>]() {
+                unsafe { crate::danger::op_sum::test_sum_hint::<$t, $im>(1 as $t) };
+            }
+
+            #[test]
+            fn [<test_ $im:lower _ $t _sum_exact>]() {
+                unsafe { crate::danger::op_sum::test_sum_exact::<$t, $im>(1 as $t) };
+            }
+
+            #[cfg(debug_assertions)]
+            #[test]
+            #[should_panic]
+            fn [<test_ $im:lower _ $t _sum_exact_bad_length>]() {
+                let l1 = vec![1 as $t; DATA_SIZE];
+                unsafe {
+                    crate::danger::op_sum::generic_sum_exact::<$t, $im, AutoMath>(&l1)
+                };
+            }
+
             #[test]
             fn [<test_ $im:lower _ $t _arithmetic_value>]() {
                 let (l1, _) = (vec![1 as $t; DATA_SIZE], vec![3 as $t; DATA_SIZE]);
                 test_arithmetic_value_all::<$t, $im>(l1, 2 as $t);
             }
 
+            #[test]
+            fn [<test_ $im:lower _ $t _vertical_add_rows>]() {
+                let (l1, l2) = crate::test_utils::get_sample_vectors::<$t>(DATA_SIZE);
+                unsafe {
+                    op_arithmetic_vertical::tests::test_vertical_add_rows::<$t, $im>(l1, l2)
+                };
+            }
+
+            #[test]
+            fn [<test_ $im:lower _ $t _vertical_lengths>]() {
+                let (l1, l2) = crate::test_utils::get_sample_vectors::<$t>(DATA_SIZE);
+                unsafe {
+                    op_arithmetic_vertical::tests::test_vertical_lengths::<$t, $im>(l1, l2)
+                };
+            }
+
             #[test]
             fn [<test_ $im:lower _ $t _arithmetic_vector>]() {
                 let (l1, l2) = (vec![1 as $t; DATA_SIZE], vec![3 as $t; DATA_SIZE]);
                 test_arithmetic_vector_all::<$t, $im>(l1, l2);
             }
 
+            #[test]
+            fn [<test_ $im:lower _ $t _outer>]() {
+                let (l1, l2) = crate::test_utils::get_sample_vectors::<$t>(DATA_SIZE);
+                unsafe {
+                    op_arithmetic_vertical::tests::test_simple_outer::<$t, $im>(
+                        l1[..4].to_vec(),
+                        l2[..5].to_vec(),
+                    );
+                    op_arithmetic_vertical::tests::test_simple_outer::<$t, $im>(
+                        l1[..13].to_vec(),
+                        l2[..533].to_vec(),
+                    );
+                    op_arithmetic_vertical::tests::test_simple_outer::<$t, $im>(
+                        l1[..3].to_vec(),
+                        Vec::new(),
+                    );
+                };
+            }
+
+            #[test]
+            fn [<test_ $im:lower _ $t _diff>]() {
+                let (l1, _) = crate::test_utils::get_sample_vectors::<$t>(DATA_SIZE);
+                unsafe { op_arithmetic_vertical::tests::test_simple_diff::<$t, $im>(l1) };
+            }
+
+            #[test]
+            #[should_panic]
+            fn [<test_ $im:lower _ $t _diff_length_missmatch>]() {
+                let l1 = vec![1 as $t, 2 as $t, 3 as $t];
+                let mut result = vec![0 as $t; 3];
+                unsafe {
+                    crate::danger::generic_diff::<$t, $im, AutoMath, _>(&l1, &mut result)
+                };
+            }
+
+            #[test]
+            #[should_panic]
+            fn [<test_ $im:lower _ $t _outer_length_missmatch>]() {
+                let l1 = vec![1 as $t, 2 as $t, 3 as $t];
+                let l2 = vec![1 as $t, 2 as $t];
+                let mut result = vec![0 as $t; 5];
+                unsafe {
+                    crate::danger::generic_outer::<$t, $im, AutoMath, _>(&l1, &l2, &mut result)
+                };
+            }
+
             #[test]
             fn [<test_ $im:lower _ $t _cmp_value>]() {
                 let (l1, _) = (vec![1 as $t; DATA_SIZE], vec![3 as $t; DATA_SIZE]);
@@ -108,6 +1293,59 @@ macro_rules! test_suite {
                 test_cmp_vector_all::<$t, $im>(l1, l2);
             }
 
+            #[test]
+            fn [<test_ $im:lower _ $t _decimate>]() {
+                let (l1, _) = crate::test_utils::get_sample_vectors::<$t>(DATA_SIZE);
+                unsafe {
+                    crate::danger::op_decimate::test_decimate::<$t, $im>(l1.clone(), 1);
+                    crate::danger::op_decimate::test_decimate::<$t, $im>(l1.clone(), 3);
+                    crate::danger::op_decimate::test_decimate::<$t, $im>(l1, 64);
+                };
+            }
+
+            #[test]
+            #[should_panic]
+            fn [<test_ $im:lower _ $t _decimate_length_missmatch>]() {
+                let l1 = vec![1 as $t; 10];
+                let mut result = vec![0 as $t; 3];
+                unsafe {
+                    crate::danger::generic_decimate::<$t, $im, AutoMath, _>(
+                        &l1,
+                        3,
+                        &mut result,
+                    )
+                };
+            }
+
+            #[test]
+            fn [<test_ $im:lower _ $t _cmp_gt_bitmask>]() {
+                let (l1, l2) = crate::test_utils::get_sample_vectors::<$t>(DATA_SIZE);
+                unsafe {
+                    op_cmp_bitmask::tests::test_simple_vectors_gt_bitmask::<$t, $im>(
+                        l1.clone(),
+                        l2,
+                    );
+                    op_cmp_bitmask::tests::test_broadcast_value_gt_bitmask::<$t, $im>(
+                        l1,
+                        2 as $t,
+                    );
+                };
+            }
+
+            #[test]
+            #[should_panic]
+            fn [<test_ $im:lower _ $t _cmp_gt_bitmask_length_missmatch>]() {
+                let l1 = vec![1 as $t; 65];
+                let mut result = vec![0; 1];
+                unsafe {
+                    crate::danger::generic_cmp_gt_bitmask::<$t, $im, AutoMath, _, _>(
+                        &l1,
+                        2 as $t,
+                        &mut result,
+                    )
+                };
+            }
+
             #[test]
             #[should_panic]
             fn [<test_ $im:lower _ $t _cosine_length_missmatch_no_projection>]() {
@@ -301,7 +1539,11 @@ where
             l1.clone(),
             value,
         );
-        op_arithmetic_vertical::tests::test_broadcast_value_mul::<_, R>(l1, value)
+        op_arithmetic_vertical::tests::test_broadcast_value_mul::<_, R>(
+            l1.clone(),
+            value,
+        );
+        op_arithmetic_vertical::tests::test_value_inplace::<_, R>(l1, value)
     };
 }
 
@@ -342,7 +1584,8 @@ where
         op_cmp_vertical::tests::test_simple_vectors_lt::<_, R>(l1.clone(), l2.clone());
         op_cmp_vertical::tests::test_simple_vectors_lte::<_, R>(l1.clone(), l2.clone());
         op_cmp_vertical::tests::test_simple_vectors_gt::<_, R>(l1.clone(), l2.clone());
-        op_cmp_vertical::tests::test_simple_vectors_gte::<_, R>(l1, l2);
+        op_cmp_vertical::tests::test_simple_vectors_gte::<_, R>(l1.clone(), l2.clone());
+        op_cmp_bitmask::tests::test_simple_vectors_gt_bitmask::<_, R>(l1, l2);
     };
 }
 
@@ -359,7 +1602,8 @@ where
         op_cmp_vertical::tests::test_broadcast_value_lt::<_, R>(l1.clone(), value);
         op_cmp_vertical::tests::test_broadcast_value_lte::<_, R>(l1.clone(), value);
         op_cmp_vertical::tests::test_broadcast_value_gt::<_, R>(l1.clone(), value);
-        op_cmp_vertical::tests::test_broadcast_value_gte::<_, R>(l1, value);
+        op_cmp_vertical::tests::test_broadcast_value_gte::<_, R>(l1.clone(), value);
+        op_cmp_bitmask::tests::test_broadcast_value_gt_bitmask::<_, R>(l1, value);
     };
 }
 
@@ -376,6 +1620,8 @@ test_suite!(u64, Fallback);
 
 test_cosine_extra!(f32, Fallback);
 test_cosine_extra!(f64, Fallback);
+test_cosine_similarity_extra!(f32, Fallback);
+test_cosine_similarity_extra!(f64, Fallback);
 // test_cosine_extra!(i32, Fallback); - Divide by zero error from RNG on miri.
 // test_cosine_extra!(i64, Fallback); - Divide by zero error from RNG on miri.
 test_cosine_extra!(u8, Fallback);
@@ -386,6 +1632,48 @@ test_cosine_extra!(u64, Fallback);
 test_nan_sanity!(f32, Fallback);
 test_nan_sanity!(f64, Fallback);
 
+test_clip_norm_extra!(f32, Fallback);
+test_clip_norm_extra!(f64, Fallback);
+test_welford_extra!(f32, Fallback);
+test_welford_extra!(f64, Fallback);
+test_pearson_extra!(f32, Fallback);
+test_pearson_extra!(f64, Fallback);
+test_weighted_mean_extra!(f32, Fallback);
+test_weighted_mean_extra!(f64, Fallback);
+test_quat_mul_extra!(f32, Fallback);
+test_quat_mul_extra!(f64, Fallback);
+test_sum_with_status_extra!(f32, Fallback);
+test_sum_with_status_extra!(f64, Fallback);
+test_axpy_extra!(f32, Fallback);
+test_axpy_extra!(f64, Fallback);
+test_sum_scaled_extra!(f32, Fallback);
+test_sum_scaled_extra!(f64, Fallback);
+test_pairwise_squared_euclidean_extra!(f32, Fallback);
+test_pairwise_squared_euclidean_extra!(f64, Fallback);
+test_sum_compensated_extra!(f32, Fallback);
+test_sum_compensated_extra!(f64, Fallback);
+test_linear_combine_extra!(f32, Fallback);
+test_linear_combine_extra!(f64, Fallback);
+test_cmp_horizontal_extra!(f32, Fallback);
+test_cmp_horizontal_extra!(f64, Fallback);
+test_recip_inplace_extra!(f32, Fallback);
+test_recip_inplace_extra!(f64, Fallback);
+test_momentum_update_extra!(f32, Fallback);
+test_momentum_update_extra!(f64, Fallback);
+test_cosine_fast_extra!(Fallback);
+test_adaptive_sum_extra!(Fallback);
+test_sum_i32_widen_extra!(Fallback);
+test_cross_entropy_extra!(Fallback);
+test_approx_median_extra!(Fallback);
+test_entropy_extra!(Fallback);
+test_canberra_extra!(Fallback);
+test_bray_curtis_extra!(Fallback);
+test_minkowski_extra!(Fallback);
+test_vecmath_extra!(Fallback);
+test_pow_extra!(Fallback);
+test_softmax_extra!(Fallback);
+test_dot_f32_widen_extra!(Fallback);
+
 #[cfg(all(target_feature = "avx2", test))]
 mod avx2_tests {
     use super::*;
@@ -403,6 +1691,8 @@ mod avx2_tests {
 
     test_cosine_extra!(f32, Avx2);
     test_cosine_extra!(f64, Avx2);
+    test_cosine_similarity_extra!(f32, Avx2);
+    test_cosine_similarity_extra!(f64, Avx2);
     // test_cosine_extra!(i32, Avx2); - Divide by zero error from RNG on miri.
     // test_cosine_extra!(i64, Avx2); - Divide by zero error from RNG on miri.
     test_cosine_extra!(u8, Avx2);
@@ -412,6 +1702,65 @@ mod avx2_tests {
 
     test_nan_sanity!(f32, Avx2);
     test_nan_sanity!(f64, Avx2);
+
+    test_clip_norm_extra!(f32, Avx2);
+    test_clip_norm_extra!(f64, Avx2);
+    test_welford_extra!(f32, Avx2);
+    test_welford_extra!(f64, Avx2);
+    test_pearson_extra!(f32, Avx2);
+    test_pearson_extra!(f64, Avx2);
+    test_weighted_mean_extra!(f32, Avx2);
+    test_weighted_mean_extra!(f64, Avx2);
+    test_quat_mul_extra!(f32, Avx2);
+    test_quat_mul_extra!(f64, Avx2);
+    test_sum_with_status_extra!(f32, Avx2);
+    test_sum_with_status_extra!(f64, Avx2);
+    test_axpy_extra!(f32, Avx2);
+    test_axpy_extra!(f64, Avx2);
+    test_sum_scaled_extra!(f32, Avx2);
+    test_sum_scaled_extra!(f64, Avx2);
+    test_pairwise_squared_euclidean_extra!(f32, Avx2);
+    test_pairwise_squared_euclidean_extra!(f64, Avx2);
+    test_sum_compensated_extra!(f32, Avx2);
+    test_sum_compensated_extra!(f64, Avx2);
+    test_linear_combine_extra!(f32, Avx2);
+    test_linear_combine_extra!(f64, Avx2);
+    test_cmp_horizontal_extra!(f32, Avx2);
+    test_cmp_horizontal_extra!(f64, Avx2);
+    test_recip_inplace_extra!(f32, Avx2);
+    test_recip_inplace_extra!(f64, Avx2);
+    test_momentum_update_extra!(f32, Avx2);
+    test_momentum_update_extra!(f64, Avx2);
+    test_cosine_fast_extra!(Avx2);
+    test_adaptive_sum_extra!(Avx2);
+    test_sum_i32_widen_extra!(Avx2);
+    test_cross_entropy_extra!(Avx2);
+    test_approx_median_extra!(Avx2);
+    test_entropy_extra!(Avx2);
+    test_canberra_extra!(Avx2);
+    test_bray_curtis_extra!(Avx2);
+    test_minkowski_extra!(Avx2);
+    test_vecmath_extra!(Avx2);
+    test_pow_extra!(Avx2);
+    test_softmax_extra!(Avx2);
+    test_dot_f32_widen_extra!(Avx2);
+}
+
+// SSE2 only provides the float registers.
+#[cfg(all(target_feature = "sse2", test))]
+mod sse2_tests {
+    use super::*;
+
+    test_suite!(f32, Sse2);
+    test_suite!(f64, Sse2);
+
+    test_nan_sanity!(f32, Sse2);
+    test_nan_sanity!(f64, Sse2);
+
+    test_cmp_horizontal_extra!(f32, Sse2);
+    test_cmp_horizontal_extra!(f64, Sse2);
+    test_sum_compensated_extra!(f32, Sse2);
+    test_sum_compensated_extra!(f64, Sse2);
 }
 
 #[cfg(all(target_feature = "avx512f", feature = "nightly", test))]
@@ -431,6 +1780,8 @@ mod avx512_tests {
 
     test_cosine_extra!(f32, Avx512);
     test_cosine_extra!(f64, Avx512);
+    test_cosine_similarity_extra!(f32, Avx512);
+    test_cosine_similarity_extra!(f64, Avx512);
     test_cosine_extra!(i32, Avx512);
     test_cosine_extra!(i64, Avx512);
     test_cosine_extra!(u8, Avx512);
@@ -440,6 +1791,51 @@ mod avx512_tests {
 
     test_nan_sanity!(f32, Avx512);
     test_nan_sanity!(f64, Avx512);
+
+    test_clip_norm_extra!(f32, Avx512);
+    test_clip_norm_extra!(f64, Avx512);
+    test_welford_extra!(f32, Avx512);
+    test_welford_extra!(f64, Avx512);
+    test_pearson_extra!(f32, Avx512);
+    test_pearson_extra!(f64, Avx512);
+    test_weighted_mean_extra!(f32, Avx512);
+    test_weighted_mean_extra!(f64, Avx512);
+    test_quat_mul_extra!(f32, Avx512);
+    test_quat_mul_extra!(f64, Avx512);
+    test_sum_with_status_extra!(f32, Avx512);
+    test_sum_with_status_extra!(f64, Avx512);
+    test_axpy_extra!(f32, Avx512);
+    test_axpy_extra!(f64, Avx512);
+    test_sum_scaled_extra!(f32, Avx512);
+    test_sum_scaled_extra!(f64, Avx512);
+    test_pairwise_squared_euclidean_extra!(f32, Avx512);
+    test_pairwise_squared_euclidean_extra!(f64, Avx512);
+    test_sum_compensated_extra!(f32, Avx512);
+    test_sum_compensated_extra!(f64, Avx512);
+    test_linear_combine_extra!(f32, Avx512);
+    test_linear_combine_extra!(f64, Avx512);
+    test_cmp_horizontal_extra!(f32, Avx512);
+    test_cmp_horizontal_extra!(f64, Avx512);
+    test_recip_inplace_extra!(f32, Avx512);
+    test_recip_inplace_extra!(f64, Avx512);
+    test_momentum_update_extra!(f32, Avx512);
+    test_momentum_update_extra!(f64, Avx512);
+    test_cosine_fast_extra!(Avx512);
+    test_adaptive_sum_extra!(Avx512);
+    test_sum_i32_widen_extra!(Avx512);
+    test_cross_entropy_extra!(Avx512);
+    test_approx_median_extra!(Avx512);
+    test_entropy_extra!(Avx512);
+    test_canberra_extra!(Avx512);
+    test_bray_curtis_extra!(Avx512);
+    test_minkowski_extra!(Avx512);
+    test_vecmath_extra!(Avx512);
+    test_pow_extra!(Avx512);
+    test_softmax_extra!(Avx512);
+    test_dot_f32_widen_extra!(Avx512);
+
+    test_dot_dd_extra!(f32, Avx512);
+    test_dot_dd_extra!(f64, Avx512);
 }
 
 #[cfg(all(target_feature = "avx2", target_feature = "fma", test))]
@@ -451,6 +1847,52 @@ mod avx2fma_tests {
 
     test_cosine_extra!(f32, Avx2Fma);
     test_cosine_extra!(f64, Avx2Fma);
+    test_cosine_similarity_extra!(f32, Avx2Fma);
+    test_cosine_similarity_extra!(f64, Avx2Fma);
+
+    test_clip_norm_extra!(f32, Avx2Fma);
+    test_clip_norm_extra!(f64, Avx2Fma);
+    test_welford_extra!(f32, Avx2Fma);
+    test_welford_extra!(f64, Avx2Fma);
+    test_pearson_extra!(f32, Avx2Fma);
+    test_pearson_extra!(f64, Avx2Fma);
+    test_weighted_mean_extra!(f32, Avx2Fma);
+    test_weighted_mean_extra!(f64, Avx2Fma);
+    test_quat_mul_extra!(f32, Avx2Fma);
+    test_quat_mul_extra!(f64, Avx2Fma);
+    test_sum_with_status_extra!(f32, Avx2Fma);
+    test_sum_with_status_extra!(f64, Avx2Fma);
+    test_axpy_extra!(f32, Avx2Fma);
+    test_axpy_extra!(f64, Avx2Fma);
+    test_sum_scaled_extra!(f32, Avx2Fma);
+    test_sum_scaled_extra!(f64, Avx2Fma);
+    test_pairwise_squared_euclidean_extra!(f32, Avx2Fma);
+    test_pairwise_squared_euclidean_extra!(f64, Avx2Fma);
+    test_sum_compensated_extra!(f32, Avx2Fma);
+    test_sum_compensated_extra!(f64, Avx2Fma);
+    test_linear_combine_extra!(f32, Avx2Fma);
+    test_linear_combine_extra!(f64, Avx2Fma);
+    test_cmp_horizontal_extra!(f32, Avx2Fma);
+    test_cmp_horizontal_extra!(f64, Avx2Fma);
+    test_recip_inplace_extra!(f32, Avx2Fma);
+    test_recip_inplace_extra!(f64, Avx2Fma);
+    test_momentum_update_extra!(f32, Avx2Fma);
+    test_momentum_update_extra!(f64, Avx2Fma);
+    test_cosine_fast_extra!(Avx2Fma);
+    test_adaptive_sum_extra!(Avx2Fma);
+    test_cross_entropy_extra!(Avx2Fma);
+    test_approx_median_extra!(Avx2Fma);
+    test_entropy_extra!(Avx2Fma);
+    test_canberra_extra!(Avx2Fma);
+    test_bray_curtis_extra!(Avx2Fma);
+    test_minkowski_extra!(Avx2Fma);
+    test_vecmath_extra!(Avx2Fma);
+    test_pow_extra!(Avx2Fma);
+    test_softmax_extra!(Avx2Fma);
+    test_dot_f32_widen_extra!(Avx2Fma);
+
+    test_dot_dd_extra!(f32, Avx2Fma);
+    test_dot_dd_extra!(f64, Avx2Fma);
 }
 
 #[cfg(all(target_feature = "neon", test))]
@@ -470,6 +1912,8 @@ mod neon_tests {
 
     test_cosine_extra!(f32, Neon);
     test_cosine_extra!(f64, Neon);
+    test_cosine_similarity_extra!(f32, Neon);
+    test_cosine_similarity_extra!(f64, Neon);
     test_cosine_extra!(i8, Neon);
     // test_cosine_extra!(i16, Neon); - Divide by zero error from RNG.
     test_cosine_extra!(i32, Neon);
@@ -481,4 +1925,49 @@ mod neon_tests {
 
     test_nan_sanity!(f32, Neon);
     test_nan_sanity!(f64, Neon);
+
+    test_clip_norm_extra!(f32, Neon);
+    test_clip_norm_extra!(f64, Neon);
+    test_welford_extra!(f32, Neon);
+    test_welford_extra!(f64, Neon);
+    test_pearson_extra!(f32, Neon);
+    test_pearson_extra!(f64, Neon);
+    test_weighted_mean_extra!(f32, Neon);
+    test_weighted_mean_extra!(f64, Neon);
+    test_quat_mul_extra!(f32, Neon);
+    test_quat_mul_extra!(f64, Neon);
+    test_sum_with_status_extra!(f32, Neon);
+    test_sum_with_status_extra!(f64, Neon);
+    test_axpy_extra!(f32, Neon);
+    test_axpy_extra!(f64, Neon);
+    test_sum_scaled_extra!(f32, Neon);
+    test_sum_scaled_extra!(f64, Neon);
+    test_pairwise_squared_euclidean_extra!(f32, Neon);
+    test_pairwise_squared_euclidean_extra!(f64, Neon);
+    test_sum_compensated_extra!(f32, Neon);
+    test_sum_compensated_extra!(f64, Neon);
+    test_linear_combine_extra!(f32, Neon);
+    test_linear_combine_extra!(f64, Neon);
+    test_cmp_horizontal_extra!(f32, Neon);
+    test_cmp_horizontal_extra!(f64, Neon);
+    test_recip_inplace_extra!(f32, Neon);
+    test_recip_inplace_extra!(f64, Neon);
+    test_momentum_update_extra!(f32, Neon);
+    test_momentum_update_extra!(f64, Neon);
+    test_cosine_fast_extra!(Neon);
+    test_adaptive_sum_extra!(Neon);
+    test_sum_i32_widen_extra!(Neon);
+    test_cross_entropy_extra!(Neon);
+    test_approx_median_extra!(Neon);
+    test_entropy_extra!(Neon);
+    test_canberra_extra!(Neon);
+    test_bray_curtis_extra!(Neon);
+    test_minkowski_extra!(Neon);
+    test_vecmath_extra!(Neon);
+    test_pow_extra!(Neon);
+    test_softmax_extra!(Neon);
+    test_dot_f32_widen_extra!(Neon);
+
+    test_dot_dd_extra!(f32, Neon);
+    test_dot_dd_extra!(f64, Neon);
 }
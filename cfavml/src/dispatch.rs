@@ -1,3 +1,6 @@
+use crate::danger::export_distance_ops::DotKernel;
+use crate::safe_trait_distance_ops::DistanceOps;
+
 #[macro_export]
 /// Dispatches a set of functions based on the available CPU features.
 ///
@@ -11,6 +14,7 @@
 /// - AVX512 (`avx512f` + `avx512bw`)
 /// - AVX2 + FMA
 /// - AVX2
+/// - SSE2 (only where the routine provides an `sse2` variant)
 /// - Fallback
 ///
 /// #### ARM
@@ -45,6 +49,7 @@ macro_rules! dispatch {
         $(avx512 = $avx512_fn:expr,)?
         $(avx2fma = $avx2fma_fn:expr,)?
         $(avx2 = $avx2_fn:expr,)?
+        $(sse2 = $sse2_fn:expr,)?
         $(neon = $neon_fn:expr,)?
         fallback = $fallback_fn:expr,
         args = $args:tt
@@ -70,6 +75,13 @@ macro_rules! dispatch {
             }
         )?
 
+        $(
+            #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+            if $crate::dispatch::is_sse2_available() {
+                return $sse2_fn $args;
+            }
+        )?
+
         $(
             #[cfg(target_arch = "aarch64")]
             if $crate::dispatch::is_neon_available() {
@@ -144,6 +156,25 @@ pub fn is_fma_available() -> bool {
     false
 }
 
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+#[inline(always)]
+/// Returns if SSE2 is available to the system.
+///
+/// If this is compiling for a no std target, this selection is done
+/// at compile time only.
+pub fn is_sse2_available() -> bool {
+    if cfg!(target_feature = "sse2") {
+        return true;
+    }
+
+    #[cfg(feature = "std")]
+    if std::arch::is_x86_feature_detected!("sse2") {
+        return true;
+    }
+
+    false
+}
+
 #[cfg(target_arch = "aarch64")]
 #[inline(always)]
 /// Returns if NEON is available to the system.
@@ -162,3 +193,118 @@ pub fn is_neon_available() -> bool {
 
     false
 }
+
+/// A dot product dispatcher which memoizes the kernel selected for a dimension.
+///
+/// The first call at a given dimension selects the kernel for the current CPU via
+/// [DistanceOps::dot_kernel], subsequent calls at the same dimension reuse the cached
+/// function pointer and skip the CPU feature dispatch entirely. Calling with a different
+/// dimension replaces the cached kernel.
+///
+/// This is intended for situations like a server computing many distances at a fixed
+/// dimension, each thread should keep its own dispatcher.
+///
+/// ### Examples
+///
+/// ```rust
+/// use cfavml::dispatch::CachedDot;
+///
+/// let a = vec![1.0f32; 1536];
+/// let b = vec![0.5f32; 1536];
+///
+/// let mut dispatcher = CachedDot::new();
+/// for _ in 0..4 {
+///     assert_eq!(dispatcher.dot(&a, &b), 768.0);
+/// }
+/// assert_eq!(dispatcher.cached_dims(), Some(1536));
+/// ```
+pub struct CachedDot<T> {
+    cached: Option<(usize, DotKernel<T>)>,
+}
+
+impl<T: DistanceOps> CachedDot<T> {
+    /// Creates a new dispatcher with no kernel selected.
+    pub const fn new() -> Self {
+        Self { cached: None }
+    }
+
+    #[inline]
+    /// Returns the dimension the currently cached kernel was selected for, if any.
+    pub fn cached_dims(&self) -> Option<usize> {
+        self.cached.map(|(dims, _)| dims)
+    }
+
+    #[inline]
+    #[must_use]
+    /// Calculates the dot product between vectors `a` and `b`, selecting and caching
+    /// the kernel for their dimension if it is not already cached.
+    ///
+    /// # Panics
+    ///
+    /// If vectors `a` and `b` are not equal in the length.
+    pub fn dot(&mut self, a: &[T], b: &[T]) -> T {
+        assert_eq!(a.len(), b.len(), "Input vector sizes do not match");
+
+        let kernel = match self.cached {
+            Some((dims, kernel)) if dims == a.len() => kernel,
+            _ => {
+                let kernel = T::dot_kernel(a.len());
+                self.cached = Some((a.len(), kernel));
+                kernel
+            },
+        };
+
+        // Safety: The kernel was selected for the available CPU features and
+        //         for vectors of exactly this length.
+        unsafe { kernel(a, b) }
+    }
+}
+
+impl<T: DistanceOps> Default for CachedDot<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math::{AutoMath, Math};
+
+    #[test]
+    fn test_cached_dot_repeated_calls() {
+        for dims in [0, 1, 7, 64, 131, 1536] {
+            let (l1, l2) = crate::test_utils::get_sample_vectors::<f32>(dims);
+            let expected_value = crate::dot(&l1, &l2);
+
+            let mut dispatcher = CachedDot::new();
+            for _ in 0..3 {
+                let value = dispatcher.dot(&l1, &l2);
+                assert!(
+                    AutoMath::is_close(value, expected_value),
+                    "value missmatch on dims {dims} {value} vs {expected_value}"
+                );
+                assert_eq!(dispatcher.cached_dims(), Some(dims));
+            }
+        }
+    }
+
+    #[test]
+    fn test_cached_dot_changing_dims() {
+        let (l1, l2) = crate::test_utils::get_sample_vectors::<i32>(1536);
+
+        let mut dispatcher = CachedDot::default();
+        for dims in [1536, 131, 1536, 64, 64] {
+            let (a, b) = (&l1[..dims], &l2[..dims]);
+            assert_eq!(dispatcher.dot(a, b), crate::dot(a, b));
+            assert_eq!(dispatcher.cached_dims(), Some(dims));
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_cached_dot_length_missmatch() {
+        let mut dispatcher = CachedDot::<f64>::new();
+        let _ = dispatcher.dot(&[1.0, 2.0, 3.0], &[1.0, 2.0]);
+    }
+}
@@ -18,6 +18,8 @@ pub mod math;
 
 pub mod buffer;
 pub mod mem_loader;
+#[cfg(feature = "rayon")]
+pub mod par;
 mod safe_function_ops;
 pub mod safe_trait_agg_ops;
 pub mod safe_trait_arithmetic_ops;
@@ -105,6 +105,20 @@ impl Math<f32> for StdMath {
         a / b
     }
 
+    #[inline(always)]
+    fn copysign(a: f32, b: f32) -> f32 {
+        f32_copysign(a, b)
+    }
+
+    #[inline(always)]
+    fn signum(a: f32) -> f32 {
+        if a.is_nan() {
+            f32::NAN
+        } else {
+            f32_copysign(1.0, a)
+        }
+    }
+
     #[cfg(test)]
     fn is_close(a: f32, b: f32) -> bool {
         let max = a.max(b);
@@ -216,6 +230,20 @@ impl Math<f64> for StdMath {
         a / b
     }
 
+    #[inline(always)]
+    fn copysign(a: f64, b: f64) -> f64 {
+        f64_copysign(a, b)
+    }
+
+    #[inline(always)]
+    fn signum(a: f64) -> f64 {
+        if a.is_nan() {
+            f64::NAN
+        } else {
+            f64_copysign(1.0, a)
+        }
+    }
+
     #[cfg(test)]
     fn is_close(a: f64, b: f64) -> bool {
         let max = a.max(b);
@@ -313,6 +341,20 @@ macro_rules! define_int_ops {
                 a.wrapping_div(b)
             }
 
+            #[inline(always)]
+            fn copysign(a: $t, b: $t) -> $t {
+                if b < 0 {
+                    a.wrapping_abs().wrapping_neg()
+                } else {
+                    a.wrapping_abs()
+                }
+            }
+
+            #[inline(always)]
+            fn signum(a: $t) -> $t {
+                a.signum()
+            }
+
             #[cfg(test)]
             fn is_close(a: $t, b: $t) -> bool {
                 a == b
@@ -406,6 +448,16 @@ macro_rules! define_int_ops {
                 a.wrapping_div(b)
             }
 
+            #[inline(always)]
+            fn copysign(a: $t, _b: $t) -> $t {
+                a
+            }
+
+            #[inline(always)]
+            fn signum(a: $t) -> $t {
+                (a != 0) as $t
+            }
+
             #[cfg(test)]
             fn is_close(a: $t, b: $t) -> bool {
                 a == b
@@ -446,6 +498,20 @@ fn f32_abs_fast(a: f32) -> f32 {
     f32::from_bits(a.to_bits() & !SIGN_MASK)
 }
 
+#[inline(always)]
+/// Returns `a` with the sign bit of `b`.
+fn f32_copysign(a: f32, b: f32) -> f32 {
+    const SIGN_MASK: u32 = 1 << 31;
+    f32::from_bits((a.to_bits() & !SIGN_MASK) | (b.to_bits() & SIGN_MASK))
+}
+
+#[inline(always)]
+/// Returns `a` with the sign bit of `b`.
+fn f64_copysign(a: f64, b: f64) -> f64 {
+    const SIGN_MASK: u64 = 1 << 63;
+    f64::from_bits((a.to_bits() & !SIGN_MASK) | (b.to_bits() & SIGN_MASK))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
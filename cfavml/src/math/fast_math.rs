@@ -107,6 +107,16 @@ impl Math<f32> for FastMath {
         }
     }
 
+    #[inline(always)]
+    fn copysign(a: f32, b: f32) -> f32 {
+        StdMath::copysign(a, b)
+    }
+
+    #[inline(always)]
+    fn signum(a: f32) -> f32 {
+        StdMath::signum(a)
+    }
+
     #[cfg(test)]
     fn is_close(a: f32, b: f32) -> bool {
         let max = a.max(b);
@@ -218,6 +228,16 @@ impl Math<f64> for FastMath {
         }
     }
 
+    #[inline(always)]
+    fn copysign(a: f64, b: f64) -> f64 {
+        StdMath::copysign(a, b)
+    }
+
+    #[inline(always)]
+    fn signum(a: f64) -> f64 {
+        StdMath::signum(a)
+    }
+
     #[cfg(test)]
     fn is_close(a: f64, b: f64) -> bool {
         let max = a.max(b);
@@ -315,6 +335,16 @@ macro_rules! define_int_ops {
                 a.wrapping_div(b)
             }
 
+            #[inline(always)]
+            fn copysign(a: $t, b: $t) -> $t {
+                StdMath::copysign(a, b)
+            }
+
+            #[inline(always)]
+            fn signum(a: $t) -> $t {
+                StdMath::signum(a)
+            }
+
             #[cfg(test)]
             fn is_close(a: $t, b: $t) -> bool {
                 a == b
@@ -408,6 +438,16 @@ macro_rules! define_int_ops {
                 a.wrapping_div(b)
             }
 
+            #[inline(always)]
+            fn copysign(a: $t, b: $t) -> $t {
+                StdMath::copysign(a, b)
+            }
+
+            #[inline(always)]
+            fn signum(a: $t) -> $t {
+                StdMath::signum(a)
+            }
+
             #[cfg(test)]
             fn is_close(a: $t, b: $t) -> bool {
                 a == b
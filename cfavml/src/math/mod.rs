@@ -69,6 +69,46 @@ pub trait Math<T> {
     #[cfg(test)]
     fn is_close(a: T, b: T) -> bool;
 
+    #[inline]
+    /// Returns a value with the magnitude of `a` and the sign of `b`.
+    ///
+    /// Floats follow `f32::copysign`, including signed zeros and NaN, for
+    /// unsigned integers this is always `a`.
+    ///
+    /// The default implementation compares both values against zero, so it
+    /// cannot see the sign of signed zeros or NaN.
+    fn copysign(a: T, b: T) -> T
+    where
+        T: Copy,
+    {
+        if Self::cmp_lt(a, Self::zero()) == Self::cmp_lt(b, Self::zero()) {
+            a
+        } else {
+            Self::sub(Self::zero(), a)
+        }
+    }
+
+    #[inline]
+    /// Returns the sign of the value as `1` or `-1`.
+    ///
+    /// Floats follow `f32::signum` where signed zeros produce `±1.0` and `NaN`
+    /// is kept as `NaN`, integers return `0` for `0`.
+    ///
+    /// The default implementation compares the value against zero, returning
+    /// zeros and NaN as they are.
+    fn signum(a: T) -> T
+    where
+        T: Copy,
+    {
+        if Self::cmp_gt(a, Self::zero()) {
+            Self::one()
+        } else if Self::cmp_lt(a, Self::zero()) {
+            Self::sub(Self::zero(), Self::one())
+        } else {
+            a
+        }
+    }
+
     #[inline]
     /// Casts a boolean to their `1` and `0` equivalent types
     fn cast_bool(v: bool) -> T {
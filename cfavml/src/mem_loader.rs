@@ -1,3 +1,5 @@
+use core::mem;
+
 use crate::danger::{DenseLane, SimdRegister};
 
 /// The stack scratch space used by the projecting buffer loader.
@@ -5,7 +7,50 @@ use crate::danger::{DenseLane, SimdRegister};
 /// This is calculated by effectively taking the maximum number of elements
 /// that could be loaded from the widest supported register in CFAVML, in
 /// this case; AVX512.
-const SCRATCH_SPACE_SIZE: usize = 64;
+pub(crate) const SCRATCH_SPACE_SIZE: usize = 64;
+
+/// The maximum number of `T` elements a buffer can hold while still being
+/// safely offset into with pointer arithmetic, which is limited to `isize::MAX` bytes.
+pub(crate) const fn max_buffer_len<T>() -> usize {
+    match (isize::MAX as usize).checked_div(mem::size_of::<T>()) {
+        Some(len) => len,
+        // Zero sized types never advance the pointer.
+        None => usize::MAX,
+    }
+}
+
+#[inline(always)]
+/// Asserts a buffer of `len` elements of `T` does not exceed `isize::MAX` bytes.
+///
+/// Every routine computes offsets into buffers with `ptr.add(i)` which is UB if the offset
+/// in bytes overflows an `isize`, slices already uphold this, but raw parts do not.
+///
+/// # Panics
+///
+/// If `len * size_of::<T>()` is greater than `isize::MAX`.
+pub(crate) fn assert_addressable_len<T>(len: usize) {
+    assert!(
+        len <= max_buffer_len::<T>(),
+        "Buffer of {len} elements exceeds `isize::MAX` bytes and cannot be safely offset"
+    );
+}
+
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+/// A hint describing how the memory backing a buffer was allocated, allowing routines
+/// to pick an inner loop tuned for it.
+///
+/// Hints never change the maths of a routine, but may change the order elements are
+/// accumulated in, so floating point results can differ slightly between hints.
+pub enum MemoryHint {
+    #[default]
+    /// Regular heap or stack memory, the routine behaves exactly as its unhinted variant.
+    Default,
+    /// Large buffers backed by huge pages (i.e. 2MB pages) or pinned host memory.
+    ///
+    /// The routine aligns its loads to the SIMD register and prefetches further ahead,
+    /// which pays off when TLB misses are cheap and the buffer is streamed from memory.
+    HugePage,
+}
 
 /// A buffer or value that can be turned into a [MemLoader].
 ///
@@ -68,6 +113,9 @@ pub trait MemLoader {
     /// This method has no concept of checking the remaining length of the loader,
     /// out of bounds access can easily happen if the routine does not track the current
     /// positions of buffers.
+    ///
+    /// Loaders also assume the underlying buffer is no larger than `isize::MAX` bytes,
+    /// the built-in loaders check this when they are created.
     unsafe fn read(&mut self) -> Self::Value;
 }
 
@@ -95,6 +143,7 @@ where
 
     fn into_mem_loader(self) -> Self::Loader {
         let slice = self.as_ref();
+        assert_addressable_len::<T>(slice.len());
         PtrBufferLoader {
             data: slice.as_ptr(),
             data_len: slice.len(),
@@ -149,6 +198,7 @@ where
 
     fn into_projected_mem_loader(self, projected_len: usize) -> Self::Loader {
         let slice = self.0.as_ref();
+        assert_addressable_len::<T>(slice.len());
 
         assert_eq!(
             projected_len % slice.len(),
@@ -167,6 +217,7 @@ where
 
     fn into_mem_loader(self) -> Self::Loader {
         let slice = self.0.as_ref();
+        assert_addressable_len::<T>(slice.len());
         ProjectedPtrBufferLoader {
             data: slice.as_ptr(),
             data_len: slice.len(),
@@ -412,6 +463,46 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_addressable_len_guard() {
+        assert_addressable_len::<f64>(0);
+        assert_addressable_len::<f64>(isize::MAX as usize / mem::size_of::<f64>());
+        assert_addressable_len::<u8>(isize::MAX as usize);
+        assert_addressable_len::<()>(usize::MAX);
+    }
+
+    #[test]
+    #[should_panic(expected = "exceeds `isize::MAX` bytes")]
+    fn test_addressable_len_guard_panics() {
+        assert_addressable_len::<f64>(isize::MAX as usize / mem::size_of::<f64>() + 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "exceeds `isize::MAX` bytes")]
+    fn test_addressable_len_guard_panics_byte_sized() {
+        assert_addressable_len::<u8>(isize::MAX as usize + 1);
+    }
+
+    #[test]
+    fn test_addressable_len_guard_loaders() {
+        // Zero sized types never advance the pointer, so any length is addressable.
+        let sample: &[()] = unsafe {
+            core::slice::from_raw_parts(
+                core::ptr::NonNull::dangling().as_ptr(),
+                usize::MAX,
+            )
+        };
+
+        let loader = sample.into_mem_loader();
+        assert_eq!(loader.projected_len(), usize::MAX);
+
+        let loader = Projected(sample).into_mem_loader();
+        assert_eq!(loader.projected_len(), usize::MAX);
+
+        let loader = Projected(sample).into_projected_mem_loader(usize::MAX);
+        assert_eq!(loader.projected_len(), usize::MAX);
+    }
+
     #[test]
     #[should_panic]
     fn test_buffer_basic_loader_projection_panic() {
@@ -0,0 +1,84 @@
+//! Extension traits for reducing slices in parallel with [rayon].
+//!
+//! The slice is split into chunks with rayon's `par_chunks`, each chunk is reduced
+//! with the dispatched SIMD routine and the chunk results are then combined.
+//!
+//! Since chunk results are combined in a non-deterministic order, the result may differ
+//! slightly from the sequential routine for floating point types.
+use rayon::prelude::*;
+
+/// The number of elements each parallel task reduces with the SIMD routine.
+pub const PAR_CHUNK_SIZE: usize = 64 << 10;
+
+/// Provides a parallel horizontal sum over a slice.
+///
+/// This trait is sealed and implemented for `&[f32]` and `&[f64]`.
+pub trait ParallelSum: sealed::Sealed {
+    type Output;
+
+    #[must_use]
+    /// Performs a horizontal sum of all elements in the slice using the rayon thread pool
+    /// returning the total.
+    ///
+    /// ### Examples
+    ///
+    /// ```rust
+    /// use cfavml::par::ParallelSum;
+    ///
+    /// let a = vec![1.0f64; 1 << 20];
+    ///
+    /// let total = a.as_slice().cfavml_par_sum();
+    /// assert_eq!(total, (1 << 20) as f64);
+    /// ```
+    fn cfavml_par_sum(self) -> Self::Output;
+}
+
+macro_rules! par_sum_impl {
+    ($t:ty) => {
+        impl ParallelSum for &[$t] {
+            type Output = $t;
+
+            #[inline]
+            fn cfavml_par_sum(self) -> Self::Output {
+                self.par_chunks(PAR_CHUNK_SIZE)
+                    .map(|chunk| crate::sum(chunk))
+                    .sum()
+            }
+        }
+    };
+}
+
+par_sum_impl!(f32);
+par_sum_impl!(f64);
+
+mod sealed {
+    pub trait Sealed {}
+
+    impl Sealed for &[f32] {}
+    impl Sealed for &[f64] {}
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math::{AutoMath, Math};
+
+    #[test]
+    fn test_par_sum_matches_sequential() {
+        let (l1, _) =
+            crate::test_utils::get_sample_vectors::<f64>(PAR_CHUNK_SIZE * 5 + 533);
+
+        let value = l1.as_slice().cfavml_par_sum();
+        let expected_value = crate::sum(&l1);
+        assert!(
+            AutoMath::is_close(value, expected_value),
+            "value missmatch {value:?} vs {expected_value:?}"
+        );
+    }
+
+    #[test]
+    fn test_par_sum_empty() {
+        let l1: Vec<f64> = Vec::new();
+        assert_eq!(l1.as_slice().cfavml_par_sum(), 0.0);
+    }
+}
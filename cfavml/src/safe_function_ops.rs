@@ -6,14 +6,20 @@
 //! This means you when runtime detection is disabled, you must compile with one of `target-cpu`
 //! or `target-feature` Rust flags set otherwise this will always use the `Fallback` implementations.
 
+use core::mem::MaybeUninit;
+use core::slice;
+
 use crate::buffer::WriteOnlyBuffer;
-use crate::mem_loader::{IntoMemLoader, MemLoader};
+use crate::danger::{DotResult, ReductionStatus};
+use crate::math::{Math, StdMath};
+use crate::mem_loader::{IntoMemLoader, MemLoader, MemoryHint};
 use crate::safe_trait_agg_ops::AggOps;
 use crate::safe_trait_arithmetic_ops::ArithmeticOps;
 use crate::safe_trait_cmp_ops::CmpOps;
 use crate::safe_trait_distance_ops::DistanceOps;
 
 #[inline]
+#[must_use]
 /// Calculates the cosine similarity distance of vectors `a` and `b`.
 ///
 /// ### Examples
@@ -70,6 +76,7 @@ where
 }
 
 #[inline]
+#[must_use]
 /// Calculates the cosine similarity distance of vectors `a` and `b`.
 ///
 /// ### Examples
@@ -116,7 +123,407 @@ where
     T::dot(a, b)
 }
 
+macro_rules! define_fixed_dims_dot {
+    ($name:ident, $dims:literal) => {
+        #[inline]
+        #[must_use]
+        #[doc = concat!("Calculates the dot product of two `", $dims, "` dimension vectors `a` and `b`.")]
+        ///
+        /// The kernel is selected for the fixed dimension via [DistanceOps::dot_kernel],
+        /// when the dimension is a multiple of the dense lane size of the backend,
+        /// as it is for `f32` and `f64` on every backend, a kernel without any
+        /// remainder handling is used. This is a convenience for embedding models
+        /// which produce vectors of a fixed size.
+        ///
+        /// See [dot] for more information.
+        pub fn $name<T>(a: &[T; $dims], b: &[T; $dims]) -> T
+        where
+            T: DistanceOps,
+        {
+            let kernel = T::dot_kernel($dims);
+
+            // Safety: The kernel was selected for the available CPU features and
+            //         for vectors of exactly this length.
+            unsafe { kernel(a, b) }
+        }
+    };
+}
+
+define_fixed_dims_dot!(dot_384, 384);
+define_fixed_dims_dot!(dot_512, 512);
+define_fixed_dims_dot!(dot_768, 768);
+define_fixed_dims_dot!(dot_1024, 1024);
+define_fixed_dims_dot!(dot_1536, 1536);
+
+#[inline]
+#[must_use]
+/// Calculates the dot product of vectors `a` and `b`, reporting the partial result
+/// while accumulating.
+///
+/// `progress(blocks_done, partial_dot)` is called every `every` blocks, where a block
+/// is one dense lane of the selected SIMD implementation. This is useful for showing the
+/// progress of very large vectors, the reporting does not alter the final result.
+///
+/// ### Examples
+///
+/// ```rust
+/// let a = vec![1.0f32; 4096];
+/// let b = vec![2.0f32; 4096];
+///
+/// let mut reported = Vec::new();
+/// let total = cfavml::dot_progress(&a, &b, 4, |blocks_done, partial| {
+///     reported.push((blocks_done, partial));
+/// });
+/// assert_eq!(total, 8192.0);
+/// assert!(reported.iter().all(|(_, partial)| *partial <= total));
+/// ```
+///
+/// ### Implementation Pseudocode
+///
+/// _This is the logic of the routine being called._
+///
+/// ```ignore
+/// result = 0;
+///
+/// for block in range(num_blocks):
+///     for i in block:
+///         result += a[i] * b[i]
+///
+///     if (block + 1) % every == 0:
+///         progress(block + 1, result)
+///
+/// return result
+/// ```
+///
+/// ### Panics
+///
+/// This function will panic if `every` is `0` or vectors `a` and `b` do not match in size.
+pub fn dot_progress<T, B1, B2, F>(a: B1, b: B2, every: usize, progress: F) -> T
+where
+    T: DistanceOps,
+    B1: IntoMemLoader<T>,
+    B1::Loader: MemLoader<Value = T>,
+    B2: IntoMemLoader<T>,
+    B2::Loader: MemLoader<Value = T>,
+    F: FnMut(usize, T),
+{
+    T::dot_progress(a, b, every, progress)
+}
+
+#[inline]
+/// Calculates the dot product of each row yielded by `rows` against the `query` vector,
+/// passing each result to `sink` as soon as it is computed.
+///
+/// `sink(row_index, dot)` is called once per row in the order the rows are yielded,
+/// so the full result vector never needs to be materialized. This is useful when
+/// streaming a database that does not fit in memory, i.e. keeping a running top-k in a heap.
+///
+/// ### Examples
+///
+/// ```rust
+/// let matrix = vec![vec![1.0, 0.0], vec![0.5, 0.5], vec![0.0, 2.0]];
+/// let query = [1.0, 2.0];
+///
+/// let mut best = (0, f64::NEG_INFINITY);
+/// cfavml::matvec_dot_streaming(matrix.iter().map(|row| row.as_slice()), &query, |idx, dot| {
+///     if dot > best.1 {
+///         best = (idx, dot);
+///     }
+/// });
+/// assert_eq!(best, (2, 4.0));
+/// ```
+///
+/// ### Implementation Pseudocode
+///
+/// _This is the logic of the routine being called._
+///
+/// ```ignore
+/// for (i, row) in enumerate(rows):
+///     result = 0
+///
+///     for j in range(dims):
+///         result += row[j] * query[j]
+///
+///     sink(i, result)
+/// ```
+///
+/// ### Panics
+///
+/// This function will panic if any row does not match `query` in size.
+pub fn matvec_dot_streaming<'a, T, I, F>(rows: I, query: &[T], mut sink: F)
+where
+    T: DistanceOps + 'a,
+    I: IntoIterator<Item = &'a [T]>,
+    F: FnMut(usize, T),
+{
+    for (idx, row) in rows.into_iter().enumerate() {
+        sink(idx, T::dot(row, query));
+    }
+}
+
+#[inline]
+#[must_use]
+/// Calculates the dot product of vectors `a` and `b` where neither vector is fully in
+/// memory, each is yielded as a sequence of chunks by `a_chunks` and `b_chunks`.
+///
+/// The chunk boundaries of `a` and `b` do not need to line up, both iterators are
+/// advanced in lockstep and the elements either side of a boundary are buffered until
+/// a full SIMD block is available. Empty chunks are skipped.
+///
+/// ### Examples
+///
+/// ```rust
+/// let a = [1.0, 2.0, 3.0, 4.0, 5.0];
+/// let b = [2.0, 2.0, 2.0, 2.0, 2.0];
+///
+/// let total = cfavml::streaming_dot_both(a.chunks(2), b.chunks(3));
+/// assert_eq!(total, 30.0);
+/// ```
+///
+/// ### Implementation Pseudocode
+///
+/// _This is the logic of the routine being called._
+///
+/// ```ignore
+/// a = concat(a_chunks)
+/// b = concat(b_chunks)
+///
+/// result = 0
+///
+/// for i in range(dims):
+///     result += a[i] * b[i]
+///
+/// return result
+/// ```
+///
+/// ### Panics
+///
+/// This function will panic if the chunks of `a` and `b` do not match in total size.
+pub fn streaming_dot_both<'a, T, I1, I2>(a_chunks: I1, b_chunks: I2) -> T
+where
+    T: DistanceOps + 'a,
+    I1: IntoIterator<Item = &'a [T]>,
+    I2: IntoIterator<Item = &'a [T]>,
+{
+    T::dot_streaming_both(a_chunks.into_iter(), b_chunks.into_iter())
+}
+
 #[inline]
+#[must_use]
+/// Calculates the dot product of vectors `a` and `b`, returning zero early if either
+/// vector is entirely zero.
+///
+/// Each vector is scanned for a non-zero element first, which stops at the first block
+/// containing one. This only helps sparse workloads where all-zero vectors are common,
+/// otherwise the extra scan is wasted work and [dot] should be used instead.
+///
+/// Skipping an all-zero vector means any `NaN` or infinite values in the other vector
+/// are not propagated, the result is always exactly zero.
+///
+/// ### Examples
+///
+/// ```rust
+/// let a = vec![0.0; 1024];
+/// let b = vec![0.5; 1024];
+///
+/// let total = cfavml::dot_skip_zero(&a, &b);
+/// assert_eq!(total, 0.0);
+/// ```
+///
+/// ### Implementation Pseudocode
+///
+/// _This is the logic of the routine being called._
+///
+/// ```ignore
+/// if all(a[i] == 0 for i in range(dims)) or all(b[i] == 0 for i in range(dims)):
+///     return 0
+///
+/// result = 0
+///
+/// for i in range(dims):
+///     result += a[i] * b[i]
+///
+/// return result
+/// ```
+///
+/// ### Panics
+///
+/// This function will panic if vectors `a` and `b` do not match in size.
+pub fn dot_skip_zero<T, B1, B2>(a: B1, b: B2) -> T
+where
+    T: DistanceOps,
+    B1: IntoMemLoader<T> + Copy,
+    B1::Loader: MemLoader<Value = T>,
+    B2: IntoMemLoader<T> + Copy,
+    B2::Loader: MemLoader<Value = T>,
+{
+    T::dot_skip_zero(a, b)
+}
+
+#[inline]
+#[must_use]
+/// Calculates the dot product of vectors `a` and `b`, returning the value along with
+/// metadata describing how it was computed.
+///
+/// This is a debugging aid for the common question of why a result does not exactly
+/// match another library like NumPy. The `reduction_order` describes how the selected
+/// backend accumulated the products and `used_fma` reports if each product was
+/// accumulated with a true fused multiply add, both of which change the rounding.
+///
+/// ### Examples
+///
+/// ```rust
+/// let a = vec![1.0, 2.0, 3.0, 4.0];
+/// let b = vec![0.5, 0.5, 0.5, 0.5];
+///
+/// let result = cfavml::dot_with_diagnostics(&a, &b);
+/// assert_eq!(result.value, 5.0);
+/// println!("{} (fma: {})", result.reduction_order, result.used_fma);
+/// ```
+///
+/// ### Implementation Pseudocode
+///
+/// _This is the logic of the routine being called._
+///
+/// ```ignore
+/// result = 0
+///
+/// for i in range(dims):
+///     result += a[i] * b[i]
+///
+/// return result
+/// ```
+///
+/// ### Panics
+///
+/// This function will panic if vectors `a` and `b` do not match in size.
+pub fn dot_with_diagnostics<T, B1, B2>(a: B1, b: B2) -> DotResult<T>
+where
+    T: DistanceOps,
+    B1: IntoMemLoader<T>,
+    B1::Loader: MemLoader<Value = T>,
+    B2: IntoMemLoader<T>,
+    B2::Loader: MemLoader<Value = T>,
+{
+    T::dot_with_diagnostics(a, b)
+}
+
+#[inline]
+#[must_use]
+/// Calculates the dot product of the first `init_len` elements of the partially
+/// initialized buffers `a` and `b`, without having to copy the initialized prefixes
+/// out of the buffers.
+///
+/// ### Examples
+///
+/// ```rust
+/// use std::mem::MaybeUninit;
+///
+/// let mut a = [MaybeUninit::<f64>::uninit(); 16];
+/// let mut b = [MaybeUninit::<f64>::uninit(); 8];
+/// for i in 0..4 {
+///     a[i].write(i as f64);
+///     b[i].write(2.0);
+/// }
+///
+/// let total = unsafe { cfavml::dot_init_prefix(&a, &b, 4) };
+/// assert_eq!(total, 12.0);
+/// ```
+///
+/// ### Implementation Pseudocode
+///
+/// _This is the logic of the routine being called._
+///
+/// ```ignore
+/// result = 0;
+///
+/// for i in range(init_len):
+///     result += a[i] * b[i]
+///
+/// return result
+/// ```
+///
+/// ### Panics
+///
+/// This function will panic if `init_len` is greater than the length of `a` or `b`.
+///
+/// # Safety
+///
+/// The first `init_len` elements of both `a` and `b` must be initialized, reading any
+/// uninitialized element is immediate UB. Elements after `init_len` are never read
+/// and can be in any state.
+pub unsafe fn dot_init_prefix<T>(
+    a: &[MaybeUninit<T>],
+    b: &[MaybeUninit<T>],
+    init_len: usize,
+) -> T
+where
+    T: DistanceOps,
+{
+    let a = assume_init_prefix(a, init_len);
+    let b = assume_init_prefix(b, init_len);
+    T::dot(a, b)
+}
+
+#[inline]
+#[must_use]
+/// Calculates the complex dot product `sum(conj(a) * b)` of planar complex vectors
+/// `a` and `b`, returning the `(real, imaginary)` parts of the result.
+///
+/// Planar storage keeps the real and imaginary parts of a vector in separate buffers,
+/// which vectorizes more cleanly than interleaved storage as no shuffles are needed.
+///
+/// ### Examples
+///
+/// ```rust
+/// // a = [1 + 2i, 3 - 1i], b = [2 + 0i, 1 + 1i]
+/// let (a_re, a_im) = ([1.0, 3.0], [2.0, -1.0]);
+/// let (b_re, b_im) = ([2.0, 1.0], [0.0, 1.0]);
+///
+/// let (real, imag) = cfavml::complex_dot_planar(&a_re, &a_im, &b_re, &b_im);
+/// assert_eq!((real, imag), (4.0, 0.0));
+/// ```
+///
+/// ### Implementation Pseudocode
+///
+/// _This is the logic of the routine being called._
+///
+/// ```ignore
+/// real = 0
+/// imag = 0
+///
+/// for i in range(dims):
+///     real += a_re[i] * b_re[i] + a_im[i] * b_im[i]
+///     imag += a_re[i] * b_im[i] - a_im[i] * b_re[i]
+///
+/// return real, imag
+/// ```
+///
+/// ### Panics
+///
+/// This function will panic if vectors `a_re`, `a_im`, `b_re` and `b_im` do not all match in size.
+pub fn complex_dot_planar<T, B1, B2, B3, B4>(
+    a_re: B1,
+    a_im: B2,
+    b_re: B3,
+    b_im: B4,
+) -> (T, T)
+where
+    T: DistanceOps,
+    B1: IntoMemLoader<T>,
+    B1::Loader: MemLoader<Value = T>,
+    B2: IntoMemLoader<T>,
+    B2::Loader: MemLoader<Value = T>,
+    B3: IntoMemLoader<T>,
+    B3::Loader: MemLoader<Value = T>,
+    B4: IntoMemLoader<T>,
+    B4::Loader: MemLoader<Value = T>,
+{
+    T::complex_dot_planar(a_re, a_im, b_re, b_im)
+}
+
+#[inline]
+#[must_use]
 /// Calculates the squared Euclidean distance of vectors `a` and `b`.
 ///
 /// ### Examples
@@ -165,6 +572,7 @@ where
 }
 
 #[inline]
+#[must_use]
 /// Calculates the squared L2 norm of vector `a`.
 ///
 /// ### Examples
@@ -205,8 +613,14 @@ where
 }
 
 #[inline]
+#[must_use]
 /// Performs a horizontal sum of all elements in a returning the result.
 ///
+/// This is safe to call on any CPU, the routine is selected from the CPU features
+/// available at runtime (AVX512, AVX2, SSE2 for `f32` and `f64`, or NEON) and otherwise
+/// falls back to a scalar loop. With the `std` feature the standard library caches the result of the feature
+/// probe after the first call, without `std` the selection is done at compile time.
+///
 /// ### Examples
 ///
 /// We can create a single vector and calculate the squared L2 norm.
@@ -246,6 +660,296 @@ where
 }
 
 #[inline]
+#[must_use]
+/// Performs a horizontal sum of all elements in vector `x` returning the total, using an
+/// inner loop tuned for the memory described by `hint`.
+///
+/// [MemoryHint::HugePage] is intended for large buffers backed by huge pages or pinned
+/// host memory, it aligns the loads and prefetches further ahead of the current position.
+/// The result is the same as [sum], although floats may differ slightly as the
+/// elements can be accumulated in a different order.
+///
+/// ### Examples
+///
+/// ```rust
+/// use cfavml::mem_loader::MemoryHint;
+///
+/// let a = vec![1.0, 2.5, 3.0, 0.5, 4.0, 1.5, 2.0, 0.5];
+///
+/// let total = cfavml::sum_horizontal_hint(&a, MemoryHint::HugePage);
+/// assert_eq!(total, 15.0);
+/// ```
+///
+/// ### Implementation Pseudocode
+///
+/// _This is the logic of the routine being called._
+///
+/// ```ignore
+/// result = 0
+///
+/// for i in range(dims):
+///     result += x[i]
+///
+/// return result
+/// ```
+pub fn sum_horizontal_hint<T>(x: &[T], hint: MemoryHint) -> T
+where
+    T: AggOps,
+{
+    T::sum_hint(x, hint)
+}
+
+#[inline]
+#[must_use]
+/// Performs a horizontal sum of all elements in vector `x` returning the total along with
+/// a [ReductionStatus] which can be checked lazily instead of validating `x` beforehand.
+///
+/// `is_finite` is `false` if the total is infinite or `NaN`, either because `x`
+/// contained a non-finite value or because the sum overflowed. `saw_nan` is `true` if
+/// `x` contained `NaN`. The status is derived from the total, so finite inputs cost no
+/// more than [sum], `x` is only scanned for `NaN` when the total is `NaN`.
+///
+/// ### Examples
+///
+/// ```rust
+/// let a = vec![1.0, 2.0, f64::INFINITY];
+///
+/// let (total, status) = cfavml::sum_with_status(&a);
+/// assert_eq!(total, f64::INFINITY);
+/// assert!(!status.is_finite);
+/// assert!(!status.saw_nan);
+/// ```
+///
+/// ### Implementation Pseudocode
+///
+/// _This is the logic of the routine being called._
+///
+/// ```ignore
+/// result = 0
+///
+/// for i in range(dims):
+///     result += x[i]
+///
+/// is_finite = result - result == 0
+/// saw_nan = result != result and any(x[i] != x[i] for i in range(dims))
+///
+/// return result, ReductionStatus(is_finite, saw_nan)
+/// ```
+pub fn sum_with_status<T>(x: &[T]) -> (T, ReductionStatus)
+where
+    T: AggOps,
+{
+    T::sum_with_status(x)
+}
+
+#[inline]
+/// Performs a horizontal sum of each vector in `vectors`, writing the total of
+/// `vectors[i]` to `out[i]`.
+///
+/// This is equivalent to calling [sum] on each vector, but the SIMD backend is only
+/// selected once for the whole batch, avoiding the per-call dispatch overhead when
+/// summing many short vectors.
+///
+/// ### Examples
+///
+/// ```rust
+/// let a = vec![1.0, 2.0, 3.0];
+/// let b = vec![4.0, 5.0];
+/// let c = vec![6.0];
+///
+/// let mut totals = [0.0; 3];
+/// cfavml::sum_batch(&[&a, &b, &c], &mut totals);
+/// assert_eq!(totals, [6.0, 9.0, 6.0]);
+/// ```
+///
+/// ### Implementation Pseudocode
+///
+/// _This is the logic of the routine being called._
+///
+/// ```ignore
+/// for i in range(len(vectors)):
+///     out[i] = sum(vectors[i])
+/// ```
+///
+/// ### Panics
+///
+/// This function will panic if `out` is not the same length as `vectors`.
+pub fn sum_batch<T>(vectors: &[&[T]], out: &mut [T])
+where
+    T: AggOps,
+{
+    T::sum_batch(vectors, out)
+}
+
+#[must_use]
+/// Performs a horizontal sum over a set of `chunks`, summing each chunk with SIMD and
+/// combining the chunk totals with a balanced binary tree reduction.
+///
+/// This is well suited for summing sharded data, the tree reduction accumulates less
+/// rounding error than folding the chunk totals left to right and the result only
+/// depends on the order of `chunks`, not on how or when each chunk is summed.
+///
+/// ### Reduction Order
+///
+/// The tree is always split at `chunks.len() / 2`, the left and right halves are reduced
+/// recursively and then added together. For example, with 5 chunks:
+///
+/// ```ignore
+/// (c0 + c1) + (c2 + (c3 + c4))
+/// ```
+///
+/// The halves are combined with [StdMath] additions, so the tree is never
+/// re-associated even when the `nightly` feature enables fast math for the
+/// per-chunk sums. For floats this means reordering `chunks` can change the result,
+/// but the same order always produces the same result.
+///
+/// ### Examples
+///
+/// ```rust
+/// let a = vec![1.0, 2.0, 3.0];
+/// let b = vec![4.0, 5.0];
+/// let c = vec![6.0];
+///
+/// let total = cfavml::tree_sum_chunks(&[&a, &b, &c]);
+/// assert_eq!(total, 21.0);
+/// ```
+pub fn tree_sum_chunks<T>(chunks: &[&[T]]) -> T
+where
+    T: AggOps,
+    StdMath: Math<T>,
+{
+    match chunks {
+        [] => T::sum(&[] as &[T]),
+        [chunk] => T::sum(*chunk),
+        _ => {
+            let (left, right) = chunks.split_at(chunks.len() / 2);
+            StdMath::add(tree_sum_chunks(left), tree_sum_chunks(right))
+        },
+    }
+}
+
+#[cfg(feature = "bytemuck")]
+#[inline]
+#[must_use]
+/// Performs a horizontal sum of all elements in a slice of `#[repr(transparent)]`
+/// newtypes wrapping a supported type, i.e. `struct Meters(f64)`.
+///
+/// The slice is reinterpreted via [bytemuck::TransparentWrapper] without copying
+/// or casting each element, see [sum] for more information.
+///
+/// ### Examples
+///
+/// ```rust
+/// use bytemuck::TransparentWrapper;
+///
+/// #[derive(Copy, Clone)]
+/// #[repr(transparent)]
+/// struct Meters(f64);
+///
+/// unsafe impl TransparentWrapper<f64> for Meters {}
+///
+/// let distances = [Meters(1.5), Meters(2.0), Meters(0.5)];
+/// let total: f64 = cfavml::sum_transparent(&distances);
+/// assert_eq!(total, 4.0);
+/// ```
+pub fn sum_transparent<T, W>(a: &[W]) -> T
+where
+    T: AggOps,
+    W: bytemuck::TransparentWrapper<T>,
+{
+    T::sum(W::peel_slice(a))
+}
+
+#[cfg(feature = "bytemuck")]
+#[inline]
+#[must_use]
+/// Calculates the dot product of two slices of `#[repr(transparent)]` newtypes
+/// wrapping a supported type, i.e. `struct Meters(f64)`.
+///
+/// The slices are reinterpreted via [bytemuck::TransparentWrapper] without copying
+/// or casting each element, see [dot] for more information.
+///
+/// ### Examples
+///
+/// ```rust
+/// use bytemuck::TransparentWrapper;
+///
+/// #[derive(Copy, Clone)]
+/// #[repr(transparent)]
+/// struct Meters(f64);
+///
+/// unsafe impl TransparentWrapper<f64> for Meters {}
+///
+/// let a = [Meters(1.0), Meters(2.0)];
+/// let b = [Meters(3.0), Meters(0.5)];
+/// let total: f64 = cfavml::dot_transparent(&a, &b);
+/// assert_eq!(total, 4.0);
+/// ```
+///
+/// ### Panics
+///
+/// This function will panic if vectors `a` and `b` do not match in size.
+pub fn dot_transparent<T, W>(a: &[W], b: &[W]) -> T
+where
+    T: DistanceOps,
+    W: bytemuck::TransparentWrapper<T>,
+{
+    T::dot(W::peel_slice(a), W::peel_slice(b))
+}
+
+#[inline]
+#[must_use]
+/// Performs a horizontal sum of the first `init_len` elements of a partially initialized
+/// buffer `a`, without having to copy the initialized prefix out of the buffer.
+///
+/// This is useful when filling a buffer incrementally, i.e. when parsing, and only
+/// the part of the buffer that has been written to so far should be reduced.
+///
+/// ### Examples
+///
+/// ```rust
+/// use std::mem::MaybeUninit;
+///
+/// let mut buffer = [MaybeUninit::<f64>::uninit(); 16];
+/// for (i, slot) in buffer.iter_mut().take(5).enumerate() {
+///     slot.write(i as f64);
+/// }
+///
+/// let total = unsafe { cfavml::sum_init_prefix(&buffer, 5) };
+/// assert_eq!(total, 10.0);
+/// ```
+///
+/// ### Implementation Pseudocode
+///
+/// _This is the logic of the routine being called._
+///
+/// ```ignore
+/// result = 0
+///
+/// for i in range(init_len):
+///     result += a[i]
+///
+/// return result
+/// ```
+///
+/// ### Panics
+///
+/// This function will panic if `init_len` is greater than the length of `a`.
+///
+/// # Safety
+///
+/// The first `init_len` elements of `a` must be initialized, reading any uninitialized
+/// element is immediate UB. Elements after `init_len` are never read and can be in any state.
+pub unsafe fn sum_init_prefix<T>(a: &[MaybeUninit<T>], init_len: usize) -> T
+where
+    T: AggOps,
+{
+    let a = assume_init_prefix(a, init_len);
+    T::sum(a)
+}
+
+#[inline]
+#[must_use]
 /// Finds the horizontal max element of a given vector and returns the result.
 ///
 /// ### Default Value Warning
@@ -438,6 +1142,7 @@ where
 }
 
 #[inline]
+#[must_use]
 /// Finds the horizontal min element of a given vector and returns the result.
 ///
 /// ### Default Value Warning
@@ -2285,3 +2990,307 @@ where
 {
     T::div_vertical(lhs, rhs, result)
 }
+
+#[inline(always)]
+/// Views the first `init_len` elements of `buffer` as initialized values.
+///
+/// # Safety
+///
+/// The first `init_len` elements of `buffer` must be initialized.
+unsafe fn assume_init_prefix<T>(buffer: &[MaybeUninit<T>], init_len: usize) -> &[T] {
+    let prefix = &buffer[..init_len];
+    // `MaybeUninit<T>` is guaranteed to have the same size, alignment and ABI as `T`.
+    slice::from_raw_parts(prefix.as_ptr().cast::<T>(), init_len)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(feature = "bytemuck")]
+    #[test]
+    fn test_transparent_newtype_reductions() {
+        #[derive(Copy, Clone)]
+        #[repr(transparent)]
+        struct Meters(f64);
+
+        unsafe impl bytemuck::TransparentWrapper<f64> for Meters {}
+
+        let (l1, l2) = crate::test_utils::get_sample_vectors::<f64>(533);
+        let m1 = l1.iter().copied().map(Meters).collect::<Vec<_>>();
+        let m2 = l2.iter().copied().map(Meters).collect::<Vec<_>>();
+
+        assert_eq!(sum_transparent::<f64, _>(&m1), sum(&l1));
+        assert_eq!(dot_transparent::<f64, _>(&m1, &m2), dot(&l1, &l2));
+    }
+
+    #[test]
+    fn test_sum_matches_scalar_on_host() {
+        let (l1, _) = crate::test_utils::get_sample_vectors::<f64>(1043);
+
+        // Lengths either side of the dense lane sizes, whatever backend the host selects.
+        for len in [0, 1, 31, 32, 33, 1043] {
+            let total = sum(&l1[..len]);
+            let expected = l1[..len].iter().sum::<f64>();
+            assert!(
+                (total - expected).abs() <= 1e-9,
+                "value missmatch on len {len} {total} vs {expected}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_dot_with_diagnostics_matches_backend() {
+        let (l1, l2) = crate::test_utils::get_sample_vectors::<f64>(533);
+
+        let result = dot_with_diagnostics(&l1, &l2);
+        assert_eq!(result.value, dot(&l1, &l2));
+
+        let (expected_backend, expected_fma) = expected_dot_backend();
+        assert!(
+            result.reduction_order.contains(expected_backend),
+            "reduction order {:?} does not describe {expected_backend}",
+            result.reduction_order,
+        );
+        assert_eq!(result.used_fma, expected_fma);
+
+        // Integer types never use a fused multiply add, and skip the AVX2 + FMA routine.
+        let (l1, l2) = crate::test_utils::get_sample_vectors::<i32>(533);
+        let result = dot_with_diagnostics(&l1, &l2);
+        assert_eq!(result.value, dot(&l1, &l2));
+        assert!(!result.used_fma);
+        assert!(!result.reduction_order.contains("FMA"));
+    }
+
+    /// Mirrors the priority order of the `dispatch!` macro for float types.
+    fn expected_dot_backend() -> (&'static str, bool) {
+        #[cfg(all(
+            any(target_arch = "x86", target_arch = "x86_64"),
+            feature = "nightly"
+        ))]
+        if crate::dispatch::is_avx512_available() {
+            return ("AVX512", true);
+        }
+
+        #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+        if crate::dispatch::is_avx2_available() && crate::dispatch::is_fma_available() {
+            return ("AVX2 + FMA", true);
+        }
+
+        #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+        if crate::dispatch::is_avx2_available() {
+            return ("AVX2", false);
+        }
+
+        #[cfg(target_arch = "aarch64")]
+        if crate::dispatch::is_neon_available() {
+            return ("NEON", true);
+        }
+
+        ("Fallback", false)
+    }
+
+    #[test]
+    fn test_matvec_dot_streaming_matches_batch() {
+        let dims = 67;
+        let (matrix, query) = crate::test_utils::get_sample_vectors::<f64>(dims * 13);
+        let query = &query[..dims];
+
+        let expected = matrix
+            .chunks(dims)
+            .map(|row| dot(row, query))
+            .collect::<Vec<_>>();
+
+        let mut streamed = Vec::new();
+        matvec_dot_streaming(matrix.chunks(dims), query, |idx, dot| {
+            streamed.push((idx, dot));
+        });
+
+        let (indices, results): (Vec<_>, Vec<_>) = streamed.into_iter().unzip();
+        assert_eq!(indices, (0..13).collect::<Vec<_>>());
+        assert_eq!(results, expected);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_matvec_dot_streaming_row_mismatch() {
+        let rows = [[1.0f32, 2.0].as_slice(), [1.0, 2.0, 3.0].as_slice()];
+        matvec_dot_streaming(rows, &[1.0, 1.0], |_, _| {});
+    }
+
+    #[test]
+    fn test_streaming_dot_both_matches_dot() {
+        let (a, b) = crate::test_utils::get_sample_vectors::<f64>(1043);
+        let expected = dot(&a, &b);
+
+        let chunkings: [(usize, usize); 5] =
+            [(1043, 1043), (1, 1043), (7, 13), (64, 3), (33, 32)];
+        for (a_size, b_size) in chunkings {
+            let value = streaming_dot_both(a.chunks(a_size), b.chunks(b_size));
+            assert!(
+                (value - expected).abs() <= 1e-9 * expected.abs(),
+                "value missmatch on chunks {a_size} {b_size}: {value} vs {expected}"
+            );
+        }
+
+        // Chunks of varying size, including empty chunks, realign the same way.
+        let mut a_chunks = Vec::new();
+        let mut rest = a.as_slice();
+        for size in [0, 5, 1, 0, 100, 17, 3].into_iter().cycle() {
+            if rest.is_empty() {
+                break;
+            }
+            let (chunk, remaining) = rest.split_at(size.min(rest.len()));
+            a_chunks.push(chunk);
+            rest = remaining;
+        }
+        let value = streaming_dot_both(a_chunks, b.chunks(29));
+        assert!(
+            (value - expected).abs() <= 1e-9 * expected.abs(),
+            "value missmatch on uneven chunks: {value} vs {expected}"
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_streaming_dot_both_length_mismatch() {
+        let a = [1.0f32; 10];
+        let b = [1.0f32; 11];
+        let _ = streaming_dot_both(a.chunks(3), b.chunks(4));
+    }
+
+    #[test]
+    fn test_sum_batch_matches_sum() {
+        let (values, _) = crate::test_utils::get_sample_vectors::<f64>(1043);
+        let vectors = [0, 1, 7, 16, 17, 64, 333, 1043]
+            .iter()
+            .map(|&len| &values[..len])
+            .collect::<Vec<_>>();
+
+        let mut totals = vec![f64::NAN; vectors.len()];
+        sum_batch(&vectors, &mut totals);
+
+        for (total, a) in totals.iter().zip(&vectors) {
+            assert_eq!(*total, sum(*a));
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_sum_batch_length_mismatch() {
+        let a = [1.0f64, 2.0];
+        let mut totals = [0.0; 2];
+        sum_batch(&[a.as_slice()], &mut totals);
+    }
+
+    #[test]
+    fn test_tree_sum_chunks_matches_sum() {
+        let (values, _) = crate::test_utils::get_sample_vectors::<u32>(1043);
+        let chunks = values.chunks(97).collect::<Vec<_>>();
+
+        assert_eq!(tree_sum_chunks(&chunks), sum(&values));
+        assert_eq!(tree_sum_chunks::<u32>(&[]), 0);
+        assert_eq!(tree_sum_chunks(&[values.as_slice()]), sum(&values));
+    }
+
+    #[test]
+    fn test_tree_sum_chunks_fixed_order() {
+        let c0 = [1e16, 0.0];
+        let c1 = [1.0];
+        let c2 = [0.5, 0.5];
+        let c3 = [1.0];
+        let c4 = [2.0];
+
+        // Split at `len / 2`: (c0 + c1) + (c2 + (c3 + c4))
+        let total = tree_sum_chunks(&[&c0, &c1, &c2, &c3, &c4]);
+        assert_eq!(total, (1e16 + 1.0) + (1.0 + (1.0 + 2.0)));
+        assert_eq!(total, 1e16 + 4.0);
+
+        // A left fold over the same chunk totals loses most of the small values.
+        let folded: f64 = (((1e16 + 1.0) + 1.0) + 1.0) + 2.0;
+        assert_ne!(total, folded);
+    }
+
+    #[test]
+    fn test_sum_init_prefix_small() {
+        let mut buffer = [MaybeUninit::<f64>::uninit(); 12];
+        for (i, slot) in buffer.iter_mut().take(7).enumerate() {
+            slot.write(i as f64);
+        }
+
+        let total = unsafe { sum_init_prefix(&buffer, 7) };
+        assert_eq!(total, 21.0);
+
+        let total = unsafe { sum_init_prefix(&buffer, 0) };
+        assert_eq!(total, 0.0);
+    }
+
+    #[test]
+    fn test_sum_init_prefix_partially_filled() {
+        let (values, _) = crate::test_utils::get_sample_vectors::<f64>(533);
+
+        let mut buffer = vec![MaybeUninit::<f64>::uninit(); 1043];
+        for (slot, value) in buffer.iter_mut().zip(values.iter()) {
+            slot.write(*value);
+        }
+
+        let total = unsafe { sum_init_prefix(&buffer, values.len()) };
+        assert_eq!(total, sum(&values));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_sum_init_prefix_out_of_bounds() {
+        let buffer = [MaybeUninit::<f64>::uninit(); 4];
+        let _ = unsafe { sum_init_prefix(&buffer, 5) };
+    }
+
+    #[test]
+    fn test_dot_init_prefix_partially_filled() {
+        let (l1, l2) = crate::test_utils::get_sample_vectors::<f64>(533);
+
+        let mut a = vec![MaybeUninit::<f64>::uninit(); 1043];
+        let mut b = vec![MaybeUninit::<f64>::uninit(); 600];
+        for (slot, value) in a.iter_mut().zip(l1.iter()) {
+            slot.write(*value);
+        }
+        for (slot, value) in b.iter_mut().zip(l2.iter()) {
+            slot.write(*value);
+        }
+
+        let total = unsafe { dot_init_prefix(&a, &b, l1.len()) };
+        assert_eq!(total, dot(&l1, &l2));
+    }
+
+    macro_rules! define_fixed_dims_dot_test {
+        ($name:ident, $dims:literal, types = $($t:ident $(,)?)+) => {
+            $(
+                paste::paste! {
+                    #[test]
+                    fn [< test_ $name _ $t >]() {
+                        let (l1, l2) = crate::test_utils::get_sample_vectors::<$t>($dims);
+                        let a: &[$t; $dims] = l1.as_slice().try_into().unwrap();
+                        let b: &[$t; $dims] = l2.as_slice().try_into().unwrap();
+
+                        let expected = l1
+                            .iter()
+                            .zip(l2.iter())
+                            .fold(0.0, |acc, (x, y)| acc + f64::from(*x) * f64::from(*y));
+
+                        let value = f64::from($name(a, b));
+                        assert!(
+                            (value - expected).abs() <= expected.abs() * 1e-5,
+                            "value missmatch {value} vs {expected}"
+                        );
+                    }
+                }
+            )*
+        };
+    }
+
+    define_fixed_dims_dot_test!(dot_384, 384, types = f32, f64);
+    define_fixed_dims_dot_test!(dot_512, 512, types = f32, f64);
+    define_fixed_dims_dot_test!(dot_768, 768, types = f32, f64);
+    define_fixed_dims_dot_test!(dot_1024, 1024, types = f32, f64);
+    define_fixed_dims_dot_test!(dot_1536, 1536, types = f32, f64);
+}
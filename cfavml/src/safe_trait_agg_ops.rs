@@ -3,8 +3,8 @@
 //! In general, I would recommend using the higher level generic functions api which provides
 //! some syntax sugar over these traits.
 
-use crate::danger::export_agg_ops;
-use crate::mem_loader::{IntoMemLoader, MemLoader};
+use crate::danger::{export_agg_ops, ReductionStatus};
+use crate::mem_loader::{IntoMemLoader, MemLoader, MemoryHint};
 
 /// Various aggregation operations on a single vector.
 pub trait AggOps: Sized + Copy {
@@ -20,14 +20,67 @@ pub trait AggOps: Sized + Copy {
     ///
     /// return result
     /// ```
+    #[must_use]
     fn sum<B1>(a: B1) -> Self
     where
         B1: IntoMemLoader<Self>,
         B1::Loader: MemLoader<Value = Self>;
+
+    /// Performs a horizontal sum of each vector in `vectors`, writing the total
+    /// of `vectors[i]` to `out[i]`.
+    ///
+    /// The SIMD backend is selected once for the whole batch rather than once per vector.
+    ///
+    /// ### Implementation Pseudocode
+    ///
+    /// ```ignore
+    /// for i in range(len(vectors)):
+    ///     out[i] = sum(vectors[i])
+    /// ```
+    ///
+    /// ### Panics
+    ///
+    /// If `out` is not the same length as `vectors`.
+    fn sum_batch(vectors: &[&[Self]], out: &mut [Self]);
+
+    /// Performs a horizontal sum of all elements in `a` returning the result, using an
+    /// inner loop tuned for the memory described by `hint`.
+    ///
+    /// ### Implementation Pseudocode
+    ///
+    /// ```ignore
+    /// result = 0
+    ///
+    /// for i in range(dims):
+    ///     result += a[i]
+    ///
+    /// return result
+    /// ```
+    #[must_use]
+    fn sum_hint(a: &[Self], hint: MemoryHint) -> Self;
+
+    /// Performs a horizontal sum of all elements in `a` returning the result along with
+    /// a [ReductionStatus] reporting if the result is finite and if `a` contained `NaN`.
+    ///
+    /// ### Implementation Pseudocode
+    ///
+    /// ```ignore
+    /// result = 0
+    ///
+    /// for i in range(dims):
+    ///     result += a[i]
+    ///
+    /// is_finite = result - result == 0
+    /// saw_nan = result != result and any(a[i] != a[i] for i in range(dims))
+    ///
+    /// return result, ReductionStatus(is_finite, saw_nan)
+    /// ```
+    #[must_use]
+    fn sum_with_status(a: &[Self]) -> (Self, ReductionStatus);
 }
 
 macro_rules! agg_ops {
-    ($t:ty) => {
+    ($t:ty $(, sse2_sum = $sse2_sum:path)?) => {
         impl AggOps for $t {
             fn sum<B1>(a: B1) -> Self
             where
@@ -38,18 +91,55 @@ macro_rules! agg_ops {
                     crate::dispatch!(
                         avx512 = export_agg_ops::generic_avx512_sum,
                         avx2 = export_agg_ops::generic_avx2_sum,
+                        $(sse2 = $sse2_sum,)?
                         neon = export_agg_ops::generic_neon_sum,
                         fallback = export_agg_ops::generic_fallback_sum,
                         args = (a)
                     )
                 }
             }
+
+            fn sum_batch(vectors: &[&[Self]], out: &mut [Self]) {
+                unsafe {
+                    crate::dispatch!(
+                        avx512 = export_agg_ops::generic_avx512_sum_batch,
+                        avx2 = export_agg_ops::generic_avx2_sum_batch,
+                        neon = export_agg_ops::generic_neon_sum_batch,
+                        fallback = export_agg_ops::generic_fallback_sum_batch,
+                        args = (vectors, out)
+                    )
+                }
+            }
+
+            fn sum_hint(a: &[Self], hint: MemoryHint) -> Self {
+                unsafe {
+                    crate::dispatch!(
+                        avx512 = export_agg_ops::generic_avx512_sum_hint,
+                        avx2 = export_agg_ops::generic_avx2_sum_hint,
+                        neon = export_agg_ops::generic_neon_sum_hint,
+                        fallback = export_agg_ops::generic_fallback_sum_hint,
+                        args = (a, hint)
+                    )
+                }
+            }
+
+            fn sum_with_status(a: &[Self]) -> (Self, ReductionStatus) {
+                unsafe {
+                    crate::dispatch!(
+                        avx512 = export_agg_ops::generic_avx512_sum_with_status,
+                        avx2 = export_agg_ops::generic_avx2_sum_with_status,
+                        neon = export_agg_ops::generic_neon_sum_with_status,
+                        fallback = export_agg_ops::generic_fallback_sum_with_status,
+                        args = (a)
+                    )
+                }
+            }
         }
     };
 }
 
-agg_ops!(f32);
-agg_ops!(f64);
+agg_ops!(f32, sse2_sum = export_agg_ops::generic_sse2_sum);
+agg_ops!(f64, sse2_sum = export_agg_ops::generic_sse2_sum);
 agg_ops!(i8);
 agg_ops!(i16);
 agg_ops!(i32);
@@ -25,6 +25,7 @@ pub trait CmpOps: Sized + Copy {
     /// ### Panics
     ///
     /// Panics if the size of vector `a` does not match `dims`.
+    #[must_use]
     fn max<B1>(a: B1) -> Self
     where
         B1: IntoMemLoader<Self>,
@@ -94,6 +95,7 @@ pub trait CmpOps: Sized + Copy {
     ///
     /// return result
     /// ```
+    #[must_use]
     fn min<B1>(a: B1) -> Self
     where
         B1: IntoMemLoader<Self>,
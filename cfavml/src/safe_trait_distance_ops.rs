@@ -3,7 +3,8 @@
 //! In general, I would recommend using the higher level generic functions api which provides
 //! some syntax sugar over these traits.
 
-use crate::danger::export_distance_ops;
+use crate::danger::export_distance_ops::DotKernel;
+use crate::danger::{export_distance_ops, DotResult};
 use crate::mem_loader::{IntoMemLoader, MemLoader};
 
 /// Various spacial distance operations between vectors.
@@ -33,6 +34,7 @@ pub trait DistanceOps: Sized + Copy {
     /// # Panics
     ///
     /// If vectors `a` and `b` are not equal in the length.
+    #[must_use]
     fn cosine<B1, B2>(a: B1, b: B2) -> Self
     where
         B1: IntoMemLoader<Self>,
@@ -56,6 +58,7 @@ pub trait DistanceOps: Sized + Copy {
     /// # Panics
     ///
     /// If vectors `a` and `b` are not equal in the length.
+    #[must_use]
     fn dot<B1, B2>(a: B1, b: B2) -> Self
     where
         B1: IntoMemLoader<Self>,
@@ -63,6 +66,177 @@ pub trait DistanceOps: Sized + Copy {
         B2: IntoMemLoader<Self>,
         B2::Loader: MemLoader<Value = Self>;
 
+    /// Calculates the dot product between vectors `a` and `b`, calling `progress` with
+    /// the number of blocks processed and the partial dot product every `every` blocks.
+    ///
+    /// A block is one dense lane of the selected SIMD implementation, the size of which
+    /// depends on the CPU features available.
+    ///
+    /// ### Implementation Pseudocode
+    ///
+    /// ```ignore
+    /// result = 0;
+    ///
+    /// for block in range(num_blocks):
+    ///     for i in block:
+    ///         result += a[i] * b[i]
+    ///
+    ///     if (block + 1) % every == 0:
+    ///         progress(block + 1, result)
+    ///
+    /// return result
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// If `every` is `0` or vectors `a` and `b` are not equal in the length.
+    #[must_use]
+    fn dot_progress<B1, B2, F>(a: B1, b: B2, every: usize, progress: F) -> Self
+    where
+        B1: IntoMemLoader<Self>,
+        B1::Loader: MemLoader<Value = Self>,
+        B2: IntoMemLoader<Self>,
+        B2::Loader: MemLoader<Value = Self>,
+        F: FnMut(usize, Self);
+
+    /// Calculates the dot product between vectors `a` and `b` where each vector is
+    /// yielded as a sequence of chunks, the chunk boundaries of `a` and `b` do not need
+    /// to line up.
+    ///
+    /// ### Implementation Pseudocode
+    ///
+    /// ```ignore
+    /// a = concat(a_chunks)
+    /// b = concat(b_chunks)
+    ///
+    /// result = 0;
+    ///
+    /// for i in range(dims):
+    ///     result += a[i] * b[i]
+    ///
+    /// return result
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// If the chunks of `a` and `b` are not equal in total length.
+    #[must_use]
+    fn dot_streaming_both<'a, I1, I2>(a_chunks: I1, b_chunks: I2) -> Self
+    where
+        Self: 'a,
+        I1: Iterator<Item = &'a [Self]>,
+        I2: Iterator<Item = &'a [Self]>;
+
+    /// Calculates the dot product between vectors `a` and `b`, returning zero early if
+    /// either vector is entirely zero.
+    ///
+    /// This only helps when all-zero vectors are common, otherwise the extra scan over
+    /// each vector is wasted work and [DistanceOps::dot] should be preferred.
+    ///
+    /// ### Implementation Pseudocode
+    ///
+    /// ```ignore
+    /// if all(a[i] == 0 for i in range(dims)) or all(b[i] == 0 for i in range(dims)):
+    ///     return 0
+    ///
+    /// result = 0;
+    ///
+    /// for i in range(dims):
+    ///     result += a[i] * b[i]
+    ///
+    /// return result
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// If vectors `a` and `b` are not equal in the length.
+    #[must_use]
+    fn dot_skip_zero<B1, B2>(a: B1, b: B2) -> Self
+    where
+        B1: IntoMemLoader<Self> + Copy,
+        B1::Loader: MemLoader<Value = Self>,
+        B2: IntoMemLoader<Self> + Copy,
+        B2::Loader: MemLoader<Value = Self>;
+
+    /// Calculates the dot product between vectors `a` and `b`, returning the value along
+    /// with metadata describing how it was computed.
+    ///
+    /// This is a debugging aid for understanding why the result differs slightly from
+    /// other libraries like NumPy, see [DotResult].
+    ///
+    /// ### Implementation Pseudocode
+    ///
+    /// ```ignore
+    /// result = 0;
+    ///
+    /// for i in range(dims):
+    ///     result += a[i] * b[i]
+    ///
+    /// return result
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// If vectors `a` and `b` are not equal in the length.
+    #[must_use]
+    fn dot_with_diagnostics<B1, B2>(a: B1, b: B2) -> DotResult<Self>
+    where
+        B1: IntoMemLoader<Self>,
+        B1::Loader: MemLoader<Value = Self>,
+        B2: IntoMemLoader<Self>,
+        B2::Loader: MemLoader<Value = Self>;
+
+    /// Selects the dot product kernel for vectors of `dims` elements on the current CPU.
+    ///
+    /// The returned kernel can be cached and reused for every dot product at that
+    /// dimension, skipping the CPU feature dispatch, see [crate::dispatch::CachedDot].
+    /// When `dims` is a multiple of the dense lane size of the selected backend, a kernel
+    /// without any remainder handling is returned.
+    ///
+    /// # Safety
+    ///
+    /// The returned kernel must only be called with vectors of `dims` elements.
+    #[must_use]
+    fn dot_kernel(dims: usize) -> DotKernel<Self>;
+
+    /// Calculates the complex dot product `sum(conj(a) * b)` of planar complex vectors
+    /// `a` and `b`, where the real and imaginary parts are stored in separate buffers.
+    ///
+    /// Returns the `(real, imaginary)` parts of the result.
+    ///
+    /// ### Implementation Pseudocode
+    ///
+    /// ```ignore
+    /// real = 0;
+    /// imag = 0;
+    ///
+    /// for i in range(dims):
+    ///     real += a_re[i] * b_re[i] + a_im[i] * b_im[i]
+    ///     imag += a_re[i] * b_im[i] - a_im[i] * b_re[i]
+    ///
+    /// return real, imag
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// If vectors `a_re`, `a_im`, `b_re` and `b_im` are not all equal in length.
+    #[must_use]
+    fn complex_dot_planar<B1, B2, B3, B4>(
+        a_re: B1,
+        a_im: B2,
+        b_re: B3,
+        b_im: B4,
+    ) -> (Self, Self)
+    where
+        B1: IntoMemLoader<Self>,
+        B1::Loader: MemLoader<Value = Self>,
+        B2: IntoMemLoader<Self>,
+        B2::Loader: MemLoader<Value = Self>,
+        B3: IntoMemLoader<Self>,
+        B3::Loader: MemLoader<Value = Self>,
+        B4: IntoMemLoader<Self>,
+        B4::Loader: MemLoader<Value = Self>;
+
     /// Calculates the squared Euclidean distance between vectors `a` and `b`.
     ///
     /// ### Implementation Pseudocode
@@ -80,6 +254,7 @@ pub trait DistanceOps: Sized + Copy {
     /// # Panics
     ///
     /// If vectors `a` and `b` are not equal in the length.
+    #[must_use]
     fn squared_euclidean<B1, B2>(a: B1, b: B2) -> Self
     where
         B1: IntoMemLoader<Self>,
@@ -99,6 +274,7 @@ pub trait DistanceOps: Sized + Copy {
     ///
     /// return result
     /// ```
+    #[must_use]
     fn squared_norm<B1>(a: B1) -> Self
     where
         B1: IntoMemLoader<Self>,
@@ -146,6 +322,128 @@ macro_rules! float_distance_ops {
                 }
             }
 
+            fn dot_progress<B1, B2, F>(a: B1, b: B2, every: usize, progress: F) -> Self
+            where
+                B1: IntoMemLoader<Self>,
+                B1::Loader: MemLoader<Value = Self>,
+                B2: IntoMemLoader<Self>,
+                B2::Loader: MemLoader<Value = Self>,
+                F: FnMut(usize, Self),
+            {
+                unsafe {
+                    crate::dispatch!(
+                        avx512 = export_distance_ops::generic_avx512_dot_progress,
+                        avx2fma = export_distance_ops::generic_avx2fma_dot_progress,
+                        avx2 = export_distance_ops::generic_avx2_dot_progress,
+                        neon = export_distance_ops::generic_neon_dot_progress,
+                        fallback = export_distance_ops::generic_fallback_dot_progress,
+                        args = (a, b, every, progress)
+                    )
+                }
+            }
+
+            fn dot_streaming_both<'a, I1, I2>(a_chunks: I1, b_chunks: I2) -> Self
+            where
+                Self: 'a,
+                I1: Iterator<Item = &'a [Self]>,
+                I2: Iterator<Item = &'a [Self]>,
+            {
+                unsafe {
+                    crate::dispatch!(
+                        avx512 = export_distance_ops::generic_avx512_dot_streaming_both,
+                        avx2fma =
+                            export_distance_ops::generic_avx2fma_dot_streaming_both,
+                        avx2 = export_distance_ops::generic_avx2_dot_streaming_both,
+                        neon = export_distance_ops::generic_neon_dot_streaming_both,
+                        fallback =
+                            export_distance_ops::generic_fallback_dot_streaming_both,
+                        args = (a_chunks, b_chunks)
+                    )
+                }
+            }
+
+            fn dot_skip_zero<B1, B2>(a: B1, b: B2) -> Self
+            where
+                B1: IntoMemLoader<Self> + Copy,
+                B1::Loader: MemLoader<Value = Self>,
+                B2: IntoMemLoader<Self> + Copy,
+                B2::Loader: MemLoader<Value = Self>,
+            {
+                unsafe {
+                    crate::dispatch!(
+                        avx512 = export_distance_ops::generic_avx512_dot_skip_zero,
+                        avx2fma = export_distance_ops::generic_avx2fma_dot_skip_zero,
+                        avx2 = export_distance_ops::generic_avx2_dot_skip_zero,
+                        neon = export_distance_ops::generic_neon_dot_skip_zero,
+                        fallback = export_distance_ops::generic_fallback_dot_skip_zero,
+                        args = (a, b)
+                    )
+                }
+            }
+
+            fn dot_with_diagnostics<B1, B2>(a: B1, b: B2) -> DotResult<Self>
+            where
+                B1: IntoMemLoader<Self>,
+                B1::Loader: MemLoader<Value = Self>,
+                B2: IntoMemLoader<Self>,
+                B2::Loader: MemLoader<Value = Self>,
+            {
+                unsafe {
+                    crate::dispatch!(
+                        avx512 =
+                            export_distance_ops::generic_avx512_dot_with_diagnostics,
+                        avx2fma =
+                            export_distance_ops::generic_avx2fma_dot_with_diagnostics,
+                        avx2 = export_distance_ops::generic_avx2_dot_with_diagnostics,
+                        neon = export_distance_ops::generic_neon_dot_with_diagnostics,
+                        fallback =
+                            export_distance_ops::generic_fallback_dot_with_diagnostics,
+                        args = (a, b)
+                    )
+                }
+            }
+
+            fn dot_kernel(dims: usize) -> DotKernel<Self> {
+                crate::dispatch!(
+                    avx512 = export_distance_ops::generic_avx512_dot_kernel,
+                    avx2fma = export_distance_ops::generic_avx2fma_dot_kernel,
+                    avx2 = export_distance_ops::generic_avx2_dot_kernel,
+                    neon = export_distance_ops::generic_neon_dot_kernel,
+                    fallback = export_distance_ops::generic_fallback_dot_kernel,
+                    args = (dims)
+                )
+            }
+
+            fn complex_dot_planar<B1, B2, B3, B4>(
+                a_re: B1,
+                a_im: B2,
+                b_re: B3,
+                b_im: B4,
+            ) -> (Self, Self)
+            where
+                B1: IntoMemLoader<Self>,
+                B1::Loader: MemLoader<Value = Self>,
+                B2: IntoMemLoader<Self>,
+                B2::Loader: MemLoader<Value = Self>,
+                B3: IntoMemLoader<Self>,
+                B3::Loader: MemLoader<Value = Self>,
+                B4: IntoMemLoader<Self>,
+                B4::Loader: MemLoader<Value = Self>,
+            {
+                unsafe {
+                    crate::dispatch!(
+                        avx512 = export_distance_ops::generic_avx512_complex_dot_planar,
+                        avx2fma =
+                            export_distance_ops::generic_avx2fma_complex_dot_planar,
+                        avx2 = export_distance_ops::generic_avx2_complex_dot_planar,
+                        neon = export_distance_ops::generic_neon_complex_dot_planar,
+                        fallback =
+                            export_distance_ops::generic_fallback_complex_dot_planar,
+                        args = (a_re, a_im, b_re, b_im)
+                    )
+                }
+            }
+
             fn squared_euclidean<B1, B2>(a: B1, b: B2) -> Self
             where
                 B1: IntoMemLoader<Self>,
@@ -225,6 +523,119 @@ macro_rules! scalar_distance_ops {
                 }
             }
 
+            fn dot_progress<B1, B2, F>(a: B1, b: B2, every: usize, progress: F) -> Self
+            where
+                B1: IntoMemLoader<Self>,
+                B1::Loader: MemLoader<Value = Self>,
+                B2: IntoMemLoader<Self>,
+                B2::Loader: MemLoader<Value = Self>,
+                F: FnMut(usize, Self),
+            {
+                unsafe {
+                    crate::dispatch!(
+                        avx512 = export_distance_ops::generic_avx512_dot_progress,
+                        avx2 = export_distance_ops::generic_avx2_dot_progress,
+                        neon = export_distance_ops::generic_neon_dot_progress,
+                        fallback = export_distance_ops::generic_fallback_dot_progress,
+                        args = (a, b, every, progress)
+                    )
+                }
+            }
+
+            fn dot_streaming_both<'a, I1, I2>(a_chunks: I1, b_chunks: I2) -> Self
+            where
+                Self: 'a,
+                I1: Iterator<Item = &'a [Self]>,
+                I2: Iterator<Item = &'a [Self]>,
+            {
+                unsafe {
+                    crate::dispatch!(
+                        avx512 = export_distance_ops::generic_avx512_dot_streaming_both,
+                        avx2 = export_distance_ops::generic_avx2_dot_streaming_both,
+                        neon = export_distance_ops::generic_neon_dot_streaming_both,
+                        fallback =
+                            export_distance_ops::generic_fallback_dot_streaming_both,
+                        args = (a_chunks, b_chunks)
+                    )
+                }
+            }
+
+            fn dot_skip_zero<B1, B2>(a: B1, b: B2) -> Self
+            where
+                B1: IntoMemLoader<Self> + Copy,
+                B1::Loader: MemLoader<Value = Self>,
+                B2: IntoMemLoader<Self> + Copy,
+                B2::Loader: MemLoader<Value = Self>,
+            {
+                unsafe {
+                    crate::dispatch!(
+                        avx512 = export_distance_ops::generic_avx512_dot_skip_zero,
+                        avx2 = export_distance_ops::generic_avx2_dot_skip_zero,
+                        neon = export_distance_ops::generic_neon_dot_skip_zero,
+                        fallback = export_distance_ops::generic_fallback_dot_skip_zero,
+                        args = (a, b)
+                    )
+                }
+            }
+
+            fn dot_with_diagnostics<B1, B2>(a: B1, b: B2) -> DotResult<Self>
+            where
+                B1: IntoMemLoader<Self>,
+                B1::Loader: MemLoader<Value = Self>,
+                B2: IntoMemLoader<Self>,
+                B2::Loader: MemLoader<Value = Self>,
+            {
+                unsafe {
+                    crate::dispatch!(
+                        avx512 =
+                            export_distance_ops::generic_avx512_dot_with_diagnostics,
+                        avx2 = export_distance_ops::generic_avx2_dot_with_diagnostics,
+                        neon = export_distance_ops::generic_neon_dot_with_diagnostics,
+                        fallback =
+                            export_distance_ops::generic_fallback_dot_with_diagnostics,
+                        args = (a, b)
+                    )
+                }
+            }
+
+            fn dot_kernel(dims: usize) -> DotKernel<Self> {
+                crate::dispatch!(
+                    avx512 = export_distance_ops::generic_avx512_dot_kernel,
+                    avx2 = export_distance_ops::generic_avx2_dot_kernel,
+                    neon = export_distance_ops::generic_neon_dot_kernel,
+                    fallback = export_distance_ops::generic_fallback_dot_kernel,
+                    args = (dims)
+                )
+            }
+
+            fn complex_dot_planar<B1, B2, B3, B4>(
+                a_re: B1,
+                a_im: B2,
+                b_re: B3,
+                b_im: B4,
+            ) -> (Self, Self)
+            where
+                B1: IntoMemLoader<Self>,
+                B1::Loader: MemLoader<Value = Self>,
+                B2: IntoMemLoader<Self>,
+                B2::Loader: MemLoader<Value = Self>,
+                B3: IntoMemLoader<Self>,
+                B3::Loader: MemLoader<Value = Self>,
+                B4: IntoMemLoader<Self>,
+                B4::Loader: MemLoader<Value = Self>,
+            {
+                unsafe {
+                    crate::dispatch!(
+                        avx512 = export_distance_ops::generic_avx512_complex_dot_planar,
+                        avx2 = export_distance_ops::generic_avx2_complex_dot_planar,
+                        neon = export_distance_ops::generic_neon_complex_dot_planar,
+                        fallback =
+                            export_distance_ops::generic_fallback_complex_dot_planar,
+                        args = (a_re, a_im, b_re, b_im)
+                    )
+                }
+            }
+
             fn squared_euclidean<B1, B2>(a: B1, b: B2) -> Self
             where
                 B1: IntoMemLoader<Self>,